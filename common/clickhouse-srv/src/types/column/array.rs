@@ -24,6 +24,8 @@ use crate::types::column::column_data::BoxColumnData;
 use crate::types::column::list::List;
 use crate::types::column::ArcColumnWrapper;
 use crate::types::column::ColumnData;
+use crate::types::column::ColumnFrom;
+use crate::types::column::ColumnWrapper;
 use crate::types::SqlType;
 use crate::types::Value;
 use crate::types::ValueRef;
@@ -55,6 +57,17 @@ impl ArrayColumnData {
     }
 }
 
+/// Builds the array column from the flattened elements and the end offsets of the rows,
+/// the maps are sent in this way as the arrays of the key-value tuples.
+impl ColumnFrom for (ArcColumnData, Vec<u64>) {
+    fn column_from<W: ColumnWrapper>(source: Self) -> W::Wrapper {
+        let (inner, ends) = source;
+        let mut offsets = List::with_capacity(ends.len());
+        ends.into_iter().for_each(|end| offsets.push(end));
+        W::wrap(ArrayColumnData { inner, offsets })
+    }
+}
+
 impl ColumnData for ArrayColumnData {
     fn sql_type(&self) -> SqlType {
         let inner_type = self.inner.sql_type();
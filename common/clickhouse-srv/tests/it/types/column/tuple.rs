@@ -45,3 +45,22 @@ fn test_tuple_type() {
     assert_eq!(3, column.len());
     assert_eq!(format!("{}", column.at(2)), "(2018-12-24, 3, bend)");
 }
+
+#[test]
+fn test_array_of_tuple_type() {
+    let inner = vec![
+        Vec::column_from::<ArcColumnWrapper>(vec!["a", "b", "c"]),
+        Vec::column_from::<ArcColumnWrapper>(vec![1_i32, 2, 3]),
+    ];
+    let inner = Vec::column_from::<ArcColumnWrapper>(inner);
+
+    let column =
+        <(ArcColumnData, Vec<u64>)>::column_from::<ArcColumnWrapper>((inner, vec![2, 2, 3]));
+    assert_eq!(
+        SqlType::Array(&SqlType::Tuple(vec![&SqlType::String, &SqlType::Int32])),
+        column.sql_type()
+    );
+    assert_eq!(3, column.len());
+    assert_eq!(format!("{}", column.at(0)), "[(a, 1), (b, 2)]");
+    assert_eq!(format!("{}", column.at(1)), "[]");
+}
@@ -24,6 +24,7 @@ use rusoto_core::ByteStream;
 use rusoto_core::Client;
 use rusoto_core::HttpClient;
 use rusoto_core::Region;
+use rusoto_s3::DeleteObjectRequest;
 use rusoto_s3::PutObjectRequest;
 use rusoto_s3::S3Client;
 use rusoto_s3::S3 as RusotoS3;
@@ -174,4 +175,17 @@ impl DataAccessor for S3 {
         self.put_byte_stream(path, ByteStream::new_with_size(input_stream, stream_len))
             .await
     }
+
+    async fn remove(&self, path: &str) -> common_exception::Result<()> {
+        let req = DeleteObjectRequest {
+            key: path.to_string(),
+            bucket: self.bucket.to_string(),
+            ..Default::default()
+        };
+        self.client
+            .delete_object(req)
+            .await
+            .map_err(|e| ErrorCode::DALTransportError(e.to_string()))?;
+        Ok(())
+    }
 }
@@ -160,4 +160,19 @@ impl DataAccessor for AzureBlobAccessor {
         }
         self.put_blob(path, data).await
     }
+
+    async fn remove(&self, path: &str) -> common_exception::Result<()> {
+        let blob = self
+            .client
+            .as_container_client(&self.container)
+            .as_blob_client(path);
+
+        match blob.delete().execute().await {
+            Err(e) => Err(ErrorCode::DALTransportError(format!(
+                "Failed on azure blob delete operation, {}",
+                e
+            ))),
+            Ok(_) => Ok(()),
+        }
+    }
 }
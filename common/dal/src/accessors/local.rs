@@ -108,6 +108,14 @@ impl DataAccessor for Local {
         new_file.flush().await?;
         Ok(())
     }
+
+    async fn remove(&self, path: &str) -> common_exception::Result<()> {
+        let path = self.prefix_with_root(path)?;
+        match tokio::fs::remove_file(path).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
 }
 
 // from cargo::util::path
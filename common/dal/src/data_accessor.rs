@@ -51,6 +51,9 @@ pub trait DataAccessor: Send + Sync {
         stream_len: usize,
     ) -> Result<()>;
 
+    /// Remove the object at `path`, it is not an error if the object does not exist.
+    async fn remove(&self, path: &str) -> Result<()>;
+
     async fn read(&self, location: &str) -> Result<Vec<u8>> {
         let mut input_stream = self.get_input_stream(location, None)?;
         let mut buffer = vec![];
@@ -42,3 +42,18 @@ async fn test_da_local_normal() -> common_exception::Result<()> {
     let read_fut = local_read(1000);
     read_fut.await
 }
+
+#[tokio::test]
+async fn test_da_local_remove() -> common_exception::Result<()> {
+    let tmp_root_dir = TempDir::new().unwrap();
+    let root_path = tmp_root_dir.path().to_str().unwrap();
+    let local_da = Local::new(root_path);
+
+    local_da.put("test_remove", vec![1, 2, 3]).await?;
+    local_da.remove("test_remove").await?;
+    assert!(local_da.read("test_remove").await.is_err());
+
+    // removing an absent file is ok
+    local_da.remove("test_remove").await?;
+    Ok(())
+}
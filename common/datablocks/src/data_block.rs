@@ -25,6 +25,7 @@ use common_datavalues::series::Series;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -206,7 +207,18 @@ impl TryFrom<DataBlock> for RecordBatch {
         let arrays = v
             .columns()
             .iter()
-            .map(|c| c.to_array().map(|series| series.get_array_ref()))
+            .zip(v.schema.fields().iter())
+            .map(|(c, f)| {
+                let series = c.to_array()?;
+                match f.data_type() {
+                    // the decimal arrays are tagged with the precision and the scale of the field
+                    DataType::Decimal(_, _) => {
+                        let array = series.decimal()?.inner().clone();
+                        Ok(Arc::new(array.to(f.data_type().to_arrow())) as ArrayRef)
+                    }
+                    _ => Ok(series.get_array_ref()),
+                }
+            })
             .collect::<Result<Vec<_>>>()?;
 
         Ok(RecordBatch::try_new(Arc::new(v.schema.to_arrow()), arrays)?)
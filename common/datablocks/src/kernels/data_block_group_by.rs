@@ -19,6 +19,7 @@ use crate::kernels::HashMethodKeysU32;
 use crate::kernels::HashMethodKeysU64;
 use crate::kernels::HashMethodKeysU8;
 use crate::kernels::HashMethodKind;
+use crate::kernels::HashMethodLowCardinality;
 use crate::kernels::HashMethodSerializer;
 use crate::DataBlock;
 use crate::HashMethod;
@@ -28,6 +29,15 @@ impl DataBlock {
         block: &DataBlock,
         column_names: &[String],
     ) -> Result<HashMethodKind> {
+        if column_names.len() == 1 {
+            let column = block.try_column_by_name(&column_names[0])?;
+            if column.data_type().is_low_cardinality() {
+                return Ok(HashMethodKind::LowCardinality(
+                    HashMethodLowCardinality::default(),
+                ));
+            }
+        }
+
         let mut group_key_len = 0;
         for col in column_names {
             let column = block.try_column_by_name(col)?;
@@ -90,6 +100,14 @@ impl DataBlock {
                     .collect();
                 blocks
             }
+            HashMethodKind::LowCardinality(s) => {
+                let blocks = s
+                    .group_by(block, column_names)?
+                    .iter()
+                    .map(|(_, _, b)| b.clone())
+                    .collect();
+                blocks
+            }
         })
     }
 }
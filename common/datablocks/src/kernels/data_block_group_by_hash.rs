@@ -16,10 +16,13 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
+use common_arrow::arrow::array::LargeBinaryArray;
 use common_datavalues::prelude::*;
 use common_datavalues::DataValue;
 use common_exception::Result;
+use common_infallible::RwLock;
 
 use crate::DataBlock;
 
@@ -127,6 +130,7 @@ pub enum HashMethodKind {
     KeysU16(HashMethodKeysU16),
     KeysU32(HashMethodKeysU32),
     KeysU64(HashMethodKeysU64),
+    LowCardinality(HashMethodLowCardinality),
 }
 
 impl HashMethodKind {
@@ -137,6 +141,7 @@ impl HashMethodKind {
             HashMethodKind::KeysU16(v) => v.name(),
             HashMethodKind::KeysU32(v) => v.name(),
             HashMethodKind::KeysU64(v) => v.name(),
+            HashMethodKind::LowCardinality(v) => v.name(),
         }
     }
     pub fn data_type(&self) -> DataType {
@@ -146,6 +151,9 @@ impl HashMethodKind {
             HashMethodKind::KeysU16(_) => DataType::UInt16,
             HashMethodKind::KeysU32(_) => DataType::UInt32,
             HashMethodKind::KeysU64(_) => DataType::UInt64,
+            HashMethodKind::LowCardinality(_) => {
+                DataType::LowCardinality(Box::new(DataType::String))
+            }
         }
     }
 }
@@ -213,6 +221,111 @@ impl HashMethod for HashMethodSerializer {
     }
 }
 
+/// The dictionary shared by all the blocks grouped by the same LowCardinality method,
+/// the codes of each block dictionary are remapped to the codes of it.
+#[derive(Debug, Default)]
+pub struct GlobalDictionary {
+    codes: HashMap<Vec<u8>, u32, ahash::RandomState>,
+    values: Vec<Vec<u8>>,
+}
+
+impl GlobalDictionary {
+    /// The key of the null rows, it never collides with the codes of the values.
+    pub const NULL_KEY: u32 = u32::MAX;
+
+    #[inline]
+    pub fn get_or_insert(&mut self, value: &[u8]) -> u32 {
+        if let Some(code) = self.codes.get(value) {
+            return *code;
+        }
+
+        let code = self.values.len() as u32;
+        self.codes.insert(value.to_vec(), code);
+        self.values.push(value.to_vec());
+        code
+    }
+
+    /// Build the LowCardinality array of the codes from the values of the dictionary.
+    pub fn to_array(&self, keys: &[u32]) -> DFDictionaryArray {
+        let keys: DFUInt32Array = keys
+            .iter()
+            .map(|k| match *k {
+                Self::NULL_KEY => None,
+                k => Some(k),
+            })
+            .collect();
+        let values = DFStringArray::new_from_slice(&self.values);
+        DFDictionaryArray::from_data(keys, values)
+    }
+}
+
+/// Group by the single LowCardinality column on its codes, the strings are never materialized.
+#[derive(Debug, Clone, Default)]
+pub struct HashMethodLowCardinality {
+    pub dictionary: Arc<RwLock<GlobalDictionary>>,
+}
+
+impl HashMethodLowCardinality {
+    #[inline]
+    pub fn get_key(&self, array: &DFDictionaryArray, row: usize) -> u32 {
+        let array = array.inner();
+        let keys = array.keys();
+        if keys.is_null(row) {
+            return GlobalDictionary::NULL_KEY;
+        }
+
+        let values = array
+            .values()
+            .as_any()
+            .downcast_ref::<LargeBinaryArray>()
+            .unwrap();
+        let value = values.value(keys.value(row) as usize);
+        self.dictionary.write().get_or_insert(value)
+    }
+
+    pub fn de_group_columns(
+        &self,
+        keys: Vec<u32>,
+        _group_fields: &[DataField],
+    ) -> Result<Vec<Series>> {
+        let array = self.dictionary.read().to_array(&keys);
+        Ok(vec![array.into_series()])
+    }
+}
+
+impl HashMethod for HashMethodLowCardinality {
+    type HashKey = u32;
+
+    fn name(&self) -> String {
+        "LowCardinality".to_string()
+    }
+
+    fn build_keys(&self, group_columns: &[&DataColumn], rows: usize) -> Result<Vec<Self::HashKey>> {
+        let series = group_columns[0].to_array()?;
+        let array = series.dictionary()?;
+
+        // Remap the codes of the block dictionary once, then the rows only look up the remapping.
+        let global_codes: Vec<u32> = {
+            let mut dictionary = self.dictionary.write();
+            array
+                .values()
+                .into_no_null_iter()
+                .map(|v| dictionary.get_or_insert(v))
+                .collect()
+        };
+
+        let keys = array.inner().keys();
+        let mut group_keys = Vec::with_capacity(rows);
+        for row in 0..rows {
+            match keys.is_null(row) {
+                true => group_keys.push(GlobalDictionary::NULL_KEY),
+                false => group_keys.push(global_codes[keys.value(row) as usize]),
+            }
+        }
+        Ok(group_keys)
+    }
+}
+
 pub struct HashMethodFixedKeys<T> {
     t: PhantomData<T>,
 }
@@ -40,7 +40,7 @@ impl DataBlock {
     ) -> Result<DataBlock> {
         let order_columns = sort_columns_descriptions
             .iter()
-            .map(|f| Ok(block.try_array_by_name(&f.column_name)?.get_array_ref()))
+            .map(|f| order_array(&block.try_array_by_name(&f.column_name)?))
             .collect::<Result<Vec<_>>>()?;
 
         let order_arrays = sort_columns_descriptions
@@ -84,7 +84,7 @@ impl DataBlock {
                 let right = rhs.try_column_by_name(&f.column_name)?.clone();
                 let right = right.to_array()?;
 
-                Ok(vec![left.get_array_ref(), right.get_array_ref()])
+                Ok(vec![order_array(&left)?, order_array(&right)?])
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -205,3 +205,12 @@ impl DataBlock {
         }
     }
 }
+
+/// The array to be ordered by, the dictionary codes don't follow the order of
+/// the values, so the low cardinality values are ordered as their value type.
+fn order_array(series: &Series) -> Result<ArrayRef> {
+    match series.data_type() {
+        DataType::LowCardinality(inner) => Ok(series.cast_with_type(inner)?.get_array_ref()),
+        _ => Ok(series.get_array_ref()),
+    }
+}
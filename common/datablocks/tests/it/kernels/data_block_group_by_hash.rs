@@ -59,3 +59,44 @@ fn test_data_block_group_by_hash() -> Result<()> {
     ]);
     Ok(())
 }
+
+#[test]
+fn test_data_block_group_by_low_cardinality() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new(
+        "x",
+        DataType::LowCardinality(Box::new(DataType::String)),
+        true,
+    )]);
+
+    let encode = |values: Vec<Option<&str>>| {
+        let array = DFStringArray::new_from_opt_slice(&values);
+        DFDictionaryArray::encode(&array).into_series()
+    };
+
+    let block1 = DataBlock::create_by_array(schema.clone(), vec![encode(vec![
+        Some("x1"),
+        Some("x2"),
+        None,
+        Some("x1"),
+    ])]);
+    let block2 = DataBlock::create_by_array(schema, vec![encode(vec![
+        Some("x3"),
+        Some("x2"),
+        Some("x1"),
+    ])]);
+
+    let method = DataBlock::choose_hash_method(&block1, &["x".to_string()])?;
+    assert_eq!(method.name(), HashMethodLowCardinality::default().name());
+
+    // The codes of both blocks are remapped to the same global dictionary.
+    let hash = HashMethodLowCardinality::default();
+    let keys = hash.build_keys(&[block1.try_column_by_name("x")?], block1.num_rows())?;
+    assert_eq!(keys, vec![0, 1, GlobalDictionary::NULL_KEY, 0]);
+    let keys = hash.build_keys(&[block2.try_column_by_name("x")?], block2.num_rows())?;
+    assert_eq!(keys, vec![2, 1, 0]);
+
+    let columns = hash.de_group_columns(vec![2, GlobalDictionary::NULL_KEY, 0], &[])?;
+    let expected = DFStringArray::new_from_opt_slice(&[Some("x3"), None, Some("x1")]);
+    assert_eq!(columns[0].dictionary()?.decode().inner(), expected.inner());
+    Ok(())
+}
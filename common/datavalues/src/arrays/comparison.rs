@@ -345,6 +345,57 @@ impl ArrayCompare<&DFNullArray> for DFNullArray {}
 
 impl ArrayCompare<&DFStructArray> for DFStructArray {}
 
+impl DFDictionaryArray {
+    /// Compare on the dictionary values when one side is a scalar, so each distinct value is
+    /// compared only once and the results are gathered to the rows by the codes.
+    fn comparison<F>(&self, rhs: &DFDictionaryArray, op: F) -> Result<DFBooleanArray>
+    where F: Fn(&DFStringArray, &DFStringArray) -> Result<DFBooleanArray> {
+        if rhs.len() == 1 && self.len() != 1 {
+            let values = op(&self.values(), &rhs.decode())?;
+            Ok(self.gather_bool(&values))
+        } else if self.len() == 1 && rhs.len() != 1 {
+            let values = op(&self.decode(), &rhs.values())?;
+            Ok(rhs.gather_bool(&values))
+        } else {
+            op(&self.decode(), &rhs.decode())
+        }
+    }
+}
+
+impl ArrayCompare<&DFDictionaryArray> for DFDictionaryArray {
+    fn eq(&self, rhs: &DFDictionaryArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, |lhs, rhs| ArrayCompare::eq(lhs, rhs))
+    }
+
+    fn neq(&self, rhs: &DFDictionaryArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, |lhs, rhs| ArrayCompare::neq(lhs, rhs))
+    }
+
+    fn gt(&self, rhs: &DFDictionaryArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, |lhs, rhs| ArrayCompare::gt(lhs, rhs))
+    }
+
+    fn gt_eq(&self, rhs: &DFDictionaryArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, |lhs, rhs| ArrayCompare::gt_eq(lhs, rhs))
+    }
+
+    fn lt(&self, rhs: &DFDictionaryArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, |lhs, rhs| ArrayCompare::lt(lhs, rhs))
+    }
+
+    fn lt_eq(&self, rhs: &DFDictionaryArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, |lhs, rhs| ArrayCompare::lt_eq(lhs, rhs))
+    }
+
+    fn like(&self, rhs: &DFDictionaryArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, |lhs, rhs| ArrayCompare::like(lhs, rhs))
+    }
+
+    fn nlike(&self, rhs: &DFDictionaryArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, |lhs, rhs| ArrayCompare::nlike(lhs, rhs))
+    }
+}
+
 macro_rules! impl_cmp_numeric_string_list {
     ($self:ident, $rhs:ident, $cmp_method:ident) => {{
         match ($self.null_count(), $rhs.null_count()) {
@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::*;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// DFDictionaryArray is the dictionary encoded String array, each row keeps the UInt32 code
+/// of its value in the dictionary, so that the operations on the few distinct values
+/// (comparisons, group by keys) can be done on the codes without materializing the strings.
+#[derive(Debug, Clone)]
+pub struct DFDictionaryArray {
+    pub(crate) array: DictionaryArray<u32>,
+    pub data_type: DataType,
+}
+
+impl From<DictionaryArray<u32>> for DFDictionaryArray {
+    fn from(array: DictionaryArray<u32>) -> Self {
+        Self::new(array)
+    }
+}
+
+impl DFDictionaryArray {
+    pub fn new(array: DictionaryArray<u32>) -> Self {
+        let data_type = DataType::LowCardinality(Box::new(DataType::String));
+        Self { array, data_type }
+    }
+
+    pub fn from_arrow_array(array: &dyn Array) -> Self {
+        let array = array
+            .as_any()
+            .downcast_ref::<DictionaryArray<u32>>()
+            .unwrap();
+
+        // the values read from the outside (e.g. Utf8 of parquet) are normalized to LargeBinary
+        let values = DFStringArray::from_arrow_array(array.values().as_ref());
+        Self::from_data(array.keys().clone().into(), values)
+    }
+
+    pub fn from_data(keys: DFUInt32Array, values: DFStringArray) -> Self {
+        let values: ArrayRef = Arc::new(values.array);
+        Self::new(DictionaryArray::<u32>::from_data(keys.array, values))
+    }
+
+    /// Dictionary encode the string array, the values are kept in the order of their
+    /// first appearance and the null rows have null codes.
+    pub fn encode(array: &DFStringArray) -> Self {
+        let mut codes: HashMap<&[u8], u32, ahash::RandomState> = HashMap::default();
+        let mut values = Vec::new();
+        let keys: DFUInt32Array = array
+            .into_iter()
+            .map(|v| {
+                v.map(|v| {
+                    *codes.entry(v).or_insert_with(|| {
+                        values.push(v);
+                        values.len() as u32 - 1
+                    })
+                })
+            })
+            .collect();
+
+        let values = DFStringArray::new_from_slice(&values);
+        Self::from_data(keys, values)
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    pub fn inner(&self) -> &DictionaryArray<u32> {
+        &self.array
+    }
+
+    /// The codes of the rows.
+    pub fn keys(&self) -> DFUInt32Array {
+        self.array.keys().clone().into()
+    }
+
+    /// The distinct values of the dictionary.
+    pub fn values(&self) -> DFStringArray {
+        DFStringArray::from_arrow_array(self.array.values().as_ref())
+    }
+
+    /// Materialize the strings of the rows.
+    pub fn decode(&self) -> DFStringArray {
+        let values = self.values();
+        let indices = self.array.keys().iter().map(|k| k.map(|k| *k as usize));
+        unsafe { take_string_opt_iter_unchecked(values.inner(), indices).into() }
+    }
+
+    /// Gather the results computed on the dictionary values to the rows by their codes.
+    pub fn gather_bool(&self, values: &DFBooleanArray) -> DFBooleanArray {
+        let values = values.inner();
+        self.array
+            .keys()
+            .iter()
+            .map(|k| {
+                let k = *k? as usize;
+                match values.is_null(k) {
+                    true => None,
+                    false => Some(values.value(k)),
+                }
+            })
+            .collect()
+    }
+
+    /// # Safety
+    /// Note this doesn't do any bound checking, for performance reason.
+    pub unsafe fn try_get(&self, index: usize) -> Result<DataValue> {
+        let keys = self.array.keys();
+        if keys.is_null(index) {
+            return Ok(DataValue::String(None));
+        }
+        let values = self.values();
+        values.try_get(keys.value_unchecked(index) as usize)
+    }
+
+    pub fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        self.array.null_count()
+    }
+
+    #[inline]
+    pub fn is_null(&self, i: usize) -> bool {
+        self.array.is_null(i)
+    }
+
+    #[inline]
+    /// Get the null count and the buffer of bits representing null values
+    pub fn null_bits(&self) -> (usize, Option<&Bitmap>) {
+        (self.array.null_count(), self.array.validity())
+    }
+
+    /// Take a view of top n elements
+    pub fn limit(&self, num_elements: usize) -> Self {
+        self.slice(0, num_elements)
+    }
+
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        let array = self.array.slice(offset, length);
+        Self::new(array)
+    }
+
+    /// Unpack a array to the same physical type.
+    ///
+    /// # Safety
+    ///
+    /// This is unsafe as the data_type may be uncorrect and
+    /// is assumed to be correct in other unsafe code.
+    pub unsafe fn unpack(&self, array: &Series) -> Result<&Self> {
+        let array_trait = &**array;
+        if self.data_type() == array.data_type() {
+            let ca = &*(array_trait as *const dyn SeriesTrait as *const Self);
+            Ok(ca)
+        } else {
+            Err(ErrorCode::IllegalDataType(format!(
+                "cannot unpack array {:?} into matching type {:?}",
+                array,
+                self.data_type()
+            )))
+        }
+    }
+}
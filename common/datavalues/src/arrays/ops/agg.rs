@@ -356,3 +356,21 @@ impl ArrayAgg for DFListArray {}
 impl ArrayAgg for DFNullArray {}
 
 impl ArrayAgg for DFStructArray {}
+
+impl ArrayAgg for DFDictionaryArray {
+    fn min(&self) -> Result<DataValue> {
+        self.decode().min()
+    }
+
+    fn max(&self) -> Result<DataValue> {
+        self.decode().max()
+    }
+
+    fn arg_min(&self) -> Result<DataValue> {
+        self.decode().arg_min()
+    }
+
+    fn arg_max(&self) -> Result<DataValue> {
+        self.decode().arg_max()
+    }
+}
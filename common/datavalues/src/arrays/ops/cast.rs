@@ -74,6 +74,8 @@ impl ArrayCast for DFStringArray {
             });
 
             Ok(c.into_series())
+        } else if data_type.is_low_cardinality() {
+            Ok(DFDictionaryArray::encode(self).into_series())
         } else {
             cast_ca(&self.array, data_type)
         }
@@ -101,8 +103,15 @@ impl ArrayCast for DFNullArray {
             DataType::Int64 => Ok(DFInt64Array::full_null(self.len()).into_series()),
             DataType::Float32 => Ok(DFFloat32Array::full_null(self.len()).into_series()),
             DataType::Float64 => Ok(DFFloat64Array::full_null(self.len()).into_series()),
-            DataType::String => Ok(DFStringArray::full_null(self.len()).into_series()),
+            DataType::Decimal(_, _) => Ok(DFDecimalArray::full_null(self.len()).into_series()),
+            DataType::String | DataType::Variant => {
+                Ok(DFStringArray::full_null(self.len()).into_series())
+            }
             DataType::List(_) => Ok(DFListArray::full_null(self.len()).into_series()),
+            DataType::LowCardinality(_) => {
+                let values = DFStringArray::full_null(self.len());
+                Ok(DFDictionaryArray::encode(&values).into_series())
+            }
 
             _ => Err(ErrorCode::BadDataValueType(format!(
                 "Unsupported cast_with_type from array: {:?} into data_type: {:?}",
@@ -114,3 +123,12 @@ impl ArrayCast for DFNullArray {
 
 impl ArrayCast for DFListArray {}
 impl ArrayCast for DFStructArray {}
+
+impl ArrayCast for DFDictionaryArray {
+    fn cast_with_type(&self, data_type: &DataType) -> Result<Series> {
+        match data_type {
+            DataType::LowCardinality(_) => Ok(self.clone().into_series()),
+            _ => self.decode().cast_with_type(data_type),
+        }
+    }
+}
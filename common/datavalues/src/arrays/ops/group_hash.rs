@@ -111,3 +111,11 @@ impl GroupHash for DFStringArray {
 impl GroupHash for DFListArray {}
 impl GroupHash for DFNullArray {}
 impl GroupHash for DFStructArray {}
+
+impl GroupHash for DFDictionaryArray {
+    fn serialize(&self, vec: &mut Vec<Vec<u8>>) -> Result<()> {
+        // the serialized keys must be the same for the same values of the different blocks,
+        // so the values are written instead of the codes of the block
+        self.decode().serialize(vec)
+    }
+}
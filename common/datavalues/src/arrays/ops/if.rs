@@ -162,3 +162,10 @@ impl ArrayIf for DFNullArray {
 
 impl ArrayIf for DFListArray {}
 impl ArrayIf for DFStructArray {}
+
+impl ArrayIf for DFDictionaryArray {
+    fn if_then_else(&self, rhs: &Self, predicate: &DFBooleanArray) -> Result<Self> {
+        let result = self.decode().if_then_else(&rhs.decode(), predicate)?;
+        Ok(DFDictionaryArray::encode(&result))
+    }
+}
@@ -212,3 +212,22 @@ impl ArrayScatter for DFStringArray {
 
 impl ArrayScatter for DFNullArray {}
 impl ArrayScatter for DFStructArray {}
+
+impl ArrayScatter for DFDictionaryArray {
+    unsafe fn scatter_unchecked(
+        &self,
+        indices: &mut dyn Iterator<Item = u64>,
+        scattered_size: usize,
+    ) -> Result<Vec<Self>>
+    where
+        Self: std::marker::Sized,
+    {
+        // the codes are scattered, all the parts share the same dictionary
+        let values = self.values();
+        let keys = self.keys().scatter_unchecked(indices, scattered_size)?;
+        Ok(keys
+            .into_iter()
+            .map(|keys| DFDictionaryArray::from_data(keys, values.clone()))
+            .collect())
+    }
+}
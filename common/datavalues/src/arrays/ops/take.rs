@@ -250,6 +250,29 @@ impl ArrayTake for DFStringArray {
 impl ArrayTake for DFNullArray {}
 impl ArrayTake for DFStructArray {}
 
+impl ArrayTake for DFDictionaryArray {
+    unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        // the codes are taken, the dictionary is kept
+        let keys = self.keys().take_unchecked(indices)?;
+        Ok(Self::from_data(keys, self.values()))
+    }
+
+    fn take<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        let keys = self.keys().take(indices)?;
+        Ok(Self::from_data(keys, self.values()))
+    }
+}
+
 pub trait AsTakeIndex {
     fn as_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a>;
 
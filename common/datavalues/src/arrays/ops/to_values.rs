@@ -145,6 +145,12 @@ impl ToValues for DFNullArray {
     }
 }
 
+impl ToValues for DFDictionaryArray {
+    fn to_values(&self) -> Result<Vec<DataValue>> {
+        self.decode().to_values()
+    }
+}
+
 impl ToValues for DFStructArray {
     fn to_values(&self) -> Result<Vec<DataValue>> {
         let mut values = Vec::with_capacity(self.len());
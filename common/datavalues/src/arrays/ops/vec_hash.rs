@@ -100,3 +100,14 @@ impl VecHash for DFListArray {
 impl VecHash for DFStructArray {}
 
 impl VecHash for DFNullArray {}
+
+impl VecHash for DFDictionaryArray {
+    fn vec_hash(&self, hasher: DFHasher) -> Result<DFUInt64Array> {
+        // hash the distinct values once, then gather the hashes by the codes
+        let hashes = self.values().vec_hash(hasher)?;
+        let hashes = hashes.inner().values().as_slice();
+        Ok(self
+            .keys()
+            .apply_cast_numeric(|k| hashes.get(k as usize).copied().unwrap_or_default()))
+    }
+}
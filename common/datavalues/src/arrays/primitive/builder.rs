@@ -33,6 +33,7 @@ pub type DFUInt64ArrayBuilder = PrimitiveArrayBuilder<u64>;
 pub type DFInt64ArrayBuilder = PrimitiveArrayBuilder<i64>;
 pub type DFFloat32ArrayBuilder = PrimitiveArrayBuilder<f32>;
 pub type DFFloat64ArrayBuilder = PrimitiveArrayBuilder<f64>;
+pub type DFDecimalArrayBuilder = PrimitiveArrayBuilder<i128>;
 
 impl<T> ArrayBuilder<T, DFPrimitiveArray<T>> for PrimitiveArrayBuilder<T>
 where T: DFPrimitiveType
@@ -57,6 +57,11 @@ impl<T: DFPrimitiveType> DFPrimitiveArray<T> {
     pub fn new(array: PrimitiveArray<T>) -> Self {
         let data_type: DataType = array.data_type().into();
         let data_type: DataType = data_type_physical(data_type);
+        // the decimals of any precision and scale share the same physical array type
+        let array = match data_type.is_decimal() && array.data_type() != &data_type.to_arrow() {
+            true => array.to(data_type.to_arrow()),
+            false => array,
+        };
         Self { array, data_type }
     }
 
@@ -119,6 +124,14 @@ impl<T: DFPrimitiveType> DFPrimitiveArray<T> {
 
                     Self::from_arrow_array(&array)
                 }
+                ArrowDataType::Decimal(_, _) => {
+                    let array = array
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<T>>()
+                        .expect("primitive cast should be ok");
+
+                    Self::new(array.clone())
+                }
                 _ => unreachable!(),
             }
         } else {
@@ -155,6 +168,7 @@ impl<T: DFPrimitiveType> DFPrimitiveArray<T> {
             DataType::Int64 => DataValue::Int64(v.to_i64()),
             DataType::Float32 => DataValue::Float32(v.to_f32()),
             DataType::Float64 => DataValue::Float64(v.to_f64()),
+            DataType::Decimal(_, _) => DataValue::Decimal128(v.to_i128()),
             _ => unreachable!(),
         };
 
@@ -258,6 +272,8 @@ pub type DFInt64Array = DFPrimitiveArray<i64>;
 pub type DFFloat32Array = DFPrimitiveArray<f32>;
 pub type DFFloat64Array = DFPrimitiveArray<f64>;
 
+pub type DFDecimalArray = DFPrimitiveArray<i128>;
+
 /// # Safety
 /// Note this doesn't do any bound checking, for performance reason.
 /// Take kernel for single chunk without nulls and an iterator as index.
@@ -17,6 +17,9 @@ use std::collections::BTreeMap;
 use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_macros::MallocSizeOf;
 
+use crate::datetime64_metadata;
+use crate::datetime64_type_from_metadata;
+use crate::map_type_from_fields;
 use crate::DataType;
 
 #[derive(
@@ -82,11 +85,15 @@ impl DataField {
             DataType::Date16 => Some("Date16"),
             DataType::Date32 => Some("Date32"),
             DataType::DateTime32(_) => Some("DateTime32"),
+            DataType::DateTime64(_, _) => Some("DateTime64"),
+            DataType::Variant => Some("Variant"),
+            DataType::Map(_, _) => Some("Map"),
             _ => None,
         };
 
         let custom_metadata = match self.data_type() {
             DataType::DateTime32(tz) => tz.clone(),
+            DataType::DateTime64(precision, tz) => Some(datetime64_metadata(*precision, tz)),
             _ => None,
         };
 
@@ -118,6 +125,13 @@ impl From<&ArrowField> for DataField {
                     "Date16" => dt = DataType::Date16,
                     "Date32" => dt = DataType::Date32,
                     "DateTime32" => dt = DataType::DateTime32(metatada.cloned()),
+                    "DateTime64" => dt = datetime64_type_from_metadata(metatada),
+                    "Variant" => dt = DataType::Variant,
+                    "Map" => {
+                        if let DataType::Struct(fields) = &dt {
+                            dt = map_type_from_fields(fields);
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -34,6 +34,7 @@ pub enum DataGroupValue {
     Int16(i16),
     Int32(i32),
     Int64(i64),
+    Decimal128(i128),
     String(Vec<u8>),
     Boolean(bool),
 }
@@ -54,6 +55,7 @@ impl TryFrom<&DataValue> for DataGroupValue {
             DataValue::UInt16(Some(v)) => DataGroupValue::UInt16(*v),
             DataValue::UInt32(Some(v)) => DataGroupValue::UInt32(*v),
             DataValue::UInt64(Some(v)) => DataGroupValue::UInt64(*v),
+            DataValue::Decimal128(Some(v)) => DataGroupValue::Decimal128(*v),
             DataValue::String(Some(v)) => DataGroupValue::String(v.clone()),
 
             DataValue::Float32(None)
@@ -67,6 +69,7 @@ impl TryFrom<&DataValue> for DataGroupValue {
             | DataValue::UInt16(None)
             | DataValue::UInt32(None)
             | DataValue::UInt64(None)
+            | DataValue::Decimal128(None)
             | DataValue::String(None) => {
                 return Err(ErrorCode::BadDataValueType(format!(
                     "Cannot convert a DataValue holding NULL ({:?})",
@@ -98,6 +101,7 @@ impl From<&DataGroupValue> for DataValue {
             DataGroupValue::UInt16(v) => DataValue::UInt16(Some(*v)),
             DataGroupValue::UInt32(v) => DataValue::UInt32(Some(*v)),
             DataGroupValue::UInt64(v) => DataValue::UInt64(Some(*v)),
+            DataGroupValue::Decimal128(v) => DataValue::Decimal128(Some(*v)),
             DataGroupValue::String(v) => DataValue::String(Some(v.to_vec())),
         }
     }
@@ -52,6 +52,8 @@ pub enum DataValue {
     UInt64(Option<u64>),
     Float32(Option<f32>),
     Float64(Option<f64>),
+    /// The unscaled value of a decimal, the scale is carried by the data type of the column.
+    Decimal128(Option<i128>),
     String(Option<Vec<u8>>),
 
     // Container struct.
@@ -76,6 +78,7 @@ impl DataValue {
                 | DataValue::UInt64(None)
                 | DataValue::Float32(None)
                 | DataValue::Float64(None)
+                | DataValue::Decimal128(None)
                 | DataValue::String(None)
                 | DataValue::Null
                 | DataValue::List(None, _)
@@ -96,6 +99,7 @@ impl DataValue {
             DataValue::UInt64(_) => DataType::UInt64,
             DataValue::Float32(_) => DataType::Float32,
             DataValue::Float64(_) => DataType::Float64,
+            DataValue::Decimal128(_) => DataType::Decimal(DECIMAL_MAX_PRECISION, 0),
             DataValue::List(_, data_type) => {
                 DataType::List(Box::new(DataField::new("item", data_type.clone(), true)))
             }
@@ -170,11 +174,15 @@ impl DataValue {
             DataValue::UInt64(values) => Ok(build_constant_series! {DFUInt64Array, values, size}),
             DataValue::Float32(values) => Ok(build_constant_series! {DFFloat32Array, values, size}),
             DataValue::Float64(values) => Ok(build_constant_series! {DFFloat64Array, values, size}),
+            DataValue::Decimal128(values) => {
+                Ok(build_constant_series! {DFDecimalArray, values, size})
+            }
             DataValue::String(values) => match values {
                 None => Ok(DFStringArray::full_null(size).into_series()),
                 Some(v) => Ok(DFStringArray::full(v.deref(), size).into_series()),
             },
-            DataValue::List(values, data_type) => match data_type {
+            // the element type may be a logical type, like Date16
+            DataValue::List(values, data_type) => match data_type_physical(data_type.clone()) {
                 DataType::Int8 => build_list_series! {i8, values, size, data_type },
                 DataType::Int16 => build_list_series! {i16, values, size, data_type },
                 DataType::Int32 => build_list_series! {i32, values, size, data_type },
@@ -343,10 +351,18 @@ impl DataValue {
             DataType::Date16 => DataValue::UInt16(Some(0)),
             DataType::Date32 => DataValue::Int32(Some(0)),
             DataType::DateTime32(_) => DataValue::UInt32(Some(0)),
+            DataType::DateTime64(_, _) => DataValue::Int64(Some(0)),
             DataType::Interval(_) => DataValue::Int64(Some(0)),
+            DataType::Decimal(_, _) => DataValue::Decimal128(Some(0)),
             DataType::List(f) => DataValue::List(Some(vec![]), f.data_type().clone()),
             DataType::Struct(_) => DataValue::Struct(vec![]),
+            DataType::Map(key, value) => DataValue::Struct(vec![
+                DataValue::List(Some(vec![]), key.as_ref().clone()),
+                DataValue::List(Some(vec![]), value.as_ref().clone()),
+            ]),
             DataType::String => DataValue::String(Some(vec![])),
+            DataType::LowCardinality(inner) => DataValue::new_from_data_type(inner, false),
+            DataType::Variant => DataValue::String(Some(b"null".to_vec())),
         }
     }
     pub fn as_string(&self) -> Result<Vec<u8>> {
@@ -386,6 +402,7 @@ typed_cast_from_data_value_to_std!(UInt32, u32);
 typed_cast_from_data_value_to_std!(UInt64, u64);
 typed_cast_from_data_value_to_std!(Float32, f32);
 typed_cast_from_data_value_to_std!(Float64, f64);
+typed_cast_from_data_value_to_std!(Decimal128, i128);
 typed_cast_from_data_value_to_std!(Boolean, bool);
 
 impl DFTryFrom<DataValue> for Vec<u8> {
@@ -411,6 +428,7 @@ std_to_data_value!(UInt32, u32);
 std_to_data_value!(UInt64, u64);
 std_to_data_value!(Float32, f32);
 std_to_data_value!(Float64, f64);
+std_to_data_value!(Decimal128, i128);
 std_to_data_value!(Boolean, bool);
 
 impl From<&[u8]> for DataValue {
@@ -456,10 +474,13 @@ impl From<&DataType> for DataValue {
             DataType::Date16 => DataValue::UInt16(None),
             DataType::Date32 => DataValue::Int32(None),
             DataType::DateTime32(_) => DataValue::UInt32(None),
+            DataType::DateTime64(_, _) => DataValue::Int64(None),
             DataType::List(f) => DataValue::List(None, f.data_type().clone()),
-            DataType::Struct(_) => DataValue::Struct(vec![]),
-            DataType::String => DataValue::String(None),
+            DataType::Struct(_) | DataType::Map(_, _) => DataValue::Struct(vec![]),
+            DataType::String | DataType::Variant => DataValue::String(None),
+            DataType::LowCardinality(inner) => DataValue::from(inner.as_ref()),
             DataType::Interval(_) => DataValue::Int64(None),
+            DataType::Decimal(_, _) => DataValue::Decimal128(None),
         }
     }
 }
@@ -488,6 +509,7 @@ impl fmt::Display for DataValue {
             DataValue::UInt16(v) => format_data_value_with_option!(f, v),
             DataValue::UInt32(v) => format_data_value_with_option!(f, v),
             DataValue::UInt64(v) => format_data_value_with_option!(f, v),
+            DataValue::Decimal128(v) => format_data_value_with_option!(f, v),
             DataValue::String(None) => write!(f, "NULL"),
             DataValue::String(Some(v)) => match std::str::from_utf8(v) {
                 Ok(v) => write!(f, "{}", v),
@@ -532,6 +554,7 @@ impl fmt::Debug for DataValue {
             DataValue::UInt64(v) => format_data_value_with_option!(f, v),
             DataValue::Float32(v) => format_data_value_with_option!(f, v),
             DataValue::Float64(v) => format_data_value_with_option!(f, v),
+            DataValue::Decimal128(v) => format_data_value_with_option!(f, v),
             DataValue::String(None) => write!(f, "{}", self),
             DataValue::String(Some(_)) => write!(f, "{}", self),
             DataValue::List(_, _) => write!(f, "[{}]", self),
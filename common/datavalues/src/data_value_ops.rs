@@ -92,7 +92,10 @@ impl DataValue {
                 try_build_array! {PrimitiveArrayBuilder, f64, Float64, values}
             }
             DataType::Boolean => try_build_array! {values},
-            DataType::String => try_build_array! {String, values},
+            DataType::String | DataType::Variant => try_build_array! {String, values},
+            DataType::LowCardinality(inner) => {
+                DataValue::try_into_data_array(values, inner)?.cast_with_type(data_type)
+            }
             DataType::Date16 => {
                 try_build_array! {PrimitiveArrayBuilder, u16, UInt16, values}
             }
@@ -102,6 +105,27 @@ impl DataValue {
             DataType::DateTime32(_) => {
                 try_build_array! {PrimitiveArrayBuilder, u32, UInt32, values}
             }
+            DataType::DateTime64(_, _) => {
+                try_build_array! {PrimitiveArrayBuilder, i64, Int64, values}
+            }
+            DataType::Decimal(_, _) => {
+                try_build_array! {PrimitiveArrayBuilder, i128, Decimal128, values}
+            }
+            DataType::List(field) => {
+                let element_type = field.data_type();
+                let physical_type = data_type_physical(element_type.clone());
+                let mut builder = get_list_builder(&physical_type, values.len() * 4, values.len());
+                for value in values.iter() {
+                    match value {
+                        DataValue::List(Some(v), _) => {
+                            builder.append_series(&DataValue::try_into_data_array(v, element_type)?)
+                        }
+                        DataValue::List(None, _) | DataValue::Null => builder.append_null(),
+                        _ => unreachable!(),
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{} for DataValue List",
                 other
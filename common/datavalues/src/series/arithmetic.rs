@@ -231,6 +231,7 @@ impl NumOpsDispatch for DFBooleanArray {}
 impl NumOpsDispatch for DFListArray {}
 impl NumOpsDispatch for DFNullArray {}
 impl NumOpsDispatch for DFStructArray {}
+impl NumOpsDispatch for DFDictionaryArray {}
 
 fn coerce_lhs_rhs(
     op: &DataValueArithmeticOperator,
@@ -22,6 +22,20 @@ use crate::prelude::*;
 
 macro_rules! impl_compare {
     ($self:expr, $rhs:expr, $method:ident) => {{
+        // The String side compared with LowCardinality is encoded, the constants are encoded
+        // as the single value dictionaries so that they are compared on the dictionary values.
+        match ($self.data_type(), $rhs.data_type()) {
+            (DataType::LowCardinality(_), DataType::String) => {
+                let rhs = DFDictionaryArray::encode($rhs.string()?);
+                return $self.dictionary()?.$method(&rhs);
+            }
+            (DataType::String, DataType::LowCardinality(_)) => {
+                let lhs = DFDictionaryArray::encode($self.string()?);
+                return lhs.$method($rhs.dictionary()?);
+            }
+            _ => {}
+        }
+
         if $self.data_type() != $rhs.data_type() {
             return Err(ErrorCode::IllegalDataType(format!(
                 "datatype must be some for comparisons, got {} and {}",
@@ -45,6 +59,10 @@ macro_rules! impl_compare {
             DataType::Float64 => $self.f64().unwrap().$method($rhs.f64().unwrap()),
             DataType::Date16 => $self.u16().unwrap().$method($rhs.u16().unwrap()),
             DataType::Date32 => $self.i32().unwrap().$method($rhs.i32().unwrap()),
+            DataType::LowCardinality(_) => $self
+                .dictionary()
+                .unwrap()
+                .$method($rhs.dictionary().unwrap()),
             _ => unimplemented!(),
         }
     }};
@@ -111,6 +111,14 @@ pub trait SeriesTrait: Send + Sync + fmt::Debug {
         )))
     }
 
+    /// Unpack to DFArray of data_type decimal
+    fn decimal(&self) -> Result<&DFDecimalArray> {
+        Err(ErrorCode::IllegalDataType(format!(
+            "{:?} != decimal",
+            self.data_type()
+        )))
+    }
+
     /// Unpack to DFArray of data_type f32
     fn f32(&self) -> Result<&DFFloat32Array> {
         Err(ErrorCode::IllegalDataType(format!(
@@ -183,6 +191,22 @@ pub trait SeriesTrait: Send + Sync + fmt::Debug {
         )))
     }
 
+    /// Unpack to DFArray of data_type list
+    fn list(&self) -> Result<&DFListArray> {
+        Err(ErrorCode::IllegalDataType(format!(
+            "{:?} != list",
+            self.data_type()
+        )))
+    }
+
+    /// Unpack to DFArray of data_type low cardinality
+    fn dictionary(&self) -> Result<&DFDictionaryArray> {
+        Err(ErrorCode::IllegalDataType(format!(
+            "{:?} != dictionary",
+            self.data_type()
+        )))
+    }
+
     /// Take by index from an iterator. This operation clones the data.
     ///
     /// # Safety
@@ -257,6 +281,7 @@ impl_from!([i32], DFInt32Array, new_from_slice);
 impl_from!([i64], DFInt64Array, new_from_slice);
 impl_from!([f32], DFFloat32Array, new_from_slice);
 impl_from!([f64], DFFloat64Array, new_from_slice);
+impl_from!([i128], DFDecimalArray, new_from_slice);
 impl_from!([Vec<u8>], DFStringArray, new_from_slice);
 
 impl_from!([Option<bool>], DFBooleanArray, new_from_opt_slice);
@@ -270,6 +295,7 @@ impl_from!([Option<i32>], DFInt32Array, new_from_opt_slice);
 impl_from!([Option<i64>], DFInt64Array, new_from_opt_slice);
 impl_from!([Option<f32>], DFFloat32Array, new_from_opt_slice);
 impl_from!([Option<f64>], DFFloat64Array, new_from_opt_slice);
+impl_from!([Option<i128>], DFDecimalArray, new_from_opt_slice);
 impl_from!([Option<Vec<u8>>], DFStringArray, new_from_opt_slice);
 
 impl Series {
@@ -334,10 +360,12 @@ impl IntoSeries for ArrayRef {
 
             Float32 => DFFloat32Array::from_arrow_array(self.as_ref()).into_series(),
             Float64 => DFFloat64Array::from_arrow_array(self.as_ref()).into_series(),
+            Int128 => DFDecimalArray::from_arrow_array(self.as_ref()).into_series(),
 
             List(_) => DFListArray::from_arrow_array(self.as_ref()).into_series(),
             Struct(_) => DFStructArray::from_arrow_array(self.as_ref()).into_series(),
             String => DFStringArray::from_arrow_array(self.as_ref()).into_series(),
+            Dictionary(_) => DFDictionaryArray::from_arrow_array(self.as_ref()).into_series(),
         }
     }
 }
@@ -211,6 +211,17 @@ macro_rules! impl_dyn_array {
                 }
             }
 
+            fn decimal(&self) -> Result<&DFDecimalArray> {
+                if matches!(self.0.data_type(), &DataType::Decimal(_, _)) {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DFDecimalArray)) }
+                } else {
+                    Err(ErrorCode::IllegalDataType(format!(
+                        "cannot unpack Series of type {:?} into decimal",
+                        self.data_type(),
+                    )))
+                }
+            }
+
             fn f32(&self) -> Result<&DFFloat32Array> {
                 if matches!(self.0.data_type(), &DataType::Float32) {
                     unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DFFloat32Array)) }
@@ -312,6 +323,30 @@ macro_rules! impl_dyn_array {
                 }
             }
 
+            /// Unpack to DFArray of data_type list
+            fn list(&self) -> Result<&DFListArray> {
+                if matches!(self.0.data_type(), &DataType::List(_)) {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DFListArray)) }
+                } else {
+                    Err(ErrorCode::IllegalDataType(format!(
+                        "cannot unpack Series of type {:?} into list",
+                        self.data_type(),
+                    )))
+                }
+            }
+
+            /// Unpack to DFArray of data_type low cardinality
+            fn dictionary(&self) -> Result<&DFDictionaryArray> {
+                if matches!(self.0.data_type(), &DataType::LowCardinality(_)) {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DFDictionaryArray)) }
+                } else {
+                    Err(ErrorCode::IllegalDataType(format!(
+                        "cannot unpack Series of type {:?} into dictionary",
+                        self.data_type(),
+                    )))
+                }
+            }
+
             fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Result<Series> {
                 Ok(ArrayTake::take(&self.0, iter.into())?.into_series())
             }
@@ -350,7 +385,9 @@ impl_dyn_array!(DFInt8Array);
 impl_dyn_array!(DFInt16Array);
 impl_dyn_array!(DFInt32Array);
 impl_dyn_array!(DFInt64Array);
+impl_dyn_array!(DFDecimalArray);
 impl_dyn_array!(DFListArray);
 impl_dyn_array!(DFBooleanArray);
 impl_dyn_array!(DFStringArray);
 impl_dyn_array!(DFStructArray);
+impl_dyn_array!(DFDictionaryArray);
@@ -17,6 +17,7 @@ use common_io::prelude::*;
 use num::NumCast;
 
 use super::data_type::*;
+use super::decimal_converter::DECIMAL_MAX_PRECISION;
 use crate::DFTryFrom;
 use crate::DataField;
 use crate::DataValue;
@@ -48,6 +49,13 @@ impl_df_datatype!(f32, Float32);
 impl_df_datatype!(f64, Float64);
 impl_df_datatype!(bool, Boolean);
 
+impl DFDataType for i128 {
+    fn data_type() -> DataType {
+        // the physical type of all the decimals
+        DataType::Decimal(DECIMAL_MAX_PRECISION, 0)
+    }
+}
+
 #[derive(Debug)]
 pub struct Null;
 impl_df_datatype!(Null, Null);
@@ -112,6 +120,7 @@ impl_primitive!(i8, i64, true, false, 1);
 impl_primitive!(i16, i64, true, false, 2);
 impl_primitive!(i32, i64, true, false, 4);
 impl_primitive!(i64, i64, true, false, 8);
+impl_primitive!(i128, i128, true, false, 16);
 impl_primitive!(f32, f64, true, true, 4);
 impl_primitive!(f64, f64, true, true, 8);
 
@@ -131,6 +140,7 @@ impl_integer!(i8, i8);
 impl_integer!(i16, i16);
 impl_integer!(i32, i32);
 impl_integer!(i64, i64);
+impl_integer!(i128, i128);
 
 pub trait DFFloatType: DFPrimitiveType {}
 impl DFFloatType for f32 {}
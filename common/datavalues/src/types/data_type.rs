@@ -15,10 +15,12 @@
 use core::fmt;
 
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::IntegerType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_macros::MallocSizeOf;
 
+use crate::datetime64_type_from_metadata;
 use crate::DataField;
 use crate::PhysicalDataType;
 
@@ -50,11 +52,33 @@ pub enum DataType {
     /// Option<String> indicates the timezone, if it's None, it's UTC
     DateTime32(Option<String>),
 
+    /// A 64-bit datetime representing the elapsed time since UNIX epoch (1970-01-01)
+    /// in ticks of 10^-precision seconds, it's physical type is Int64
+    /// Option<String> indicates the timezone, if it's None, it's UTC
+    DateTime64(u32, Option<String>),
+
     Interval(IntervalUnit),
 
+    /// A decimal number with the precision (total number of digits) and the scale
+    /// (number of digits after the decimal point), its physical type is Int128
+    /// holding the number multiplied by 10^scale
+    Decimal(usize, usize),
+
     List(Box<DataField>),
     Struct(Vec<DataField>),
+
+    /// A map from the key type to the value type, it's physical type is Struct
+    /// holding the keys and the values of each row in two lists
+    Map(Box<DataType>, Box<DataType>),
     String,
+
+    /// A dictionary encoded column of the inner type (only String now), it's physical
+    /// type is Dictionary holding the UInt32 codes and the distinct values of the column
+    LowCardinality(Box<DataType>),
+
+    /// A semi-structured JSON value, it's physical type is String
+    /// holding the compact JSON text
+    Variant,
 }
 
 #[derive(
@@ -134,7 +158,10 @@ impl DataType {
     pub fn is_date_or_date_time(&self) -> bool {
         matches!(
             self,
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_)
+            DataType::Date16
+                | DataType::Date32
+                | DataType::DateTime32(_)
+                | DataType::DateTime64(_, _)
         )
     }
 
@@ -167,6 +194,35 @@ impl DataType {
         matches!(self, DataType::Interval(_))
     }
 
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, DataType::Decimal(_, _))
+    }
+
+    #[inline]
+    pub fn is_variant(&self) -> bool {
+        matches!(self, DataType::Variant)
+    }
+
+    #[inline]
+    pub fn is_map(&self) -> bool {
+        matches!(self, DataType::Map(_, _))
+    }
+
+    #[inline]
+    pub fn is_low_cardinality(&self) -> bool {
+        matches!(self, DataType::LowCardinality(_))
+    }
+
+    /// The type of the values, the dictionary encoded type is the type of its dictionary.
+    #[inline]
+    pub fn remove_low_cardinality(&self) -> DataType {
+        match self {
+            DataType::LowCardinality(inner) => inner.as_ref().clone(),
+            other => other.clone(),
+        }
+    }
+
     #[inline]
     pub fn numeric_byte_size(&self) -> Result<usize> {
         match self {
@@ -174,6 +230,7 @@ impl DataType {
             DataType::Int16 | DataType::UInt16 => Ok(2),
             DataType::Int32 | DataType::UInt32 | DataType::Float32 => Ok(4),
             DataType::Int64 | DataType::UInt64 | DataType::Float64 => Ok(8),
+            DataType::Decimal(_, _) => Ok(16),
             _ => Result::Err(ErrorCode::BadArguments(format!(
                 "Function number_byte_size argument must be numeric types, but got {:?}",
                 self
@@ -200,13 +257,25 @@ impl DataType {
             Date32 => ArrowDataType::Int32,
             // we don't use DataType::Extension because extension types are not supported in parquet
             DateTime32(_) => ArrowDataType::UInt32,
+            DateTime64(_, _) => ArrowDataType::Int64,
             List(dt) => ArrowDataType::LargeList(Box::new(dt.to_arrow())),
             Struct(fs) => {
                 let arrows_fields = fs.iter().map(|f| f.to_arrow()).collect();
                 ArrowDataType::Struct(arrows_fields)
             }
-            String => ArrowDataType::LargeBinary,
+            Map(key, value) => {
+                let arrows_fields = map_struct_fields(key, value)
+                    .iter()
+                    .map(|f| f.to_arrow())
+                    .collect();
+                ArrowDataType::Struct(arrows_fields)
+            }
+            String | Variant => ArrowDataType::LargeBinary,
+            LowCardinality(inner) => {
+                ArrowDataType::Dictionary(IntegerType::UInt32, Box::new(inner.to_arrow()))
+            }
             Interval(_) => ArrowDataType::Int64,
+            Decimal(precision, scale) => ArrowDataType::Decimal(*precision, *scale),
         }
     }
 }
@@ -233,6 +302,7 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Boolean => DataType::Boolean,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
+            ArrowDataType::Decimal(precision, scale) => DataType::Decimal(*precision, *scale),
             ArrowDataType::List(f) | ArrowDataType::LargeList(f) => {
                 let f: DataField = (f.as_ref()).into();
                 DataType::List(Box::new(f))
@@ -244,10 +314,16 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Date32 => DataType::Date16,
             ArrowDataType::Date64 => DataType::Date32,
 
-            ArrowDataType::Extension(name, _arrow_type, extra) => match name.as_str() {
+            ArrowDataType::Extension(name, arrow_type, extra) => match name.as_str() {
                 "Date16" => DataType::Date16,
                 "Date32" => DataType::Date32,
                 "DateTime32" => DataType::DateTime32(extra.clone()),
+                "DateTime64" => datetime64_type_from_metadata(extra.as_ref()),
+                "Variant" => DataType::Variant,
+                "Map" => match DataType::from(arrow_type.as_ref()) {
+                    DataType::Struct(fields) => map_type_from_fields(&fields),
+                    _ => unimplemented!("data_type: {}", dt),
+                },
                 _ => unimplemented!("data_type: {}", dt),
             },
 
@@ -256,6 +332,10 @@ impl From<&ArrowDataType> for DataType {
                 DataType::Struct(fields)
             }
 
+            ArrowDataType::Dictionary(_, values) => {
+                DataType::LowCardinality(Box::new(values.as_ref().into()))
+            }
+
             // this is safe, because we define the datatype firstly
             _ => {
                 unimplemented!("data_type: {}", dt)
@@ -264,6 +344,31 @@ impl From<&ArrowDataType> for DataType {
     }
 }
 
+/// The fields of the physical Struct of the map, the keys and the values of each row
+/// are stored in two lists with the same length.
+pub fn map_struct_fields(key_type: &DataType, value_type: &DataType) -> Vec<DataField> {
+    let keys = DataField::new("item", key_type.clone(), false);
+    let values = DataField::new("item", value_type.clone(), true);
+    vec![
+        DataField::new("keys", DataType::List(Box::new(keys)), false),
+        DataField::new("values", DataType::List(Box::new(values)), false),
+    ]
+}
+
+/// Restores the map type from the fields of its physical Struct.
+pub fn map_type_from_fields(fields: &[DataField]) -> DataType {
+    match fields {
+        [keys, values] => match (keys.data_type(), values.data_type()) {
+            (DataType::List(key), DataType::List(value)) => DataType::Map(
+                Box::new(key.data_type().clone()),
+                Box::new(value.data_type().clone()),
+            ),
+            _ => DataType::Struct(fields.to_vec()),
+        },
+        _ => DataType::Struct(fields.to_vec()),
+    }
+}
+
 pub fn get_physical_arrow_type(data_type: &ArrowDataType) -> &ArrowDataType {
     if let ArrowDataType::Extension(_name, arrow_type, _extra) = data_type {
         return get_physical_arrow_type(arrow_type.as_ref());
@@ -296,10 +401,21 @@ impl fmt::Debug for DataType {
                     write!(f, "DateTime32")
                 }
             }
+            Self::DateTime64(precision, arg1) => {
+                if let Some(tz) = arg1 {
+                    write!(f, "DateTime64({}, {:?})", precision, tz)
+                } else {
+                    write!(f, "DateTime64({})", precision)
+                }
+            }
             Self::List(arg0) => f.debug_tuple("List").field(arg0).finish(),
             Self::Struct(arg0) => f.debug_tuple("Struct").field(arg0).finish(),
+            Self::Map(key, value) => write!(f, "Map({:?}, {:?})", key, value),
             Self::String => write!(f, "String"),
+            Self::Variant => write!(f, "Variant"),
+            Self::LowCardinality(inner) => write!(f, "LowCardinality({:?})", inner),
             Self::Interval(unit) => write!(f, "Interval({})", unit),
+            Self::Decimal(precision, scale) => write!(f, "Decimal({}, {})", precision, scale),
         }
     }
 }
@@ -20,6 +20,7 @@ use common_exception::Result;
 use crate::prelude::DataType;
 use crate::DataField;
 use crate::DataValueArithmeticOperator;
+use crate::DECIMAL_MAX_PRECISION;
 
 fn next_size(size: usize) -> usize {
     if size < 8_usize {
@@ -200,6 +201,81 @@ pub fn numerical_arithmetic_coercion(
     }
 }
 
+/// The scale of the decimals, the integers are taken as the decimals with scale 0.
+#[inline]
+fn decimal_scale(data_type: &DataType) -> Option<usize> {
+    match data_type {
+        DataType::Decimal(_, scale) => Some(*scale),
+        other if other.is_integer() => Some(0),
+        _ => None,
+    }
+}
+
+/// Coercion rule for the arithmetic of decimals, the result has the max precision
+/// and the scale which keeps the digits of the operands, the floats make the result Float64.
+#[inline]
+pub fn decimal_arithmetic_coercion(
+    op: &DataValueArithmeticOperator,
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Result<DataType> {
+    if !lhs_type.is_decimal() && !rhs_type.is_decimal() {
+        return Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported decimal coercion ({:?}) {} ({:?})",
+            lhs_type, op, rhs_type
+        )));
+    }
+
+    if lhs_type.is_floating() || rhs_type.is_floating() {
+        return Ok(DataType::Float64);
+    }
+
+    match (decimal_scale(lhs_type), decimal_scale(rhs_type)) {
+        (Some(lhs_scale), Some(rhs_scale)) => {
+            let scale = match op {
+                DataValueArithmeticOperator::Mul => {
+                    cmp::min(lhs_scale + rhs_scale, DECIMAL_MAX_PRECISION)
+                }
+                DataValueArithmeticOperator::IntDiv => 0,
+                _ => cmp::max(lhs_scale, rhs_scale),
+            };
+            Ok(DataType::Decimal(DECIMAL_MAX_PRECISION, scale))
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported ({:?}) {} ({:?})",
+            lhs_type, op, rhs_type
+        ))),
+    }
+}
+
+/// Coercion rule for the comparison and the merge of decimals.
+fn decimal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    match (lhs_type, rhs_type) {
+        (DataType::Decimal(p1, s1), DataType::Decimal(p2, s2)) => {
+            let scale = cmp::max(*s1, *s2);
+            let precision = cmp::max(p1 - s1, p2 - s2) + scale;
+            Ok(DataType::Decimal(
+                cmp::min(precision, DECIMAL_MAX_PRECISION),
+                scale,
+            ))
+        }
+        (DataType::Decimal(_, scale), other) | (other, DataType::Decimal(_, scale))
+            if other.is_integer() || other == &DataType::String =>
+        {
+            Ok(DataType::Decimal(DECIMAL_MAX_PRECISION, *scale))
+        }
+        (DataType::Decimal(_, _), other) | (other, DataType::Decimal(_, _))
+            if other.is_floating() =>
+        {
+            Ok(DataType::Float64)
+        }
+        _ => Err(ErrorCode::IllegalDataType(format!(
+            "Can not coerce {} with {}",
+            lhs_type, rhs_type
+        ))),
+    }
+}
+
 #[inline]
 pub fn datetime_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
@@ -228,6 +304,9 @@ pub fn datetime_arithmetic_coercion(
         DataValueArithmeticOperator::Minus => {
             if b.is_numeric() || b.is_interval() {
                 Ok(a)
+            } else if matches!(a, DataType::DateTime64(_, _)) {
+                // DateTime64 minus DateTime64, in ticks
+                Ok(DataType::Int64)
             } else {
                 // Date minus Date or DateTime minus DateTime
                 Ok(DataType::Int32)
@@ -321,6 +400,30 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
         }
     }
 
+    // one of is low cardinality, the values of the other one are cast to the low cardinality
+    // to be compared on the dictionary, if they are of its value type
+    match (lhs_type, rhs_type) {
+        (DataType::LowCardinality(inner), other) | (other, DataType::LowCardinality(inner)) => {
+            return match inner.as_ref() == other {
+                true => Ok(DataType::LowCardinality(inner.clone())),
+                false => compare_coercion(inner, &other.remove_low_cardinality()),
+            };
+        }
+        _ => {}
+    }
+
+    // one of is variant, the variant is cast to the type of the other one
+    if lhs_type.is_variant() {
+        return Ok(rhs_type.clone());
+    }
+    if rhs_type.is_variant() {
+        return Ok(lhs_type.clone());
+    }
+
+    if lhs_type.is_decimal() || rhs_type.is_decimal() {
+        return decimal_coercion(lhs_type, rhs_type);
+    }
+
     // one of is String and other is number
     if (lhs_type.is_numeric() && rhs_type == &DataType::String)
         || (rhs_type.is_numeric() && lhs_type == &DataType::String)
@@ -345,6 +448,16 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
 
     // one of is datetime and other is number or string
     if lhs_type.is_date_or_date_time() || rhs_type.is_date_or_date_time() {
+        // one of is datetime64, keep the higher precision
+        match (lhs_type, rhs_type) {
+            (DataType::DateTime64(p1, tz), DataType::DateTime64(p2, _)) => {
+                return Ok(DataType::DateTime64(*p1.max(p2), tz.clone()));
+            }
+            (DataType::DateTime64(_, _), _) => return Ok(lhs_type.clone()),
+            (_, DataType::DateTime64(_, _)) => return Ok(rhs_type.clone()),
+            _ => {}
+        }
+
         // one of is datetime
         if matches!(lhs_type, DataType::DateTime32(_))
             || matches!(rhs_type, DataType::DateTime32(_))
@@ -420,12 +533,24 @@ pub fn merge_types(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType>
                 .collect::<Result<Vec<_>>>()?;
             Ok(DataType::Struct(fields))
         }
+        (DataType::LowCardinality(a), DataType::LowCardinality(b)) => {
+            Ok(DataType::LowCardinality(Box::new(merge_types(a, b)?)))
+        }
+        (DataType::LowCardinality(a), other) | (other, DataType::LowCardinality(a)) => {
+            merge_types(a, other)
+        }
+        (DataType::Map(a_key, a_value), DataType::Map(b_key, b_value)) => Ok(DataType::Map(
+            Box::new(merge_types(a_key, b_key)?),
+            Box::new(merge_types(a_value, b_value)?),
+        )),
         _ => {
             if lhs_type == rhs_type {
                 return Ok(lhs_type.clone());
             }
             if lhs_type.is_numeric() && rhs_type.is_numeric() {
                 numerical_coercion(lhs_type, rhs_type, false)
+            } else if lhs_type.is_decimal() || rhs_type.is_decimal() {
+                decimal_coercion(lhs_type, rhs_type)
             } else {
                 Result::Err(ErrorCode::BadDataValueType(format!(
                     "Can't merge types from {} and {}",
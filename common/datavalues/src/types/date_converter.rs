@@ -17,11 +17,115 @@ use chrono::DateTime;
 use chrono::Duration;
 use chrono::TimeZone;
 use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use num::cast::AsPrimitive;
 
+use crate::DataType;
+
+/// The max precision of DateTime64, nanoseconds.
+pub const DATETIME64_MAX_PRECISION: u32 = 9;
+
+/// Creates the type DateTime64(precision, tz), checking the precision and the timezone.
+pub fn create_datetime64_type(precision: u32, tz: Option<String>) -> Result<DataType> {
+    if precision > DATETIME64_MAX_PRECISION {
+        return Err(ErrorCode::BadArguments(format!(
+            "DateTime64 precision must be between 0 and {}, but got {}",
+            DATETIME64_MAX_PRECISION, precision
+        )));
+    }
+    parse_timezone(&tz)?;
+    Ok(DataType::DateTime64(precision, tz))
+}
+
+/// 10^precision, the number of ticks of DateTime64 in one second.
+#[inline]
+pub fn datetime64_factor(precision: u32) -> i64 {
+    10_i64.pow(precision)
+}
+
+/// Encodes the precision and the timezone of DateTime64 into the arrow field metadata,
+/// e.g. "3" or "6,Asia/Shanghai".
+pub fn datetime64_metadata(precision: u32, tz: &Option<String>) -> String {
+    match tz {
+        Some(tz) => format!("{},{}", precision, tz),
+        None => precision.to_string(),
+    }
+}
+
+/// Decodes the metadata written by `datetime64_metadata`.
+pub fn datetime64_type_from_metadata(metadata: Option<&String>) -> DataType {
+    let metadata = metadata.map(|m| m.as_str()).unwrap_or("");
+    let (precision, tz) = match metadata.split_once(',') {
+        Some((precision, tz)) => (precision, Some(tz.to_string())),
+        None => (metadata, None),
+    };
+    DataType::DateTime64(precision.parse().unwrap_or(3), tz)
+}
+
+/// Formats the DateTime64 ticks in the timezone, with `precision` digits of the fractional seconds.
+pub fn datetime64_to_string(ticks: i64, precision: u32, tz: &Tz) -> String {
+    let dt = ticks.to_date_time64(precision, tz);
+    let date_time = dt.format("%Y-%m-%d %H:%M:%S");
+    match precision {
+        0 => date_time.to_string(),
+        _ => format!(
+            "{}.{:0width$}",
+            date_time,
+            ticks.rem_euclid(datetime64_factor(precision)),
+            width = precision as usize
+        ),
+    }
+}
+
+/// Parses the DateTime64 ticks from a string like "2021-01-01 00:00:00.123456" in the timezone,
+/// the extra digits of the fractional seconds are truncated.
+pub fn datetime64_from_str(value: &str, precision: u32, tz: &Tz) -> Result<i64> {
+    let dt = tz
+        .datetime_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .map_err(|_| {
+            ErrorCode::BadBytes(format!("Cannot parse value '{}' to DateTime64 type", value))
+        })?;
+    let sub_seconds =
+        dt.timestamp_subsec_nanos() as i64 / 10_i64.pow(DATETIME64_MAX_PRECISION - precision);
+    Ok(dt.timestamp() * datetime64_factor(precision) + sub_seconds)
+}
+
+/// Converts the DateTime64 ticks between the precisions, the extra digits are truncated.
+#[inline]
+pub fn datetime64_rescale(ticks: i64, from_precision: u32, to_precision: u32) -> i64 {
+    if to_precision >= from_precision {
+        ticks * datetime64_factor(to_precision - from_precision)
+    } else {
+        ticks.div_euclid(datetime64_factor(from_precision - to_precision))
+    }
+}
+
+/// Parses the timezone of DateTime types, None is UTC.
+pub fn parse_timezone(tz: &Option<String>) -> Result<Tz> {
+    match tz {
+        Some(tz) => tz
+            .parse::<Tz>()
+            .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone '{}'", tz))),
+        None => Ok(Tz::UTC),
+    }
+}
+
+// Splits the DateTime64 ticks into the seconds and the nanoseconds.
+#[inline]
+fn datetime64_split(ticks: i64, precision: u32) -> (i64, u32) {
+    let factor = datetime64_factor(precision);
+    let secs = ticks.div_euclid(factor);
+    let nanos = ticks.rem_euclid(factor) * 10_i64.pow(DATETIME64_MAX_PRECISION - precision);
+    (secs, nanos as u32)
+}
+
 pub trait DateConverter {
     fn to_date(&self, tz: &Tz) -> Date<Tz>;
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz>;
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz>;
+    /// Like `to_date_time64`, but returns an error if the ticks are out of the range of chrono.
+    fn try_to_date_time64(&self, precision: u32, tz: &Tz) -> Result<DateTime<Tz>>;
 }
 
 impl<T> DateConverter for T
@@ -36,4 +140,17 @@ where T: AsPrimitive<i64>
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz> {
         tz.timestamp_millis(self.as_() * 1000)
     }
+
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz> {
+        let (secs, nanos) = datetime64_split(self.as_(), precision);
+        tz.timestamp(secs, nanos)
+    }
+
+    fn try_to_date_time64(&self, precision: u32, tz: &Tz) -> Result<DateTime<Tz>> {
+        let ticks: i64 = self.as_();
+        let (secs, nanos) = datetime64_split(ticks, precision);
+        tz.timestamp_opt(secs, nanos).single().ok_or_else(|| {
+            ErrorCode::Overflow(format!("Overflow on DateTime64 with ticks {}.", ticks))
+        })
+    }
 }
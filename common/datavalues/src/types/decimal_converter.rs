@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DataType;
+
+/// The max precision of the decimals, the numbers of 38 digits fit in 128 bits.
+pub const DECIMAL_MAX_PRECISION: usize = 38;
+
+/// Creates the type Decimal(precision, scale), checking the precision and the scale.
+pub fn create_decimal_type(precision: usize, scale: usize) -> Result<DataType> {
+    if precision == 0 || precision > DECIMAL_MAX_PRECISION {
+        return Err(ErrorCode::BadArguments(format!(
+            "Decimal precision must be between 1 and {}, but got {}",
+            DECIMAL_MAX_PRECISION, precision
+        )));
+    }
+    if scale > precision {
+        return Err(ErrorCode::BadArguments(format!(
+            "Decimal scale must be between 0 and the precision {}, but got {}",
+            precision, scale
+        )));
+    }
+    Ok(DataType::Decimal(precision, scale))
+}
+
+/// 10^scale, the factor of the decimals with the scale.
+#[inline]
+pub fn decimal_factor(scale: usize) -> i128 {
+    10_i128.pow(scale as u32)
+}
+
+/// Changes the scale of a decimal, the extra digits are truncated when the scale decreases.
+#[inline]
+pub fn decimal_rescale(value: i128, from_scale: usize, to_scale: usize) -> i128 {
+    if to_scale >= from_scale {
+        value * decimal_factor(to_scale - from_scale)
+    } else {
+        value / decimal_factor(from_scale - to_scale)
+    }
+}
+
+/// Whether the decimal has at most `precision` digits, 10^38 still fits in i128.
+#[inline]
+pub fn decimal_fits_precision(value: i128, precision: usize) -> bool {
+    let precision = precision.min(DECIMAL_MAX_PRECISION);
+    value.unsigned_abs() < decimal_factor(precision) as u128
+}
+
+/// Checks the decimal has at most `precision` digits.
+#[inline]
+pub fn decimal_check_precision(value: i128, precision: usize) -> Result<i128> {
+    match decimal_fits_precision(value, precision) {
+        true => Ok(value),
+        false => Err(ErrorCode::Overflow(format!(
+            "Decimal overflow: {} has more than {} digits",
+            value, precision
+        ))),
+    }
+}
+
+#[inline]
+pub fn decimal_to_f64(value: i128, scale: usize) -> f64 {
+    value as f64 / decimal_factor(scale) as f64
+}
+
+#[inline]
+pub fn decimal_from_f64(value: f64, scale: usize) -> i128 {
+    (value * decimal_factor(scale) as f64).round() as i128
+}
+
+/// Formats the decimal with exactly `scale` digits after the decimal point, e.g. `-1.50`.
+pub fn decimal_to_string(value: i128, scale: usize) -> String {
+    if scale == 0 {
+        return value.to_string();
+    }
+
+    let factor = decimal_factor(scale);
+    let sign = if value < 0 { "-" } else { "" };
+    let value = value.unsigned_abs();
+    format!(
+        "{}{}.{:0width$}",
+        sign,
+        value / factor as u128,
+        value % factor as u128,
+        width = scale
+    )
+}
+
+/// Parses a decimal like `-1.5` with the scale, the extra digits after the decimal point
+/// are truncated.
+pub fn decimal_from_str(text: &str, scale: usize) -> Result<i128> {
+    let error = || ErrorCode::BadBytes(format!("Cannot parse value '{}' to Decimal", text));
+
+    let text = text.trim();
+    let (negative, digits) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (digits, ""),
+    };
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(error());
+    }
+
+    let mut value: i128 = 0;
+    let fraction = fraction.bytes().chain(std::iter::repeat(b'0')).take(scale);
+    for digit in integer.bytes().chain(fraction) {
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((digit - b'0') as i128))
+            .ok_or_else(error)?;
+    }
+
+    Ok(if negative { -value } else { value })
+}
@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct LowCardinalityDeserializer {
+    pub inner: Box<dyn TypeDeserializer>,
+}
+
+impl TypeDeserializer for LowCardinalityDeserializer {
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.inner.de(reader)
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        self.inner.de_batch(reader, step, rows)
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        self.inner.de_text(reader)
+    }
+
+    fn de_null(&mut self) {
+        self.inner.de_null()
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        let series = self.inner.finish_to_series();
+        match series.string() {
+            Ok(values) => DFDictionaryArray::encode(values).into_series(),
+            Err(_) => series,
+        }
+    }
+}
@@ -21,14 +21,22 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod date_time64;
+mod decimal;
+mod low_cardinality;
 mod number;
 mod string;
+mod variant;
 
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use date_time64::*;
+pub use decimal::*;
+pub use low_cardinality::*;
 pub use number::*;
 pub use string::*;
+pub use variant::*;
 
 pub trait TypeDeserializer: Send + Sync {
     fn de(&mut self, reader: &mut &[u8]) -> Result<()>;
@@ -66,9 +74,25 @@ impl DataType {
                         tz: tz.parse::<Tz>().unwrap(),
                     }))
                 }
+                DataType::DateTime64(precision, tz) => Ok(Box::new(DateTime64Deserializer {
+                    builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                    precision,
+                    tz: parse_timezone(&tz)?,
+                })),
+                DataType::Decimal(precision, scale) => Ok(Box::new(DecimalDeserializer {
+                    builder: PrimitiveArrayBuilder::<i128>::with_capacity(capacity),
+                    precision,
+                    scale,
+                })),
                 DataType::String => Ok(Box::new(StringDeserializer {
                     builder: StringArrayBuilder::with_capacity(capacity),
                 })),
+                DataType::Variant => Ok(Box::new(VariantDeserializer {
+                    builder: StringArrayBuilder::with_capacity(capacity),
+                })),
+                DataType::LowCardinality(inner) => Ok(Box::new(LowCardinalityDeserializer {
+                    inner: inner.create_deserializer(capacity)?,
+                })),
                 DataType::Interval(_) => Ok(Box::new(DateDeserializer::<i64> {
                     builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
                 })),
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::map_struct_fields;
 use crate::DataField;
 use crate::DataType;
+use crate::DECIMAL_MAX_PRECISION;
 
 #[derive(
     serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord,
@@ -31,9 +33,11 @@ pub enum PhysicalDataType {
     Int64,
     Float32,
     Float64,
+    Int128,
     List(Box<DataField>),
     Struct(Vec<DataField>),
     String,
+    Dictionary(Box<DataType>),
 }
 
 impl From<DataType> for PhysicalDataType {
@@ -49,13 +53,16 @@ impl From<DataType> for PhysicalDataType {
             DataType::Int8 => Int8,
             DataType::Int16 => Int16,
             DataType::Int32 | DataType::Date32 => Int32,
-            DataType::Int64 => Int64,
+            DataType::Int64 | DataType::DateTime64(_, _) => Int64,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::List(x) => List(x),
             DataType::Struct(x) => Struct(x),
-            DataType::String => String,
+            DataType::Map(key, value) => Struct(map_struct_fields(&key, &value)),
+            DataType::String | DataType::Variant => String,
+            DataType::LowCardinality(x) => Dictionary(x),
             DataType::Interval(_) => Int64,
+            DataType::Decimal(_, _) => Int128,
         }
     }
 }
@@ -76,9 +83,11 @@ impl From<PhysicalDataType> for DataType {
             PhysicalDataType::Int64 => Int64,
             PhysicalDataType::Float32 => Float32,
             PhysicalDataType::Float64 => Float64,
+            PhysicalDataType::Int128 => Decimal(DECIMAL_MAX_PRECISION, 0),
             PhysicalDataType::List(x) => List(x),
             PhysicalDataType::Struct(x) => Struct(x),
             PhysicalDataType::String => String,
+            PhysicalDataType::Dictionary(x) => LowCardinality(x),
         }
     }
 }
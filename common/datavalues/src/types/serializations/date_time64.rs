@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono_tz::Tz;
+use common_exception::*;
+
+use crate::prelude::*;
+
+pub struct DateTime64Serializer {
+    pub precision: u32,
+    pub tz: Tz,
+}
+
+impl TypeSerializer for DateTime64Serializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        if value.is_null() {
+            return Ok("NULL".to_owned());
+        }
+
+        Ok(datetime64_to_string(
+            value.as_i64()?,
+            self.precision,
+            &self.tz,
+        ))
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array = array.i64()?;
+
+        let result: Vec<String> = array
+            .iter()
+            .map(|x| {
+                x.map(|v| datetime64_to_string(*v, self.precision, &self.tz))
+                    .unwrap_or_else(|| "NULL".to_owned())
+            })
+            .collect();
+        Ok(result)
+    }
+}
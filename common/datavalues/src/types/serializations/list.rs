@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct ListSerializer {
+    pub inner: DataField,
+}
+
+impl ListSerializer {
+    fn format_items(&self, items: Vec<String>, is_null: impl Fn(usize) -> bool) -> String {
+        let data_type = self.inner.data_type();
+        let quoted = matches!(
+            data_type,
+            DataType::String
+                | DataType::Variant
+                | DataType::LowCardinality(_)
+                | DataType::Date16
+                | DataType::Date32
+                | DataType::DateTime32(_)
+                | DataType::DateTime64(_, _)
+        );
+
+        let items: Vec<String> = items
+            .into_iter()
+            .enumerate()
+            .map(|(i, s)| {
+                if quoted && !is_null(i) {
+                    format!("'{}'", s)
+                } else {
+                    s
+                }
+            })
+            .collect();
+        format!("[{}]", items.join(", "))
+    }
+}
+
+impl TypeSerializer for ListSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        match value {
+            DataValue::List(None, _) => Ok("NULL".to_owned()),
+            DataValue::List(Some(vals), _) => {
+                let serializer = self.inner.data_type().create_serializer();
+                let items = vals
+                    .iter()
+                    .map(|v| serializer.serialize_value(v))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(self.format_items(items, |i| vals[i].is_null()))
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect List value")),
+        }
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array = array.list()?;
+        let serializer = self.inner.data_type().create_serializer();
+
+        let mut result = Vec::with_capacity(array.len());
+        for items in array.into_iter() {
+            match items {
+                None => result.push("NULL".to_owned()),
+                Some(items) => {
+                    let strs = serializer.serialize_column(&items.clone().into())?;
+                    result.push(self.format_items(strs, |i| items.is_null(i)));
+                }
+            }
+        }
+        Ok(result)
+    }
+}
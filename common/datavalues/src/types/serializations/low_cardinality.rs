@@ -0,0 +1,45 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct LowCardinalitySerializer {
+    pub inner: Box<dyn TypeSerializer>,
+}
+
+impl TypeSerializer for LowCardinalitySerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        self.inner.serialize_value(value)
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array = array.dictionary()?;
+
+        // the distinct values are serialized once and shared by the rows with the same code
+        let values: DataColumn = array.values().into_series().into();
+        let values = self.inner.serialize_column(&values)?;
+        let keys = array.keys();
+        let result = keys
+            .into_iter()
+            .map(|k| match k {
+                Some(k) => values[*k as usize].clone(),
+                None => "NULL".to_owned(),
+            })
+            .collect();
+        Ok(result)
+    }
+}
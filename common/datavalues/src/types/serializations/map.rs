@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct MapSerializer {
+    pub key_type: DataType,
+    pub value_type: DataType,
+}
+
+impl MapSerializer {
+    fn format_item(data_type: &DataType, value: &DataValue) -> Result<String> {
+        let s = data_type.create_serializer().serialize_value(value)?;
+        let quoted = matches!(
+            data_type,
+            DataType::String
+                | DataType::Variant
+                | DataType::LowCardinality(_)
+                | DataType::Date16
+                | DataType::Date32
+                | DataType::DateTime32(_)
+                | DataType::DateTime64(_, _)
+        );
+
+        if quoted && !value.is_null() {
+            Ok(format!("'{}'", s))
+        } else {
+            Ok(s)
+        }
+    }
+}
+
+impl TypeSerializer for MapSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        match value {
+            DataValue::Null => Ok("NULL".to_owned()),
+            DataValue::Struct(vals) if vals.len() == 2 => match (&vals[0], &vals[1]) {
+                (DataValue::List(None, _), _) => Ok("NULL".to_owned()),
+                (DataValue::List(Some(keys), _), DataValue::List(Some(values), _)) => {
+                    let items = keys
+                        .iter()
+                        .zip(values.iter())
+                        .map(|(k, v)| {
+                            Ok(format!(
+                                "{}: {}",
+                                Self::format_item(&self.key_type, k)?,
+                                Self::format_item(&self.value_type, v)?
+                            ))
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(format!("{{{}}}", items.join(", ")))
+                }
+                _ => Err(ErrorCode::BadBytes("Incorrect Map value")),
+            },
+            _ => Err(ErrorCode::BadBytes("Incorrect Map value")),
+        }
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let series = column.to_array()?;
+        (0..series.len())
+            .map(|row| match series.is_null(row) {
+                true => Ok("NULL".to_owned()),
+                false => self.serialize_value(&series.try_get(row)?),
+            })
+            .collect()
+    }
+}
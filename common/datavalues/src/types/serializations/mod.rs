@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono_tz::Tz;
 use common_exception::Result;
 
 use crate::prelude::*;
@@ -19,6 +20,11 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod date_time64;
+mod decimal;
+mod list;
+mod low_cardinality;
+mod map;
 mod nulls;
 mod number;
 mod string;
@@ -27,6 +33,11 @@ mod r#struct;
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use date_time64::*;
+pub use decimal::*;
+pub use list::*;
+pub use low_cardinality::*;
+pub use map::*;
 pub use nulls::*;
 pub use number::*;
 pub use r#struct::*;
@@ -55,10 +66,25 @@ impl DataType {
             DataType::Date16 => Box::new(DateSerializer::<u16>::default()),
             DataType::Date32 => Box::new(DateSerializer::<i32>::default()),
             DataType::DateTime32(_) => Box::new(DateTimeSerializer::<u32>::default()),
-            DataType::String => Box::new(StringSerializer {}),
+            DataType::DateTime64(precision, tz) => Box::new(DateTime64Serializer {
+                precision: *precision,
+                tz: parse_timezone(tz).unwrap_or(Tz::UTC),
+            }),
+            DataType::Decimal(_, scale) => Box::new(DecimalSerializer { scale: *scale }),
+            DataType::String | DataType::Variant => Box::new(StringSerializer {}),
             DataType::Struct(fields) => Box::new(StructSerializer {
                 fields: fields.to_vec(),
             }),
+            DataType::List(inner) => Box::new(ListSerializer {
+                inner: inner.as_ref().clone(),
+            }),
+            DataType::Map(key, value) => Box::new(MapSerializer {
+                key_type: key.as_ref().clone(),
+                value_type: value.as_ref().clone(),
+            }),
+            DataType::LowCardinality(inner) => Box::new(LowCardinalitySerializer {
+                inner: inner.create_serializer(),
+            }),
             _ => todo!(),
         }
     }
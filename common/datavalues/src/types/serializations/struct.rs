@@ -44,6 +44,7 @@ impl TypeSerializer for StructSerializer {
                             | DataType::Date16
                             | DataType::Date32
                             | DataType::DateTime32(_)
+                            | DataType::DateTime64(_, _)
                     ) {
                         res.push_str(&format!("'{}'", s));
                     } else {
@@ -57,7 +58,13 @@ impl TypeSerializer for StructSerializer {
         }
     }
 
-    fn serialize_column(&self, _column: &DataColumn) -> Result<Vec<String>> {
-        unimplemented!()
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let series = column.to_array()?;
+        (0..series.len())
+            .map(|row| match series.is_null(row) {
+                true => Ok("NULL".to_owned()),
+                false => self.serialize_value(&series.try_get(row)?),
+            })
+            .collect()
     }
 }
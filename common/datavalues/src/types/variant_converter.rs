@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+/// An element of the path to access the variant values, e.g. `a.b[0]` is
+/// `[Key("a"), Key("b"), Index(0)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VariantPathElem {
+    Key(String),
+    Index(usize),
+}
+
+/// Parses the JSON text of a variant value.
+pub fn variant_from_slice(value: &[u8]) -> Result<JsonValue> {
+    serde_json::from_slice(value).map_err(|e| {
+        ErrorCode::BadBytes(format!(
+            "Cannot parse value '{}' to Variant type: {}",
+            String::from_utf8_lossy(value),
+            e
+        ))
+    })
+}
+
+/// Normalizes the JSON text to the compact form stored in the variant columns.
+pub fn variant_normalize(value: &[u8]) -> Result<Vec<u8>> {
+    Ok(variant_from_slice(value)?.to_string().into_bytes())
+}
+
+/// The text of a variant value, the strings are unquoted and the JSON nulls are None.
+pub fn variant_to_text(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::Null => None,
+        JsonValue::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Parses a path like `a.b[0]`, `a['b c'].d` or `[1].a` into the path elements.
+pub fn parse_variant_path(path: &str) -> Result<Vec<VariantPathElem>> {
+    let err = || ErrorCode::BadArguments(format!("Invalid variant path '{}'", path));
+    let chars: Vec<char> = path.chars().collect();
+    let mut elems = Vec::new();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        match chars[pos] {
+            '[' => {
+                let end = (pos..chars.len())
+                    .find(|i| chars[*i] == ']')
+                    .ok_or_else(err)?;
+                let inner: String = chars[pos + 1..end].iter().collect();
+                let inner = inner.trim();
+                let quoted = inner.len() >= 2
+                    && ((inner.starts_with('\'') && inner.ends_with('\''))
+                        || (inner.starts_with('"') && inner.ends_with('"')));
+                match quoted {
+                    true => elems.push(VariantPathElem::Key(inner[1..inner.len() - 1].to_string())),
+                    false => elems.push(VariantPathElem::Index(
+                        inner.parse::<usize>().map_err(|_| err())?,
+                    )),
+                }
+                pos = end + 1;
+            }
+            '.' if !elems.is_empty() => {
+                // The dot is followed by a key, `a..b`, `a.[0]` and `a.` are invalid.
+                pos += 1;
+                if pos == chars.len() || chars[pos] == '.' || chars[pos] == '[' {
+                    return Err(err());
+                }
+            }
+            _ => {
+                let end = (pos..chars.len())
+                    .find(|i| chars[*i] == '.' || chars[*i] == '[')
+                    .unwrap_or(chars.len());
+                if end == pos {
+                    return Err(err());
+                }
+                elems.push(VariantPathElem::Key(chars[pos..end].iter().collect()));
+                pos = end;
+            }
+        }
+    }
+
+    match elems.is_empty() {
+        true => Err(err()),
+        false => Ok(elems),
+    }
+}
+
+/// Gets the value at the path of a variant, None if the path does not exist.
+pub fn variant_get_path<'a>(
+    value: &'a JsonValue,
+    path: &[VariantPathElem],
+) -> Option<&'a JsonValue> {
+    path.iter().try_fold(value, |value, elem| match elem {
+        VariantPathElem::Key(key) => value.as_object()?.get(key),
+        VariantPathElem::Index(index) => value.as_array()?.get(*index),
+    })
+}
+
+/// The boolean of a variant value, the numbers are true if they are not zero.
+pub fn variant_to_bool(value: &JsonValue) -> Option<bool> {
+    match value {
+        JsonValue::Bool(b) => Some(*b),
+        JsonValue::Number(n) => n.as_f64().map(|v| v != 0.0),
+        JsonValue::String(s) => s.parse::<bool>().ok(),
+        _ => None,
+    }
+}
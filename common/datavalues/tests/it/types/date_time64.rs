@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_datetime64_from_str() -> Result<()> {
+    let utc = Tz::UTC;
+    assert_eq!(
+        datetime64_from_str("2021-08-30 10:47:42.123", 3, &utc)?,
+        1630320462123
+    );
+    assert_eq!(
+        datetime64_from_str("2021-08-30 10:47:42.123456", 3, &utc)?,
+        1630320462123
+    );
+    assert_eq!(
+        datetime64_from_str("2021-08-30 10:47:42", 6, &utc)?,
+        1630320462000000
+    );
+    assert!(datetime64_from_str("2021-08-30", 3, &utc).is_err());
+
+    let shanghai: Tz = "Asia/Shanghai".parse().unwrap();
+    assert_eq!(
+        datetime64_from_str("2021-08-30 18:47:42.123", 3, &shanghai)?,
+        1630320462123
+    );
+    assert_eq!(
+        datetime64_to_string(1630320462123, 3, &shanghai),
+        "2021-08-30 18:47:42.123"
+    );
+    assert_eq!(datetime64_to_string(-1, 2, &utc), "1969-12-31 23:59:59.99");
+    assert_eq!(
+        datetime64_to_string(1630320462, 0, &utc),
+        "2021-08-30 10:47:42"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_datetime64_type() -> Result<()> {
+    assert!(create_datetime64_type(10, None).is_err());
+    assert!(create_datetime64_type(3, Some("Mars/Base".to_string())).is_err());
+    assert_eq!(
+        create_datetime64_type(6, Some("Asia/Shanghai".to_string()))?,
+        DataType::DateTime64(6, Some("Asia/Shanghai".to_string()))
+    );
+
+    let metadata = datetime64_metadata(6, &Some("Asia/Shanghai".to_string()));
+    assert_eq!(
+        datetime64_type_from_metadata(Some(&metadata)),
+        DataType::DateTime64(6, Some("Asia/Shanghai".to_string()))
+    );
+    assert_eq!(
+        datetime64_type_from_metadata(None),
+        DataType::DateTime64(3, None)
+    );
+
+    assert_eq!(datetime64_rescale(1234, 3, 6), 1234000);
+    assert_eq!(datetime64_rescale(1234567, 6, 3), 1234);
+    assert_eq!(datetime64_rescale(-1, 3, 0), -1);
+
+    let a = DataType::DateTime64(3, None);
+    let b = DataType::DateTime64(6, None);
+    assert_eq!(compare_coercion(&a, &b)?, b);
+    assert_eq!(compare_coercion(&a, &DataType::DateTime32(None))?, a);
+    Ok(())
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_decimal_from_str() -> Result<()> {
+    assert_eq!(decimal_from_str("1.5", 2)?, 150);
+    assert_eq!(decimal_from_str("-0.125", 2)?, -12);
+    assert_eq!(decimal_from_str("+3", 1)?, 30);
+    assert_eq!(decimal_from_str(".5", 1)?, 5);
+    assert!(decimal_from_str("1.a", 2).is_err());
+    assert!(decimal_from_str("-", 2).is_err());
+
+    assert_eq!(decimal_to_string(-5, 2), "-0.05");
+    assert_eq!(decimal_to_string(12345, 0), "12345");
+    Ok(())
+}
+
+#[test]
+fn test_decimal_precision() -> Result<()> {
+    assert!(create_decimal_type(0, 0).is_err());
+    assert!(create_decimal_type(39, 2).is_err());
+    assert!(create_decimal_type(5, 6).is_err());
+    assert_eq!(create_decimal_type(10, 2)?, DataType::Decimal(10, 2));
+
+    assert_eq!(decimal_check_precision(99999, 5)?, 99999);
+    assert!(decimal_check_precision(-100000, 5).is_err());
+    assert!(decimal_check_precision(decimal_factor(38) - 1, 38).is_ok());
+    assert!(decimal_check_precision(decimal_factor(38), 38).is_err());
+    assert!(decimal_check_precision(i128::MIN, 38).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_decimal_coercion() -> Result<()> {
+    let a = DataType::Decimal(10, 2);
+    let b = DataType::Decimal(20, 4);
+
+    assert_eq!(
+        decimal_arithmetic_coercion(&DataValueArithmeticOperator::Plus, &a, &b)?,
+        DataType::Decimal(38, 4)
+    );
+    assert_eq!(
+        decimal_arithmetic_coercion(&DataValueArithmeticOperator::Mul, &a, &b)?,
+        DataType::Decimal(38, 6)
+    );
+    assert_eq!(
+        decimal_arithmetic_coercion(&DataValueArithmeticOperator::Minus, &a, &DataType::Int32)?,
+        DataType::Decimal(38, 2)
+    );
+    assert_eq!(
+        decimal_arithmetic_coercion(&DataValueArithmeticOperator::Div, &a, &DataType::Float32)?,
+        DataType::Float64
+    );
+    assert!(
+        decimal_arithmetic_coercion(&DataValueArithmeticOperator::Plus, &a, &DataType::String)
+            .is_err()
+    );
+
+    assert_eq!(compare_coercion(&a, &b)?, DataType::Decimal(20, 4));
+    assert_eq!(
+        compare_coercion(&a, &DataType::Int64)?,
+        DataType::Decimal(38, 2)
+    );
+    assert_eq!(compare_coercion(&a, &DataType::Float64)?, DataType::Float64);
+    assert_eq!(merge_types(&a, &DataType::Null)?, a);
+    Ok(())
+}
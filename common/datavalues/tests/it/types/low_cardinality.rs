@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_low_cardinality_type() -> Result<()> {
+    let data_type = DataType::LowCardinality(Box::new(DataType::String));
+    assert_eq!(format!("{:?}", data_type), "LowCardinality(String)");
+    assert!(data_type.is_low_cardinality());
+    assert_eq!(data_type.remove_low_cardinality(), DataType::String);
+
+    let field = DataField::new("c", data_type.clone(), true);
+    let restored: DataField = (&field.to_arrow()).into();
+    assert_eq!(restored.data_type(), &data_type);
+
+    // the constants are compared with LowCardinality on the dictionary
+    assert_eq!(compare_coercion(&data_type, &DataType::String)?, data_type);
+    assert_eq!(
+        merge_types(&data_type, &DataType::String)?,
+        DataType::String
+    );
+    Ok(())
+}
+
+#[test]
+fn test_low_cardinality_array() -> Result<()> {
+    let strings = DFStringArray::new_from_opt_slice(&[Some("a"), Some("b"), None, Some("a")]);
+    let array = DFDictionaryArray::encode(&strings);
+
+    // the values are kept in the order of their first appearance
+    assert_eq!(
+        array.values().inner(),
+        DFStringArray::new_from_slice(&["a", "b"]).inner()
+    );
+    let keys: Vec<Option<u32>> = array.keys().into_iter().map(|k| k.copied()).collect();
+    assert_eq!(keys, vec![Some(0), Some(1), None, Some(0)]);
+    assert_eq!(array.decode().inner(), strings.inner());
+    assert_eq!(array.null_count(), 1);
+
+    let series = array.into_series();
+    assert_eq!(
+        series.data_type(),
+        &DataType::LowCardinality(Box::new(DataType::String))
+    );
+    assert_eq!(series.try_get(1)?, DataValue::String(Some(b"b".to_vec())));
+    assert_eq!(series.try_get(2)?, DataValue::String(None));
+
+    // the scalar is compared with the dictionary values only
+    let scalar = DFStringArray::new_from_slice(&["a"]).into_series();
+    let result = ArrayCompare::eq(&series, &scalar)?;
+    let result: Vec<Option<bool>> = result.into_iter().collect();
+    assert_eq!(result, vec![Some(true), Some(false), None, Some(true)]);
+
+    let strings = series.cast_with_type(&DataType::String)?;
+    assert_eq!(strings.data_type(), &DataType::String);
+    let encoded = strings.cast_with_type(&DataType::LowCardinality(Box::new(DataType::String)))?;
+    assert_eq!(encoded.dictionary()?.values().len(), 2);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_map_type() -> Result<()> {
+    let data_type = DataType::Map(Box::new(DataType::String), Box::new(DataType::Date16));
+    assert_eq!(format!("{:?}", data_type), "Map(String, Date16)");
+    assert!(data_type.is_map());
+
+    // the map is stored as a struct of the keys and the values
+    match data_type_physical(data_type.clone()) {
+        DataType::Struct(fields) => {
+            assert_eq!(fields.len(), 2);
+            assert_eq!(fields[0].name(), "keys");
+            assert_eq!(fields[1].name(), "values");
+        }
+        other => panic!("unexpected physical type {:?}", other),
+    }
+
+    let field = DataField::new("m", data_type.clone(), true);
+    let restored: DataField = (&field.to_arrow()).into();
+    assert_eq!(restored.data_type(), &data_type);
+
+    let struct_type = DataType::Struct(vec![
+        DataField::new("a", DataType::Int32, true),
+        DataField::new("b", DataType::String, true),
+    ]);
+    let field = DataField::new("s", struct_type.clone(), true);
+    let restored: DataField = (&field.to_arrow()).into();
+    assert_eq!(restored.data_type(), &struct_type);
+    Ok(())
+}
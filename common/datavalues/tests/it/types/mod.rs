@@ -13,4 +13,9 @@
 // limitations under the License.
 
 mod cast;
+mod date_time64;
+mod decimal;
+mod low_cardinality;
+mod map;
 mod serializations;
+mod variant;
@@ -55,6 +55,18 @@ fn test_serializers() -> Result<()> {
                 "NULL".to_owned(),
             ],
         },
+        Test {
+            name: "datetime64",
+            data_type: DataType::DateTime64(6, Some("Asia/Shanghai".to_string())),
+            value: DataValue::Int64(Some(1630320462123456)),
+            column: Series::new(vec![Some(1630320462123456i64), Some(-1i64), None]).into(),
+            val_str: "2021-08-30 18:47:42.123456",
+            col_str: vec![
+                "2021-08-30 18:47:42.123456".to_owned(),
+                "1970-01-01 07:59:59.999999".to_owned(),
+                "NULL".to_owned(),
+            ],
+        },
         Test {
             name: "date32",
             data_type: DataType::Date32,
@@ -67,6 +79,14 @@ fn test_serializers() -> Result<()> {
                 "NULL".to_owned(),
             ],
         },
+        Test {
+            name: "decimal",
+            data_type: DataType::Decimal(10, 2),
+            value: DataValue::Decimal128(Some(-105)),
+            column: Series::new(vec![Some(12345i128), Some(5i128), None]).into(),
+            val_str: "-1.05",
+            col_str: vec!["123.45".to_owned(), "0.05".to_owned(), "NULL".to_owned()],
+        },
         Test {
             name: "string",
             data_type: DataType::String,
@@ -75,6 +95,20 @@ fn test_serializers() -> Result<()> {
             val_str: "hello",
             col_str: vec!["hello".to_owned(), "world".to_owned(), "NULL".to_owned()],
         },
+        Test {
+            name: "low_cardinality",
+            data_type: DataType::LowCardinality(Box::new(DataType::String)),
+            value: DataValue::String(Some("hello".as_bytes().to_vec())),
+            column: DFDictionaryArray::encode(&DFStringArray::new_from_opt_slice(&[
+                Some("hello"),
+                None,
+                Some("hello"),
+            ]))
+            .into_series()
+            .into(),
+            val_str: "hello",
+            col_str: vec!["hello".to_owned(), "NULL".to_owned(), "hello".to_owned()],
+        },
     ];
 
     for test in tests {
@@ -105,5 +139,57 @@ fn test_serializers() -> Result<()> {
         assert_eq!(&result, expect);
     }
 
+    {
+        let data_type = DataType::List(Box::new(DataField::new("item", DataType::String, true)));
+        let serializer = data_type.create_serializer();
+        let value = DataValue::List(
+            Some(vec![
+                DataValue::String(Some("a".as_bytes().to_vec())),
+                DataValue::String(None),
+            ]),
+            DataType::String,
+        );
+        assert_eq!(&serializer.serialize_value(&value)?, "['a', NULL]");
+
+        let mut builder = get_list_builder(&DataType::Int32, 4, 3);
+        builder.append_series(&Series::new(vec![1i32, 2]));
+        builder.append_null();
+        builder.append_series(&Series::new(Vec::<i32>::new()));
+        let column: DataColumn = builder.finish().into_series().into();
+
+        let data_type = DataType::List(Box::new(DataField::new("item", DataType::Int32, true)));
+        let serializer = data_type.create_serializer();
+        assert_eq!(serializer.serialize_column(&column)?, vec![
+            "[1, 2]".to_owned(),
+            "NULL".to_owned(),
+            "[]".to_owned()
+        ]);
+    }
+
+    {
+        let data_type = DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32));
+        let serializer = data_type.create_serializer();
+        let value = DataValue::Struct(vec![
+            DataValue::List(
+                Some(vec![
+                    DataValue::String(Some("a".as_bytes().to_vec())),
+                    DataValue::String(Some("b".as_bytes().to_vec())),
+                ]),
+                DataType::String,
+            ),
+            DataValue::List(
+                Some(vec![DataValue::Int32(Some(1)), DataValue::Int32(None)]),
+                DataType::Int32,
+            ),
+        ]);
+        assert_eq!(&serializer.serialize_value(&value)?, "{'a': 1, 'b': NULL}");
+
+        let column: DataColumn = value.to_series_with_size(2)?.into();
+        assert_eq!(serializer.serialize_column(&column)?, vec![
+            "{'a': 1, 'b': NULL}".to_owned(),
+            "{'a': 1, 'b': NULL}".to_owned()
+        ]);
+    }
+
     Ok(())
 }
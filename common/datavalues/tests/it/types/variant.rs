@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_variant_path() -> Result<()> {
+    use VariantPathElem::*;
+
+    assert_eq!(parse_variant_path("a")?, vec![Key("a".to_string())]);
+    assert_eq!(parse_variant_path("a.b[0]")?, vec![
+        Key("a".to_string()),
+        Key("b".to_string()),
+        Index(0)
+    ]);
+    assert_eq!(parse_variant_path("[1]['b c'].d")?, vec![
+        Index(1),
+        Key("b c".to_string()),
+        Key("d".to_string())
+    ]);
+    assert!(parse_variant_path("").is_err());
+    assert!(parse_variant_path(".a").is_err());
+    assert!(parse_variant_path("a[x]").is_err());
+    assert!(parse_variant_path("a[0").is_err());
+    assert!(parse_variant_path("a..b").is_err());
+    assert!(parse_variant_path("a.").is_err());
+    assert!(parse_variant_path("a.[0]").is_err());
+
+    let value = variant_from_slice(br#"{"a": {"b": [1, "x", null]}}"#)?;
+    let get = |path: &str| -> Result<Option<String>> {
+        let path = parse_variant_path(path)?;
+        Ok(variant_get_path(&value, &path).map(|v| v.to_string()))
+    };
+    assert_eq!(get("a.b")?, Some("[1,\"x\",null]".to_string()));
+    assert_eq!(get("a.b[1]")?, Some("\"x\"".to_string()));
+    assert_eq!(get("a.b[3]")?, None);
+    assert_eq!(get("a.c")?, None);
+    assert_eq!(get("a[0]")?, None);
+    Ok(())
+}
+
+#[test]
+fn test_variant_values() -> Result<()> {
+    assert_eq!(
+        variant_normalize(b" { \"a\" : [1, 2.5, true] } ")?,
+        b"{\"a\":[1,2.5,true]}".to_vec()
+    );
+    assert!(variant_normalize(b"{a: 1}").is_err());
+
+    let text =
+        |v: &[u8]| -> Result<Option<String>> { Ok(variant_to_text(&variant_from_slice(v)?)) };
+    assert_eq!(text(b"\"abc\"")?, Some("abc".to_string()));
+    assert_eq!(text(b"12.5")?, Some("12.5".to_string()));
+    assert_eq!(text(b"[1]")?, Some("[1]".to_string()));
+    assert_eq!(text(b"null")?, None);
+
+    let boolean =
+        |v: &[u8]| -> Result<Option<bool>> { Ok(variant_to_bool(&variant_from_slice(v)?)) };
+    assert_eq!(boolean(b"true")?, Some(true));
+    assert_eq!(boolean(b"0")?, Some(false));
+    assert_eq!(boolean(b"\"false\"")?, Some(false));
+    assert_eq!(boolean(b"{}")?, None);
+
+    assert_eq!(
+        compare_coercion(&DataType::Variant, &DataType::Int64)?,
+        DataType::Int64
+    );
+    assert_eq!(
+        compare_coercion(&DataType::String, &DataType::Variant)?,
+        DataType::String
+    );
+    Ok(())
+}
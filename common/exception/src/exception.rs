@@ -30,6 +30,7 @@ use tonic::Status;
 
 pub static ABORT_SESSION: u16 = 42;
 pub static ABORT_QUERY: u16 = 43;
+pub static QUERY_TIMEOUT: u16 = 60;
 
 pub static UNKNOWN_USER: u16 = 3000;
 
@@ -188,6 +189,9 @@ build_exceptions! {
     SHA1CheckFailed(57),
     UnknownColumn(58),
     InvalidSourceFormat(59),
+    QueryTimeout(QUERY_TIMEOUT),
+    TooManyRowsOrBytesToRead(61),
+    NullValueInNotNullColumn(62),
 
     // uncategorized
     UnexpectedResponseType(600),
@@ -28,6 +28,7 @@ use num::NumCast;
 use super::StateAddr;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::aggregator_common::SumAdd;
 use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
 use crate::with_match_primitive_type;
@@ -40,27 +41,29 @@ struct AggregateAvgState<T: DFPrimitiveType> {
 }
 
 impl<T> AggregateAvgState<T>
-where T: std::ops::Add<Output = T> + DFPrimitiveType
+where T: SumAdd + DFPrimitiveType
 {
     #[inline(always)]
-    fn add(&mut self, value: &Option<T>, count: u64) {
+    fn add(&mut self, value: &Option<T>, count: u64) -> Result<()> {
         if let Some(v) = value {
-            self.value = self.value.add(*v);
+            self.value = self.value.sum_add(*v)?;
             self.count += count;
         }
+        Ok(())
     }
 
     #[inline(always)]
-    fn merge(&mut self, other: &Self) {
-        self.value = self.value.add(other.value);
+    fn merge(&mut self, other: &Self) -> Result<()> {
+        self.value = self.value.sum_add(other.value)?;
         self.count += other.count;
+        Ok(())
     }
 }
 
 #[derive(Clone)]
 pub struct AggregateAvgFunction<T, SumT> {
     display_name: String,
-    _arguments: Vec<DataField>,
+    arguments: Vec<DataField>,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -68,7 +71,7 @@ pub struct AggregateAvgFunction<T, SumT> {
 impl<T, SumT> AggregateFunction for AggregateAvgFunction<T, SumT>
 where
     T: DFPrimitiveType + AsPrimitive<SumT>,
-    SumT: DFPrimitiveType + std::ops::Add<Output = SumT>,
+    SumT: DFPrimitiveType + SumAdd,
     Option<SumT>: Into<DataValue>,
 {
     fn name(&self) -> &str {
@@ -76,6 +79,11 @@ where
     }
 
     fn return_type(&self) -> Result<DataType> {
+        // the average of the decimals keeps the scale of the argument
+        if let DataType::Decimal(_, scale) = self.arguments[0].data_type() {
+            return Ok(DataType::Decimal(DECIMAL_MAX_PRECISION, *scale));
+        }
+
         Ok(DataType::Float64)
     }
 
@@ -96,12 +104,21 @@ where
 
     fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
         let state = place.get::<AggregateAvgState<SumT>>();
+
+        // the sum of the series does not check overflow, add the decimals one by one
+        if self.arguments[0].data_type().is_decimal() {
+            let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+            return array
+                .into_iter()
+                .flatten()
+                .try_for_each(|v| state.add(&Some(v.as_()), 1));
+        }
+
         let value = arrays[0].sum()?;
         let count = arrays[0].len() - arrays[0].null_count();
         let opt_sum: Option<SumT> = DFTryFrom::try_from(value).ok();
 
-        state.add(&opt_sum, count as u64);
-        Ok(())
+        state.add(&opt_sum, count as u64)
     }
 
     fn accumulate_keys(
@@ -113,13 +130,14 @@ where
     ) -> Result<()> {
         let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
 
-        array.into_iter().zip(places.iter()).for_each(|(v, place)| {
-            let place = place.next(offset);
-            let state = place.get::<AggregateAvgState<SumT>>();
-            state.add(&v.map(|v| v.as_()), 1);
-        });
-
-        Ok(())
+        array
+            .into_iter()
+            .zip(places.iter())
+            .try_for_each(|(v, place)| {
+                let place = place.next(offset);
+                let state = place.get::<AggregateAvgState<SumT>>();
+                state.add(&v.map(|v| v.as_()), 1)
+            })
     }
 
     fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
@@ -138,13 +156,20 @@ where
     fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
         let state = place.get::<AggregateAvgState<SumT>>();
         let rhs = rhs.get::<AggregateAvgState<SumT>>();
-        state.merge(rhs);
-        Ok(())
+        state.merge(rhs)
     }
 
     fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
         let state = place.get::<AggregateAvgState<SumT>>();
 
+        if self.arguments[0].data_type().is_decimal() {
+            if state.count == 0 {
+                return Ok(DataValue::Decimal128(None));
+            }
+            let v: i128 = NumCast::from(state.value).unwrap_or_default();
+            return Ok(DataValue::Decimal128(Some(v / state.count as i128)));
+        }
+
         if state.count == 0 {
             return Ok(DataValue::Float64(None));
         }
@@ -162,7 +187,7 @@ impl<T, SumT> fmt::Display for AggregateAvgFunction<T, SumT> {
 impl<T, SumT> AggregateAvgFunction<T, SumT>
 where
     T: DFPrimitiveType + AsPrimitive<SumT>,
-    SumT: DFPrimitiveType + std::ops::Add<Output = SumT>,
+    SumT: DFPrimitiveType + SumAdd,
     Option<SumT>: Into<DataValue>,
 {
     pub fn try_create(
@@ -171,7 +196,7 @@ where
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_string(),
-            _arguments: arguments,
+            arguments,
             t: PhantomData,
             sum_t: PhantomData,
         }))
@@ -186,6 +211,10 @@ pub fn try_create_aggregate_avg_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if data_type.is_decimal() {
+        return AggregateAvgFunction::<i128, i128>::try_create(display_name, arguments);
+    }
+
     with_match_primitive_type!(data_type, |$T| {
         AggregateAvgFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
             display_name,
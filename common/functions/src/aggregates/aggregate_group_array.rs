@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+
+pub struct AggregateGroupArrayState {
+    values: Vec<DataValue>,
+}
+
+impl AggregateGroupArrayState {
+    fn add(&mut self, value: DataValue) {
+        // NULLs are skipped
+        if !value.is_null() {
+            self.values.push(value);
+        }
+    }
+}
+
+/// `groupArray(x)` collects the values of the group into an array.
+#[derive(Clone)]
+pub struct AggregateGroupArrayFunction {
+    display_name: String,
+    arguments: Vec<DataField>,
+}
+
+impl AggregateGroupArrayFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<Arc<dyn AggregateFunction>> {
+        assert_unary_arguments(display_name, arguments.len())?;
+        Ok(Arc::new(AggregateGroupArrayFunction {
+            display_name: display_name.to_string(),
+            arguments,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+}
+
+impl AggregateFunction for AggregateGroupArrayFunction {
+    fn name(&self) -> &str {
+        "AggregateGroupArrayFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        let element_type = self.arguments[0].data_type().clone();
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            element_type,
+            true,
+        ))))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateGroupArrayState { values: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateGroupArrayState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        for row in 0..input_rows {
+            state.add(arrays[0].try_get(row)?);
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let place = place.next(offset);
+            let state = place.get::<AggregateGroupArrayState>();
+            state.add(arrays[0].try_get(row)?);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        writer.write_uvarint(state.values.len() as u64)?;
+        for value in state.values.iter() {
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        let size = reader.read_uvarint()?;
+        state.values = Vec::with_capacity(size as usize);
+        for _i in 0..size {
+            state.values.push(DataValue::deserialize(reader)?);
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        let rhs = rhs.get::<AggregateGroupArrayState>();
+        state.values.extend_from_slice(&rhs.values);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateGroupArrayState>();
+        let element_type = self.arguments[0].data_type().clone();
+        Ok(DataValue::List(Some(state.values.clone()), element_type))
+    }
+}
+
+impl fmt::Display for AggregateGroupArrayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
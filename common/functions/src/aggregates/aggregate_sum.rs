@@ -29,6 +29,7 @@ use super::AggregateFunctionRef;
 use super::StateAddr;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::aggregator_common::SumAdd;
 use crate::aggregates::AggregateFunction;
 use crate::with_match_primitive_type;
 
@@ -38,15 +39,16 @@ struct AggregateSumState<T> {
 
 impl<T> AggregateSumState<T>
 where
-    T: std::ops::Add<Output = T> + Copy + Clone,
+    T: SumAdd + Copy + Clone,
     Option<T>: BinarySer + BinaryDe,
 {
     #[inline(always)]
-    fn add(&mut self, other: T) {
+    fn add(&mut self, other: T) -> Result<()> {
         match &self.value {
-            Some(a) => self.value = Some(a.add(other)),
+            Some(a) => self.value = Some(a.sum_add(other)?),
             None => self.value = Some(other),
         }
+        Ok(())
     }
 
     fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
@@ -62,7 +64,7 @@ where
 #[derive(Clone)]
 pub struct AggregateSumFunction<T, SumT> {
     display_name: String,
-    _arguments: Vec<DataField>,
+    arguments: Vec<DataField>,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -70,7 +72,7 @@ pub struct AggregateSumFunction<T, SumT> {
 impl<T, SumT> AggregateFunction for AggregateSumFunction<T, SumT>
 where
     T: DFPrimitiveType + AsPrimitive<SumT>,
-    SumT: DFPrimitiveType + std::ops::Add<Output = SumT>,
+    SumT: DFPrimitiveType + SumAdd,
     Option<SumT>: Into<DataValue>,
 {
     fn name(&self) -> &str {
@@ -78,6 +80,10 @@ where
     }
 
     fn return_type(&self) -> Result<DataType> {
+        if let DataType::Decimal(_, scale) = self.arguments[0].data_type() {
+            return Ok(DataType::Decimal(DECIMAL_MAX_PRECISION, *scale));
+        }
+
         let value: DataValue = Some(SumT::default()).into();
 
         Ok(value.data_type())
@@ -96,12 +102,22 @@ where
     }
 
     fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateSumState<SumT>>();
+
+        // the sum of the series does not check overflow, add the decimals one by one
+        if self.arguments[0].data_type().is_decimal() {
+            let darray: &DFPrimitiveArray<T> = arrays[0].static_cast();
+            return darray
+                .into_iter()
+                .flatten()
+                .try_for_each(|v| state.add(v.as_()));
+        }
+
         let value = arrays[0].sum()?;
         let opt_sum: Result<SumT> = DFTryFrom::try_from(value);
 
         if let Ok(s) = opt_sum {
-            let state = place.get::<AggregateSumState<SumT>>();
-            state.add(s);
+            state.add(s)?;
         }
 
         Ok(())
@@ -122,25 +138,24 @@ where
                 .as_slice()
                 .iter()
                 .zip(places.iter())
-                .for_each(|(v, place)| {
+                .try_for_each(|(v, place)| {
                     let place = place.next(offset);
                     let state = place.get::<AggregateSumState<SumT>>();
-                    state.add(v.as_());
-                });
+                    state.add(v.as_())
+                })
         } else {
             darray
                 .into_iter()
                 .zip(places.iter())
-                .for_each(|(c, place)| {
-                    if let Some(v) = c {
+                .try_for_each(|(c, place)| match c {
+                    Some(v) => {
                         let place = place.next(offset);
                         let state = place.get::<AggregateSumState<SumT>>();
-                        state.add(v.as_());
+                        state.add(v.as_())
                     }
-                });
+                    None => Ok(()),
+                })
         }
-
-        Ok(())
     }
 
     fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
@@ -157,7 +172,7 @@ where
         let rhs = rhs.get::<AggregateSumState<SumT>>();
         if let Some(s) = &rhs.value {
             let state = place.get::<AggregateSumState<SumT>>();
-            state.add(*s);
+            state.add(*s)?;
         }
         Ok(())
     }
@@ -177,7 +192,7 @@ impl<T, SumT> fmt::Display for AggregateSumFunction<T, SumT> {
 impl<T, SumT> AggregateSumFunction<T, SumT>
 where
     T: DFPrimitiveType + AsPrimitive<SumT>,
-    SumT: DFPrimitiveType + std::ops::Add<Output = SumT>,
+    SumT: DFPrimitiveType + SumAdd,
     Option<SumT>: Into<DataValue>,
 {
     pub fn try_create(
@@ -186,7 +201,7 @@ where
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_owned(),
-            _arguments: arguments,
+            arguments,
             t: PhantomData,
             sum_t: PhantomData,
         }))
@@ -201,6 +216,10 @@ pub fn try_create_aggregate_sum_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if data_type.is_decimal() {
+        return AggregateSumFunction::<i128, i128>::try_create(display_name, arguments);
+    }
+
     with_match_primitive_type!(data_type, |$T| {
         AggregateSumFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
              display_name,
@@ -25,6 +25,7 @@ use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
 use crate::aggregates::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use crate::aggregates::AggregateCountFunction;
 use crate::aggregates::AggregateDistinctCombinator;
+use crate::aggregates::AggregateGroupArrayFunction;
 use crate::aggregates::AggregateIfCombinator;
 
 pub struct Aggregators;
@@ -46,6 +47,7 @@ impl Aggregators {
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("groupArray", AggregateGroupArrayFunction::desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
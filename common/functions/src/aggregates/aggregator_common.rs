@@ -70,3 +70,32 @@ pub fn assert_variadic_arguments<D: Display>(
     }
     Ok(())
 }
+
+/// The accumulator types of sum and avg, the decimals fail on overflow like the
+/// decimal arithmetic does.
+pub trait SumAdd: Sized {
+    fn sum_add(self, other: Self) -> Result<Self>;
+}
+
+macro_rules! impl_sum_add {
+    ($ty:ty) => {
+        impl SumAdd for $ty {
+            #[inline(always)]
+            fn sum_add(self, other: Self) -> Result<Self> {
+                Ok(self + other)
+            }
+        }
+    };
+}
+
+impl_sum_add!(u64);
+impl_sum_add!(i64);
+impl_sum_add!(f64);
+
+impl SumAdd for i128 {
+    #[inline(always)]
+    fn sum_add(self, other: Self) -> Result<Self> {
+        self.checked_add(other)
+            .ok_or_else(|| ErrorCode::Overflow("Decimal overflow in sum"))
+    }
+}
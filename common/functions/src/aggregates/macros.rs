@@ -99,6 +99,9 @@ macro_rules! with_match_date_date_time_types {
             DataType::DateTime32(_) => {
                 $dispatch! { u32, DataType::UInt32, $($args),* }
             },
+            DataType::DateTime64(_, _) => {
+                $dispatch! { i64, DataType::Int64, $($args),* }
+            },
             _ => {},
         }
     };
@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::fmt;
 
 use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::*;
 use common_datavalues::DataSchema;
 use common_datavalues::DataValueArithmeticOperator;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::scalars::dates::IntervalFunctionFactory;
@@ -28,6 +30,7 @@ use crate::scalars::ArithmeticMinusFunction;
 use crate::scalars::ArithmeticModuloFunction;
 use crate::scalars::ArithmeticMulFunction;
 use crate::scalars::ArithmeticPlusFunction;
+use crate::scalars::CastFunction;
 use crate::scalars::Function;
 use crate::scalars::Monotonicity;
 
@@ -58,6 +61,102 @@ impl ArithmeticFunction {
     pub fn new(op: DataValueArithmeticOperator) -> Self {
         ArithmeticFunction { op }
     }
+
+    // The scale of the decimals is carried by the field, so the decimals are computed on the
+    // unscaled values after casting the operands to the right scales.
+    fn eval_decimal(
+        &self,
+        columns: &DataColumnsWithField,
+        input_rows: usize,
+    ) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let data_type = self.return_type(&args)?;
+
+        if columns.len() == 1 {
+            let array = columns[0].column().to_array()?;
+            let array = array.decimal()?;
+            let array = match self.op {
+                Minus => array.apply(|v| -v),
+                _ => array.clone(),
+            };
+            return Ok(array.into_series().into());
+        }
+
+        if data_type.is_floating() {
+            let lhs = cast_column(&columns[0], &data_type, input_rows)?;
+            let rhs = cast_column(&columns[1], &data_type, input_rows)?;
+            return self.eval(&[lhs, rhs], input_rows);
+        }
+
+        let (precision, scale) = match data_type {
+            DataType::Decimal(precision, scale) => (precision, scale),
+            _ => unreachable!(),
+        };
+        let lhs_scale = decimal_scale(columns[0].data_type());
+        let rhs_scale = decimal_scale(columns[1].data_type());
+
+        // Plus, minus, modulo and int div work on the operands with the same scale.
+        let (lhs_to_scale, rhs_to_scale) = match self.op {
+            Mul | Div => (lhs_scale, rhs_scale),
+            _ => (
+                cmp::max(lhs_scale, rhs_scale),
+                cmp::max(lhs_scale, rhs_scale),
+            ),
+        };
+        let lhs = cast_column(
+            &columns[0],
+            &DataType::Decimal(DECIMAL_MAX_PRECISION, lhs_to_scale),
+            input_rows,
+        )?;
+        let rhs = cast_column(
+            &columns[1],
+            &DataType::Decimal(DECIMAL_MAX_PRECISION, rhs_to_scale),
+            input_rows,
+        )?;
+        let lhs = lhs.column().to_array()?;
+        let rhs = rhs.column().to_array()?;
+
+        let overflow = || ErrorCode::Overflow(format!("Decimal overflow in {}", self.op));
+        let div_factor = match self.op {
+            Div => 10_i128
+                .checked_pow((scale + rhs_scale - lhs_scale) as u32)
+                .ok_or_else(overflow)?,
+            _ => 1,
+        };
+        let values = lhs
+            .decimal()?
+            .into_iter()
+            .zip(rhs.decimal()?.into_iter())
+            .map(|(a, b)| match (a, b) {
+                (Some(a), Some(b)) => {
+                    let value = match self.op {
+                        Plus => a.checked_add(*b),
+                        Minus => a.checked_sub(*b),
+                        Mul => a
+                            .checked_mul(*b)
+                            .map(|v| decimal_rescale(v, lhs_scale + rhs_scale, scale)),
+                        Div | IntDiv | Modulo if *b == 0 => {
+                            return Err(ErrorCode::BadArguments("Division by zero"));
+                        }
+                        Div => a.checked_mul(div_factor).map(|v| v / b),
+                        IntDiv => Some(a / b),
+                        Modulo => Some(a % b),
+                    };
+                    value
+                        .filter(|v| decimal_fits_precision(*v, precision))
+                        .map(Some)
+                        .ok_or_else(overflow)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DFDecimalArray::new_from_opt_slice(&values)
+            .into_series()
+            .into())
+    }
 }
 
 impl Function for ArithmeticFunction {
@@ -67,6 +166,9 @@ impl Function for ArithmeticFunction {
 
     fn return_type(&self, args: &[DataType]) -> Result<DataType> {
         if args.len() == 1 {
+            if args[0].is_decimal() {
+                return Ok(args[0].clone());
+            }
             return numerical_unary_arithmetic_coercion(&self.op, &args[0]);
         }
 
@@ -76,6 +178,9 @@ impl Function for ArithmeticFunction {
         if args[0].is_date_or_date_time() || args[1].is_date_or_date_time() {
             return datetime_arithmetic_coercion(&self.op, &args[0], &args[1]);
         }
+        if args[0].is_decimal() || args[1].is_decimal() {
+            return decimal_arithmetic_coercion(&self.op, &args[0], &args[1]);
+        }
         numerical_arithmetic_coercion(&self.op, &args[0], &args[1])
     }
 
@@ -83,7 +188,11 @@ impl Function for ArithmeticFunction {
         Ok(false)
     }
 
-    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if columns.iter().any(|c| c.data_type().is_decimal()) {
+            return self.eval_decimal(columns, input_rows);
+        }
+
         let result: DataColumn = {
             // Some logic type need DateType information, try arithmetic on column with field first.
             if let Some(f) = IntervalFunctionFactory::try_get_arithmetic_func(columns) {
@@ -137,3 +246,23 @@ impl fmt::Display for ArithmeticFunction {
         write!(f, "{}", self.op)
     }
 }
+
+#[inline]
+fn decimal_scale(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Decimal(_, scale) => *scale,
+        _ => 0,
+    }
+}
+
+fn cast_column(
+    column: &DataColumnWithField,
+    data_type: &DataType,
+    input_rows: usize,
+) -> Result<DataColumnWithField> {
+    let new_col = CastFunction::create("cast".to_string(), data_type.clone())?
+        .eval(&[column.clone()], input_rows)?;
+
+    let new_field = DataField::new(column.field().name(), data_type.clone(), false);
+    Ok(DataColumnWithField::new(new_col, new_field))
+}
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array_concat::ArrayConcatFunction;
+use super::array_constructor::ArrayConstructorFunction;
+use super::array_element::ArrayElementFunction;
+use super::array_has::ArrayHasFunction;
+use super::array_join::ArrayJoinFunction;
+use super::array_length::ArrayLengthFunction;
+use super::array_slice::ArraySliceFunction;
+use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::CastFunction;
+
+#[derive(Clone)]
+pub struct ArrayFunction {}
+
+impl ArrayFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("array", ArrayConstructorFunction::desc());
+        factory.register("length", ArrayLengthFunction::desc());
+        factory.register("has", ArrayHasFunction::desc(false));
+        factory.register("indexOf", ArrayHasFunction::desc(true));
+        factory.register("arrayConcat", ArrayConcatFunction::desc());
+        factory.register("arraySlice", ArraySliceFunction::desc());
+        factory.register("arrayElement", ArrayElementFunction::desc());
+        factory.register("arrayJoin", ArrayJoinFunction::desc());
+        factory.register("unnest", ArrayJoinFunction::desc());
+    }
+}
+
+/// Checks the argument is an array, returns the element type of it.
+pub(crate) fn check_array_argument(
+    name: &str,
+    pos: usize,
+    data_type: &DataType,
+) -> Result<DataType> {
+    match data_type {
+        DataType::List(field) => Ok(field.data_type().clone()),
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Expected parameter {} of {} is array, but got {}",
+            pos, name, other
+        ))),
+    }
+}
+
+/// Checks the arrays can hold the elements of the type, the nested arrays
+/// and the decimals are not supported yet.
+pub(crate) fn check_array_element_type(name: &str, data_type: &DataType) -> Result<()> {
+    match data_type_physical(data_type.clone()) {
+        DataType::Boolean
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::String => Ok(()),
+        _ => Err(ErrorCode::IllegalDataType(format!(
+            "The element type {} of {} is not supported",
+            data_type, name
+        ))),
+    }
+}
+
+pub(crate) fn array_type(element_type: DataType) -> DataType {
+    DataType::List(Box::new(DataField::new("item", element_type, true)))
+}
+
+/// Casts the column with field to the type, used to unify the types of the arguments.
+pub(crate) fn cast_column(
+    column: &DataColumnWithField,
+    data_type: &DataType,
+    input_rows: usize,
+) -> Result<DataColumn> {
+    CastFunction::create("cast".to_string(), data_type.clone())?.eval(&[column.clone()], input_rows)
+}
+
+/// Gets the elements of the array at the row, None if the array is null.
+pub(crate) fn array_values_at(column: &DataColumn, row: usize) -> Result<Option<Vec<DataValue>>> {
+    match column.try_get(row)? {
+        DataValue::List(values, _) => Ok(values),
+        DataValue::Null => Ok(None),
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Expected array value, but got {:?}",
+            other
+        ))),
+    }
+}
+
+/// Builds the array column from the elements of the rows, None if the row is null.
+pub(crate) fn build_array_column<I>(
+    element_type: &DataType,
+    rows: I,
+    input_rows: usize,
+) -> Result<DataColumn>
+where
+    I: Iterator<Item = Result<Option<Vec<DataValue>>>>,
+{
+    let physical_type = data_type_physical(element_type.clone());
+    let mut builder = get_list_builder(&physical_type, input_rows * 4, input_rows);
+    for row in rows {
+        match row? {
+            Some(values) => {
+                let series = DataValue::try_into_data_array(&values, element_type)?;
+                builder.append_series(&series);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_series().into())
+}
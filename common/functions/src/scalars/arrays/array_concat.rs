@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array::array_type;
+use super::array::array_values_at;
+use super::array::build_array_column;
+use super::array::cast_column;
+use super::array::check_array_argument;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `arrayConcat(arr, ...)` concatenates the arrays, the result is NULL if any array is NULL.
+#[derive(Clone)]
+pub struct ArrayConcatFunction {
+    display_name: String,
+}
+
+impl ArrayConcatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayConcatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn element_type(&self, args: &[DataType]) -> Result<DataType> {
+        let element_types = args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| check_array_argument(&self.display_name, i + 1, arg))
+            .collect::<Result<Vec<_>>>()?;
+        aggregate_types(&element_types)
+    }
+}
+
+impl Function for ArrayConcatFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        Ok(array_type(self.element_type(args)?))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let element_type = self.element_type(&args)?;
+        let arrays = columns
+            .iter()
+            .map(|c| cast_column(c, &array_type(element_type.clone()), input_rows))
+            .collect::<Result<Vec<_>>>()?;
+
+        let rows = (0..input_rows).map(|row| -> Result<Option<Vec<DataValue>>> {
+            let mut result = vec![];
+            for array in arrays.iter() {
+                match array_values_at(array, row)? {
+                    Some(values) => result.extend(values),
+                    None => return Ok(None),
+                }
+            }
+            Ok(Some(result))
+        });
+        build_array_column(&element_type, rows, input_rows)
+    }
+}
+
+impl fmt::Display for ArrayConcatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
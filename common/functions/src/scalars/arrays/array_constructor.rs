@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array::array_type;
+use super::array::build_array_column;
+use super::array::cast_column;
+use super::array::check_array_element_type;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `array(x, ...)` builds an array from the arguments, it's also the result of the
+/// array literals `[x, ...]` and `ARRAY[x, ...]`.
+#[derive(Clone)]
+pub struct ArrayConstructorFunction {
+    display_name: String,
+}
+
+impl ArrayConstructorFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayConstructorFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn element_type(args: &[DataType]) -> Result<DataType> {
+        match args.is_empty() {
+            // the empty arrays and the arrays of NULLs hold UInt8 elements
+            true => Ok(DataType::UInt8),
+            false => match aggregate_types(args)? {
+                DataType::Null => Ok(DataType::UInt8),
+                element_type => Ok(element_type),
+            },
+        }
+    }
+}
+
+impl Function for ArrayConstructorFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((0, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let element_type = Self::element_type(args)?;
+        check_array_element_type(&self.display_name, &element_type)?;
+        Ok(array_type(element_type))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let element_type = Self::element_type(&args)?;
+
+        let elements = columns
+            .iter()
+            .map(|c| cast_column(c, &element_type, input_rows))
+            .collect::<Result<Vec<_>>>()?;
+
+        let rows = (0..input_rows).map(|row| -> Result<Option<Vec<DataValue>>> {
+            let values = elements
+                .iter()
+                .map(|c| c.try_get(row))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Some(values))
+        });
+        build_array_column(&element_type, rows, input_rows)
+    }
+}
+
+impl fmt::Display for ArrayConstructorFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array::array_values_at;
+use super::array::cast_column;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::map_entries_at;
+use crate::scalars::Function;
+
+/// `arrayElement(arr, n)` gets the n-th element of the array, counting from the end if n
+/// is negative, `arrayElement(m, key)` gets the value of the key in the map. It's also the
+/// result of the subscripts `arr[n]` and `m[key]`, NULL is returned if not found.
+#[derive(Clone)]
+pub struct ArrayElementFunction {
+    display_name: String,
+}
+
+impl ArrayElementFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayElementFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn element_at(values: Vec<DataValue>, index: i64) -> Option<DataValue> {
+        let len = values.len() as i64;
+        let pos = match index {
+            index if index > 0 && index <= len => index - 1,
+            index if index < 0 && -index <= len => len + index,
+            _ => return None,
+        };
+        values.into_iter().nth(pos as usize)
+    }
+}
+
+impl Function for ArrayElementFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(field) if args[1].is_integer() || args[1].is_null() => {
+                Ok(field.data_type().clone())
+            }
+            DataType::Map(key, value) => {
+                merge_types(key, &args[1])?;
+                Ok(value.as_ref().clone())
+            }
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "{} expects array and integer or map and key, but got {} and {}",
+                self.display_name, other, args[1]
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = [
+            columns[0].data_type().clone(),
+            columns[1].data_type().clone(),
+        ];
+        let return_type = self.return_type(&args)?;
+
+        let values = match columns[0].data_type() {
+            DataType::Map(key, _) => {
+                let needles = cast_column(&columns[1], key, input_rows)?;
+                (0..input_rows)
+                    .map(|row| -> Result<DataValue> {
+                        let needle = needles.try_get(row)?;
+                        let value =
+                            map_entries_at(columns[0].column(), row)?.and_then(|(keys, values)| {
+                                let pos = keys.iter().position(|k| k == &needle)?;
+                                values.into_iter().nth(pos)
+                            });
+                        Ok(value.unwrap_or_else(|| DataValue::from(&return_type)))
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+            _ => {
+                let indexes = cast_column(&columns[1], &DataType::Int64, input_rows)?;
+                (0..input_rows)
+                    .map(|row| -> Result<DataValue> {
+                        let value = match indexes.try_get(row)? {
+                            DataValue::Int64(Some(index)) => {
+                                array_values_at(columns[0].column(), row)?
+                                    .and_then(|values| Self::element_at(values, index))
+                            }
+                            _ => None,
+                        };
+                        Ok(value.unwrap_or_else(|| DataValue::from(&return_type)))
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+        };
+
+        let series = DataValue::try_into_data_array(&values, &return_type)?;
+        Ok(series.into())
+    }
+}
+
+impl fmt::Display for ArrayElementFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array::array_type;
+use super::array::array_values_at;
+use super::array::cast_column;
+use super::array::check_array_argument;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `has(arr, x)` checks whether the array contains the element, `indexOf(arr, x)` returns
+/// the 1-based position of the first matched element, 0 if not found.
+#[derive(Clone)]
+pub struct ArrayHasFunction {
+    display_name: String,
+    index_of: bool,
+}
+
+impl ArrayHasFunction {
+    pub fn try_create(display_name: &str, index_of: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayHasFunction {
+            display_name: display_name.to_string(),
+            index_of,
+        }))
+    }
+
+    pub fn desc(index_of: bool) -> FunctionDescription {
+        FunctionDescription::creator(Box::new(move |display_name| {
+            Self::try_create(display_name, index_of)
+        }))
+        .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ArrayHasFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let element_type = check_array_argument(&self.display_name, 1, &args[0])?;
+        merge_types(&element_type, &args[1])?;
+        match self.index_of {
+            true => Ok(DataType::UInt64),
+            false => Ok(DataType::Boolean),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let element_type = check_array_argument(&self.display_name, 1, columns[0].data_type())?;
+        let element_type = merge_types(&element_type, columns[1].data_type())?;
+
+        // the elements are compared in the common type of the elements and the needle
+        let arrays = cast_column(&columns[0], &array_type(element_type.clone()), input_rows)?;
+        let needles = cast_column(&columns[1], &element_type, input_rows)?;
+
+        let positions = (0..input_rows).map(|row| -> Result<Option<Option<u64>>> {
+            let needle = needles.try_get(row)?;
+            Ok(array_values_at(&arrays, row)?.map(|values| {
+                values
+                    .iter()
+                    .position(|v| v == &needle)
+                    .map(|pos| pos as u64 + 1)
+            }))
+        });
+
+        let column: DataColumn = match self.index_of {
+            true => positions
+                .map(|pos| -> Result<Option<u64>> { Ok(pos?.map(|pos| pos.unwrap_or(0))) })
+                .collect::<Result<DFUInt64Array>>()?
+                .into_series()
+                .into(),
+            false => positions
+                .map(|pos| -> Result<Option<bool>> { Ok(pos?.map(|pos| pos.is_some())) })
+                .collect::<Result<DFBooleanArray>>()?
+                .into_series()
+                .into(),
+        };
+        Ok(column)
+    }
+}
+
+impl fmt::Display for ArrayHasFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
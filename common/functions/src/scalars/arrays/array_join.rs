@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array::check_array_argument;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `arrayJoin(arr)` or `unnest(arr)` expands each row into the rows of the array elements,
+/// the other columns are replicated. It changes the number of the rows, so it's evaluated
+/// by the expression executor rather than `eval`.
+#[derive(Clone)]
+pub struct ArrayJoinFunction {
+    display_name: String,
+}
+
+impl ArrayJoinFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayJoinFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        // not deterministic, it must not be folded as a constant
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default())
+    }
+
+    pub fn is_array_join(func_name: &str) -> bool {
+        func_name.eq_ignore_ascii_case("arrayJoin") || func_name.eq_ignore_ascii_case("unnest")
+    }
+}
+
+impl Function for ArrayJoinFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_array_argument(&self.display_name, 1, &args[0])
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, _columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        Err(ErrorCode::LogicalError(format!(
+            "{} can only be evaluated by the expression executor",
+            self.display_name
+        )))
+    }
+}
+
+impl fmt::Display for ArrayJoinFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
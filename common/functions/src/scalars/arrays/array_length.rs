@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array::array_values_at;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `length(x)` returns the number of the elements of an array or the bytes of a string.
+#[derive(Clone)]
+pub struct ArrayLengthFunction {
+    display_name: String,
+}
+
+impl ArrayLengthFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayLengthFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ArrayLengthFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(_) | DataType::String | DataType::Null => Ok(DataType::UInt64),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 1 of {} is array or string, but got {}",
+                self.display_name, other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let column = columns[0].column();
+        match columns[0].data_type() {
+            DataType::String => {
+                let array = column.to_minimal_array()?;
+                let result = array
+                    .string()?
+                    .into_iter()
+                    .map(|v| v.map(|x| x.len() as u64))
+                    .collect::<DFUInt64Array>();
+                let column: DataColumn = result.into_series().into();
+                Ok(column.resize_constant(input_rows))
+            }
+            DataType::List(_) => {
+                let result = (0..input_rows)
+                    .map(|row| -> Result<Option<u64>> {
+                        Ok(array_values_at(column, row)?.map(|v| v.len() as u64))
+                    })
+                    .collect::<Result<DFUInt64Array>>()?;
+                Ok(result.into_series().into())
+            }
+            _ => Ok(DataColumn::Constant(DataValue::UInt64(None), input_rows)),
+        }
+    }
+}
+
+impl fmt::Display for ArrayLengthFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array::array_values_at;
+use super::array::build_array_column;
+use super::array::check_array_argument;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `arraySlice(arr, offset[, length])` returns a slice of the array. The offset is 1-based
+/// and counts from the end if it's negative, a negative length leaves out the elements
+/// at the end.
+#[derive(Clone)]
+pub struct ArraySliceFunction {
+    display_name: String,
+}
+
+impl ArraySliceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArraySliceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    /// Returns the range of the slice in the array of the size.
+    fn slice_range(size: usize, offset: i64, length: Option<i64>) -> (usize, usize) {
+        let size = size as i64;
+        let start = match offset {
+            0 => return (0, 0),
+            offset if offset > 0 => offset - 1,
+            offset => size + offset,
+        }
+        .clamp(0, size);
+        let end = match length {
+            None => size,
+            Some(length) if length >= 0 => start.saturating_add(length),
+            Some(length) => size + length,
+        }
+        .clamp(start, size);
+        (start as usize, end as usize)
+    }
+}
+
+impl Function for ArraySliceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_array_argument(&self.display_name, 1, &args[0])?;
+        for (i, arg) in args.iter().enumerate().skip(1) {
+            if !arg.is_integer() && arg != &DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected parameter {} of {} is integer, but got {}",
+                    i + 1,
+                    self.display_name,
+                    arg
+                )));
+            }
+        }
+        Ok(args[0].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let element_type = check_array_argument(&self.display_name, 1, columns[0].data_type())?;
+
+        let rows = (0..input_rows).map(|row| -> Result<Option<Vec<DataValue>>> {
+            let offset = columns[1].column().try_get(row)?;
+            let length = match columns.get(2) {
+                Some(c) => Some(c.column().try_get(row)?),
+                None => None,
+            };
+            if offset.is_null() || length.as_ref().map_or(false, |l| l.is_null()) {
+                return Ok(None);
+            }
+            let offset = offset.as_i64()?;
+            let length = length.map(|l| l.as_i64()).transpose()?;
+
+            Ok(array_values_at(columns[0].column(), row)?.map(|values| {
+                let (start, end) = Self::slice_range(values.len(), offset, length);
+                values[start..end].to_vec()
+            }))
+        });
+        build_array_column(&element_type, rows, input_rows)
+    }
+}
+
+impl fmt::Display for ArraySliceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
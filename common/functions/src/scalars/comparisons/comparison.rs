@@ -24,6 +24,7 @@ use crate::scalars::CastFunction;
 use crate::scalars::ComparisonEqFunction;
 use crate::scalars::ComparisonGtEqFunction;
 use crate::scalars::ComparisonGtFunction;
+use crate::scalars::ComparisonInFunction;
 use crate::scalars::ComparisonLikeFunction;
 use crate::scalars::ComparisonLtEqFunction;
 use crate::scalars::ComparisonLtFunction;
@@ -47,6 +48,10 @@ impl ComparisonFunction {
         factory.register("<>", ComparisonNotEqFunction::desc());
         factory.register("like", ComparisonLikeFunction::desc());
         factory.register("not like", ComparisonNotLikeFunction::desc());
+        factory.register("in", ComparisonInFunction::desc());
+        factory.register("not in", ComparisonInFunction::not_desc());
+        factory.register("in_subquery", ComparisonInFunction::subquery_desc());
+        factory.register("not_in_subquery", ComparisonInFunction::not_subquery_desc());
     }
 
     pub fn try_create_func(op: DataValueComparisonOperator) -> Result<Box<dyn Function>> {
@@ -72,6 +77,14 @@ impl Function for ComparisonFunction {
             let compare_coercion_type =
                 compare_coercion(columns[0].data_type(), columns[1].data_type())?;
 
+            // LowCardinality is compared with its inner type without the cast, the other
+            // side is encoded by the comparison so that the constants stay as the scalars.
+            if compare_coercion_type.is_low_cardinality() {
+                return columns[0]
+                    .column()
+                    .compare(self.op.clone(), columns[1].column());
+            }
+
             let col0 = cast_column(&columns[0], compare_coercion_type.clone(), input_rows)?;
             let col1 = cast_column(&columns[1], compare_coercion_type, input_rows)?;
             return self.eval(&[col0, col1], input_rows);
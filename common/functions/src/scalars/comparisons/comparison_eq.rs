@@ -32,7 +32,8 @@ impl ComparisonEqFunction {
             FunctionFeatures::default()
                 .deterministic()
                 .negative_function("<>")
-                .bool_function(),
+                .bool_function()
+                .low_cardinality_function(),
         )
     }
 }
@@ -0,0 +1,252 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `x IN (v1, v2, ...)` and `x IN (subquery)`, the subquery is the constant list of its result set.
+#[derive(Clone)]
+pub struct ComparisonInFunction {
+    negated: bool,
+    subquery: bool,
+}
+
+impl ComparisonInFunction {
+    pub fn try_create_in(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ComparisonInFunction {
+            negated: false,
+            subquery: false,
+        }))
+    }
+
+    pub fn try_create_not_in(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ComparisonInFunction {
+            negated: true,
+            subquery: false,
+        }))
+    }
+
+    pub fn try_create_in_subquery(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ComparisonInFunction {
+            negated: false,
+            subquery: true,
+        }))
+    }
+
+    pub fn try_create_not_in_subquery(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ComparisonInFunction {
+            negated: true,
+            subquery: true,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_in)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("not in")
+                .bool_function(),
+        )
+    }
+
+    pub fn not_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_not_in)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("in")
+                .bool_function(),
+        )
+    }
+
+    pub fn subquery_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_in_subquery)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("not_in_subquery")
+                .bool_function(),
+        )
+    }
+
+    pub fn not_subquery_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_not_in_subquery)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("in_subquery")
+                .bool_function(),
+        )
+    }
+
+    // The values of the list, they are the items of the constant list for the subquery.
+    fn list_columns(&self, columns: &DataColumnsWithField) -> Result<Vec<DataColumn>> {
+        if !self.subquery {
+            return Ok(columns[1..]
+                .iter()
+                .map(|column| column.column().clone())
+                .collect());
+        }
+
+        if columns.len() != 2 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "The IN subquery expect 2 arguments, but got {}",
+                columns.len()
+            )));
+        }
+        match columns[1].column() {
+            DataColumn::Constant(DataValue::List(values, _), size) => Ok(values
+                .iter()
+                .flatten()
+                .map(|value| DataColumn::Constant(value.clone(), *size))
+                .collect()),
+            DataColumn::Constant(DataValue::Struct(_), _) => Err(ErrorCode::BadArguments(
+                "The subquery of IN must return a single column",
+            )),
+            _ => Err(ErrorCode::BadArguments(
+                "The subquery of IN must be the constant list of its result set",
+            )),
+        }
+    }
+}
+
+impl Function for ComparisonInFunction {
+    fn name(&self) -> &str {
+        "ComparisonInFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    // NULL IN (...) and x IN (..., NULL) without a match are NULL
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let list = self.list_columns(columns)?;
+
+        let mut data_type = columns[0].data_type().remove_low_cardinality();
+        for column in &list {
+            let value_type = column.data_type().remove_low_cardinality();
+            data_type = compare_coercion(&data_type, &value_type)?;
+        }
+
+        let cast = |column: &DataColumn| -> Result<DataColumn> {
+            match column {
+                DataColumn::Constant(value, size) => {
+                    let value = match column.data_type() == data_type || value.is_null() {
+                        true => value.clone(),
+                        false => value.to_array()?.cast_with_type(&data_type)?.try_get(0)?,
+                    };
+                    Ok(DataColumn::Constant(value, *size))
+                }
+                DataColumn::Array(array) => match array.data_type() == &data_type {
+                    true => Ok(column.clone()),
+                    false => Ok(array.cast_with_type(&data_type)?.into()),
+                },
+            }
+        };
+
+        let column = cast(columns[0].column())?;
+        let list = list.iter().map(cast).collect::<Result<Vec<_>>>()?;
+
+        // The constants of the list are hashed once and probed for each row,
+        // the columns of the list are compared with the column in the vectorized way.
+        let mut set = HashSet::new();
+        let mut has_null = false;
+        let mut rows_list = Vec::new();
+        for item in list {
+            match item {
+                DataColumn::Constant(value, _) if value.is_null() => has_null = true,
+                DataColumn::Constant(value, _) => {
+                    set.insert(DataGroupValue::try_from(&value)?);
+                }
+                DataColumn::Array(_) => rows_list.push(item),
+            }
+        }
+
+        let probe = |value: &DataValue| -> Result<Option<bool>> {
+            match value.is_null() {
+                true => Ok(None),
+                false => Ok(Some(set.contains(&DataGroupValue::try_from(value)?))),
+            }
+        };
+        // Not found, the NULL in the list makes the result unknown.
+        let negated = self.negated;
+        let finish = |found: Option<bool>| match found {
+            Some(false) if has_null => None,
+            found => found.map(|v| v ^ negated),
+        };
+
+        if let (DataColumn::Constant(value, _), true) = (&column, rows_list.is_empty()) {
+            let result = finish(probe(value)?);
+            return Ok(DataColumn::Constant(DataValue::Boolean(result), input_rows));
+        }
+
+        let array = column.to_array()?;
+        let mut found = match set.is_empty() {
+            true => (0..input_rows)
+                .map(|row| match array.is_null(row) {
+                    true => None,
+                    false => Some(false),
+                })
+                .collect::<Vec<_>>(),
+            false => array
+                .to_values()?
+                .iter()
+                .map(probe)
+                .collect::<Result<Vec<_>>>()?,
+        };
+
+        // x IN (..., y, ...) is true if any x = y is true, else NULL if any of them is NULL.
+        for item in &rows_list {
+            let eq = column.compare(DataValueComparisonOperator::Eq, item)?;
+            let eq = eq.to_array()?;
+            for (found, eq) in found.iter_mut().zip(eq.bool()?.into_iter()) {
+                *found = match (*found, eq) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (None, _) | (_, None) => None,
+                    _ => Some(false),
+                };
+            }
+        }
+
+        let result = found.into_iter().map(finish).collect::<Vec<_>>();
+        Ok(DFBooleanArray::new_from_opt_slice(&result)
+            .into_series()
+            .into())
+    }
+}
+
+impl fmt::Display for ComparisonInFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.negated {
+            true => write!(f, "NOT IN"),
+            false => write!(f, "IN"),
+        }
+    }
+}
@@ -32,7 +32,8 @@ impl ComparisonLikeFunction {
             FunctionFeatures::default()
                 .deterministic()
                 .negative_function("not like")
-                .bool_function(),
+                .bool_function()
+                .low_cardinality_function(),
         )
     }
 }
@@ -32,7 +32,8 @@ impl ComparisonLtFunction {
             FunctionFeatures::default()
                 .deterministic()
                 .negative_function(">=")
-                .bool_function(),
+                .bool_function()
+                .low_cardinality_function(),
         )
     }
 }
@@ -16,6 +16,7 @@ mod comparison;
 mod comparison_eq;
 mod comparison_gt;
 mod comparison_gt_eq;
+mod comparison_in;
 mod comparison_like;
 mod comparison_lt;
 mod comparison_lt_eq;
@@ -26,6 +27,7 @@ pub use comparison::ComparisonFunction;
 pub use comparison_eq::ComparisonEqFunction;
 pub use comparison_gt::ComparisonGtFunction;
 pub use comparison_gt_eq::ComparisonGtEqFunction;
+pub use comparison_in::ComparisonInFunction;
 pub use comparison_like::ComparisonLikeFunction;
 pub use comparison_lt::ComparisonLtFunction;
 pub use comparison_lt_eq::ComparisonLtEqFunction;
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::ExtremumFunction;
 use crate::scalars::IfFunction;
+use crate::scalars::MultiIfFunction;
 
 #[derive(Clone)]
 pub struct ConditionalFunction;
@@ -21,5 +23,8 @@ pub struct ConditionalFunction;
 impl ConditionalFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("if", IfFunction::desc());
+        factory.register("multiif", MultiIfFunction::desc());
+        factory.register("greatest", ExtremumFunction::greatest_desc());
+        factory.register("least", ExtremumFunction::least_desc());
     }
 }
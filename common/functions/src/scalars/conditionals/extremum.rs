@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+/// greatest(x1, x2, ...) and least(x1, x2, ...), NULL if any of the arguments is NULL.
+#[derive(Clone)]
+pub struct ExtremumFunction {
+    greatest: bool,
+}
+
+impl ExtremumFunction {
+    pub fn try_create_greatest(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ExtremumFunction { greatest: true }))
+    }
+
+    pub fn try_create_least(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ExtremumFunction { greatest: false }))
+    }
+
+    pub fn greatest_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_greatest))
+            .features(FunctionFeatures::default().deterministic().monotonicity())
+    }
+
+    pub fn least_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_least))
+            .features(FunctionFeatures::default().deterministic().monotonicity())
+    }
+
+    fn cast_column(column: &DataColumn, data_type: &DataType) -> Result<DataColumn> {
+        if &column.data_type() == data_type {
+            return Ok(column.clone());
+        }
+
+        let array = column.to_minimal_array()?.cast_with_type(data_type)?;
+        let result: DataColumn = array.into();
+        Ok(result.resize_constant(column.len()))
+    }
+}
+
+impl Function for ExtremumFunction {
+    fn name(&self) -> &str {
+        "ExtremumFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        aggregate_types(args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let types = columns
+            .iter()
+            .map(|column| column.data_type().clone())
+            .collect::<Vec<_>>();
+        let data_type = aggregate_types(&types)?;
+
+        let op = match self.greatest {
+            true => DataValueComparisonOperator::GtEq,
+            false => DataValueComparisonOperator::LtEq,
+        };
+
+        let mut result = Self::cast_column(columns[0].column(), &data_type)?;
+        let mut has_null = columns[0].column().is_null()?;
+        for column in &columns[1..] {
+            let column = column.column();
+            let value = Self::cast_column(column, &data_type)?;
+            let predicate = result.compare(op.clone(), &value)?;
+            let predicate =
+                predicate.logic(DataValueLogicOperator::And, &[predicate.is_not_null()?])?;
+            result = predicate.if_then_else(&result, &value)?;
+            has_null = has_null.logic(DataValueLogicOperator::Or, &[column.is_null()?])?;
+        }
+
+        let null = DataColumn::Constant(DataValue::Null, input_rows);
+        has_null.if_then_else(&null, &result)
+    }
+
+    // It is monotonic if all the non-constant arguments are monotonic in the same direction.
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        let mut monotonicity = Monotonicity::create_constant();
+        for arg in args {
+            if !arg.is_monotonic {
+                return Ok(Monotonicity::default());
+            }
+
+            if arg.is_constant {
+                continue;
+            }
+
+            if !monotonicity.is_constant && monotonicity.is_positive != arg.is_positive {
+                return Ok(Monotonicity::default());
+            }
+
+            monotonicity = Monotonicity::create(true, arg.is_positive, false);
+        }
+
+        Ok(monotonicity)
+    }
+}
+
+impl fmt::Display for ExtremumFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.greatest {
+            true => write!(f, "greatest"),
+            false => write!(f, "least"),
+        }
+    }
+}
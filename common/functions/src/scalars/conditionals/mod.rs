@@ -13,7 +13,11 @@
 // limitations under the License.
 
 mod conditional;
+mod extremum;
 mod r#if;
+mod multi_if;
 
 pub use conditional::ConditionalFunction;
+pub use extremum::ExtremumFunction;
+pub use multi_if::MultiIfFunction;
 pub use r#if::IfFunction;
@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// multiIf(cond1, then1, cond2, then2, ..., else) is if(cond1, then1, if(cond2, then2, ..., else)).
+#[derive(Clone)]
+pub struct MultiIfFunction {
+    _display_name: String,
+}
+
+impl MultiIfFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MultiIfFunction {
+            _display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for MultiIfFunction {
+    fn name(&self) -> &str {
+        "MultiIfFunction"
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args.len() % 2 == 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function multiIf expect an odd number of arguments, but got {}",
+                args.len()
+            )));
+        }
+
+        let mut results = Vec::with_capacity(args.len() / 2 + 1);
+        for index in (1..args.len()).step_by(2) {
+            results.push(args[index].clone());
+        }
+        results.push(args[args.len() - 1].clone());
+        common_datavalues::aggregate_types(&results)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let mut result = columns[columns.len() - 1].column().clone();
+        for index in (0..columns.len() - 1).step_by(2).rev() {
+            result = columns[index]
+                .column()
+                .if_then_else(columns[index + 1].column(), &result)?;
+        }
+        Ok(result)
+    }
+}
+
+impl fmt::Display for MultiIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "multiIf")
+    }
+}
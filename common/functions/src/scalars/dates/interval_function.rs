@@ -193,9 +193,10 @@ impl IntervalFunctionFactory {
         let mut date_datetime_opt = None;
         columns.iter().for_each(|column| match column.data_type() {
             DataType::Interval(_) => interval_opt = Some(column),
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) => {
-                date_datetime_opt = Some(column)
-            }
+            DataType::Date16
+            | DataType::Date32
+            | DataType::DateTime32(_)
+            | DataType::DateTime64(_, _) => date_datetime_opt = Some(column),
             _ => {}
         });
 
@@ -238,6 +239,8 @@ impl IntervalFunctionFactory {
     //   4. interval_month_plus_minus_date16 --------- Interval(YearMonth) +/-  Date16
     //   5. interval_month_plus_minus_date32 --------- Interval(YearMonth) +/-  Date32
     //   6. interval_month_plus_minus_datetime32 ----- Interval(YearMonth) +/-  DateTime32
+    //   7. interval_daytime_plus_minus_datetime64 --- Interval(DayTime)   +/-  DateTime64
+    //   8. interval_month_plus_minus_datetime64 ----- Interval(YearMonth) +/-  DateTime64
 
     fn get_interval_arithmetic_func(
         interval: &DataType,
@@ -248,12 +251,14 @@ impl IntervalFunctionFactory {
                 DataType::Date16 => Self::interval_month_plus_minus_date16,
                 DataType::Date32 => Self::interval_month_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_month_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_month_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             DataType::Interval(IntervalUnit::DayTime) => match date_datetime {
                 DataType::Date16 => Self::interval_daytime_plus_minus_date16,
                 DataType::Date32 => Self::interval_daytime_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_daytime_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_daytime_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -333,6 +338,29 @@ impl IntervalFunctionFactory {
         Ok(res.into())
     }
 
+    fn interval_daytime_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        let ticks_per_second = Self::ticks_per_second(datetime.data_type());
+        let res = Self::interval_operation(
+            interval.column().to_array()?.i64()?,
+            datetime.column().to_array()?.i64()?,
+            |ms: &i64, ticks: &i64| {
+                let delta = (*ms as i128 * ticks_per_second as i128 / 1000) as i64;
+                let r = match op {
+                    DataValueArithmeticOperator::Plus => *ticks + delta,
+                    DataValueArithmeticOperator::Minus => *ticks - delta,
+                    _ => unreachable!(),
+                };
+                Ok(r)
+            },
+        )?;
+        Ok(res.into())
+    }
+
     fn interval_month_plus_minus_date16(
         op: &DataValueArithmeticOperator,
         a: &DataColumnWithField,
@@ -360,6 +388,15 @@ impl IntervalFunctionFactory {
         Self::month_i64_plus_minus_datetime32(op, interval, datetime, 1)
     }
 
+    fn interval_month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        Self::month_i64_plus_minus_datetime64(op, interval, datetime, 1)
+    }
+
     //  End of interval typed arithmetic functions.
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -406,6 +443,17 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::month_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => match integer {
+                DataType::UInt8 => Self::month_u8_plus_minus_datetime64,
+                DataType::UInt16 => Self::month_u16_plus_minus_datetime64,
+                DataType::UInt32 => Self::month_u32_plus_minus_datetime64,
+                DataType::UInt64 => Self::month_u64_plus_minus_datetime64,
+                DataType::Int8 => Self::month_i8_plus_minus_datetime64,
+                DataType::Int16 => Self::month_i16_plus_minus_datetime64,
+                DataType::Int32 => Self::month_i32_plus_minus_datetime64,
+                DataType::Int64 => Self::month_i64_plus_minus_datetime64,
+                _ => unreachable!(),
+            },
             _ => unreachable!(),
         }
     }
@@ -439,6 +487,15 @@ impl IntervalFunctionFactory {
     crate::define_month_plus_minus_datetime32!(month_u16_plus_minus_datetime32, u16);
     crate::define_month_plus_minus_datetime32!(month_u8_plus_minus_datetime32, u8);
 
+    crate::define_month_plus_minus_datetime64!(month_i64_plus_minus_datetime64, i64);
+    crate::define_month_plus_minus_datetime64!(month_i32_plus_minus_datetime64, i32);
+    crate::define_month_plus_minus_datetime64!(month_i16_plus_minus_datetime64, i16);
+    crate::define_month_plus_minus_datetime64!(month_i8_plus_minus_datetime64, i8);
+    crate::define_month_plus_minus_datetime64!(month_u64_plus_minus_datetime64, u64);
+    crate::define_month_plus_minus_datetime64!(month_u32_plus_minus_datetime64, u32);
+    crate::define_month_plus_minus_datetime64!(month_u16_plus_minus_datetime64, u16);
+    crate::define_month_plus_minus_datetime64!(month_u8_plus_minus_datetime64, u8);
+
     //  End of months integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -485,6 +542,17 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::time_secs_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => match integer {
+                DataType::UInt8 => Self::time_secs_u8_plus_minus_datetime64,
+                DataType::UInt16 => Self::time_secs_u16_plus_minus_datetime64,
+                DataType::UInt32 => Self::time_secs_u32_plus_minus_datetime64,
+                DataType::UInt64 => Self::time_secs_u64_plus_minus_datetime64,
+                DataType::Int8 => Self::time_secs_i8_plus_minus_datetime64,
+                DataType::Int16 => Self::time_secs_i16_plus_minus_datetime64,
+                DataType::Int32 => Self::time_secs_i32_plus_minus_datetime64,
+                DataType::Int64 => Self::time_secs_i64_plus_minus_datetime64,
+                _ => unreachable!(),
+            },
             _ => unreachable!(),
         }
     }
@@ -518,11 +586,20 @@ impl IntervalFunctionFactory {
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u16_plus_minus_datetime32, u16);
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u8_plus_minus_datetime32, u8);
 
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i64_plus_minus_datetime64, i64);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i32_plus_minus_datetime64, i32);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i16_plus_minus_datetime64, i16);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i8_plus_minus_datetime64, i8);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u64_plus_minus_datetime64, u64);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u32_plus_minus_datetime64, u32);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u16_plus_minus_datetime64, u16);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u8_plus_minus_datetime64, u8);
+
     // End of seconds integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
     // A private helper function for validate operator, returns a tuple of
-    // (interval|integer, date16|date32|datetime32)
+    // (interval|integer, date16|date32|datetime32|datetime64)
     fn validate_input<'a>(
         op: &DataValueArithmeticOperator,
         col0: &'a DataColumnWithField,
@@ -543,6 +620,14 @@ impl IntervalFunctionFactory {
         }
     }
 
+    // A private helper function to get the number of DateTime64 ticks in one second
+    fn ticks_per_second(data_type: &DataType) -> i64 {
+        match data_type {
+            DataType::DateTime64(precision, _) => datetime64_factor(*precision),
+            _ => 1,
+        }
+    }
+
     // A private helper function to add/subtract month to/from days
     fn days_plus_signed_months(days: i64, months: i64) -> Result<u32> {
         let naive = NaiveDateTime::from_timestamp(0, 0).checked_add_signed(Duration::days(days));
@@ -654,6 +739,42 @@ macro_rules! define_month_plus_minus_datetime32 {
     };
 }
 
+#[macro_export]
+macro_rules! define_month_plus_minus_datetime64 {
+    ($fn_name:ident, $type:ident) => {
+        fn $fn_name(
+            op: &DataValueArithmeticOperator,
+            a: &DataColumnWithField,
+            b: &DataColumnWithField,
+            mul: i64,
+        ) -> Result<DataColumn> {
+            let (interval_months, datetime64) = Self::validate_input(op, a, b)?;
+            let ticks_per_second = Self::ticks_per_second(datetime64.data_type());
+
+            let res = Self::interval_operation(
+                interval_months.column().to_array()?.$type()?,
+                datetime64.column().to_array()?.i64()?,
+                |months: &$type, ticks: &i64| {
+                    let seconds = ticks.div_euclid(ticks_per_second);
+                    let sub_seconds = ticks.rem_euclid(ticks_per_second);
+                    let dt = Self::seconds_to_datetime(seconds)?;
+                    let new_dt = match op {
+                        DataValueArithmeticOperator::Plus => {
+                            Self::datetime_plus_signed_months(&dt, (*months as i64) * mul)?
+                        }
+                        DataValueArithmeticOperator::Minus => {
+                            Self::datetime_plus_signed_months(&dt, -(*months as i64) * mul)?
+                        }
+                        _ => unreachable!(),
+                    };
+                    Ok(new_dt.timestamp() * ticks_per_second + sub_seconds)
+                },
+            )?;
+            Ok(res.into())
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_month_plus_minus_date {
     ($fn_name:ident, $month_type:ident, $date_type:ident) => {
@@ -714,6 +835,37 @@ macro_rules! define_time_secs_plus_minus_datetime32 {
     };
 }
 
+#[macro_export]
+macro_rules! define_time_secs_plus_minus_datetime64 {
+    ($fn_name:ident, $type:ident) => {
+        fn $fn_name(
+            op: &DataValueArithmeticOperator,
+            interval: &DataColumnWithField,
+            datetime: &DataColumnWithField,
+            mul: i64,
+        ) -> Result<DataColumn> {
+            let ticks_per_second = Self::ticks_per_second(datetime.data_type());
+            let res = Self::interval_operation(
+                interval.column().to_array()?.$type()?,
+                datetime.column().to_array()?.i64()?,
+                |secs: &$type, ticks: &i64| {
+                    let r = match op {
+                        DataValueArithmeticOperator::Plus => {
+                            *ticks + *secs as i64 * mul * ticks_per_second
+                        }
+                        DataValueArithmeticOperator::Minus => {
+                            *ticks - *secs as i64 * mul * ticks_per_second
+                        }
+                        _ => unreachable!(),
+                    };
+                    Ok(r)
+                },
+            )?;
+            Ok(res.into())
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_time_secs_plus_minus_date {
     ($fn_name:ident, $seconds_type:ident, $date_type:ident) => {
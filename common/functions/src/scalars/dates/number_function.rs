@@ -21,6 +21,7 @@ use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Timelike;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -432,52 +433,67 @@ where
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = seconds_to_datetime(v.as_u64()? as i64 * 24 * 3600)?;
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
-                    let result: DFPrimitiveArray<R> = columns[0].column()
-                        .to_array()?
-                        .u16()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time)
-                        }
-                        );
+                    let result: DFPrimitiveArray<R> = try_apply_cast_numeric(
+                        columns[0].column().to_array()?.u16()?,
+                        |v| {
+                            let date_time = seconds_to_datetime(v as i64 * 24 * 3600)?;
+                            Ok(T::to_number(date_time))
+                        },
+                    )?;
                     Ok(result.into())
                 }
             }
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()? * 24 * 3600, 0_u32);
+                    let date_time = seconds_to_datetime(v.as_i64()? * 24 * 3600)?;
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
-                    let result = columns[0].column()
-                        .to_array()?
-                        .i32()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time)
-                        }
-                        );
+                    let result: DFPrimitiveArray<R> = try_apply_cast_numeric(
+                        columns[0].column().to_array()?.i32()?,
+                        |v| {
+                            let date_time = seconds_to_datetime(v as i64 * 24 * 3600)?;
+                            Ok(T::to_number(date_time))
+                        },
+                    )?;
                     Ok(result.into())
                 }
             }
             DataType::DateTime32(_) => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64, 0_u32);
+                    let date_time = seconds_to_datetime(v.as_u64()? as i64)?;
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
-                    let result = columns[0].column()
-                        .to_array()?
-                        .u32()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
-                            T::to_number(date_time)
-                        }
-                        );
+                    let result: DFPrimitiveArray<R> = try_apply_cast_numeric(
+                        columns[0].column().to_array()?.u32()?,
+                        |v| {
+                            let date_time = seconds_to_datetime(v as i64)?;
+                            Ok(T::to_number(date_time))
+                        },
+                    )?;
+                    Ok(result.into())
+                }
+            }
+            DataType::DateTime64(precision, tz) => {
+                let precision = *precision;
+                let tz = parse_timezone(tz)?;
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = datetime64_to_local(v.as_i64()?, precision, &tz)?;
+                    let constant_result = T::to_constant_value(date_time);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result: DFPrimitiveArray<R> = try_apply_cast_numeric(
+                        columns[0].column().to_array()?.i64()?,
+                        |v| {
+                            let date_time = datetime64_to_local(v, precision, &tz)?;
+                            Ok(T::to_number(date_time))
+                        },
+                    )?;
                     Ok(result.into())
                 }
             }
@@ -519,6 +535,43 @@ impl<T, R> fmt::Display for NumberFunction<T, R> {
     }
 }
 
+// The wall clock time of the DateTime64 ticks in the timezone.
+#[inline]
+pub(crate) fn datetime64_to_local(ticks: i64, precision: u32, tz: &Tz) -> Result<DateTime<Utc>> {
+    let date_time = ticks.try_to_date_time64(precision, tz)?;
+    Ok(Utc.from_utc_datetime(&date_time.naive_local()))
+}
+
+// The datetime of the seconds since the epoch, an error instead of a panic if it's out of range.
+#[inline]
+pub(crate) fn seconds_to_datetime(seconds: i64) -> Result<DateTime<Utc>> {
+    Utc.timestamp_opt(seconds, 0).single().ok_or_else(|| {
+        ErrorCode::Overflow(format!("Overflow on datetime with seconds {}.", seconds))
+    })
+}
+
+// Applies the fallible `f` on the values of the array, the nulls remain null.
+pub(crate) fn try_apply_cast_numeric<T, R>(
+    array: &DFPrimitiveArray<T>,
+    f: impl Fn(T) -> Result<R>,
+) -> Result<DFPrimitiveArray<R>>
+where
+    T: DFPrimitiveType,
+    R: DFPrimitiveType,
+{
+    let values = array
+        .into_iter()
+        .map(|v| match v {
+            Some(v) => f(*v),
+            None => Ok(R::default()),
+        })
+        .collect::<Result<AlignedVec<R>>>()?;
+    let validity = array.inner().validity().cloned();
+    Ok(DFPrimitiveArray::<R>::new_from_owned_with_null_bitmap(
+        values, validity,
+    ))
+}
+
 fn get_day(date: DateTime<Utc>) -> u32 {
     let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
     let duration = date.signed_duration_since(start);
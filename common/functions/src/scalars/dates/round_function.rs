@@ -44,6 +44,34 @@ impl RoundFunction {
     fn execute(&self, time: u32) -> u32 {
         time / self.round * self.round
     }
+
+    // Rounds the DateTime64 ticks, there are `ticks_per_second` ticks in one second.
+    #[inline]
+    fn execute_ticks(&self, ticks: i64, ticks_per_second: i64) -> i64 {
+        let round = self.round as i64 * ticks_per_second;
+        ticks.div_euclid(round) * round
+    }
+
+    fn eval_datetime64(&self, column: &DataColumn, precision: u32) -> Result<DataColumn> {
+        let ticks_per_second = datetime64_factor(precision);
+        match column {
+            DataColumn::Array(array) => {
+                let array = array.i64()?;
+                let arr = array.apply(|x| self.execute_ticks(x, ticks_per_second));
+                Ok(DataColumn::Array(arr.into_series()))
+            }
+            DataColumn::Constant(v, rows) => {
+                if v.is_null() {
+                    return Ok(DataColumn::Constant(DataValue::Int64(None), *rows));
+                }
+                let value = v.as_i64()?;
+                Ok(DataColumn::Constant(
+                    DataValue::Int64(Some(self.execute_ticks(value, ticks_per_second))),
+                    *rows,
+                ))
+            }
+        }
+    }
 }
 
 impl Function for RoundFunction {
@@ -54,6 +82,7 @@ impl Function for RoundFunction {
     fn return_type(&self, args: &[DataType]) -> Result<DataType> {
         match args[0] {
             DataType::DateTime32(_) => Ok(DataType::DateTime32(None)),
+            DataType::DateTime64(_, _) => Ok(args[0].clone()),
             _ => Err(ErrorCode::BadDataValueType(format!(
                 "Function {} must have a DateTime type as argument, but got {}",
                 self.display_name, args[0],
@@ -66,6 +95,10 @@ impl Function for RoundFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        if let DataType::DateTime64(precision, _) = columns[0].data_type() {
+            return self.eval_datetime64(columns[0].column(), *precision);
+        }
+
         match columns[0].column() {
             DataColumn::Array(array) => {
                 let array = array.u32()?;
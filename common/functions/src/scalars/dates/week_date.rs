@@ -25,6 +25,9 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::number_function::datetime64_to_local;
+use super::number_function::seconds_to_datetime;
+use super::number_function::try_apply_cast_numeric;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
@@ -139,52 +142,67 @@ where
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = seconds_to_datetime(v.as_u64()? as i64 * 24 * 3600)?;
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
-                    let result: DFPrimitiveArray<R> = columns[0].column()
-                        .to_array()?
-                        .u16()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time, mode)
-                        }
-                        );
+                    let result: DFPrimitiveArray<R> = try_apply_cast_numeric(
+                        columns[0].column().to_array()?.u16()?,
+                        |v| {
+                            let date_time = seconds_to_datetime(v as i64 * 24 * 3600)?;
+                            Ok(T::to_number(date_time, mode))
+                        },
+                    )?;
                     Ok(result.into())
                 }
             },
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?  * 24 * 3600, 0_u32);
+                    let date_time = seconds_to_datetime(v.as_i64()? * 24 * 3600)?;
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
-                    let result = columns[0].column()
-                        .to_array()?
-                        .i32()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time, mode)
-                        }
-                        );
+                    let result: DFPrimitiveArray<R> = try_apply_cast_numeric(
+                        columns[0].column().to_array()?.i32()?,
+                        |v| {
+                            let date_time = seconds_to_datetime(v as i64 * 24 * 3600)?;
+                            Ok(T::to_number(date_time, mode))
+                        },
+                    )?;
                     Ok(result.into())
                 }
             },
             DataType::DateTime32(_) => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?, 0_u32);
+                    let date_time = seconds_to_datetime(v.as_i64()?)?;
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
-                    let result = columns[0].column()
-                        .to_array()?
-                        .u32()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
-                            T::to_number(date_time, mode)
-                        }
-                        );
+                    let result: DFPrimitiveArray<R> = try_apply_cast_numeric(
+                        columns[0].column().to_array()?.u32()?,
+                        |v| {
+                            let date_time = seconds_to_datetime(v as i64)?;
+                            Ok(T::to_number(date_time, mode))
+                        },
+                    )?;
+                    Ok(result.into())
+                }
+            },
+            DataType::DateTime64(precision, tz) => {
+                let precision = *precision;
+                let tz = parse_timezone(tz)?;
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = datetime64_to_local(v.as_i64()?, precision, &tz)?;
+                    let constant_result = T::to_constant_value(date_time, mode);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result: DFPrimitiveArray<R> = try_apply_cast_numeric(
+                        columns[0].column().to_array()?.i64()?,
+                        |v| {
+                            let date_time = datetime64_to_local(v, precision, &tz)?;
+                            Ok(T::to_number(date_time, mode))
+                        },
+                    )?;
                     Ok(result.into())
                 }
             },
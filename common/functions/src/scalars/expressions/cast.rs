@@ -14,6 +14,9 @@
 
 use std::fmt;
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
@@ -22,17 +25,47 @@ use common_datavalues::chrono::NaiveDateTime;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::data_type_physical;
+use common_datavalues::prelude::datetime64_factor;
+use common_datavalues::prelude::datetime64_from_str;
+use common_datavalues::prelude::datetime64_rescale;
+use common_datavalues::prelude::datetime64_to_string;
+use common_datavalues::prelude::decimal_check_precision;
+use common_datavalues::prelude::decimal_factor;
+use common_datavalues::prelude::decimal_from_f64;
+use common_datavalues::prelude::decimal_from_str;
+use common_datavalues::prelude::decimal_rescale;
+use common_datavalues::prelude::decimal_to_f64;
+use common_datavalues::prelude::decimal_to_string;
+use common_datavalues::prelude::get_list_builder;
+use common_datavalues::prelude::map_struct_fields;
+use common_datavalues::prelude::parse_timezone;
+use common_datavalues::prelude::variant_from_slice;
+use common_datavalues::prelude::variant_normalize;
+use common_datavalues::prelude::variant_to_bool;
+use common_datavalues::prelude::variant_to_text;
 use common_datavalues::prelude::ArrayApply;
+use common_datavalues::prelude::DFBooleanArray;
+use common_datavalues::prelude::DFDecimalArray;
+use common_datavalues::prelude::DFDictionaryArray;
+use common_datavalues::prelude::DFFloat64Array;
 use common_datavalues::prelude::DFInt32Array;
+use common_datavalues::prelude::DFInt64Array;
 use common_datavalues::prelude::DFStringArray;
+use common_datavalues::prelude::DFStructArray;
 use common_datavalues::prelude::DFUInt16Array;
 use common_datavalues::prelude::DFUInt32Array;
+use common_datavalues::prelude::DataColumnWithField;
 use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::prelude::NewDataArray;
 use common_datavalues::series::IntoSeries;
+use common_datavalues::series::Series;
+use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use serde_json::Value as JsonValue;
 
 use crate::scalars::Function;
 use crate::with_match_primitive_type;
@@ -82,6 +115,153 @@ impl Function for CastFunction {
         ));
 
         let array = match (columns[0].data_type(), &self.cast_type) {
+            // LowCardinality to others, the values are decoded then cast
+            (DataType::LowCardinality(inner), _) => {
+                let values = series.dictionary()?.decode().into_series();
+                let field = DataField::new(columns[0].field().name(), inner.as_ref().clone(), true);
+                return self.eval(
+                    &[DataColumnWithField::new(values.into(), field)],
+                    input_rows,
+                );
+            }
+
+            // others to LowCardinality, the values are cast to the inner type then encoded
+            (_, DataType::LowCardinality(inner)) => {
+                let values = CastFunction::create("".to_string(), inner.as_ref().clone())?
+                    .eval(columns, input_rows)?
+                    .to_minimal_array()?;
+                let array = DFDictionaryArray::encode(values.string()?);
+
+                // DataValue can't keep LowCardinality, so the constant is expanded by the codes
+                // instead of being resized to the constant column
+                let array = match array.len() == 1 && input_rows != 1 {
+                    true => {
+                        let key = (!array.is_null(0)).then(|| 0u32);
+                        let keys: DFUInt32Array = std::iter::repeat(key).take(input_rows).collect();
+                        DFDictionaryArray::from_data(keys, array.values())
+                    }
+                    false => array,
+                };
+                return Ok(array.into_series().into());
+            }
+
+            // Variant to others, the variant values are cast by their texts
+            (DataType::Variant, _) => {
+                let values = series
+                    .string()?
+                    .into_iter()
+                    .map(|v| v.map(variant_from_slice).transpose())
+                    .collect::<Result<Vec<_>>>()?;
+                if self.cast_type == DataType::Boolean {
+                    let it = values.iter().map(|v| v.as_ref().and_then(variant_to_bool));
+                    Ok(DFBooleanArray::new_from_opt_iter(it).into_series())
+                } else {
+                    let it = values.iter().map(|v| v.as_ref().and_then(variant_to_text));
+                    let texts = DFStringArray::new_from_opt_iter(it).into_series();
+                    let field = DataField::new(columns[0].field().name(), DataType::String, true);
+                    return self.eval(&[DataColumnWithField::new(texts.into(), field)], input_rows);
+                }
+            }
+
+            // others to Variant
+            (from_type, DataType::Variant) => match from_type {
+                DataType::Null => series.cast_with_type(&DataType::String),
+                DataType::String => {
+                    let values = series
+                        .string()?
+                        .into_iter()
+                        .map(|v| v.map(variant_normalize).transpose())
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(DFStringArray::new_from_opt_slice(&values).into_series())
+                }
+                DataType::Boolean => Ok(DFStringArray::new_from_opt_iter(
+                    series.bool()?.into_iter().map(|v| v.map(|x| x.to_string())),
+                )
+                .into_series()),
+                _ => {
+                    // the numbers are kept as the JSON numbers, the others become the JSON strings
+                    let texts = CastFunction::create("".to_string(), DataType::String)?
+                        .eval(columns, input_rows)?
+                        .to_minimal_array()?;
+                    let it = texts.string()?.into_iter().map(|v| {
+                        v.map(|x| match variant_from_slice(x) {
+                            Ok(value) => value.to_string(),
+                            Err(_) => JsonValue::String(String::from_utf8_lossy(x).to_string())
+                                .to_string(),
+                        })
+                    });
+                    Ok(DFStringArray::new_from_opt_iter(it).into_series())
+                }
+            },
+
+            // Array to Array, the elements are cast one array by one array
+            (DataType::List(from), DataType::List(to)) => {
+                let cast = CastFunction::create("".to_string(), to.data_type().clone())?;
+                let physical_type = data_type_physical(to.data_type().clone());
+                let mut builder = get_list_builder(&physical_type, series.len() * 4, series.len());
+                for values in series.list()?.into_iter() {
+                    match values {
+                        Some(values) => {
+                            let rows = values.len();
+                            let field = DataField::new(from.name(), from.data_type().clone(), true);
+                            let values = DataColumnWithField::new(values.into(), field);
+                            let values = cast.eval(&[values], rows)?.to_array()?;
+                            builder.append_series(&values);
+                        }
+                        None => builder.append_null(),
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
+
+            // Map to Map, the keys and the values are cast as the lists of the physical struct
+            (DataType::Map(from_key, from_value), DataType::Map(to_key, to_value)) => {
+                let from = DataType::Struct(map_struct_fields(from_key, from_value));
+                let from = DataField::new(columns[0].field().name(), from, true);
+                let values = DataColumnWithField::new(series.into(), from);
+                let to = DataType::Struct(map_struct_fields(to_key, to_value));
+                return CastFunction::create("".to_string(), to)?.eval(&[values], input_rows);
+            }
+
+            // Struct to Struct, the fields are picked by their names, or by their positions
+            // if the names are not matched, like the unnamed tuples, and cast one by one
+            (DataType::Struct(from), DataType::Struct(to)) => {
+                let array = series.tuple()?.inner();
+                let mut arrays = Vec::with_capacity(to.len());
+                for (i, to_field) in to.iter().enumerate() {
+                    let pos = from
+                        .iter()
+                        .position(|f| f.name() == to_field.name())
+                        .or_else(|| (from.len() == to.len()).then(|| i))
+                        .ok_or_else(|| {
+                            ErrorCode::BadDataValueType(format!(
+                                "Unknown field {} of {:?}",
+                                to_field.name(),
+                                columns[0].data_type()
+                            ))
+                        })?;
+                    let values = array.values()[pos].clone().into_series();
+                    let values = DataColumnWithField::new(values.into(), from[pos].clone());
+                    let values =
+                        CastFunction::create("".to_string(), to_field.data_type().clone())?
+                            .eval(&[values], input_rows)?
+                            .to_array()?;
+                    arrays.push(values.get_array_ref());
+                }
+                let fields = to.iter().map(|f| f.to_arrow()).collect();
+                let validity = array.validity().cloned();
+                let array: DFStructArray =
+                    StructArray::from_data(ArrowType::Struct(fields), arrays, validity).into();
+                Ok(array.into_series())
+            }
+
+            // Struct with only one field is cast as the field
+            (DataType::Struct(from), _) if from.len() == 1 => {
+                let values = series.tuple()?.inner().values()[0].clone().into_series();
+                let values = DataColumnWithField::new(values.into(), from[0].clone());
+                return self.eval(&[values], input_rows);
+            }
+
             // Date/DateTime to others
             (DataType::Date16, _) => with_match_primitive_type!(&self.cast_type, |$T| {
                 series.cast_with_type(&self.cast_type)
@@ -90,6 +270,7 @@ impl Function for CastFunction {
                match &self.cast_type {
                 Date32 => Ok(arr.apply_cast_numeric(|v| v as i32).into_series()),
                 DateTime32(_) => Ok(arr.apply_cast_numeric(|v|  Utc.timestamp(v as i64 * 24 * 3600, 0_u32).timestamp() as u32 ).into_series() ),
+                DateTime64(precision, _) => Ok(arr.apply_cast_numeric(|v| v as i64 * 24 * 3600 * datetime64_factor(*precision)).into_series()),
                 String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64 * 24 * 3600, 0_u32), DATE_FMT))) ).into_series()),
                 _ =>  Err(error)
                }
@@ -102,6 +283,7 @@ impl Function for CastFunction {
                match &self.cast_type {
                 Date32 => Ok(arr.apply_cast_numeric(|v| v as i32).into_series()),
                 DateTime32(_) => Ok(arr.apply_cast_numeric(|v|  Utc.timestamp(v as i64 * 24 * 3600, 0_u32).timestamp()  as u32).into_series() ),
+                DateTime64(precision, _) => Ok(arr.apply_cast_numeric(|v| v as i64 * 24 * 3600 * datetime64_factor(*precision)).into_series()),
                 String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64 * 24 * 3600, 0_u32), DATE_FMT))) ).into_series()),
                 _ =>  Err(error)
                }
@@ -114,11 +296,42 @@ impl Function for CastFunction {
                match &self.cast_type {
                 Date16 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as u16).into_series()),
                 Date32 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as i32).into_series()),
+                DateTime64(precision, _) => Ok(arr.apply_cast_numeric(|v| v as i64 * datetime64_factor(*precision)).into_series()),
                 String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64, 0_u32), TIME_FMT))) ).into_series()),
                 _ =>  Err(error)
                }
             }),
 
+            (DataType::DateTime64(precision, tz), _) => {
+                let precision = *precision;
+                let factor = datetime64_factor(precision);
+                let arr = series.i64()?;
+                match &self.cast_type {
+                    DataType::DateTime64(to_precision, _) => Ok(arr
+                        .apply_cast_numeric(|v| datetime64_rescale(v, precision, *to_precision))
+                        .into_series()),
+                    DataType::DateTime32(_) => Ok(arr
+                        .apply_cast_numeric(|v| v.div_euclid(factor) as u32)
+                        .into_series()),
+                    DataType::Date16 => Ok(arr
+                        .apply_cast_numeric(|v| v.div_euclid(factor * 24 * 3600) as u16)
+                        .into_series()),
+                    DataType::Date32 => Ok(arr
+                        .apply_cast_numeric(|v| v.div_euclid(factor * 24 * 3600) as i32)
+                        .into_series()),
+                    DataType::String => {
+                        let tz = parse_timezone(tz)?;
+                        Ok(DFStringArray::from_iter(
+                            arr.into_iter()
+                                .map(|v| v.map(|x| datetime64_to_string(*x, precision, &tz))),
+                        )
+                        .into_series())
+                    }
+                    other if other.is_numeric() => series.cast_with_type(other),
+                    _ => Err(error),
+                }
+            }
+
             // others to Date/DateTime
             (_, DataType::Date16) => with_match_primitive_type!(columns[0].data_type(), |$T| {
                 series.cast_with_type(&self.cast_type)
@@ -164,6 +377,109 @@ impl Function for CastFunction {
                 })
             }
 
+            // the numbers are the seconds since UNIX epoch
+            (from_type, DataType::DateTime64(precision, tz)) => match from_type {
+                DataType::Null => series.cast_with_type(&DataType::Int64),
+                DataType::String => {
+                    let tz = parse_timezone(tz)?;
+                    let it = series.string()?.into_iter().map(|v| {
+                        v.and_then(|x| std::str::from_utf8(x).ok())
+                            .and_then(|x| datetime64_from_str(x, *precision, &tz).ok())
+                    });
+                    Ok(DFInt64Array::from_iter(it).into_series())
+                }
+                other if other.is_floating() => {
+                    let factor = datetime64_factor(*precision) as f64;
+                    let arr = series.cast_with_type(&DataType::Float64)?;
+                    Ok(arr
+                        .f64()?
+                        .apply_cast_numeric(|v| (v * factor) as i64)
+                        .into_series())
+                }
+                other if other.is_integer() => {
+                    let factor = datetime64_factor(*precision);
+                    let arr = series.cast_with_type(&DataType::Int64)?;
+                    Ok(arr.i64()?.apply_cast_numeric(|v| v * factor).into_series())
+                }
+                _ => Err(error),
+            },
+
+            // Decimal to others
+            (DataType::Decimal(_, scale), _) => {
+                let scale = *scale;
+                let arr = series.decimal()?;
+                match &self.cast_type {
+                    DataType::Decimal(precision, to_scale) => {
+                        let it = arr
+                            .into_iter()
+                            .map(|v| v.map(|x| decimal_rescale(*x, scale, *to_scale)));
+                        decimal_series_from_iter(it, *precision)
+                    }
+                    DataType::Float32 | DataType::Float64 => {
+                        let arr = arr.apply_cast_numeric(|v| decimal_to_f64(v, scale));
+                        arr.into_series().cast_with_type(&self.cast_type)
+                    }
+                    DataType::String => Ok(DFStringArray::from_iter(
+                        arr.into_iter()
+                            .map(|v| v.map(|x| decimal_to_string(*x, scale))),
+                    )
+                    .into_series()),
+                    other if other.is_integer() => {
+                        let factor = decimal_factor(scale);
+                        let arr = arr.apply_cast_numeric(|v| (v / factor) as i64);
+                        arr.into_series().cast_with_type(&self.cast_type)
+                    }
+                    _ => Err(error),
+                }
+            }
+
+            // others to Decimal
+            (from_type, DataType::Decimal(precision, scale)) => match from_type {
+                DataType::Null => series.cast_with_type(&self.cast_type),
+                DataType::String => {
+                    let values = series
+                        .string()?
+                        .into_iter()
+                        .map(|v| {
+                            v.map(|x| {
+                                let text = std::str::from_utf8(x).map_err(|_| {
+                                    ErrorCode::BadBytes("Cannot convert value to utf8")
+                                })?;
+                                decimal_from_str(text, *scale)
+                            })
+                            .transpose()
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    decimal_series_from_iter(values.into_iter(), *precision)
+                }
+                other if other.is_floating() => {
+                    let arr = series.cast_with_type(&DataType::Float64)?;
+                    let it = arr
+                        .f64()?
+                        .into_iter()
+                        .map(|v| v.map(|x| decimal_from_f64(*x, *scale)));
+                    decimal_series_from_iter(it, *precision)
+                }
+                other if other.is_integer() => {
+                    let factor = decimal_factor(*scale);
+                    let arr = series.cast_with_type(&DataType::Int64)?;
+                    let values = arr
+                        .i64()?
+                        .into_iter()
+                        .map(|v| {
+                            v.map(|x| {
+                                (*x as i128).checked_mul(factor).ok_or_else(|| {
+                                    ErrorCode::Overflow(format!("Decimal overflow: {}", x))
+                                })
+                            })
+                            .transpose()
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    decimal_series_from_iter(values.into_iter(), *precision)
+                }
+                _ => Err(error),
+            },
+
             _ => series.cast_with_type(&self.cast_type),
         }?;
 
@@ -182,6 +498,16 @@ impl fmt::Display for CastFunction {
     }
 }
 
+fn decimal_series_from_iter(
+    it: impl Iterator<Item = Option<i128>>,
+    precision: usize,
+) -> Result<Series> {
+    let values = it
+        .map(|v| v.map(|x| decimal_check_precision(x, precision)).transpose())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DFDecimalArray::new_from_opt_slice(&values).into_series())
+}
+
 #[inline]
 fn datetime_to_string(date: DateTime<Utc>, fmt: &str) -> String {
     date.format(fmt).to_string()
@@ -24,7 +24,10 @@ pub struct ToCastFunction;
 
 impl ToCastFunction {
     fn cast_function_creator(to_type: DataType) -> FunctionDescription {
-        let mut features = FunctionFeatures::default().deterministic().monotonicity();
+        let mut features = FunctionFeatures::default()
+            .deterministic()
+            .monotonicity()
+            .low_cardinality_function();
         if to_type == DataType::Boolean {
             features = features.bool_function();
         }
@@ -51,7 +54,15 @@ impl ToCastFunction {
         factory.register("toFloat64", Self::cast_function_creator(DataType::Float64));
         factory.register("toDate16", Self::cast_function_creator(DataType::Date16));
         factory.register("toDate32", Self::cast_function_creator(DataType::Date32));
+        factory.register(
+            "toDateTime64",
+            Self::cast_function_creator(DataType::DateTime64(3, None)),
+        );
         factory.register("toString", Self::cast_function_creator(DataType::String));
+        factory.register(
+            "toLowCardinality",
+            Self::cast_function_creator(DataType::LowCardinality(Box::new(DataType::String))),
+        );
 
         // aliases
         factory.register("toDate", Self::cast_function_creator(DataType::Date16));
@@ -20,15 +20,19 @@ use common_exception::Result;
 use lazy_static::lazy_static;
 
 use crate::scalars::ArithmeticFunction;
+use crate::scalars::ArrayFunction;
 use crate::scalars::ComparisonFunction;
 use crate::scalars::ConditionalFunction;
 use crate::scalars::DateFunction;
 use crate::scalars::Function;
 use crate::scalars::HashesFunction;
 use crate::scalars::LogicFunction;
+use crate::scalars::LowCardinalityFunction;
+use crate::scalars::MapFunction;
 use crate::scalars::MathsFunction;
 use crate::scalars::NullableFunction;
 use crate::scalars::OtherFunction;
+use crate::scalars::SemiStructuredFunction;
 use crate::scalars::StringFunction;
 use crate::scalars::ToCastFunction;
 use crate::scalars::TupleClassFunction;
@@ -43,6 +47,7 @@ pub struct FunctionFeatures {
     pub is_bool_func: bool,
     pub is_context_func: bool,
     pub maybe_monotonic: bool,
+    pub is_low_cardinality_func: bool,
 }
 
 impl FunctionFeatures {
@@ -53,6 +58,7 @@ impl FunctionFeatures {
             is_bool_func: false,
             is_context_func: false,
             maybe_monotonic: false,
+            is_low_cardinality_func: false,
         }
     }
 
@@ -80,6 +86,13 @@ impl FunctionFeatures {
         self.maybe_monotonic = true;
         self
     }
+
+    /// The function evaluates the LowCardinality arguments by itself,
+    /// otherwise they are decoded to their inner types before the evaluation.
+    pub fn low_cardinality_function(mut self) -> FunctionFeatures {
+        self.is_low_cardinality_func = true;
+        self
+    }
 }
 
 pub struct FunctionDescription {
@@ -123,6 +136,9 @@ lazy_static! {
         OtherFunction::register(&mut function_factory);
         MathsFunction::register(&mut function_factory);
         TupleClassFunction::register(&mut function_factory);
+        SemiStructuredFunction::register(&mut function_factory);
+        ArrayFunction::register(&mut function_factory);
+        MapFunction::register(&mut function_factory);
 
         Arc::new(function_factory)
     };
@@ -153,7 +169,13 @@ impl FunctionFactory {
                 "Unsupported Function: {}",
                 origin_name
             ))),
-            Some(desc) => (desc.function_creator)(origin_name),
+            Some(desc) => {
+                let function = (desc.function_creator)(origin_name)?;
+                match desc.features.is_low_cardinality_func {
+                    true => Ok(function),
+                    false => LowCardinalityFunction::create(function),
+                }
+            }
         }
     }
 
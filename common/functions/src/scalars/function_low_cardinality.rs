@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnWithField;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::series::IntoSeries;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+/// Decode the LowCardinality arguments for the functions which don't evaluate them by
+/// themselves, so the functions only see the inner types of the arguments.
+#[derive(Clone)]
+pub struct LowCardinalityFunction {
+    function: Box<dyn Function>,
+}
+
+impl LowCardinalityFunction {
+    pub fn create(function: Box<dyn Function>) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LowCardinalityFunction { function }))
+    }
+}
+
+impl Function for LowCardinalityFunction {
+    fn name(&self) -> &str {
+        self.function.name()
+    }
+
+    fn num_arguments(&self) -> usize {
+        self.function.num_arguments()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        self.function.variadic_arguments()
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        self.function.get_monotonicity(args)
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let args = args
+            .iter()
+            .map(|arg| arg.remove_low_cardinality())
+            .collect::<Vec<_>>();
+        self.function.return_type(&args)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.function.nullable(input_schema)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if !columns.iter().any(|c| c.data_type().is_low_cardinality()) {
+            return self.function.eval(columns, input_rows);
+        }
+
+        let columns = columns
+            .iter()
+            .map(|c| match c.data_type() {
+                DataType::LowCardinality(inner) => {
+                    let values = c.column().to_array()?.dictionary()?.decode();
+                    let field = DataField::new(
+                        c.field().name(),
+                        inner.as_ref().clone(),
+                        c.field().is_nullable(),
+                    );
+                    Ok(DataColumnWithField::new(values.into_series().into(), field))
+                }
+                _ => Ok(c.clone()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.function.eval(&columns, input_rows)
+    }
+}
+
+impl fmt::Display for LowCardinalityFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.function)
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::map_constructor::MapConstructorFunction;
+use super::map_keys::MapKeysFunction;
+use crate::scalars::function_factory::FunctionFactory;
+
+#[derive(Clone)]
+pub struct MapFunction {}
+
+impl MapFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("map", MapConstructorFunction::desc());
+        factory.register("mapKeys", MapKeysFunction::desc(false));
+        factory.register("mapValues", MapKeysFunction::desc(true));
+    }
+}
+
+/// Checks the argument is a map, returns the key type and the value type of it.
+pub(crate) fn check_map_argument(
+    name: &str,
+    pos: usize,
+    data_type: &DataType,
+) -> Result<(DataType, DataType)> {
+    match data_type {
+        DataType::Map(key, value) => Ok((key.as_ref().clone(), value.as_ref().clone())),
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Expected parameter {} of {} is map, but got {}",
+            pos, name, other
+        ))),
+    }
+}
+
+/// Gets the keys and the values of the map at the row, None if the map is null.
+pub(crate) fn map_entries_at(
+    column: &DataColumn,
+    row: usize,
+) -> Result<Option<(Vec<DataValue>, Vec<DataValue>)>> {
+    if let DataColumn::Array(series) = column {
+        if series.is_null(row) {
+            return Ok(None);
+        }
+    }
+
+    match column.try_get(row)? {
+        DataValue::Struct(values) if values.len() == 2 => match (&values[0], &values[1]) {
+            (DataValue::List(Some(keys), _), DataValue::List(Some(values), _)) => {
+                Ok(Some((keys.clone(), values.clone())))
+            }
+            _ => Ok(None),
+        },
+        DataValue::Null => Ok(None),
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Expected map value, but got {:?}",
+            other
+        ))),
+    }
+}
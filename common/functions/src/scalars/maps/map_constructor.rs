@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::arrays::build_array_column;
+use crate::scalars::arrays::cast_column;
+use crate::scalars::arrays::check_array_element_type;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `map(k1, v1, k2, v2, ...)` builds a map from the pairs of the keys and the values.
+#[derive(Clone)]
+pub struct MapConstructorFunction {
+    display_name: String,
+}
+
+impl MapConstructorFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapConstructorFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn entry_types(&self, args: &[DataType]) -> Result<(DataType, DataType)> {
+        if args.len() % 2 != 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expects the pairs of the keys and the values, but got {} arguments",
+                self.display_name,
+                args.len()
+            )));
+        }
+
+        let keys = args.iter().step_by(2).cloned().collect::<Vec<_>>();
+        let values = args.iter().skip(1).step_by(2).cloned().collect::<Vec<_>>();
+        Ok((Self::common_type(&keys)?, Self::common_type(&values)?))
+    }
+
+    fn common_type(args: &[DataType]) -> Result<DataType> {
+        match args.is_empty() {
+            // the empty maps and the maps of NULLs hold UInt8 entries like the arrays
+            true => Ok(DataType::UInt8),
+            false => match aggregate_types(args)? {
+                DataType::Null => Ok(DataType::UInt8),
+                data_type => Ok(data_type),
+            },
+        }
+    }
+}
+
+impl Function for MapConstructorFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((0, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let (key_type, value_type) = self.entry_types(args)?;
+        check_array_element_type(&self.display_name, &key_type)?;
+        check_array_element_type(&self.display_name, &value_type)?;
+        Ok(DataType::Map(Box::new(key_type), Box::new(value_type)))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let (key_type, value_type) = self.entry_types(&args)?;
+
+        let mut keys = Vec::with_capacity(columns.len() / 2);
+        let mut values = Vec::with_capacity(columns.len() / 2);
+        for pair in columns.chunks(2) {
+            keys.push(cast_column(&pair[0], &key_type, input_rows)?);
+            values.push(cast_column(&pair[1], &value_type, input_rows)?);
+        }
+
+        let build = |data_type: &DataType, columns: &[DataColumn]| -> Result<Series> {
+            let rows = (0..input_rows).map(|row| -> Result<Option<Vec<DataValue>>> {
+                let values = columns
+                    .iter()
+                    .map(|c| c.try_get(row))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Some(values))
+            });
+            build_array_column(data_type, rows, input_rows)?.to_array()
+        };
+
+        let arrays = vec![
+            build(&key_type, &keys)?.get_array_ref(),
+            build(&value_type, &values)?.get_array_ref(),
+        ];
+        let fields = map_struct_fields(&key_type, &value_type)
+            .iter()
+            .map(|f| f.to_arrow())
+            .collect();
+        let array: DFStructArray =
+            StructArray::from_data(ArrowType::Struct(fields), arrays, None).into();
+        Ok(array.into_series().into())
+    }
+}
+
+impl fmt::Display for MapConstructorFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
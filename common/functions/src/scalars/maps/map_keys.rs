@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::map::check_map_argument;
+use crate::scalars::arrays::array_type;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `mapKeys(m)` returns the keys of the map as an array, `mapValues(m)` returns the values.
+#[derive(Clone)]
+pub struct MapKeysFunction {
+    display_name: String,
+    values: bool,
+}
+
+impl MapKeysFunction {
+    pub fn try_create(display_name: &str, values: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapKeysFunction {
+            display_name: display_name.to_string(),
+            values,
+        }))
+    }
+
+    pub fn desc(values: bool) -> FunctionDescription {
+        FunctionDescription::creator(Box::new(move |display_name| {
+            Self::try_create(display_name, values)
+        }))
+        .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for MapKeysFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let (key_type, value_type) = check_map_argument(&self.display_name, 1, &args[0])?;
+        match self.values {
+            true => Ok(array_type(value_type)),
+            false => Ok(array_type(key_type)),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        check_map_argument(&self.display_name, 1, columns[0].data_type())?;
+
+        // the keys and the values are already stored as the lists in the physical struct
+        let series = columns[0].column().to_array()?;
+        let lists = series.tuple()?.inner().values();
+        let list = match self.values {
+            true => lists[1].clone(),
+            false => lists[0].clone(),
+        };
+        Ok(list.into_series().into())
+    }
+}
+
+impl fmt::Display for MapKeysFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
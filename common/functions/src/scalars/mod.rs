@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -22,17 +23,21 @@ mod function_alias;
 mod function_column;
 mod function_factory;
 mod function_literal;
+mod function_low_cardinality;
 mod function_monotonic;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod nullables;
 mod others;
+mod semi_structureds;
 mod strings;
 mod tuples;
 mod udfs;
 
 pub use arithmetics::*;
+pub use arrays::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use dates::*;
@@ -42,12 +47,15 @@ pub use function_alias::AliasFunction;
 pub use function_column::ColumnFunction;
 pub use function_factory::FunctionFactory;
 pub use function_literal::LiteralFunction;
+pub use function_low_cardinality::LowCardinalityFunction;
 pub use function_monotonic::Monotonicity;
 pub use hashes::*;
 pub use logics::*;
+pub use maps::*;
 pub use maths::*;
 pub use nullables::*;
 pub use others::*;
+pub use semi_structureds::*;
 pub use strings::*;
 pub use tuples::*;
 pub use udfs::*;
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+/// assumeNotNull(x) is x, the NULL values are replaced by the default value of the type.
+#[derive(Clone)]
+pub struct AssumeNotNullFunction {
+    _display_name: String,
+}
+
+impl AssumeNotNullFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(AssumeNotNullFunction {
+            _display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic().monotonicity())
+    }
+}
+
+impl Function for AssumeNotNullFunction {
+    fn name(&self) -> &str {
+        "AssumeNotNullFunction"
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        Ok(args[0].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let column = columns[0].column();
+        match column {
+            DataColumn::Array(array) if array.null_count() == 0 => Ok(column.clone()),
+            DataColumn::Constant(value, _) if !value.is_null() => Ok(column.clone()),
+            _ => {
+                let data_type = columns[0].data_type();
+                let default = DataValue::new_from_data_type(data_type, false);
+                let default = DataColumn::Constant(default, input_rows);
+                column.is_not_null()?.if_then_else(column, &default)
+            }
+        }
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        Ok(Monotonicity::clone_without_range(&args[0]))
+    }
+}
+
+impl fmt::Display for AssumeNotNullFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "assumeNotNull")
+    }
+}
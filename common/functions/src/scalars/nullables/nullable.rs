@@ -13,8 +13,12 @@
 // limitations under the License.
 
 use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::AssumeNotNullFunction;
+use crate::scalars::CoalesceFunction;
+use crate::scalars::IfNullFunction;
 use crate::scalars::IsNotNullFunction;
 use crate::scalars::IsNullFunction;
+use crate::scalars::NullIfFunction;
 
 #[derive(Clone)]
 pub struct NullableFunction;
@@ -23,5 +27,9 @@ impl NullableFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("isnull", IsNullFunction::desc());
         factory.register("isnotnull", IsNotNullFunction::desc());
+        factory.register("coalesce", CoalesceFunction::desc());
+        factory.register("ifnull", IfNullFunction::desc());
+        factory.register("nullif", NullIfFunction::desc());
+        factory.register("assumenotnull", AssumeNotNullFunction::desc());
     }
 }
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::semi_structured::check_variant_argument;
+use super::semi_structured::extract_variant_paths;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `get(v, key)` gets the field of an object by the name or the element of an array by
+/// the index, `get_path(v, path)` gets the value at a path like `a.b[0]`.
+#[derive(Clone)]
+pub struct GetFunction {
+    display_name: String,
+    by_path: bool,
+}
+
+impl GetFunction {
+    pub fn try_create(display_name: &str, by_path: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GetFunction {
+            display_name: display_name.to_string(),
+            by_path,
+        }))
+    }
+
+    pub fn desc(by_path: bool) -> FunctionDescription {
+        FunctionDescription::creator(Box::new(move |display_name| {
+            Self::try_create(display_name, by_path)
+        }))
+        .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for GetFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_variant_argument(&self.display_name, &args[0])?;
+
+        let valid_key = match self.by_path {
+            true => matches!(args[1], DataType::String | DataType::Null),
+            false => args[1].is_integer() || matches!(args[1], DataType::String | DataType::Null),
+        };
+        if !valid_key {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 2 of {} is {}, but got {}",
+                self.display_name,
+                if self.by_path {
+                    "string"
+                } else {
+                    "string or integer"
+                },
+                args[1]
+            )));
+        }
+        Ok(DataType::Variant)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let by_path = self.by_path;
+        let to_path = |key: DataValue| -> Result<Option<Vec<VariantPathElem>>> {
+            Ok(match key {
+                DataValue::String(Some(path)) if by_path => {
+                    Some(parse_variant_path(&String::from_utf8_lossy(&path))?)
+                }
+                DataValue::String(Some(key)) => Some(vec![VariantPathElem::Key(
+                    String::from_utf8_lossy(&key).to_string(),
+                )]),
+                key if key.is_integer() && !key.is_null() => {
+                    let index = key.as_i64()?;
+                    (index >= 0).then(|| vec![VariantPathElem::Index(index as usize)])
+                }
+                _ => None,
+            })
+        };
+
+        extract_variant_paths(columns, input_rows, to_path, |value| {
+            Some(value.to_string().into_bytes())
+        })
+    }
+}
+
+impl fmt::Display for GetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::semi_structured::check_variant_argument;
+use super::semi_structured::extract_variant_paths;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Extracts the value at the path of the JSON strings as a text, the strings are unquoted.
+#[derive(Clone)]
+pub struct JsonExtractPathTextFunction {
+    display_name: String,
+}
+
+impl JsonExtractPathTextFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(JsonExtractPathTextFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for JsonExtractPathTextFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_variant_argument(&self.display_name, &args[0])?;
+        if !matches!(args[1], DataType::String | DataType::Null) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected parameter 2 of {} is string, but got {}",
+                self.display_name, args[1]
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let to_path = |path: DataValue| -> Result<Option<Vec<VariantPathElem>>> {
+            match path {
+                DataValue::String(Some(path)) => {
+                    Ok(Some(parse_variant_path(&String::from_utf8_lossy(&path))?))
+                }
+                _ => Ok(None),
+            }
+        };
+
+        extract_variant_paths(columns, input_rows, to_path, |value| {
+            variant_to_text(value).map(|text| text.into_bytes())
+        })
+    }
+}
+
+impl fmt::Display for JsonExtractPathTextFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
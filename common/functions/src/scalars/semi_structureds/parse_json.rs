@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::semi_structured::check_variant_argument;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Parses the JSON strings into variant values, `try_parse_json` returns NULL
+/// instead of an error for the invalid JSON.
+#[derive(Clone)]
+pub struct ParseJsonFunction {
+    display_name: String,
+    suppress_error: bool,
+}
+
+impl ParseJsonFunction {
+    pub fn try_create(display_name: &str, suppress_error: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseJsonFunction {
+            display_name: display_name.to_string(),
+            suppress_error,
+        }))
+    }
+
+    pub fn desc(suppress_error: bool) -> FunctionDescription {
+        FunctionDescription::creator(Box::new(move |display_name| {
+            Self::try_create(display_name, suppress_error)
+        }))
+        .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ParseJsonFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_variant_argument(&self.display_name, &args[0])?;
+        Ok(DataType::Variant)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = columns[0].column().to_minimal_array()?;
+        let series = series.cast_with_type(&DataType::String)?;
+
+        let mut builder = StringArrayBuilder::with_capacity(series.len());
+        for value in series.string()? {
+            match value.map(variant_normalize) {
+                Some(Ok(value)) => builder.append_value(value),
+                Some(Err(_)) if self.suppress_error => builder.append_null(),
+                Some(Err(e)) => return Err(e),
+                None => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ParseJsonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use super::get::GetFunction;
+use super::json_extract_path_text::JsonExtractPathTextFunction;
+use super::parse_json::ParseJsonFunction;
+use crate::scalars::function_factory::FunctionFactory;
+
+#[derive(Clone)]
+pub struct SemiStructuredFunction {}
+
+impl SemiStructuredFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("parse_json", ParseJsonFunction::desc(false));
+        factory.register("try_parse_json", ParseJsonFunction::desc(true));
+        factory.register("get", GetFunction::desc(false));
+        factory.register("get_path", GetFunction::desc(true));
+        factory.register(
+            "json_extract_path_text",
+            JsonExtractPathTextFunction::desc(),
+        );
+    }
+}
+
+/// Checks the argument holds the JSON values, i.e. it's a variant or a string.
+pub(crate) fn check_variant_argument(name: &str, data_type: &DataType) -> Result<()> {
+    match data_type {
+        DataType::Variant | DataType::String | DataType::Null => Ok(()),
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Expected parameter 1 of {} is variant or string, but got {}",
+            name, other
+        ))),
+    }
+}
+
+/// Extracts the values at the paths from the JSON values row by row, the paths are
+/// built from the values of the second column by `to_path`, None if the row is null.
+pub(crate) fn extract_variant_paths<P, E>(
+    columns: &DataColumnsWithField,
+    input_rows: usize,
+    to_path: P,
+    extract: E,
+) -> Result<DataColumn>
+where
+    P: Fn(DataValue) -> Result<Option<Vec<VariantPathElem>>>,
+    E: Fn(&JsonValue) -> Option<Vec<u8>>,
+{
+    let paths = columns[1].column();
+
+    // the constant path is only parsed once
+    let (values, const_path) = match paths {
+        DataColumn::Constant(value, _) => (
+            columns[0].column().to_minimal_array()?,
+            Some(to_path(value.clone())?),
+        ),
+        DataColumn::Array(_) => (columns[0].column().to_array()?, None),
+    };
+    let values = values.cast_with_type(&DataType::String)?;
+
+    let mut builder = StringArrayBuilder::with_capacity(input_rows);
+    for (row, value) in values.string()?.into_iter().enumerate() {
+        let row_path;
+        let path = match &const_path {
+            Some(path) => path,
+            None => {
+                row_path = to_path(paths.try_get(row)?)?;
+                &row_path
+            }
+        };
+
+        match (value, path) {
+            (Some(value), Some(path)) => {
+                let value = variant_from_slice(value)?;
+                builder.append_option(variant_get_path(&value, path).and_then(&extract));
+            }
+            _ => builder.append_null(),
+        }
+    }
+
+    let column: DataColumn = builder.finish().into();
+    Ok(column.resize_constant(input_rows))
+}
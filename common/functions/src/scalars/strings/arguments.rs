@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+/// The string argument of a row, the constant is the same for all the rows.
+pub struct StringArgument {
+    values: DFStringArray,
+    constant: bool,
+}
+
+impl StringArgument {
+    pub fn try_create(column: &DataColumnWithField) -> Result<Self> {
+        let series = column.column().to_minimal_array()?;
+        let series = series.cast_with_type(&DataType::String)?;
+        Ok(StringArgument {
+            values: series.string()?.clone(),
+            constant: matches!(column.column(), DataColumn::Constant(_, _)),
+        })
+    }
+
+    /// The argument which is not given.
+    pub fn create_constant(value: &[u8]) -> Self {
+        StringArgument {
+            values: DFStringArray::new_from_slice(&[value]),
+            constant: true,
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, row: usize) -> Option<&[u8]> {
+        let row = if self.constant { 0 } else { row };
+        self.values.get(row)
+    }
+}
+
+/// The integer argument of a row, the constant is the same for all the rows.
+pub struct Int64Argument {
+    values: DFInt64Array,
+    constant: bool,
+}
+
+impl Int64Argument {
+    pub fn try_create(column: Option<&DataColumnWithField>, default: i64) -> Result<Self> {
+        match column {
+            None => Ok(Int64Argument {
+                values: DFInt64Array::new_from_slice(&[default]),
+                constant: true,
+            }),
+            Some(column) => {
+                let series = column.column().to_minimal_array()?;
+                let series = series.cast_with_type(&DataType::Int64)?;
+                Ok(Int64Argument {
+                    values: series.i64()?.clone(),
+                    constant: matches!(column.column(), DataColumn::Constant(_, _)),
+                })
+            }
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, row: usize) -> Option<i64> {
+        let row = if self.constant { 0 } else { row };
+        self.values.get(row)
+    }
+}
+
+/// The rows to evaluate, the result of the constant arguments is a constant.
+pub fn evaluate_rows(columns: &DataColumnsWithField, input_rows: usize) -> usize {
+    match columns
+        .iter()
+        .all(|column| matches!(column.column(), DataColumn::Constant(_, _)))
+    {
+        true => 1,
+        false => input_rows,
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+
+use super::string2string::String2StringFunction;
+use super::string2string::StringOperator;
+
+// The case conversion of a character may take more bytes, like 'ŉ' to 'ʼN',
+// it is at most three times of the bytes.
+const CASE_EXPANSION: usize = 3;
+
+#[inline]
+fn write_str(s: &str, buffer: &mut [u8]) -> usize {
+    buffer[..s.len()].copy_from_slice(s.as_bytes());
+    s.len()
+}
+
+#[derive(Clone, Default)]
+pub struct Upper {}
+
+impl StringOperator for Upper {
+    #[inline]
+    fn apply_with_no_null<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> usize {
+        match std::str::from_utf8(s) {
+            Ok(s) if !s.is_ascii() => write_str(&s.to_uppercase(), buffer),
+            _ => {
+                let buffer = &mut buffer[0..s.len()];
+                buffer.copy_from_slice(s);
+                buffer.make_ascii_uppercase();
+                s.len()
+            }
+        }
+    }
+
+    fn estimate_bytes(&self, array: &DFStringArray) -> usize {
+        array.inner().values().len() * CASE_EXPANSION
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Lower {}
+
+impl StringOperator for Lower {
+    #[inline]
+    fn apply_with_no_null<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> usize {
+        match std::str::from_utf8(s) {
+            Ok(s) if !s.is_ascii() => write_str(&s.to_lowercase(), buffer),
+            _ => {
+                let buffer = &mut buffer[0..s.len()];
+                buffer.copy_from_slice(s);
+                buffer.make_ascii_lowercase();
+                s.len()
+            }
+        }
+    }
+
+    fn estimate_bytes(&self, array: &DFStringArray) -> usize {
+        array.inner().values().len() * CASE_EXPANSION
+    }
+}
+
+/// The first letter of each word is in upper case and the others are in lower case,
+/// the words are separated by the characters which are not alphanumeric.
+#[derive(Clone, Default)]
+pub struct InitCap {
+    buf: String,
+}
+
+impl StringOperator for InitCap {
+    #[inline]
+    fn apply_with_no_null<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> usize {
+        self.buf.clear();
+        let mut word_start = true;
+        for c in String::from_utf8_lossy(s).chars() {
+            match word_start {
+                true => self.buf.extend(c.to_uppercase()),
+                false => self.buf.extend(c.to_lowercase()),
+            }
+            word_start = !c.is_alphanumeric();
+        }
+        write_str(&self.buf, buffer)
+    }
+
+    fn estimate_bytes(&self, array: &DFStringArray) -> usize {
+        array.inner().values().len() * CASE_EXPANSION
+    }
+}
+
+pub type UpperFunction = String2StringFunction<Upper>;
+pub type LowerFunction = String2StringFunction<Lower>;
+pub type InitCapFunction = String2StringFunction<InitCap>;
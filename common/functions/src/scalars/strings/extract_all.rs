@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use crate::scalars::arrays::build_array_column;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::strings::regexp::check_regexp_arguments;
+use crate::scalars::strings::regexp::RegexpCache;
+use crate::scalars::Function;
+
+/// extractAll(haystack, pattern), the array of all the matches, each one is the first group
+/// of the match if the pattern has groups.
+#[derive(Clone)]
+pub struct ExtractAllFunction {
+    display_name: String,
+}
+
+impl ExtractAllFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ExtractAllFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn extract_all(value: &[u8], regexp: &Regex) -> Vec<DataValue> {
+        let group = if regexp.captures_len() > 1 { 1 } else { 0 };
+        regexp
+            .captures_iter(value)
+            .filter_map(|captures| captures.get(group))
+            .map(|matched| DataValue::String(Some(matched.as_bytes().to_vec())))
+            .collect()
+    }
+}
+
+impl Function for ExtractAllFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(&self.display_name, args, &[0, 1])?;
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            DataType::String,
+            true,
+        ))))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringArgument::try_create(&columns[0])?;
+        let patterns = StringArgument::try_create(&columns[1])?;
+
+        let mut cache = RegexpCache::default();
+        let rows = evaluate_rows(columns, input_rows);
+        let result = (0..rows).map(|row| match (values.get(row), patterns.get(row)) {
+            (Some(value), Some(pattern)) => {
+                let regexp = cache.get(pattern, b"")?;
+                Ok(Some(Self::extract_all(value, regexp)))
+            }
+            _ => Ok(None),
+        });
+
+        let result = build_array_column(&DataType::String, result, rows)?;
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ExtractAllFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
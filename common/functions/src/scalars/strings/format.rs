@@ -0,0 +1,191 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::Function;
+
+/// format(pattern, s0, s1, ...), the `{}` in the pattern is replaced by the next argument
+/// and `{n}` by the n-th (0-based) argument, `{{` and `}}` are the escaped braces.
+#[derive(Clone)]
+pub struct FormatFunction {
+    display_name: String,
+}
+
+enum FormatPart {
+    Literal(Vec<u8>),
+    Argument(usize),
+}
+
+impl FormatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn parse(pattern: &[u8], arguments: usize) -> Result<Vec<FormatPart>> {
+        let error = |message: &str| {
+            ErrorCode::BadArguments(format!(
+                "Invalid format pattern '{}': {}",
+                String::from_utf8_lossy(pattern),
+                message
+            ))
+        };
+
+        let mut parts = vec![];
+        let mut literal = vec![];
+        let mut next_argument = 0;
+        let (mut implicit, mut explicit) = (false, false);
+        let mut pos = 0;
+        while pos < pattern.len() {
+            match (pattern[pos], pattern.get(pos + 1)) {
+                (b'{', Some(b'{')) | (b'}', Some(b'}')) => {
+                    literal.push(pattern[pos]);
+                    pos += 2;
+                }
+                (b'{', _) => {
+                    let end = pattern[pos..]
+                        .iter()
+                        .position(|c| *c == b'}')
+                        .ok_or_else(|| error("unclosed '{'"))?;
+                    let index = &pattern[pos + 1..pos + end];
+                    let index = match index.is_empty() {
+                        true => {
+                            implicit = true;
+                            next_argument += 1;
+                            next_argument - 1
+                        }
+                        false => {
+                            explicit = true;
+                            std::str::from_utf8(index)
+                                .ok()
+                                .and_then(|index| index.parse::<usize>().ok())
+                                .ok_or_else(|| error("the argument index must be a number"))?
+                        }
+                    };
+
+                    if implicit && explicit {
+                        return Err(error("cannot mix '{}' and '{n}'"));
+                    }
+                    if index >= arguments {
+                        return Err(error("the argument index is out of range"));
+                    }
+
+                    parts.push(FormatPart::Literal(std::mem::take(&mut literal)));
+                    parts.push(FormatPart::Argument(index));
+                    pos += end + 1;
+                }
+                (b'}', _) => return Err(error("unmatched '}'")),
+                (c, _) => {
+                    literal.push(c);
+                    pos += 1;
+                }
+            }
+        }
+        parts.push(FormatPart::Literal(literal));
+        Ok(parts)
+    }
+}
+
+impl Function for FormatFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0] != DataType::String && !args[0].is_null() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string pattern of function {}, but got {}",
+                self.display_name, args[0]
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let patterns = StringArgument::try_create(&columns[0])?;
+        let arguments = columns[1..]
+            .iter()
+            .map(StringArgument::try_create)
+            .collect::<Result<Vec<_>>>()?;
+
+        // The parsed pattern is reused by the rows with the same pattern.
+        let mut parsed: Option<(&[u8], Vec<FormatPart>)> = None;
+        let mut buf = Vec::new();
+        let rows = evaluate_rows(columns, input_rows);
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        for row in 0..rows {
+            let pattern = match patterns.get(row) {
+                None => {
+                    builder.append_null();
+                    continue;
+                }
+                Some(pattern) => pattern,
+            };
+
+            if !matches!(&parsed, Some((p, _)) if *p == pattern) {
+                parsed = Some((pattern, Self::parse(pattern, arguments.len())?));
+            }
+
+            let values = arguments
+                .iter()
+                .map(|argument| argument.get(row))
+                .collect::<Option<Vec<_>>>();
+            match (&parsed, values) {
+                (Some((_, parts)), Some(values)) => {
+                    buf.clear();
+                    for part in parts {
+                        match part {
+                            FormatPart::Literal(literal) => buf.extend_from_slice(literal),
+                            FormatPart::Argument(index) => buf.extend_from_slice(values[*index]),
+                        }
+                    }
+                    builder.append_value(&buf);
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let result: DataColumn = builder.finish().into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for FormatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -25,10 +25,15 @@ use crate::scalars::Function;
 const FUNC_LOCATE: u8 = 1;
 const FUNC_POSITION: u8 = 2;
 const FUNC_INSTR: u8 = 3;
+const FUNC_POSITION_UTF8: u8 = 4;
+const FUNC_POSITION_CASE_INSENSITIVE_UTF8: u8 = 5;
 
 pub type LocateFunction = LocatingFunction<FUNC_LOCATE>;
 pub type PositionFunction = LocatingFunction<FUNC_POSITION>;
 pub type InstrFunction = LocatingFunction<FUNC_INSTR>;
+pub type PositionUTF8Function = LocatingFunction<FUNC_POSITION_UTF8>;
+pub type PositionCaseInsensitiveUTF8Function =
+    LocatingFunction<FUNC_POSITION_CASE_INSENSITIVE_UTF8>;
 
 #[derive(Clone)]
 pub struct LocatingFunction<const T: u8> {
@@ -46,6 +51,18 @@ impl<const T: u8> LocatingFunction<T> {
         FunctionDescription::creator(Box::new(Self::try_create))
             .features(FunctionFeatures::default().deterministic())
     }
+
+    // The UTF-8 variants take the haystack first and locate by the characters.
+    const IS_UTF8: bool = T == FUNC_POSITION_UTF8 || T == FUNC_POSITION_CASE_INSENSITIVE_UTF8;
+
+    #[inline]
+    fn find(str: &[u8], substr: &[u8], pos: &u64) -> u64 {
+        match T {
+            FUNC_POSITION_UTF8 => find_at_utf8(str, substr, pos, false),
+            FUNC_POSITION_CASE_INSENSITIVE_UTF8 => find_at_utf8(str, substr, pos, true),
+            _ => find_at(str, substr, pos),
+        }
+    }
 }
 
 impl<const T: u8> Function for LocatingFunction<T> {
@@ -54,7 +71,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
     }
 
     fn variadic_arguments(&self) -> Option<(usize, usize)> {
-        if T == FUNC_LOCATE {
+        if T == FUNC_LOCATE || Self::IS_UTF8 {
             Some((2, 3))
         } else {
             Some((2, 2))
@@ -70,7 +87,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let (ss_column, s_column) = if T == FUNC_INSTR {
+        let (ss_column, s_column) = if T == FUNC_INSTR || Self::IS_UTF8 {
             (
                 columns[1].column().cast_with_type(&DataType::String)?,
                 columns[0].column().cast_with_type(&DataType::String)?,
@@ -82,7 +99,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
             )
         };
 
-        let p_column = if T == FUNC_LOCATE && columns.len() == 3 {
+        let p_column = if (T == FUNC_LOCATE || Self::IS_UTF8) && columns.len() == 3 {
             columns[2].column().cast_with_type(&DataType::UInt64)?
         } else {
             DataColumn::Constant(DataValue::UInt64(Some(1)), input_rows)
@@ -95,7 +112,10 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 DataColumn::Constant(DataValue::UInt64(p), _),
             ) => {
                 if let (Some(ss), Some(s), Some(p)) = (ss, s, p) {
-                    DataColumn::Constant(DataValue::UInt64(Some(find_at(&s, &ss, &p))), input_rows)
+                    DataColumn::Constant(
+                        DataValue::UInt64(Some(Self::find(&s, &ss, &p))),
+                        input_rows,
+                    )
                 } else {
                     DataColumn::Constant(DataValue::Null, input_rows)
                 }
@@ -108,7 +128,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 if let (Some(ss), Some(p)) = (ss, p) {
                     let s_array = s_series.string()?;
                     DFUInt64Array::new_from_iter_validity(
-                        s_array.into_no_null_iter().map(|s| find_at(s, &ss, &p)),
+                        s_array.into_no_null_iter().map(|s| Self::find(s, &ss, &p)),
                         s_array.inner().validity().cloned(),
                     )
                     .into()
@@ -124,7 +144,9 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 if let (Some(s), Some(p)) = (s, p) {
                     let ss_array = ss_series.string()?;
                     DFUInt64Array::new_from_iter_validity(
-                        ss_array.into_no_null_iter().map(|ss| find_at(&s, ss, &p)),
+                        ss_array
+                            .into_no_null_iter()
+                            .map(|ss| Self::find(&s, ss, &p)),
                         ss_array.inner().validity().cloned(),
                     )
                     .into()
@@ -144,7 +166,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
                         ss_array
                             .into_no_null_iter()
                             .zip(s_array.into_no_null_iter())
-                            .map(|(ss, s)| find_at(s, ss, &p)),
+                            .map(|(ss, s)| Self::find(s, ss, &p)),
                         combine_validities(ss_array.inner().validity(), s_array.inner().validity()),
                     )
                     .into()
@@ -160,7 +182,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 if let (Some(ss), Some(s)) = (ss, s) {
                     let p_array = p_series.u64()?;
                     DFUInt64Array::new_from_iter_validity(
-                        p_array.into_no_null_iter().map(|p| find_at(&s, &ss, p)),
+                        p_array.into_no_null_iter().map(|p| Self::find(&s, &ss, p)),
                         p_array.inner().validity().cloned(),
                     )
                     .into()
@@ -180,7 +202,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
                         s_array
                             .into_no_null_iter()
                             .zip(p_array.into_no_null_iter())
-                            .map(|(s, p)| find_at(s, &ss, p)),
+                            .map(|(s, p)| Self::find(s, &ss, p)),
                         combine_validities(s_array.inner().validity(), p_array.inner().validity()),
                     )
                     .into()
@@ -200,7 +222,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
                         ss_array
                             .into_no_null_iter()
                             .zip(p_array.into_no_null_iter())
-                            .map(|(ss, p)| find_at(&s, ss, p)),
+                            .map(|(ss, p)| Self::find(&s, ss, p)),
                         combine_validities(ss_array.inner().validity(), p_array.inner().validity()),
                     )
                     .into()
@@ -223,7 +245,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
                         s_array.into_no_null_iter(),
                         p_array.into_no_null_iter(),
                     )
-                    .map(|(ss, s, p)| find_at(s, ss, p)),
+                    .map(|(ss, s, p)| Self::find(s, ss, p)),
                     combine_validities(
                         combine_validities(
                             ss_array.inner().validity(),
@@ -264,3 +286,37 @@ fn find_at(str: &[u8], substr: &[u8], pos: &u64) -> u64 {
         0_u64
     }
 }
+
+/// The character position of the substring from the character position `pos`, 0 if not found.
+#[inline]
+fn find_at_utf8(str: &[u8], substr: &[u8], pos: &u64, case_insensitive: bool) -> u64 {
+    let pos = (*pos) as usize;
+    if pos == 0 {
+        return 0_u64;
+    }
+
+    let str = String::from_utf8_lossy(str);
+    let substr = String::from_utf8_lossy(substr);
+    let chars = str.chars().count();
+    if substr.is_empty() {
+        return if pos <= chars + 1 { pos as u64 } else { 0_u64 };
+    }
+
+    str.char_indices()
+        .enumerate()
+        .skip(pos - 1)
+        .find(|(_, (offset, _))| match case_insensitive {
+            true => starts_with_ignore_case(&str[*offset..], &substr),
+            false => str[*offset..].starts_with(&*substr),
+        })
+        .map(|(i, _)| i as u64 + 1)
+        .unwrap_or(0)
+}
+
+fn starts_with_ignore_case(str: &str, prefix: &str) -> bool {
+    let mut str = str.chars().flat_map(char::to_lowercase);
+    prefix
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|c| str.next() == Some(c))
+}
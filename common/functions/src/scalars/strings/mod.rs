@@ -12,15 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod arguments;
 mod ascii;
 mod base_64;
 mod bin;
 mod bit_length;
+mod case;
 mod char_length;
 mod concat;
 mod concat_ws;
 mod elt;
+mod extract_all;
 mod field;
+mod format;
 mod hex;
 mod insert;
 mod leftright;
@@ -30,16 +34,26 @@ mod octet_length;
 mod ord;
 mod pad;
 mod quote;
+mod regexp;
+mod regexp_extract;
+mod regexp_instr;
+mod regexp_like;
+mod regexp_replace;
+mod regexp_substr;
 mod repeat;
 mod replace;
 mod reverse;
 mod space;
+mod split_by_char;
+mod split_part;
+mod starts_with;
 mod strcmp;
 mod string;
 mod string2number;
 mod string2string;
 mod substring;
 mod substring_index;
+mod translate;
 mod trim;
 mod unhex;
 
@@ -48,33 +62,49 @@ pub use base_64::Base64DecodeFunction;
 pub use base_64::Base64EncodeFunction;
 pub use bin::BinFunction;
 pub use bit_length::BitLengthFunction;
+pub use case::InitCapFunction;
+pub use case::LowerFunction;
+pub use case::UpperFunction;
 pub use char_length::CharLengthFunction;
 pub use concat::ConcatFunction;
 pub use concat_ws::ConcatWsFunction;
 pub use elt::EltFunction;
+pub use extract_all::ExtractAllFunction;
 pub use field::FieldFunction;
+pub use format::FormatFunction;
 pub use insert::InsertFunction;
 pub use leftright::LeftFunction;
 pub use leftright::RightFunction;
 pub use locate::InstrFunction;
 pub use locate::LocateFunction;
+pub use locate::PositionCaseInsensitiveUTF8Function;
 pub use locate::PositionFunction;
+pub use locate::PositionUTF8Function;
 pub use oct::OctFunction;
 pub use octet_length::OctetLengthFunction;
 pub use ord::OrdFunction;
 pub use pad::LeftPadFunction;
 pub use pad::RightPadFunction;
 pub use quote::QuoteFunction;
+pub use regexp_extract::RegexpExtractFunction;
+pub use regexp_instr::RegexpInStrFunction;
+pub use regexp_like::RegexpLikeFunction;
+pub use regexp_replace::RegexpReplaceFunction;
+pub use regexp_substr::RegexpSubStrFunction;
 pub use repeat::RepeatFunction;
 pub use replace::ReplaceFunction;
 pub use reverse::ReverseFunction;
 pub use space::SpaceFunction;
+pub use split_by_char::SplitByCharFunction;
+pub use split_part::SplitPartFunction;
+pub use starts_with::StartsWithFunction;
 pub use strcmp::StrcmpFunction;
 pub use string::StringFunction;
 pub use string2number::NumberResultFunction;
 pub use string2number::String2NumberFunction;
 pub use substring::SubstringFunction;
 pub use substring_index::SubstringIndexFunction;
+pub use translate::TranslateFunction;
 pub use trim::LTrimFunction;
 pub use trim::RTrimFunction;
 pub use trim::TrimFunction;
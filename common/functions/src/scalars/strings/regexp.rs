@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+use regex::bytes::RegexBuilder;
+
+/// The patterns compiled in a block, each distinct pattern is compiled once,
+/// so a constant pattern is compiled only once for all the rows.
+#[derive(Default)]
+pub struct RegexpCache {
+    // match type -> pattern -> regexp
+    regexps: HashMap<Vec<u8>, HashMap<Vec<u8>, Regex>>,
+}
+
+impl RegexpCache {
+    pub fn get(&mut self, pattern: &[u8], match_type: &[u8]) -> Result<&Regex> {
+        let compiled = self
+            .regexps
+            .get(match_type)
+            .map_or(false, |regexps| regexps.contains_key(pattern));
+
+        if !compiled {
+            let regexp = build_regexp(pattern, match_type)?;
+            self.regexps
+                .entry(match_type.to_vec())
+                .or_default()
+                .insert(pattern.to_vec(), regexp);
+        }
+
+        Ok(&self.regexps[match_type][pattern])
+    }
+}
+
+/// The match type is as MySQL, 'c' case sensitive, 'i' case insensitive,
+/// 'm' multiple lines, 'n' the '.' matches line terminators and 'u' unix line endings only.
+/// The latter one wins when the flags are contradictory.
+pub fn build_regexp(pattern: &[u8], match_type: &[u8]) -> Result<Regex> {
+    let pattern = std::str::from_utf8(pattern).map_err(|e| {
+        ErrorCode::BadArguments(format!("Unable to convert the pattern to string: {}", e))
+    })?;
+
+    let mut builder = RegexBuilder::new(pattern);
+    for flag in match_type {
+        match flag {
+            b'c' => builder.case_insensitive(false),
+            b'i' => builder.case_insensitive(true),
+            b'm' => builder.multi_line(true),
+            b'n' => builder.dot_matches_new_line(true),
+            b'u' => &mut builder,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Incorrect match type '{}' of regular expression",
+                    *flag as char
+                )))
+            }
+        };
+    }
+
+    builder
+        .build()
+        .map_err(|e| ErrorCode::BadArguments(format!("Unable to build regex from pattern: {}", e)))
+}
+
+/// Check the types of the arguments, the strings are the positions of the string arguments
+/// and the others are integers.
+pub fn check_regexp_arguments(name: &str, args: &[DataType], strings: &[usize]) -> Result<()> {
+    for (index, arg) in args.iter().enumerate() {
+        let expected = match strings.contains(&index) {
+            true => arg == &DataType::String || arg.is_null(),
+            false => arg.is_integer() || arg.is_null(),
+        };
+
+        if !expected {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Unexpected type {} of the argument {} of function {}",
+                arg,
+                index + 1,
+                name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The byte offset of the position-th (1-based) character, None if it is beyond the end.
+pub fn char_position_to_offset(value: &[u8], position: i64) -> Result<Option<usize>> {
+    if position < 1 {
+        return Err(ErrorCode::BadArguments(format!(
+            "Incorrect position {} of regular expression, it must be positive",
+            position
+        )));
+    }
+
+    Ok(value
+        .iter()
+        .enumerate()
+        .filter(|(_, byte)| (**byte & 0xC0) != 0x80)
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(value.len()))
+        .nth(position as usize - 1))
+}
+
+/// The 1-based character position of the byte offset.
+pub fn offset_to_char_position(value: &[u8], offset: usize) -> u64 {
+    value[..offset]
+        .iter()
+        .filter(|byte| (**byte & 0xC0) != 0x80)
+        .count() as u64
+        + 1
+}
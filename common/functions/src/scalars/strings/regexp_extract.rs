@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::Int64Argument;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::strings::regexp::check_regexp_arguments;
+use crate::scalars::strings::regexp::RegexpCache;
+use crate::scalars::Function;
+
+/// regexp_extract(haystack, pattern[, group]), the group of the first match, the group is 1 by default
+/// and it is the whole match if the pattern has no groups. Returns the empty string if there is no match.
+#[derive(Clone)]
+pub struct RegexpExtractFunction {
+    display_name: String,
+}
+
+impl RegexpExtractFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpExtractFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn extract<'a>(value: &'a [u8], regexp: &Regex, group: Option<i64>) -> Result<&'a [u8]> {
+        let groups = regexp.captures_len() - 1;
+        let group = group.unwrap_or(if groups == 0 { 0 } else { 1 });
+        if group < 0 || group as usize > groups {
+            return Err(ErrorCode::BadArguments(format!(
+                "Incorrect group {} of regular expression, the pattern has {} groups",
+                group, groups
+            )));
+        }
+
+        Ok(regexp
+            .captures(value)
+            .and_then(|captures| captures.get(group as usize))
+            .map_or(&b""[..], |matched| matched.as_bytes()))
+    }
+}
+
+impl Function for RegexpExtractFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(&self.display_name, args, &[0, 1])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringArgument::try_create(&columns[0])?;
+        let patterns = StringArgument::try_create(&columns[1])?;
+        let groups = match columns.get(2) {
+            None => None,
+            Some(column) => Some(Int64Argument::try_create(Some(column), 0)?),
+        };
+
+        let mut cache = RegexpCache::default();
+        let rows = evaluate_rows(columns, input_rows);
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        for row in 0..rows {
+            let group = match &groups {
+                None => Some(None),
+                Some(groups) => groups.get(row).map(Some),
+            };
+
+            match (values.get(row), patterns.get(row), group) {
+                (Some(value), Some(pattern), Some(group)) => {
+                    let regexp = cache.get(pattern, b"")?;
+                    builder.append_value(Self::extract(value, regexp, group)?);
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let result: DataColumn = builder.finish().into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpExtractFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
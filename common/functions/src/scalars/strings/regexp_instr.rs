@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::Int64Argument;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::strings::regexp::char_position_to_offset;
+use crate::scalars::strings::regexp::check_regexp_arguments;
+use crate::scalars::strings::regexp::offset_to_char_position;
+use crate::scalars::strings::regexp::RegexpCache;
+use crate::scalars::Function;
+
+/// regexp_instr(expr, pat[, pos[, occurrence[, return_option[, match_type]]]]), the character
+/// position of the occurrence-th match, it is the position after the match if the return option is 1.
+/// Returns 0 if there is no such match.
+#[derive(Clone)]
+pub struct RegexpInStrFunction {
+    display_name: String,
+}
+
+impl RegexpInStrFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpInStrFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn instr(
+        value: &[u8],
+        regexp: &Regex,
+        position: i64,
+        occurrence: i64,
+        return_option: i64,
+    ) -> Result<u64> {
+        if occurrence < 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Incorrect occurrence {} of regular expression, it must be positive",
+                occurrence
+            )));
+        }
+        if return_option != 0 && return_option != 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Incorrect return option {} of regular expression, it must be 0 or 1",
+                return_option
+            )));
+        }
+
+        let offset = match char_position_to_offset(value, position)? {
+            None => return Ok(0),
+            Some(offset) => offset,
+        };
+
+        Ok(
+            match regexp
+                .find_iter(&value[offset..])
+                .nth(occurrence as usize - 1)
+            {
+                None => 0,
+                Some(matched) if return_option == 0 => {
+                    offset_to_char_position(value, offset + matched.start())
+                }
+                Some(matched) => offset_to_char_position(value, offset + matched.end()),
+            },
+        )
+    }
+}
+
+impl Function for RegexpInStrFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 6))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(&self.display_name, args, &[0, 1, 5])?;
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringArgument::try_create(&columns[0])?;
+        let patterns = StringArgument::try_create(&columns[1])?;
+        let positions = Int64Argument::try_create(columns.get(2), 1)?;
+        let occurrences = Int64Argument::try_create(columns.get(3), 1)?;
+        let return_options = Int64Argument::try_create(columns.get(4), 0)?;
+        let match_types = match columns.get(5) {
+            None => StringArgument::create_constant(b""),
+            Some(column) => StringArgument::try_create(column)?,
+        };
+
+        let mut cache = RegexpCache::default();
+        let rows = evaluate_rows(columns, input_rows);
+        let mut result = Vec::with_capacity(rows);
+        for row in 0..rows {
+            match (
+                values.get(row),
+                patterns.get(row),
+                positions.get(row),
+                occurrences.get(row),
+                return_options.get(row),
+                match_types.get(row),
+            ) {
+                (
+                    Some(value),
+                    Some(pattern),
+                    Some(position),
+                    Some(occurrence),
+                    Some(return_option),
+                    Some(match_type),
+                ) => {
+                    let regexp = cache.get(pattern, match_type)?;
+                    let pos = Self::instr(value, regexp, position, occurrence, return_option)?;
+                    result.push(Some(pos));
+                }
+                _ => result.push(None),
+            }
+        }
+
+        let result: DataColumn = DFUInt64Array::new_from_opt_slice(&result).into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpInStrFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
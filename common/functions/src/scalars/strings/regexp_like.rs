@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::strings::regexp::check_regexp_arguments;
+use crate::scalars::strings::regexp::RegexpCache;
+use crate::scalars::Function;
+
+/// regexp_like(expr, pat[, match_type]), the operators `expr [NOT] REGEXP pat` and
+/// `expr [NOT] RLIKE pat` are the same.
+#[derive(Clone)]
+pub struct RegexpLikeFunction {
+    display_name: String,
+    negated: bool,
+}
+
+impl RegexpLikeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpLikeFunction {
+            display_name: display_name.to_string(),
+            negated: false,
+        }))
+    }
+
+    pub fn try_create_not(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpLikeFunction {
+            display_name: display_name.to_string(),
+            negated: true,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("not regexp")
+                .bool_function(),
+        )
+    }
+
+    pub fn not_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_not)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function("regexp")
+                .bool_function(),
+        )
+    }
+}
+
+impl Function for RegexpLikeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(&self.display_name, args, &[0, 1, 2])?;
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringArgument::try_create(&columns[0])?;
+        let patterns = StringArgument::try_create(&columns[1])?;
+        let match_types = match columns.get(2) {
+            None => StringArgument::create_constant(b""),
+            Some(column) => StringArgument::try_create(column)?,
+        };
+
+        let mut cache = RegexpCache::default();
+        let rows = evaluate_rows(columns, input_rows);
+        let mut result = Vec::with_capacity(rows);
+        for row in 0..rows {
+            result.push(
+                match (values.get(row), patterns.get(row), match_types.get(row)) {
+                    (Some(value), Some(pattern), Some(match_type)) => {
+                        let regexp = cache.get(pattern, match_type)?;
+                        Some(regexp.is_match(value) != self.negated)
+                    }
+                    _ => None,
+                },
+            );
+        }
+
+        let result: DataColumn = DFBooleanArray::new_from_opt_slice(&result).into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpLikeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
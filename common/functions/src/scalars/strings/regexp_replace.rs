@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::Int64Argument;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::strings::regexp::char_position_to_offset;
+use crate::scalars::strings::regexp::check_regexp_arguments;
+use crate::scalars::strings::regexp::RegexpCache;
+use crate::scalars::Function;
+
+/// regexp_replace(expr, pat, repl[, pos[, occurrence[, match_type]]]), the occurrence 0 replaces
+/// all the matches from the position, the groups are referred as $1 in the replacement.
+#[derive(Clone)]
+pub struct RegexpReplaceFunction {
+    display_name: String,
+}
+
+impl RegexpReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpReplaceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn replace(
+        value: &[u8],
+        regexp: &Regex,
+        replacement: &[u8],
+        position: i64,
+        occurrence: i64,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        if occurrence < 0 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Incorrect occurrence {} of regular expression, it must not be negative",
+                occurrence
+            )));
+        }
+
+        buf.clear();
+        let offset = match char_position_to_offset(value, position)? {
+            None => {
+                buf.extend_from_slice(value);
+                return Ok(());
+            }
+            Some(offset) => offset,
+        };
+
+        let (head, tail) = value.split_at(offset);
+        buf.extend_from_slice(head);
+        match occurrence {
+            0 => buf.extend_from_slice(&regexp.replace_all(tail, replacement)),
+            n => match regexp.captures_iter(tail).nth(n as usize - 1) {
+                None => buf.extend_from_slice(tail),
+                Some(captures) => {
+                    let matched = captures.get(0).unwrap();
+                    buf.extend_from_slice(&tail[..matched.start()]);
+                    captures.expand(replacement, buf);
+                    buf.extend_from_slice(&tail[matched.end()..]);
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+impl Function for RegexpReplaceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, 6))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        check_regexp_arguments(&self.display_name, args, &[0, 1, 2, 5])?;
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringArgument::try_create(&columns[0])?;
+        let patterns = StringArgument::try_create(&columns[1])?;
+        let replacements = StringArgument::try_create(&columns[2])?;
+        let positions = Int64Argument::try_create(columns.get(3), 1)?;
+        let occurrences = Int64Argument::try_create(columns.get(4), 0)?;
+        let match_types = match columns.get(5) {
+            None => StringArgument::create_constant(b""),
+            Some(column) => StringArgument::try_create(column)?,
+        };
+
+        let mut cache = RegexpCache::default();
+        let mut buf = Vec::new();
+        let rows = evaluate_rows(columns, input_rows);
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        for row in 0..rows {
+            match (
+                values.get(row),
+                patterns.get(row),
+                replacements.get(row),
+                positions.get(row),
+                occurrences.get(row),
+                match_types.get(row),
+            ) {
+                (
+                    Some(value),
+                    Some(pattern),
+                    Some(replacement),
+                    Some(position),
+                    Some(occurrence),
+                    Some(match_type),
+                ) => {
+                    let regexp = cache.get(pattern, match_type)?;
+                    Self::replace(value, regexp, replacement, position, occurrence, &mut buf)?;
+                    builder.append_value(&buf);
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let result: DataColumn = builder.finish().into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
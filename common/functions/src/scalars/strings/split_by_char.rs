@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::arrays::build_array_column;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::strings::split_part::split;
+use crate::scalars::Function;
+
+/// splitByChar(separator, s), the array of the substrings split by the separator,
+/// which must be a single character.
+#[derive(Clone)]
+pub struct SplitByCharFunction {
+    display_name: String,
+}
+
+impl SplitByCharFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(SplitByCharFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for SplitByCharFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if arg != &DataType::String && !arg.is_null() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or null, but got {}",
+                    arg
+                )));
+            }
+        }
+
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            DataType::String,
+            true,
+        ))))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let separators = StringArgument::try_create(&columns[0])?;
+        let values = StringArgument::try_create(&columns[1])?;
+
+        let rows = evaluate_rows(columns, input_rows);
+        let result = (0..rows).map(|row| match (separators.get(row), values.get(row)) {
+            (Some(separator), Some(value)) => {
+                let is_char = std::str::from_utf8(separator)
+                    .map_or(false, |separator| separator.chars().count() == 1);
+                if !is_char {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The separator of {} must be a single character, but got '{}'",
+                        self.display_name,
+                        String::from_utf8_lossy(separator)
+                    )));
+                }
+
+                Ok(Some(
+                    split(value, separator)
+                        .into_iter()
+                        .map(|field| DataValue::String(Some(field.to_vec())))
+                        .collect(),
+                ))
+            }
+            _ => Ok(None),
+        });
+
+        let result = build_array_column(&DataType::String, result, rows)?;
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for SplitByCharFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
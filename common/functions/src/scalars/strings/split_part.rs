@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::Int64Argument;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::Function;
+
+/// split_part(s, delimiter, n), the n-th field of the string split by the delimiter,
+/// the negative n counts from the end. Returns the empty string if there is no such field.
+#[derive(Clone)]
+pub struct SplitPartFunction {
+    display_name: String,
+}
+
+impl SplitPartFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(SplitPartFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn split_part<'a>(value: &'a [u8], delimiter: &[u8], n: i64) -> Result<&'a [u8]> {
+        if n == 0 {
+            return Err(ErrorCode::BadArguments(
+                "The field position of split_part must not be zero",
+            ));
+        }
+
+        let fields = split(value, delimiter);
+        let index = match n > 0 {
+            true => Some(n as usize - 1),
+            false => fields.len().checked_sub(n.unsigned_abs() as usize),
+        };
+        Ok(index
+            .and_then(|index| fields.get(index))
+            .map_or(&b""[..], |field| *field))
+    }
+}
+
+/// Split the value by the delimiter, the empty delimiter does not split the value.
+pub fn split<'a>(value: &'a [u8], delimiter: &[u8]) -> Vec<&'a [u8]> {
+    if delimiter.is_empty() {
+        return vec![value];
+    }
+
+    let mut fields = Vec::new();
+    let mut start = 0;
+    let mut pos = 0;
+    while pos + delimiter.len() <= value.len() {
+        if &value[pos..pos + delimiter.len()] == delimiter {
+            fields.push(&value[start..pos]);
+            pos += delimiter.len();
+            start = pos;
+        } else {
+            pos += 1;
+        }
+    }
+    fields.push(&value[start..]);
+    fields
+}
+
+impl Function for SplitPartFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if (args[0] != DataType::String && !args[0].is_null())
+            || (args[1] != DataType::String && !args[1].is_null())
+            || (!args[2].is_integer() && !args[2].is_null())
+        {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected (String, String, Integer) arguments of function {}, but got ({}, {}, {})",
+                self.display_name, args[0], args[1], args[2]
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringArgument::try_create(&columns[0])?;
+        let delimiters = StringArgument::try_create(&columns[1])?;
+        let positions = Int64Argument::try_create(Some(&columns[2]), 1)?;
+
+        let rows = evaluate_rows(columns, input_rows);
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        for row in 0..rows {
+            match (values.get(row), delimiters.get(row), positions.get(row)) {
+                (Some(value), Some(delimiter), Some(n)) => {
+                    builder.append_value(Self::split_part(value, delimiter, n)?)
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let result: DataColumn = builder.finish().into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for SplitPartFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::Function;
+
+/// startsWith(s, prefix) and endsWith(s, suffix).
+#[derive(Clone)]
+pub struct StartsWithFunction {
+    display_name: String,
+    suffix: bool,
+}
+
+impl StartsWithFunction {
+    pub fn try_create_starts_with(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StartsWithFunction {
+            display_name: display_name.to_string(),
+            suffix: false,
+        }))
+    }
+
+    pub fn try_create_ends_with(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StartsWithFunction {
+            display_name: display_name.to_string(),
+            suffix: true,
+        }))
+    }
+
+    pub fn starts_with_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_starts_with))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    pub fn ends_with_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_ends_with))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for StartsWithFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if arg != &DataType::String && !arg.is_null() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or null, but got {}",
+                    arg
+                )));
+            }
+        }
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringArgument::try_create(&columns[0])?;
+        let affixes = StringArgument::try_create(&columns[1])?;
+
+        let rows = evaluate_rows(columns, input_rows);
+        let result = (0..rows)
+            .map(|row| match (values.get(row), affixes.get(row)) {
+                (Some(value), Some(affix)) if self.suffix => Some(value.ends_with(affix)),
+                (Some(value), Some(affix)) => Some(value.starts_with(affix)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let result: DataColumn = DFBooleanArray::new_from_opt_slice(&result).into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for StartsWithFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
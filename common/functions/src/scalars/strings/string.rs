@@ -22,31 +22,47 @@ use crate::scalars::CharLengthFunction;
 use crate::scalars::ConcatFunction;
 use crate::scalars::ConcatWsFunction;
 use crate::scalars::EltFunction;
+use crate::scalars::ExtractAllFunction;
 use crate::scalars::FieldFunction;
+use crate::scalars::FormatFunction;
 use crate::scalars::HexFunction;
+use crate::scalars::InitCapFunction;
 use crate::scalars::InsertFunction;
 use crate::scalars::InstrFunction;
 use crate::scalars::LTrimFunction;
 use crate::scalars::LeftFunction;
 use crate::scalars::LeftPadFunction;
 use crate::scalars::LocateFunction;
+use crate::scalars::LowerFunction;
 use crate::scalars::OctFunction;
 use crate::scalars::OctetLengthFunction;
 use crate::scalars::OrdFunction;
+use crate::scalars::PositionCaseInsensitiveUTF8Function;
 use crate::scalars::PositionFunction;
+use crate::scalars::PositionUTF8Function;
 use crate::scalars::QuoteFunction;
 use crate::scalars::RTrimFunction;
+use crate::scalars::RegexpExtractFunction;
+use crate::scalars::RegexpInStrFunction;
+use crate::scalars::RegexpLikeFunction;
+use crate::scalars::RegexpReplaceFunction;
+use crate::scalars::RegexpSubStrFunction;
 use crate::scalars::RepeatFunction;
 use crate::scalars::ReplaceFunction;
 use crate::scalars::ReverseFunction;
 use crate::scalars::RightFunction;
 use crate::scalars::RightPadFunction;
 use crate::scalars::SpaceFunction;
+use crate::scalars::SplitByCharFunction;
+use crate::scalars::SplitPartFunction;
+use crate::scalars::StartsWithFunction;
 use crate::scalars::StrcmpFunction;
 use crate::scalars::SubstringFunction;
 use crate::scalars::SubstringIndexFunction;
+use crate::scalars::TranslateFunction;
 use crate::scalars::TrimFunction;
 use crate::scalars::UnhexFunction;
+use crate::scalars::UpperFunction;
 
 #[derive(Clone)]
 pub struct StringFunction;
@@ -90,5 +106,31 @@ impl StringFunction {
         factory.register("character_length", CharLengthFunction::desc());
         factory.register("ord", OrdFunction::desc());
         factory.register("bin", BinFunction::desc());
+        factory.register("regexp_like", RegexpLikeFunction::desc());
+        factory.register("regexp", RegexpLikeFunction::desc());
+        factory.register("rlike", RegexpLikeFunction::desc());
+        factory.register("not regexp", RegexpLikeFunction::not_desc());
+        factory.register("not rlike", RegexpLikeFunction::not_desc());
+        factory.register("regexp_replace", RegexpReplaceFunction::desc());
+        factory.register("regexp_substr", RegexpSubStrFunction::desc());
+        factory.register("regexp_instr", RegexpInStrFunction::desc());
+        factory.register("regexp_extract", RegexpExtractFunction::desc());
+        factory.register("extractAll", ExtractAllFunction::desc());
+        factory.register("upper", UpperFunction::desc());
+        factory.register("ucase", UpperFunction::desc());
+        factory.register("lower", LowerFunction::desc());
+        factory.register("lcase", LowerFunction::desc());
+        factory.register("initcap", InitCapFunction::desc());
+        factory.register("split_part", SplitPartFunction::desc());
+        factory.register("splitByChar", SplitByCharFunction::desc());
+        factory.register("translate", TranslateFunction::desc());
+        factory.register("startsWith", StartsWithFunction::starts_with_desc());
+        factory.register("endsWith", StartsWithFunction::ends_with_desc());
+        factory.register("positionUTF8", PositionUTF8Function::desc());
+        factory.register(
+            "positionCaseInsensitiveUTF8",
+            PositionCaseInsensitiveUTF8Function::desc(),
+        );
+        factory.register("format", FormatFunction::desc());
     }
 }
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::arguments::evaluate_rows;
+use crate::scalars::strings::arguments::StringArgument;
+use crate::scalars::Function;
+
+/// translate(s, from, to), each character of the string in `from` is replaced by the character
+/// at the same position in `to`, it is removed if `to` is shorter.
+#[derive(Clone)]
+pub struct TranslateFunction {
+    display_name: String,
+}
+
+impl TranslateFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(TranslateFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn translate(value: &[u8], from: &[u8], to: &[u8], buf: &mut String) {
+        // The first occurrence wins if a character is repeated in `from`.
+        let mut map: HashMap<char, Option<char>> = HashMap::new();
+        let to = String::from_utf8_lossy(to);
+        let mut to = to.chars();
+        for c in String::from_utf8_lossy(from).chars() {
+            let replacement = to.next();
+            map.entry(c).or_insert(replacement);
+        }
+
+        buf.clear();
+        for c in String::from_utf8_lossy(value).chars() {
+            match map.get(&c) {
+                None => buf.push(c),
+                Some(Some(replacement)) => buf.push(*replacement),
+                Some(None) => {}
+            }
+        }
+    }
+}
+
+impl Function for TranslateFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if arg != &DataType::String && !arg.is_null() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or null, but got {}",
+                    arg
+                )));
+            }
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = StringArgument::try_create(&columns[0])?;
+        let froms = StringArgument::try_create(&columns[1])?;
+        let tos = StringArgument::try_create(&columns[2])?;
+
+        let mut buf = String::new();
+        let rows = evaluate_rows(columns, input_rows);
+        let mut builder = StringArrayBuilder::with_capacity(rows);
+        for row in 0..rows {
+            match (values.get(row), froms.get(row), tos.get(row)) {
+                (Some(value), Some(from), Some(to)) => {
+                    Self::translate(value, from, to, &mut buf);
+                    builder.append_value(&buf);
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let result: DataColumn = builder.finish().into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for TranslateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
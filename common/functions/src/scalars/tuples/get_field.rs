@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// `get_field(struct, 'name')` returns the field of the struct by its name, the name must be
+/// a constant, so the function is created with it, see `create_with_params`.
+#[derive(Clone)]
+pub struct GetFieldFunction {
+    display_name: String,
+    field_name: Option<String>,
+}
+
+impl GetFieldFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GetFieldFunction {
+            display_name: display_name.to_string(),
+            field_name: None,
+        }))
+    }
+
+    pub fn create_with_params(
+        display_name: &str,
+        params: &[DataValue],
+    ) -> Result<Box<dyn Function>> {
+        match params {
+            [DataValue::String(Some(name))] => Ok(Box::new(GetFieldFunction {
+                display_name: display_name.to_string(),
+                field_name: Some(String::from_utf8_lossy(name).to_string()),
+            })),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "The field name of {} must be a constant string, but got {:?}",
+                display_name, params
+            ))),
+        }
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    pub fn is_get_field(func_name: &str) -> bool {
+        func_name.eq_ignore_ascii_case("get_field")
+    }
+
+    fn field_position(&self, data_type: &DataType) -> Result<(usize, DataField)> {
+        let field_name = self.field_name.as_ref().ok_or_else(|| {
+            ErrorCode::LogicalError(format!(
+                "{} must be created with the field name",
+                self.display_name
+            ))
+        })?;
+
+        let field = match data_type {
+            DataType::Struct(fields) => fields
+                .iter()
+                .enumerate()
+                .find(|(_, f)| f.name() == field_name),
+            _ => None,
+        };
+
+        match field {
+            Some((pos, field)) => Ok((pos, field.clone())),
+            None => Err(ErrorCode::IllegalDataType(format!(
+                "Unknown field {} of {:?}",
+                field_name, data_type
+            ))),
+        }
+    }
+}
+
+impl Function for GetFieldFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let (_, field) = self.field_position(&args[0])?;
+        Ok(field.data_type().clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let (pos, _) = self.field_position(columns[0].data_type())?;
+
+        let series = columns[0].column().to_minimal_array()?;
+        let array = series.tuple()?.inner();
+        let values = &array.values()[pos];
+
+        // the field is null if the struct is null
+        let values = match array.validity() {
+            None => values.clone(),
+            Some(validity) => {
+                let validity = combine_validities(values.validity(), Some(validity));
+                Arc::from(values.with_validity(validity))
+            }
+        };
+
+        let column: DataColumn = values.into_series().into();
+        match columns[0].column() {
+            DataColumn::Constant(_, _) => Ok(column.resize_constant(input_rows)),
+            _ => Ok(column),
+        }
+    }
+}
+
+impl fmt::Display for GetFieldFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod get_field;
 mod tuple;
 mod tuple_class;
 
+pub use get_field::GetFieldFunction;
 pub use tuple::TupleFunction;
 pub use tuple_class::TupleClassFunction;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::GetFieldFunction;
 use crate::scalars::TupleFunction;
 
 #[derive(Clone)]
@@ -21,5 +22,6 @@ pub struct TupleClassFunction;
 impl TupleClassFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("tuple", TupleFunction::desc());
+        factory.register("get_field", GetFieldFunction::desc());
     }
 }
@@ -229,6 +229,25 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::UInt8(Some(3)),
             error: "",
         },
+        Test {
+            name: "groupArray-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupArray",
+            func_name: "groupArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![
+                    DataValue::Int64(Some(4)),
+                    DataValue::Int64(Some(3)),
+                    DataValue::Int64(Some(2)),
+                    DataValue::Int64(Some(1)),
+                ]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -776,3 +795,53 @@ fn test_covariance_with_comparable_data_sets() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_aggregate_decimal_overflow() -> Result<()> {
+    let args = vec![DataField::new("a", DataType::Decimal(38, 2), false)];
+    let arrays: Vec<Series> = vec![Series::new(vec![i128::MAX, 1])];
+    let halves: Vec<Series> = vec![Series::new(vec![i128::MAX / 2 + 1])];
+
+    for func_name in ["sum", "avg"] {
+        let arena = Bump::new();
+        let factory = AggregateFunctionFactory::instance();
+        let func = factory.get(func_name, vec![], args.clone())?;
+
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        let result = func.accumulate(addr1.into(), &arrays, 2);
+        assert_eq!(
+            "Code: 49, displayText = Decimal overflow in sum.",
+            result.unwrap_err().to_string(),
+            "{}",
+            func_name
+        );
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        let places = vec![addr2.into(), addr2.into()];
+        let result = func.accumulate_keys(&places, 0, &arrays, 2);
+        assert_eq!(
+            "Code: 49, displayText = Decimal overflow in sum.",
+            result.unwrap_err().to_string(),
+            "{}",
+            func_name
+        );
+
+        let addr3 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr3.into());
+        func.accumulate(addr3.into(), &halves, 1)?;
+        let addr4 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr4.into());
+        func.accumulate(addr4.into(), &halves, 1)?;
+        let result = func.merge(addr3.into(), addr4.into());
+        assert_eq!(
+            "Code: 49, displayText = Decimal overflow in sum.",
+            result.unwrap_err().to_string(),
+            "{}",
+            func_name
+        );
+    }
+
+    Ok(())
+}
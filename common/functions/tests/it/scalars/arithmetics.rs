@@ -455,3 +455,86 @@ fn test_arithmetic_date_interval() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_decimal() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        fields: Vec<DataField>,
+        columns: Vec<DataColumn>,
+        expect_type: DataType,
+        expect: DataColumn,
+    }
+
+    let a = DataField::new("a", DataType::Decimal(10, 2), false);
+    let b = DataField::new("b", DataType::Decimal(10, 1), false);
+    let c = DataField::new("c", DataType::Int32, false);
+
+    let tests = vec![
+        Test {
+            name: "add-decimal-passed",
+            func: ArithmeticPlusFunction::try_create_func("")?,
+            fields: vec![a.clone(), b.clone()],
+            columns: vec![
+                Series::new(vec![125i128, -50]).into(),
+                Series::new(vec![15i128, 3]).into(),
+            ],
+            expect_type: DataType::Decimal(38, 2),
+            expect: Series::new(vec![275i128, -20]).into(),
+        },
+        Test {
+            name: "sub-decimal-int-passed",
+            func: ArithmeticMinusFunction::try_create_func("")?,
+            fields: vec![a.clone(), c.clone()],
+            columns: vec![
+                Series::new(vec![125i128, -50]).into(),
+                Series::new(vec![1i32, 2]).into(),
+            ],
+            expect_type: DataType::Decimal(38, 2),
+            expect: Series::new(vec![25i128, -250]).into(),
+        },
+        Test {
+            name: "mul-decimal-passed",
+            func: ArithmeticMulFunction::try_create_func("")?,
+            fields: vec![a.clone(), b.clone()],
+            columns: vec![
+                Series::new(vec![125i128, -50]).into(),
+                Series::new(vec![15i128, 3]).into(),
+            ],
+            expect_type: DataType::Decimal(38, 3),
+            expect: Series::new(vec![1875i128, -150]).into(),
+        },
+        Test {
+            name: "div-decimal-passed",
+            func: ArithmeticDivFunction::try_create_func("")?,
+            fields: vec![a, b],
+            columns: vec![
+                Series::new(vec![100i128, -50]).into(),
+                Series::new(vec![30i128, 20]).into(),
+            ],
+            expect_type: DataType::Decimal(38, 2),
+            expect: Series::new(vec![33i128, -25]).into(),
+        },
+    ];
+
+    for t in tests {
+        let rows = t.columns[0].len();
+        let args = t
+            .fields
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect::<Vec<_>>();
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .zip(t.fields.iter())
+            .map(|(c, f)| DataColumnWithField::new(c.clone(), f.clone()))
+            .collect();
+
+        assert_eq!(t.expect_type, t.func.return_type(&args)?, "{}", t.name);
+        let v = t.func.eval(&columns, rows)?;
+        assert_eq!(v, t.expect, "{}", t.name);
+    }
+    Ok(())
+}
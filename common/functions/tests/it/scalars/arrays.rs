@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+fn array_texts(column: &DataColumn, data_type: &DataType) -> Result<Vec<String>> {
+    data_type.create_serializer().serialize_column(column)
+}
+
+fn int64_arrays() -> DataColumnWithField {
+    let mut builder = get_list_builder(&DataType::Int64, 8, 3);
+    builder.append_series(&Series::new(vec![1i64, 2, 3]));
+    builder.append_null();
+    builder.append_series(&Series::new(vec![Some(4i64), None]));
+    DataColumnWithField::new(
+        builder.finish().into_series().into(),
+        DataField::new(
+            "arr",
+            DataType::List(Box::new(DataField::new("item", DataType::Int64, true))),
+            true,
+        ),
+    )
+}
+
+#[test]
+fn test_array_constructor_function() -> Result<()> {
+    let func = ArrayConstructorFunction::try_create("array")?;
+    let args = [
+        DataColumnWithField::new(
+            Series::new(vec![1u8, 2]).into(),
+            DataField::new("a", DataType::UInt8, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Float64(Some(0.5)), 2),
+            DataField::new("b", DataType::Float64, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Null, 2),
+            DataField::new("c", DataType::Null, true),
+        ),
+    ];
+    let arg_types = args
+        .iter()
+        .map(|a| a.data_type().clone())
+        .collect::<Vec<_>>();
+    let return_type = func.return_type(&arg_types)?;
+    assert_eq!(
+        return_type,
+        DataType::List(Box::new(DataField::new("item", DataType::Float64, true)))
+    );
+
+    let got = func.eval(&args, 2)?;
+    assert_eq!(array_texts(&got, &return_type)?, vec![
+        "[1, 0.5, NULL]",
+        "[2, 0.5, NULL]"
+    ]);
+
+    // the empty arrays hold UInt8 elements
+    let return_type = func.return_type(&[])?;
+    let got = func.eval(&[], 1)?;
+    assert_eq!(array_texts(&got, &return_type)?, vec!["[]"]);
+    Ok(())
+}
+
+#[test]
+fn test_array_functions() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Result<Box<dyn Function>>,
+        args: Vec<DataColumnWithField>,
+        expect: Vec<&'static str>,
+    }
+
+    let int64_const = |v: i64| {
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int64(Some(v)), 3),
+            DataField::new("c", DataType::Int64, false),
+        )
+    };
+
+    let tests = vec![
+        Test {
+            name: "length",
+            func: ArrayLengthFunction::try_create("length"),
+            args: vec![int64_arrays()],
+            expect: vec!["3", "NULL", "2"],
+        },
+        Test {
+            name: "has",
+            func: ArrayHasFunction::try_create("has", false),
+            args: vec![int64_arrays(), int64_const(2)],
+            expect: vec!["1", "NULL", "0"],
+        },
+        Test {
+            name: "indexOf",
+            func: ArrayHasFunction::try_create("indexOf", true),
+            args: vec![int64_arrays(), int64_const(3)],
+            expect: vec!["3", "NULL", "0"],
+        },
+        Test {
+            name: "arrayConcat",
+            func: ArrayConcatFunction::try_create("arrayConcat"),
+            args: vec![int64_arrays(), int64_arrays()],
+            expect: vec!["[1, 2, 3, 1, 2, 3]", "NULL", "[4, NULL, 4, NULL]"],
+        },
+        Test {
+            name: "arraySlice",
+            func: ArraySliceFunction::try_create("arraySlice"),
+            args: vec![int64_arrays(), int64_const(2)],
+            expect: vec!["[2, 3]", "NULL", "[NULL]"],
+        },
+        Test {
+            name: "arraySlice-negative-offset",
+            func: ArraySliceFunction::try_create("arraySlice"),
+            args: vec![int64_arrays(), int64_const(-2), int64_const(1)],
+            expect: vec!["[2]", "NULL", "[4]"],
+        },
+        Test {
+            name: "arraySlice-negative-length",
+            func: ArraySliceFunction::try_create("arraySlice"),
+            args: vec![int64_arrays(), int64_const(1), int64_const(-1)],
+            expect: vec!["[1, 2]", "NULL", "[4]"],
+        },
+    ];
+
+    for t in tests {
+        let func = t.func?;
+        let arg_types = t
+            .args
+            .iter()
+            .map(|a| a.data_type().clone())
+            .collect::<Vec<_>>();
+        let return_type = func.return_type(&arg_types)?;
+        let got = func.eval(&t.args, 3)?;
+        assert_eq!(
+            array_texts(&got, &return_type)?,
+            t.expect,
+            "case: {}",
+            t.name
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_array_join_function() -> Result<()> {
+    let func = ArrayJoinFunction::try_create("arrayJoin")?;
+    let arg = int64_arrays();
+    assert_eq!(
+        func.return_type(&[arg.data_type().clone()])?,
+        DataType::Int64
+    );
+    assert!(func.return_type(&[DataType::Int64]).is_err());
+    // the rows are expanded by the expression executor
+    assert!(func.eval(&[arg], 3).is_err());
+    Ok(())
+}
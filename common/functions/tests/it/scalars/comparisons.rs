@@ -188,3 +188,115 @@ fn test_comparison_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_comparison_in_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "in-constant-list",
+            display: "IN",
+            func: ComparisonInFunction::try_create_in("")?,
+            columns: vec![
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(DataValue::UInt8(Some(2)), 4),
+                DataColumn::Constant(DataValue::Float64(Some(4.0)), 4),
+            ],
+            expect: Series::new(vec![false, true, false, true]).into(),
+        },
+        Test {
+            name: "not-in-constant-list",
+            display: "NOT IN",
+            func: ComparisonInFunction::try_create_not_in("")?,
+            columns: vec![
+                Series::new(vec!["a", "b", "c"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 3),
+            ],
+            expect: Series::new(vec![true, false, true]).into(),
+        },
+        Test {
+            name: "in-column-list",
+            display: "IN",
+            func: ComparisonInFunction::try_create_in("")?,
+            columns: vec![
+                Series::new(vec![1i32, 2, 3]).into(),
+                Series::new(vec![1i32, 1, 1]).into(),
+                Series::new(vec![0i32, 2, 0]).into(),
+            ],
+            expect: Series::new(vec![true, true, false]).into(),
+        },
+        Test {
+            name: "in-list-with-null",
+            display: "IN",
+            func: ComparisonInFunction::try_create_in("")?,
+            columns: vec![
+                Series::new(vec![Some(1u32), None, Some(3)]).into(),
+                DataColumn::Constant(DataValue::UInt32(Some(1)), 3),
+                DataColumn::Constant(DataValue::Null, 3),
+            ],
+            expect: Series::new(vec![Some(true), None, None]).into(),
+        },
+        Test {
+            name: "in-column-list-with-null",
+            display: "NOT IN",
+            func: ComparisonInFunction::try_create_not_in("")?,
+            columns: vec![
+                Series::new(vec![Some(1i32), None, Some(3), Some(4)]).into(),
+                Series::new(vec![Some(1i32), Some(2), None, Some(5)]).into(),
+                DataColumn::Constant(DataValue::Int32(Some(3)), 4),
+            ],
+            expect: Series::new(vec![Some(false), None, Some(false), Some(true)]).into(),
+        },
+        Test {
+            name: "in-subquery",
+            display: "IN",
+            func: ComparisonInFunction::try_create_in_subquery("")?,
+            columns: vec![
+                Series::new(vec![1u64, 2, 3]).into(),
+                DataColumn::Constant(
+                    DataValue::List(
+                        Some(vec![DataValue::UInt64(Some(3)), DataValue::UInt64(Some(5))]),
+                        DataType::UInt64,
+                    ),
+                    3,
+                ),
+            ],
+            expect: Series::new(vec![false, false, true]).into(),
+        },
+        Test {
+            name: "not-in-empty-subquery",
+            display: "NOT IN",
+            func: ComparisonInFunction::try_create_not_in_subquery("")?,
+            columns: vec![
+                Series::new(vec![1u64, 2]).into(),
+                DataColumn::Constant(DataValue::List(Some(vec![]), DataType::UInt64), 2),
+            ],
+            expect: Series::new(vec![true, true]).into(),
+        },
+    ];
+
+    for t in tests {
+        let rows = t.columns[0].len();
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        let func = t.func;
+        assert_eq!(t.display, format!("{}", func), "{}", t.name);
+        assert!(func.nullable(&DataSchema::empty())?, "{}", t.name);
+
+        let v = func.eval(&columns, rows)?;
+        assert_eq!(DataType::Boolean, v.data_type(), "{}", t.name);
+        assert_eq!(t.expect.to_values()?, v.to_values()?, "{}", t.name);
+    }
+    Ok(())
+}
@@ -37,24 +37,75 @@ fn test_if_function() -> Result<()> {
         DataField::new("b", DataType::Int64, false),
     ]);
 
-    let tests = vec![Test {
-        name: "if-passed",
-        display: "IF",
-        nullable: false,
-        func: IfFunction::try_create_func("")?,
-        args: vec![
-            DataType::Boolean,
-            schema.field_with_name("a")?.data_type().clone(),
-            DataType::Float64,
-        ],
-        columns: vec![
-            Series::new(vec![true, false, false, true]).into(),
-            Series::new(vec![1i32, 2, 3, 4]).into(),
-            DataColumn::Constant(DataValue::Float64(Some(2.5)), 4),
-        ],
-        expect: Series::new(vec![1f64, 2.5, 2.5, 4f64]),
-        error: "",
-    }];
+    let tests = vec![
+        Test {
+            name: "if-passed",
+            display: "IF",
+            nullable: false,
+            func: IfFunction::try_create_func("")?,
+            args: vec![
+                DataType::Boolean,
+                schema.field_with_name("a")?.data_type().clone(),
+                DataType::Float64,
+            ],
+            columns: vec![
+                Series::new(vec![true, false, false, true]).into(),
+                Series::new(vec![1i32, 2, 3, 4]).into(),
+                DataColumn::Constant(DataValue::Float64(Some(2.5)), 4),
+            ],
+            expect: Series::new(vec![1f64, 2.5, 2.5, 4f64]),
+            error: "",
+        },
+        Test {
+            name: "multi-if-passed",
+            display: "multiIf",
+            nullable: true,
+            func: MultiIfFunction::try_create_func("")?,
+            args: vec![
+                DataType::Boolean,
+                DataType::Int32,
+                DataType::Boolean,
+                DataType::Int64,
+                DataType::Float64,
+            ],
+            columns: vec![
+                Series::new(vec![true, false, false, true]).into(),
+                Series::new(vec![1i32, 2, 3, 4]).into(),
+                Series::new(vec![false, true, false, true]).into(),
+                Series::new(vec![5i64, 6, 7, 8]).into(),
+                DataColumn::Constant(DataValue::Float64(Some(2.5)), 4),
+            ],
+            expect: Series::new(vec![1f64, 6f64, 2.5, 4f64]),
+            error: "",
+        },
+        Test {
+            name: "greatest-passed",
+            display: "greatest",
+            nullable: true,
+            func: ExtremumFunction::try_create_greatest("")?,
+            args: vec![DataType::Int32, DataType::Int64, DataType::Int64],
+            columns: vec![
+                Series::new(vec![Some(1i32), Some(5), None]).into(),
+                Series::new(vec![4i64, 2, 3]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(3)), 3),
+            ],
+            expect: Series::new(vec![Some(4i64), Some(5), None]),
+            error: "",
+        },
+        Test {
+            name: "least-passed",
+            display: "least",
+            nullable: true,
+            func: ExtremumFunction::try_create_least("")?,
+            args: vec![DataType::String, DataType::String],
+            columns: vec![
+                Series::new(vec!["b", "a", "c"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 3),
+            ],
+            expect: Series::new(vec!["b", "a", "b"]),
+            error: "",
+        },
+    ];
 
     for t in tests {
         let func = t.func;
@@ -81,8 +132,7 @@ fn test_if_function() -> Result<()> {
         let actual_type = v.data_type();
         assert_eq!(expect_type, actual_type);
 
-        let cmp = v.to_array()?.eq(&t.expect)?;
-        assert!(cmp.all_true());
+        assert_eq!(t.expect.to_values()?, v.to_values()?, "{}", t.name);
     }
 
     Ok(())
@@ -132,6 +132,96 @@ fn test_cast_function() -> Result<()> {
             expect: Series::new(vec!["2021-03-05 01:01:01", "2021-10-24 10:10:10"]),
             error: "",
         },
+        Test {
+            name: "cast-string-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["1.5", "-0.25", "3"]).into()],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(10, 2)),
+            expect: Series::new(vec![150i128, -25, 300]),
+            error: "",
+        },
+        Test {
+            name: "cast-decimal-to-string-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![150i128, -25, 300]).into()],
+            column_types: vec![DataType::Decimal(10, 2)],
+            func: CastFunction::create("cast".to_string(), DataType::String),
+            expect: Series::new(vec!["1.50", "-0.25", "3.00"]),
+            error: "",
+        },
+        Test {
+            name: "cast-decimal-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![150i128, -25, 300]).into()],
+            column_types: vec![DataType::Decimal(10, 2)],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(10, 1)),
+            expect: Series::new(vec![15i128, -2, 30]),
+            error: "",
+        },
+        Test {
+            name: "cast-int64-to-decimal-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![4i64, -3]).into()],
+            column_types: vec![DataType::Int64],
+            func: CastFunction::create("cast".to_string(), DataType::Decimal(5, 3)),
+            expect: Series::new(vec![4000i128, -3000]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-variant-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![" {\"a\": 1} ", "[1, \"b\"]"]).into()],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::Variant),
+            expect: Series::new(vec!["{\"a\":1}", "[1,\"b\"]"]),
+            error: "",
+        },
+        Test {
+            name: "cast-int64-to-variant-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![4i64, -3]).into()],
+            column_types: vec![DataType::Int64],
+            func: CastFunction::create("cast".to_string(), DataType::Variant),
+            expect: Series::new(vec!["4", "-3"]),
+            error: "",
+        },
+        Test {
+            name: "cast-variant-to-string-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["\"abc\"", "{\"a\":1}", "12"]).into()],
+            column_types: vec![DataType::Variant],
+            func: CastFunction::create("cast".to_string(), DataType::String),
+            expect: Series::new(vec!["abc", "{\"a\":1}", "12"]),
+            error: "",
+        },
+        Test {
+            name: "cast-variant-to-int64-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["12", "\"-3\""]).into()],
+            column_types: vec![DataType::Variant],
+            func: CastFunction::create("cast".to_string(), DataType::Int64),
+            expect: Series::new(vec![12i64, -3]),
+            error: "",
+        },
+        Test {
+            name: "cast-variant-to-boolean-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec!["true", "0", "\"false\""]).into()],
+            column_types: vec![DataType::Variant],
+            func: CastFunction::create("cast".to_string(), DataType::Boolean),
+            expect: Series::new(vec![true, false, false]),
+            error: "",
+        },
     ];
 
     for t in tests {
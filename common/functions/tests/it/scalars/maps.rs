@@ -0,0 +1,164 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+fn texts(column: &DataColumn, data_type: &DataType) -> Result<Vec<String>> {
+    data_type.create_serializer().serialize_column(column)
+}
+
+// map('a', number, 'b', number * 10) of the numbers 1, 2
+fn number_maps() -> Result<DataColumnWithField> {
+    let func = MapConstructorFunction::try_create("map")?;
+    let args = vec![
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"a".to_vec())), 2),
+            DataField::new("k1", DataType::String, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![1u32, 2]).into(),
+            DataField::new("v1", DataType::UInt32, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 2),
+            DataField::new("k2", DataType::String, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![10u32, 20]).into(),
+            DataField::new("v2", DataType::UInt32, false),
+        ),
+    ];
+    let arg_types = args
+        .iter()
+        .map(|a| a.data_type().clone())
+        .collect::<Vec<_>>();
+    let return_type = func.return_type(&arg_types)?;
+    let column = func.eval(&args, 2)?;
+    Ok(DataColumnWithField::new(
+        column,
+        DataField::new("m", return_type, false),
+    ))
+}
+
+#[test]
+fn test_map_constructor_function() -> Result<()> {
+    let maps = number_maps()?;
+    let map_type = DataType::Map(Box::new(DataType::String), Box::new(DataType::UInt32));
+    assert_eq!(maps.data_type(), &map_type);
+    assert_eq!(texts(maps.column(), &map_type)?, vec![
+        "{'a': 1, 'b': 10}",
+        "{'a': 2, 'b': 20}"
+    ]);
+
+    // the arguments must be the pairs
+    let func = MapConstructorFunction::try_create("map")?;
+    assert!(func.return_type(&[DataType::String]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_map_functions() -> Result<()> {
+    let maps = number_maps()?;
+    let array_type = |t: DataType| DataType::List(Box::new(DataField::new("item", t, true)));
+
+    let func = MapKeysFunction::try_create("mapKeys", false)?;
+    let return_type = func.return_type(&[maps.data_type().clone()])?;
+    assert_eq!(return_type, array_type(DataType::String));
+    let got = func.eval(&[maps.clone()], 2)?;
+    assert_eq!(texts(&got, &return_type)?, vec!["['a', 'b']", "['a', 'b']"]);
+
+    let func = MapKeysFunction::try_create("mapValues", true)?;
+    let return_type = func.return_type(&[maps.data_type().clone()])?;
+    assert_eq!(return_type, array_type(DataType::UInt32));
+    let got = func.eval(&[maps.clone()], 2)?;
+    assert_eq!(texts(&got, &return_type)?, vec!["[1, 10]", "[2, 20]"]);
+
+    let func = ArrayElementFunction::try_create("arrayElement")?;
+    let keys = DataColumnWithField::new(
+        Series::new(vec!["b", "c"]).into(),
+        DataField::new("key", DataType::String, false),
+    );
+    let return_type = func.return_type(&[maps.data_type().clone(), DataType::String])?;
+    assert_eq!(return_type, DataType::UInt32);
+    let got = func.eval(&[maps.clone(), keys], 2)?;
+    assert_eq!(texts(&got, &return_type)?, vec!["10", "NULL"]);
+
+    // the key type must be comparable with the keys of the map
+    assert!(func
+        .return_type(&[maps.data_type().clone(), DataType::Boolean])
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_map_struct_cast() -> Result<()> {
+    // the map values are cast as the lists of the physical struct
+    let maps = number_maps()?;
+    let to_type = DataType::Map(Box::new(DataType::String), Box::new(DataType::Int64));
+    let func = CastFunction::create("cast".to_string(), to_type.clone())?;
+    let got = func.eval(&[maps], 2)?;
+    assert_eq!(texts(&got, &to_type)?, vec![
+        "{'a': 1, 'b': 10}",
+        "{'a': 2, 'b': 20}"
+    ]);
+
+    // the unnamed tuples are cast to the named structs by the positions
+    let tuple = TupleFunction::try_create_func("tuple")?.eval(
+        &[
+            DataColumnWithField::new(
+                Series::new(vec![1u8, 2]).into(),
+                DataField::new("x", DataType::UInt8, false),
+            ),
+            DataColumnWithField::new(
+                Series::new(vec!["x", "y"]).into(),
+                DataField::new("y", DataType::String, false),
+            ),
+        ],
+        2,
+    )?;
+    let tuple_type = DataType::Struct(vec![
+        DataField::new("item_0", DataType::UInt8, false),
+        DataField::new("item_1", DataType::String, false),
+    ]);
+    let struct_type = DataType::Struct(vec![
+        DataField::new("a", DataType::Int32, true),
+        DataField::new("b", DataType::String, true),
+    ]);
+    let structs = CastFunction::create("cast".to_string(), struct_type.clone())?.eval(
+        &[DataColumnWithField::new(
+            tuple,
+            DataField::new("t", tuple_type, false),
+        )],
+        2,
+    )?;
+    assert_eq!(texts(&structs, &struct_type)?, vec!["(1,'x')", "(2,'y')"]);
+
+    // the field is picked by the name, then the struct with only the field is cast as it
+    let field_type = DataType::Struct(vec![DataField::new("b", DataType::String, true)]);
+    let structs = DataColumnWithField::new(structs, DataField::new("s", struct_type, false));
+    let fields =
+        CastFunction::create("cast".to_string(), field_type.clone())?.eval(&[structs], 2)?;
+    let got = CastFunction::create("cast".to_string(), DataType::String)?.eval(
+        &[DataColumnWithField::new(
+            fields,
+            DataField::new("f", field_type, false),
+        )],
+        2,
+    )?;
+    assert_eq!(texts(&got, &DataType::String)?, vec!["x", "y"]);
+    Ok(())
+}
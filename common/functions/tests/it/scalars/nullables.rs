@@ -58,6 +58,55 @@ fn test_nullable_function() -> Result<()> {
             expect: Series::new(vec![true, true, false]),
             error: "",
         },
+        Test {
+            name: "coalesce-passed",
+            display: "coalesce",
+            nullable: true,
+            func: CoalesceFunction::try_create_func("")?,
+            args: vec![DataType::Int32, DataType::Int64],
+            columns: vec![
+                Series::new(vec![Some(1i32), None, None]).into(),
+                Series::new(vec![Some(2i64), Some(3i64), None]).into(),
+            ],
+            expect: Series::new(vec![Some(1i64), Some(3i64), None]),
+            error: "",
+        },
+        Test {
+            name: "if-null-passed",
+            display: "ifNull",
+            nullable: true,
+            func: IfNullFunction::try_create_func("")?,
+            args: vec![DataType::Int32, DataType::Int64],
+            columns: vec![
+                Series::new(vec![Some(1i32), None]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(0)), 2),
+            ],
+            expect: Series::new(vec![1i64, 0]),
+            error: "",
+        },
+        Test {
+            name: "null-if-passed",
+            display: "nullIf",
+            nullable: true,
+            func: NullIfFunction::try_create_func("")?,
+            args: vec![DataType::Int32, DataType::Int32],
+            columns: vec![
+                Series::new(vec![1i32, 2, 3]).into(),
+                DataColumn::Constant(DataValue::Int32(Some(2)), 3),
+            ],
+            expect: Series::new(vec![Some(1i32), None, Some(3)]),
+            error: "",
+        },
+        Test {
+            name: "assume-not-null-passed",
+            display: "assumeNotNull",
+            nullable: false,
+            func: AssumeNotNullFunction::try_create_func("")?,
+            args: vec![DataType::Int32],
+            columns: vec![Series::new(vec![Some(1i32), None]).into()],
+            expect: Series::new(vec![1i32, 0]),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -85,8 +134,7 @@ fn test_nullable_function() -> Result<()> {
         let actual_type = v.data_type();
         assert_eq!(expect_type, actual_type);
 
-        let cmp = v.to_array()?.eq(&t.expect)?;
-        assert!(cmp.all_true());
+        assert_eq!(t.expect.to_values()?, v.to_values()?, "{}", t.name);
     }
 
     Ok(())
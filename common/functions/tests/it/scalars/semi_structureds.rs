@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_parse_json_function() -> Result<()> {
+    let arg = DataColumnWithField::new(
+        Series::new(vec![" {\"a\": [1, 2]} ", "\"x\"", "{a}"]).into(),
+        DataField::new("arg1", DataType::String, true),
+    );
+
+    let func = ParseJsonFunction::try_create("try_parse_json", true)?;
+    assert_eq!(func.return_type(&[DataType::String])?, DataType::Variant);
+    let got = func.eval(&[arg.clone()], 3)?;
+    let expected: DataColumn =
+        DFStringArray::new_from_opt_slice(&[Some("{\"a\":[1,2]}"), Some("\"x\""), None]).into();
+    assert_eq!(got, expected);
+
+    let func = ParseJsonFunction::try_create("parse_json", false)?;
+    assert!(func.eval(&[arg], 3).is_err());
+    assert!(func.return_type(&[DataType::Int32]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_get_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Result<Box<dyn Function>>,
+        key: DataColumnWithField,
+        expect: DataColumn,
+    }
+
+    let value = DataColumnWithField::new(
+        Series::new(vec![
+            "{\"a\":{\"b\":[1,{\"c\":\"x\"}]},\"d d\":2}",
+            "[10,20]",
+        ])
+        .into(),
+        DataField::new("v", DataType::Variant, true),
+    );
+
+    let tests = vec![
+        Test {
+            name: "get-by-name",
+            func: GetFunction::try_create("get", false),
+            key: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::String(Some(b"a".to_vec())), 2),
+                DataField::new("k", DataType::String, false),
+            ),
+            expect: DFStringArray::new_from_opt_slice(&[Some("{\"b\":[1,{\"c\":\"x\"}]}"), None])
+                .into(),
+        },
+        Test {
+            name: "get-by-index",
+            func: GetFunction::try_create("get", false),
+            key: DataColumnWithField::new(
+                Series::new(vec![0u8, 1]).into(),
+                DataField::new("k", DataType::UInt8, false),
+            ),
+            expect: DFStringArray::new_from_opt_slice(&[None, Some("20")]).into(),
+        },
+        Test {
+            name: "get-path",
+            func: GetFunction::try_create("get_path", true),
+            key: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::String(Some(b"a.b[1]['c']".to_vec())), 2),
+                DataField::new("k", DataType::String, false),
+            ),
+            expect: DFStringArray::new_from_opt_slice(&[Some("\"x\""), None]).into(),
+        },
+        Test {
+            name: "json-extract-path-text",
+            func: JsonExtractPathTextFunction::try_create("json_extract_path_text"),
+            key: DataColumnWithField::new(
+                Series::new(vec!["a.b[1].c", "[0]"]).into(),
+                DataField::new("k", DataType::String, false),
+            ),
+            expect: DFStringArray::new_from_opt_slice(&[Some("x"), Some("10")]).into(),
+        },
+    ];
+
+    for t in tests {
+        let func = t.func?;
+        let got = func.eval(&[value.clone(), t.key], 2)?;
+        assert_eq!(got, t.expect, "case: {}", t.name);
+    }
+    Ok(())
+}
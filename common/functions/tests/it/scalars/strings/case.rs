@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::run_args_tests;
+use super::string_column;
+use super::string_constant;
+use super::ArgsTest;
+
+#[test]
+fn test_case_functions() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "upper",
+            func: UpperFunction::try_create("upper")?,
+            args: vec![string_column(&[
+                Some("abc"),
+                Some("straße"),
+                Some("ǆemal"),
+                None,
+            ])],
+            input_rows: 4,
+            expect: Series::new(vec![Some("ABC"), Some("STRASSE"), Some("ǄEMAL"), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "lower",
+            func: LowerFunction::try_create("lower")?,
+            args: vec![string_column(&[Some("ABC"), Some("ΑΒΓ"), Some("ÀÉÎ")])],
+            input_rows: 3,
+            expect: Series::new(vec!["abc", "αβγ", "àéî"]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "upper-const",
+            func: UpperFunction::try_create("ucase")?,
+            args: vec![string_constant("ŉ")],
+            input_rows: 2,
+            expect: DataColumn::Constant(DataValue::String(Some("ʼN".as_bytes().to_vec())), 2),
+            error: "",
+        },
+        ArgsTest {
+            name: "initcap",
+            func: InitCapFunction::try_create("initcap")?,
+            args: vec![string_column(&[
+                Some("hello wORLD"),
+                Some("élan vital-über"),
+                Some("123abc x"),
+            ])],
+            input_rows: 3,
+            expect: Series::new(vec!["Hello World", "Élan Vital-Über", "123abc X"]).into(),
+            error: "",
+        },
+    ];
+    run_args_tests(tests)
+}
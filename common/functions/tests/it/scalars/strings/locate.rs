@@ -16,6 +16,11 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
 
+use super::run_args_tests;
+use super::string_column;
+use super::string_constant;
+use super::ArgsTest;
+
 #[test]
 fn test_locate_function() -> Result<()> {
     struct Test {
@@ -248,3 +253,47 @@ fn test_locate_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_position_utf8_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "positionUTF8",
+            func: PositionUTF8Function::try_create("positionUTF8")?,
+            args: vec![
+                string_column(&[Some("привет, мир"), Some("abc"), None]),
+                string_constant("мир"),
+            ],
+            input_rows: 3,
+            expect: Series::new(vec![Some(9_u64), Some(0), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "positionUTF8-start",
+            func: PositionUTF8Function::try_create("positionUTF8")?,
+            args: vec![
+                string_column(&[Some("αβαβ"), Some("αβαβ")]),
+                string_constant("α"),
+                DataColumnWithField::new(
+                    Series::new([2_u64, 5]).into(),
+                    DataField::new("p", DataType::UInt64, false),
+                ),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec![3_u64, 0]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "positionCaseInsensitiveUTF8",
+            func: PositionCaseInsensitiveUTF8Function::try_create("positionCaseInsensitiveUTF8")?,
+            args: vec![
+                string_column(&[Some("Привет, МИР"), Some("ÀB")]),
+                string_constant("мир"),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec![9_u64, 0]).into(),
+            error: "",
+        },
+    ];
+    run_args_tests(tests)
+}
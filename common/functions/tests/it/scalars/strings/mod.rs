@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod case;
 mod locate;
+mod regexp;
+mod split_part;
 mod substring;
+mod translate;
 mod trim;
 
 mod utils;
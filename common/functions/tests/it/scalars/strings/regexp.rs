@@ -0,0 +1,206 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::int64_constant;
+use super::run_args_tests;
+use super::string_column;
+use super::string_constant;
+use super::ArgsTest;
+
+#[test]
+fn test_regexp_like_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "regexp_like-series-const",
+            func: RegexpLikeFunction::try_create("regexp_like")?,
+            args: vec![
+                string_column(&[Some("abc"), Some("ABC"), Some("xyz"), None]),
+                string_constant("^a.c$"),
+            ],
+            input_rows: 4,
+            expect: Series::new(vec![Some(true), Some(false), Some(false), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "regexp_like-match-type",
+            func: RegexpLikeFunction::try_create("regexp_like")?,
+            args: vec![
+                string_column(&[Some("abc"), Some("ABC")]),
+                string_constant("^a.c$"),
+                string_constant("i"),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec![true, true]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "not-regexp-series-series",
+            func: RegexpLikeFunction::try_create_not("not regexp")?,
+            args: vec![
+                string_column(&[Some("abc"), Some("abc")]),
+                string_column(&[Some("b+"), Some("d+")]),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec![false, true]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "regexp_like-bad-match-type",
+            func: RegexpLikeFunction::try_create("regexp_like")?,
+            args: vec![
+                string_column(&[Some("abc")]),
+                string_constant("a"),
+                string_constant("x"),
+            ],
+            input_rows: 1,
+            expect: Series::new(vec![false]).into(),
+            error: "Code: 6, displayText = Incorrect match type 'x' of regular expression.",
+        },
+    ];
+    run_args_tests(tests)
+}
+
+#[test]
+fn test_regexp_replace_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "regexp_replace-all",
+            func: RegexpReplaceFunction::try_create("regexp_replace")?,
+            args: vec![
+                string_column(&[Some("a b c"), Some("abc"), None]),
+                string_constant("b"),
+                string_constant("X"),
+            ],
+            input_rows: 3,
+            expect: Series::new(vec![Some("a X c"), Some("aXc"), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "regexp_replace-groups-position-occurrence",
+            func: RegexpReplaceFunction::try_create("regexp_replace")?,
+            args: vec![
+                string_column(&[Some("ab ab ab"), Some("ab")]),
+                string_constant("(a)(b)"),
+                string_constant("$2$1"),
+                int64_constant(2),
+                int64_constant(1),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec!["ab ba ab", "ab"]).into(),
+            error: "",
+        },
+    ];
+    run_args_tests(tests)
+}
+
+#[test]
+fn test_regexp_substr_instr_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "regexp_substr-occurrence",
+            func: RegexpSubStrFunction::try_create("regexp_substr")?,
+            args: vec![
+                string_column(&[Some("abc def ghi"), Some("abc")]),
+                string_constant("[a-z]+"),
+                int64_constant(1),
+                int64_constant(2),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec![Some("def"), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "regexp_instr-start-end",
+            func: RegexpInStrFunction::try_create("regexp_instr")?,
+            args: vec![
+                string_column(&[Some("héllo wörld"), Some("abc")]),
+                string_constant("w.r"),
+                int64_constant(1),
+                int64_constant(1),
+                int64_constant(1),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec![10_u64, 0]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "regexp_instr-bad-position",
+            func: RegexpInStrFunction::try_create("regexp_instr")?,
+            args: vec![
+                string_column(&[Some("abc")]),
+                string_constant("b"),
+                int64_constant(0),
+            ],
+            input_rows: 1,
+            expect: Series::new(vec![0_u64]).into(),
+            error: "Code: 6, displayText = Incorrect position 0 of regular expression, it must be positive.",
+        },
+    ];
+    run_args_tests(tests)
+}
+
+#[test]
+fn test_regexp_extract_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "regexp_extract-default-group",
+            func: RegexpExtractFunction::try_create("regexp_extract")?,
+            args: vec![
+                string_column(&[Some("key=value"), Some("nothing")]),
+                string_constant("(\\w+)=(\\w+)"),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec!["key", ""]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "regexp_extract-group",
+            func: RegexpExtractFunction::try_create("regexp_extract")?,
+            args: vec![
+                string_column(&[Some("key=value")]),
+                string_constant("(\\w+)=(\\w+)"),
+                int64_constant(2),
+            ],
+            input_rows: 1,
+            expect: Series::new(vec!["value"]).into(),
+            error: "",
+        },
+    ];
+
+    run_args_tests(tests)
+}
+
+#[test]
+fn test_extract_all_function() -> Result<()> {
+    let func = ExtractAllFunction::try_create("extractAll")?;
+    let args = vec![
+        string_column(&[Some("a1 b22 c333"), Some("")]),
+        string_constant("[a-z](\\d+)"),
+    ];
+
+    let item = |s: &str| DataValue::String(Some(s.as_bytes().to_vec()));
+    let expect = vec![
+        DataValue::List(
+            Some(vec![item("1"), item("22"), item("333")]),
+            DataType::String,
+        ),
+        DataValue::List(Some(vec![]), DataType::String),
+    ];
+    assert_eq!(func.eval(&args, 2)?.to_values()?, expect);
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::int64_constant;
+use super::run_args_tests;
+use super::string_column;
+use super::string_constant;
+use super::ArgsTest;
+
+#[test]
+fn test_split_part_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "split_part",
+            func: SplitPartFunction::try_create("split_part")?,
+            args: vec![
+                string_column(&[Some("a,b,c"), Some("a,b,c"), Some("abc"), None]),
+                string_constant(","),
+                int64_constant(2),
+            ],
+            input_rows: 4,
+            expect: Series::new(vec![Some("b"), Some("b"), Some(""), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "split_part-negative-utf8",
+            func: SplitPartFunction::try_create("split_part")?,
+            args: vec![
+                string_column(&[Some("α→β→γ"), Some("α")]),
+                string_constant("→"),
+                int64_constant(-1),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec!["γ", "α"]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "split_part-zero",
+            func: SplitPartFunction::try_create("split_part")?,
+            args: vec![
+                string_column(&[Some("a,b")]),
+                string_constant(","),
+                int64_constant(0),
+            ],
+            input_rows: 1,
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = The field position of split_part must not be zero.",
+        },
+    ];
+    run_args_tests(tests)
+}
+
+#[test]
+fn test_split_by_char_function() -> Result<()> {
+    let func = SplitByCharFunction::try_create("splitByChar")?;
+    let args = vec![
+        string_constant("é"),
+        string_column(&[Some("aébéc"), Some(""), Some("abc")]),
+    ];
+
+    let item = |s: &str| DataValue::String(Some(s.as_bytes().to_vec()));
+    let expect = vec![
+        DataValue::List(
+            Some(vec![item("a"), item("b"), item("c")]),
+            DataType::String,
+        ),
+        DataValue::List(Some(vec![item("")]), DataType::String),
+        DataValue::List(Some(vec![item("abc")]), DataType::String),
+    ];
+    assert_eq!(func.eval(&args, 3)?.to_values()?, expect);
+
+    let args = vec![string_constant("ab"), string_column(&[Some("abc")])];
+    assert_eq!(
+        func.eval(&args, 1).unwrap_err().to_string(),
+        "Code: 6, displayText = The separator of splitByChar must be a single character, but got 'ab'."
+    );
+    Ok(())
+}
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::run_args_tests;
+use super::string_column;
+use super::string_constant;
+use super::ArgsTest;
+
+#[test]
+fn test_translate_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "translate",
+            func: TranslateFunction::try_create("translate")?,
+            args: vec![
+                string_column(&[Some("12345"), Some("héllo"), None]),
+                string_constant("143é"),
+                string_constant("axe"),
+            ],
+            input_rows: 3,
+            expect: Series::new(vec![Some("a2ex5"), Some("hllo"), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "translate-series",
+            func: TranslateFunction::try_create("translate")?,
+            args: vec![
+                string_constant("abc"),
+                string_column(&[Some("a"), Some("ca")]),
+                string_column(&[Some("β"), Some("γα")]),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec!["βbc", "αbγ"]).into(),
+            error: "",
+        },
+    ];
+    run_args_tests(tests)
+}
+
+#[test]
+fn test_starts_with_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "startsWith",
+            func: StartsWithFunction::try_create_starts_with("startsWith")?,
+            args: vec![
+                string_column(&[Some("ἀρχή"), Some("abc"), None]),
+                string_constant("ἀρ"),
+            ],
+            input_rows: 3,
+            expect: Series::new(vec![Some(true), Some(false), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "endsWith",
+            func: StartsWithFunction::try_create_ends_with("endsWith")?,
+            args: vec![
+                string_column(&[Some("abc"), Some("abc")]),
+                string_column(&[Some("bc"), Some("")]),
+            ],
+            input_rows: 2,
+            expect: Series::new(vec![true, true]).into(),
+            error: "",
+        },
+    ];
+    run_args_tests(tests)
+}
+
+#[test]
+fn test_format_function() -> Result<()> {
+    let tests = vec![
+        ArgsTest {
+            name: "format-implicit",
+            func: FormatFunction::try_create("format")?,
+            args: vec![
+                string_constant("{} → {}{{}}"),
+                string_column(&[Some("α"), Some("a"), None]),
+                string_constant("β"),
+            ],
+            input_rows: 3,
+            expect: Series::new(vec![Some("α → β{}"), Some("a → β{}"), None]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "format-explicit",
+            func: FormatFunction::try_create("format")?,
+            args: vec![
+                string_constant("{1}-{0}-{1}"),
+                string_constant("a"),
+                string_constant("b"),
+            ],
+            input_rows: 1,
+            expect: Series::new(vec!["b-a-b"]).into(),
+            error: "",
+        },
+        ArgsTest {
+            name: "format-mixed",
+            func: FormatFunction::try_create("format")?,
+            args: vec![string_constant("{} {0}"), string_constant("a")],
+            input_rows: 1,
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = Invalid format pattern '{} {0}': cannot mix '{}' and '{n}'.",
+        },
+        ArgsTest {
+            name: "format-out-of-range",
+            func: FormatFunction::try_create("format")?,
+            args: vec![string_constant("{} {}"), string_constant("a")],
+            input_rows: 1,
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = Invalid format pattern '{} {}': the argument index is out of range.",
+        },
+    ];
+    run_args_tests(tests)
+}
@@ -75,3 +75,54 @@ pub fn run_tests(tests: Vec<Test>, schema: Arc<DataSchema>) -> Result<()> {
 
     Ok(())
 }
+
+/// The test of the function with the given arguments.
+pub struct ArgsTest {
+    pub name: &'static str,
+    pub func: Box<dyn Function>,
+    pub args: Vec<DataColumnWithField>,
+    pub input_rows: usize,
+    pub expect: DataColumn,
+    pub error: &'static str,
+}
+
+pub fn run_args_tests(tests: Vec<ArgsTest>) -> Result<()> {
+    for t in tests {
+        let args = t
+            .args
+            .iter()
+            .map(|arg| arg.data_type().clone())
+            .collect::<Vec<_>>();
+
+        match t.func.eval(&t.args, t.input_rows) {
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+            Ok(v) => {
+                assert_eq!(t.error, "", "{}", t.name);
+                assert_eq!(t.func.return_type(&args)?, v.data_type(), "{}", t.name);
+                assert_eq!(v.to_values()?, t.expect.to_values()?, "case: {}", t.name);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn string_column(values: &[Option<&str>]) -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(values.to_vec()).into(),
+        DataField::new("s", DataType::String, true),
+    )
+}
+
+pub fn string_constant(value: &str) -> DataColumnWithField {
+    DataColumnWithField::new(
+        DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), 1),
+        DataField::new("c", DataType::String, false),
+    )
+}
+
+pub fn int64_constant(value: i64) -> DataColumnWithField {
+    DataColumnWithField::new(
+        DataColumn::Constant(DataValue::Int64(Some(value)), 1),
+        DataField::new("i", DataType::Int64, false),
+    )
+}
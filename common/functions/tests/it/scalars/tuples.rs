@@ -68,3 +68,55 @@ fn test_tuple_function() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_get_field_function() -> Result<()> {
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new(vec![1i32, 2, 3]).into(),
+            DataField::new("a", DataType::Int32, false),
+        ),
+        DataColumnWithField::new(
+            Series::new(vec![4i64, 5, 6]).into(),
+            DataField::new("b", DataType::Int64, false),
+        ),
+    ];
+    let tuple = TupleFunction::try_create_func("")?.eval(&columns, 3)?;
+    let tuple_type = tuple.data_type();
+
+    let name = DataValue::String(Some("item_1".as_bytes().to_vec()));
+    let input = vec![
+        DataColumnWithField::new(
+            tuple.clone(),
+            DataField::new("s", tuple_type.clone(), false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(name.clone(), 3),
+            DataField::new("name", DataType::String, false),
+        ),
+    ];
+
+    let func = GetFieldFunction::create_with_params("get_field", &[name])?;
+    let args = vec![tuple_type.clone(), DataType::String];
+    assert_eq!(DataType::Int64, func.return_type(&args)?);
+
+    let v = func.eval(&input, 3)?;
+    assert_eq!(DataType::Int64, v.data_type());
+    assert_eq!("[4, 5, 6]", format!("{:?}", v.to_values()?));
+
+    let name = DataValue::String(Some("item_2".as_bytes().to_vec()));
+    let func = GetFieldFunction::create_with_params("get_field", &[name])?;
+    assert_eq!(
+        format!(
+            "Code: 7, displayText = Unknown field item_2 of {:?}.",
+            tuple_type
+        ),
+        func.return_type(&args).unwrap_err().to_string()
+    );
+
+    // the field name is not a constant string
+    let result = GetFieldFunction::create_with_params("get_field", &[]);
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -20,15 +20,22 @@ use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
+use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
 
@@ -179,6 +186,68 @@ impl MetaApiTestSuite {
 
         Ok(())
     }
+    pub async fn database_rename<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        tracing::info!("--- prepare db1 and db2, table db1.tb1");
+        {
+            self.create_database(mt, "db1").await?;
+            self.create_database(mt, "db2").await?;
+            self.create_table(mt, "db1", "tb1").await?;
+        }
+
+        tracing::info!("--- rename db1 to db3");
+        {
+            mt.rename_database(RenameDatabaseReq {
+                if_exists: false,
+                db: "db1".to_string(),
+                new_db: "db3".to_string(),
+            })
+            .await?;
+
+            let res = mt.get_database(GetDatabaseReq::new("db1")).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+
+            let res = mt.get_database(GetDatabaseReq::new("db3")).await?;
+            assert_eq!(1, res.database_id, "db3 keeps the id of db1");
+
+            let res = mt.get_table(("db3", "tb1").into()).await?;
+            assert_eq!(1, res.ident.table_id, "tables follow the renamed database");
+        }
+
+        tracing::info!("--- rename db3 to an existing name, error");
+        {
+            let res = mt
+                .rename_database(RenameDatabaseReq {
+                    if_exists: false,
+                    db: "db3".to_string(),
+                    new_db: "db2".to_string(),
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::DatabaseAlreadyExists("").code(), err.code());
+
+            let res = mt.get_database(GetDatabaseReq::new("db2")).await?;
+            assert_eq!(2, res.database_id, "db2 is unaffected");
+        }
+
+        tracing::info!("--- rename an unknown db");
+        {
+            let mut req = RenameDatabaseReq {
+                if_exists: false,
+                db: "db1".to_string(),
+                new_db: "db4".to_string(),
+            };
+            let res = mt.rename_database(req.clone()).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+
+            req.if_exists = true;
+            mt.rename_database(req).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn table_create_get_drop<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let db_name = "db1";
         let tbl_name = "tb2";
@@ -325,6 +394,52 @@ impl MetaApiTestSuite {
                 }
             }
 
+            tracing::info!("--- update table schema");
+            {
+                let new_schema = Arc::new(DataSchema::new(vec![
+                    DataField::new("number", DataType::UInt64, false),
+                    DataField::new("added", DataType::String, true),
+                ]));
+
+                tracing::info!("--- update table schema with key2=val2");
+                {
+                    let table = mt.get_table(("db1", "tb2").into()).await.unwrap();
+
+                    mt.update_table_schema(
+                        UpdateTableSchemaReq::new(&table.ident, new_schema.clone())
+                            .with_option("key2", "val2"),
+                    )
+                    .await?;
+
+                    let table = mt.get_table(("db1", "tb2").into()).await.unwrap();
+                    assert_eq!(table.schema(), new_schema);
+                    assert_eq!(table.options().get("key1"), Some(&"val1".into()));
+                    assert_eq!(table.options().get("key2"), Some(&"val2".into()));
+                }
+
+                tracing::info!("--- update table schema with mismatched version");
+                {
+                    let table = mt.get_table(("db1", "tb2").into()).await.unwrap();
+
+                    let got = mt
+                        .update_table_schema(UpdateTableSchemaReq::new(
+                            &TableIdent {
+                                table_id: table.ident.table_id,
+                                version: table.ident.version - 1,
+                            },
+                            schema.clone(),
+                        ))
+                        .await;
+
+                    let got = got.unwrap_err();
+                    assert_eq!(ErrorCode::TableVersionMissMatch("").code(), got.code());
+
+                    // table is not affected.
+                    let table = mt.get_table(("db1", "tb2").into()).await.unwrap();
+                    assert_eq!(table.schema(), new_schema);
+                }
+            }
+
             tracing::info!("--- drop table with if_exists = false");
             {
                 let plan = DropTableReq {
@@ -428,6 +543,268 @@ impl MetaApiTestSuite {
 
         Ok(())
     }
+
+    pub async fn table_rename<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        tracing::info!("--- prepare db1, db2 and tables db1.tb1, db1.tb2");
+        {
+            self.create_database(mt, "db1").await?;
+            self.create_database(mt, "db2").await?;
+            self.create_table(mt, "db1", "tb1").await?;
+            self.create_table(mt, "db1", "tb2").await?;
+        }
+
+        tracing::info!("--- rename db1.tb1 to db1.tb3");
+        {
+            let prev = mt.get_table(("db1", "tb1").into()).await?;
+
+            mt.rename_table(RenameTableReq {
+                if_exists: false,
+                db: "db1".to_string(),
+                table: "tb1".to_string(),
+                new_db: "db1".to_string(),
+                new_table: "tb3".to_string(),
+            })
+            .await?;
+
+            let res = mt.get_table(("db1", "tb1").into()).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownTable("").code(), err.code());
+
+            let got = mt.get_table(("db1", "tb3").into()).await?;
+            assert_eq!(prev.ident.table_id, got.ident.table_id);
+            assert_eq!(prev.meta, got.meta);
+            assert!(
+                got.ident.version > prev.ident.version,
+                "version is bumped by renaming"
+            );
+        }
+
+        tracing::info!("--- rename db1.tb3 to an existing name, error");
+        {
+            let res = mt
+                .rename_table(RenameTableReq {
+                    if_exists: false,
+                    db: "db1".to_string(),
+                    table: "tb3".to_string(),
+                    new_db: "db1".to_string(),
+                    new_table: "tb2".to_string(),
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::TableAlreadyExists("").code(), err.code());
+
+            let got = mt.get_table(("db1", "tb2").into()).await?;
+            assert_eq!(2, got.ident.table_id, "db1.tb2 is unaffected");
+        }
+
+        tracing::info!("--- move db1.tb3 to db2.tb1");
+        {
+            mt.rename_table(RenameTableReq {
+                if_exists: false,
+                db: "db1".to_string(),
+                table: "tb3".to_string(),
+                new_db: "db2".to_string(),
+                new_table: "tb1".to_string(),
+            })
+            .await?;
+
+            let got = mt.get_table(("db2", "tb1").into()).await?;
+            assert_eq!(1, got.ident.table_id);
+
+            let res = mt.list_tables(ListTableReq::new("db1")).await?;
+            let got = res.iter().map(|t| t.name.clone()).collect::<Vec<_>>();
+            assert_eq!(vec!["tb2".to_string()], got);
+        }
+
+        tracing::info!("--- rename an unknown table");
+        {
+            let mut req = RenameTableReq {
+                if_exists: false,
+                db: "db1".to_string(),
+                table: "tb1".to_string(),
+                new_db: "db1".to_string(),
+                new_table: "tb4".to_string(),
+            };
+            let res = mt.rename_table(req.clone()).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownTable("").code(), err.code());
+
+            req.if_exists = true;
+            mt.rename_table(req).await?;
+        }
+
+        tracing::info!("--- rename into an unknown database, error");
+        {
+            let res = mt
+                .rename_table(RenameTableReq {
+                    if_exists: false,
+                    db: "db1".to_string(),
+                    table: "tb2".to_string(),
+                    new_db: "db5".to_string(),
+                    new_table: "tb2".to_string(),
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+        }
+
+        Ok(())
+    }
+
+    pub async fn table_drop_undrop<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let drop = |table: &str| DropTableReq {
+            if_exists: false,
+            db: "db1".to_string(),
+            table: table.to_string(),
+        };
+        let undrop = |table: &str| UndropTableReq {
+            db: "db1".to_string(),
+            table: table.to_string(),
+        };
+
+        tracing::info!("--- prepare db1 and table db1.tb1");
+        {
+            self.create_database(mt, "db1").await?;
+            self.create_table(mt, "db1", "tb1").await?;
+        }
+
+        tracing::info!("--- drop db1.tb1, it is listed as dropped");
+        {
+            let prev = mt.get_table(("db1", "tb1").into()).await?;
+            mt.drop_table(drop("tb1")).await?;
+
+            let res = mt.list_tables(ListTableReq::new("db1")).await?;
+            assert!(res.is_empty());
+
+            let res = mt
+                .list_dropped_tables(ListDroppedTableReq::new("db1"))
+                .await?;
+            assert_eq!(1, res.len());
+            assert_eq!("tb1", res[0].table_info.name);
+            assert_eq!(prev.ident.table_id, res[0].table_info.ident.table_id);
+            assert_eq!(prev.meta, res[0].table_info.meta);
+            assert!(res[0].drop_on > 0);
+        }
+
+        tracing::info!("--- undrop db1.tb1");
+        {
+            mt.undrop_table(undrop("tb1")).await?;
+
+            let got = mt.get_table(("db1", "tb1").into()).await?;
+            assert_eq!(1, got.ident.table_id);
+
+            let res = mt
+                .list_dropped_tables(ListDroppedTableReq::new("db1"))
+                .await?;
+            assert!(res.is_empty());
+
+            let res = mt.undrop_table(undrop("tb1")).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownTable("").code(), err.code());
+        }
+
+        tracing::info!("--- undrop when the name is taken, error");
+        {
+            mt.drop_table(drop("tb1")).await?;
+            self.create_table(mt, "db1", "tb1").await?;
+
+            let res = mt.undrop_table(undrop("tb1")).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::TableAlreadyExists("").code(), err.code());
+        }
+
+        tracing::info!("--- the latest dropped table is undropped first");
+        {
+            mt.drop_table(drop("tb1")).await?;
+
+            let res = mt
+                .list_dropped_tables(ListDroppedTableReq::new("db1"))
+                .await?;
+            let got = res
+                .iter()
+                .map(|t| t.table_info.ident.table_id)
+                .collect::<Vec<_>>();
+            assert_eq!(vec![1, 2], got);
+
+            mt.undrop_table(undrop("tb1")).await?;
+            let got = mt.get_table(("db1", "tb1").into()).await?;
+            assert_eq!(2, got.ident.table_id);
+        }
+
+        tracing::info!("--- purge a dropped table");
+        {
+            let db_id = mt
+                .get_database(GetDatabaseReq::new("db1"))
+                .await?
+                .database_id;
+            let req = PurgeDroppedTableReq {
+                db_id,
+                table: "tb1".to_string(),
+                table_id: 1,
+            };
+            mt.purge_dropped_table(req.clone()).await?;
+
+            let res = mt
+                .list_dropped_tables(ListDroppedTableReq::new("db1"))
+                .await?;
+            assert!(res.is_empty());
+
+            let res = mt.purge_dropped_table(req).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownTable("").code(), err.code());
+
+            let got = mt.get_table(("db1", "tb1").into()).await?;
+            assert_eq!(2, got.ident.table_id, "db1.tb1 is unaffected");
+        }
+
+        tracing::info!("--- the dropped tables of a dropped database are still listed and purged");
+        {
+            self.create_database(mt, "db2").await?;
+            self.create_table(mt, "db2", "tb2").await?;
+            mt.drop_table(DropTableReq {
+                if_exists: false,
+                db: "db2".to_string(),
+                table: "tb2".to_string(),
+            })
+            .await?;
+            mt.drop_database(DropDatabaseReq {
+                if_exists: false,
+                db: "db2".to_string(),
+            })
+            .await?;
+
+            let res = mt.list_dropped_tables(ListDroppedTableReq::all()).await?;
+            let dropped = res
+                .iter()
+                .find(|t| t.table_info.name == "tb2")
+                .expect("db2.tb2 is listed");
+            assert_eq!("''.'tb2'", dropped.table_info.desc);
+
+            mt.purge_dropped_table(PurgeDroppedTableReq {
+                db_id: dropped.db_id,
+                table: "tb2".to_string(),
+                table_id: dropped.table_info.ident.table_id,
+            })
+            .await?;
+
+            let res = mt.list_dropped_tables(ListDroppedTableReq::all()).await?;
+            assert!(res.iter().all(|t| t.table_info.name != "tb2"));
+        }
+
+        tracing::info!("--- undrop in an unknown database, error");
+        {
+            let res = mt
+                .undrop_table(UndropTableReq {
+                    db: "db5".to_string(),
+                    table: "tb1".to_string(),
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+        }
+
+        Ok(())
+    }
 }
 
 impl MetaApiTestSuite {
@@ -449,4 +826,34 @@ impl MetaApiTestSuite {
         tracing::info!("create database res: {:?}", res);
         Ok(res)
     }
+
+    async fn create_table<MT: MetaApi>(
+        &self,
+        mt: &MT,
+        db_name: &str,
+        tbl_name: &str,
+    ) -> anyhow::Result<CreateTableReply> {
+        tracing::info!("--- create table {}.{}", db_name, tbl_name);
+
+        let schema = Arc::new(DataSchema::new(vec![DataField::new(
+            "number",
+            DataType::UInt64,
+            false,
+        )]));
+
+        let req = CreateTableReq {
+            if_not_exists: false,
+            db: db_name.to_string(),
+            table: tbl_name.to_string(),
+            table_meta: TableMeta {
+                schema,
+                engine: "JSON".to_string(),
+                options: Default::default(),
+            },
+        };
+
+        let res = mt.create_table(req).await?;
+        tracing::info!("create table res: {:?}", res);
+        Ok(res)
+    }
 }
@@ -26,14 +26,26 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReply;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -51,6 +63,11 @@ impl MetaApi for MetaEmbedded {
         sm.drop_database(req).await
     }
 
+    async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
+        let sm = self.inner.lock().await;
+        sm.rename_database(req).await
+    }
+
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>> {
         let sm = self.inner.lock().await;
         sm.get_database(req).await
@@ -71,6 +88,16 @@ impl MetaApi for MetaEmbedded {
         sm.drop_table(req).await
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        let sm = self.inner.lock().await;
+        sm.undrop_table(req).await
+    }
+
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        let sm = self.inner.lock().await;
+        sm.rename_table(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>> {
         let sm = self.inner.lock().await;
         sm.get_table(req).await
@@ -81,6 +108,22 @@ impl MetaApi for MetaEmbedded {
         sm.list_tables(req).await
     }
 
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> Result<Vec<Arc<DroppedTableInfo>>> {
+        let sm = self.inner.lock().await;
+        sm.list_dropped_tables(req).await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        let sm = self.inner.lock().await;
+        sm.purge_dropped_table(req).await
+    }
+
     async fn get_table_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
         let sm = self.inner.lock().await;
         sm.get_table_by_id(table_id).await
@@ -94,6 +137,14 @@ impl MetaApi for MetaEmbedded {
         sm.upsert_table_option(req).await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        let sm = self.inner.lock().await;
+        sm.update_table_schema(req).await
+    }
+
     fn name(&self) -> String {
         "meta-embedded".to_string()
     }
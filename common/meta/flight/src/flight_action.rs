@@ -28,15 +28,27 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaId;
 use common_meta_types::PrefixListReply;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReply;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableInfo;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_meta_types::UpsertTableOptionReply;
@@ -60,15 +72,21 @@ pub struct FlightReq<T> {
 pub enum MetaFlightAction {
     CreateDatabase(FlightReq<CreateDatabaseReq>),
     DropDatabase(FlightReq<DropDatabaseReq>),
+    RenameDatabase(FlightReq<RenameDatabaseReq>),
     GetDatabase(FlightReq<GetDatabaseReq>),
     ListDatabases(FlightReq<ListDatabaseReq>),
 
     CreateTable(FlightReq<CreateTableReq>),
     DropTable(FlightReq<DropTableReq>),
+    UndropTable(FlightReq<UndropTableReq>),
+    RenameTable(FlightReq<RenameTableReq>),
     GetTable(FlightReq<GetTableReq>),
     GetTableExt(GetTableExtReq),
     ListTables(FlightReq<ListTableReq>),
+    ListDroppedTables(FlightReq<ListDroppedTableReq>),
+    PurgeDroppedTable(FlightReq<PurgeDroppedTableReq>),
     CommitTable(FlightReq<UpsertTableOptionReq>),
+    UpdateTableSchema(FlightReq<UpdateTableSchemaReq>),
 
     UpsertKV(UpsertKVAction),
     GetKV(GetKVAction),
@@ -163,6 +181,10 @@ impl RequestFor for FlightReq<DropDatabaseReq> {
     type Reply = DropDatabaseReply;
 }
 
+impl RequestFor for FlightReq<RenameDatabaseReq> {
+    type Reply = RenameDatabaseReply;
+}
+
 impl RequestFor for FlightReq<CreateTableReq> {
     type Reply = CreateTableReply;
 }
@@ -171,6 +193,14 @@ impl RequestFor for FlightReq<DropTableReq> {
     type Reply = DropTableReply;
 }
 
+impl RequestFor for FlightReq<UndropTableReq> {
+    type Reply = UndropTableReply;
+}
+
+impl RequestFor for FlightReq<RenameTableReq> {
+    type Reply = RenameTableReply;
+}
+
 impl RequestFor for FlightReq<GetTableReq> {
     type Reply = Arc<TableInfo>;
 }
@@ -187,10 +217,22 @@ impl RequestFor for FlightReq<UpsertTableOptionReq> {
     type Reply = UpsertTableOptionReply;
 }
 
+impl RequestFor for FlightReq<UpdateTableSchemaReq> {
+    type Reply = UpdateTableSchemaReply;
+}
+
 impl RequestFor for FlightReq<ListTableReq> {
     type Reply = Vec<Arc<TableInfo>>;
 }
 
+impl RequestFor for FlightReq<ListDroppedTableReq> {
+    type Reply = Vec<Arc<DroppedTableInfo>>;
+}
+
+impl RequestFor for FlightReq<PurgeDroppedTableReq> {
+    type Reply = PurgeDroppedTableReply;
+}
+
 impl RequestFor for FlightReq<ListDatabaseReq> {
     type Reply = Vec<Arc<DatabaseInfo>>;
 }
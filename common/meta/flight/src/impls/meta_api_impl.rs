@@ -26,14 +26,26 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReply;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -54,6 +66,13 @@ impl MetaApi for MetaFlightClient {
         self.do_action(FlightReq { req }).await
     }
 
+    async fn rename_database(
+        &self,
+        req: RenameDatabaseReq,
+    ) -> Result<RenameDatabaseReply, ErrorCode> {
+        self.do_action(FlightReq { req }).await
+    }
+
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, ErrorCode> {
         self.do_action(FlightReq { req }).await
     }
@@ -73,6 +92,14 @@ impl MetaApi for MetaFlightClient {
         self.do_action(FlightReq { req }).await
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, ErrorCode> {
+        self.do_action(FlightReq { req }).await
+    }
+
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, ErrorCode> {
+        self.do_action(FlightReq { req }).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> common_exception::Result<Arc<TableInfo>> {
         self.do_action(FlightReq { req }).await
     }
@@ -84,6 +111,20 @@ impl MetaApi for MetaFlightClient {
         self.do_action(FlightReq { req }).await
     }
 
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> common_exception::Result<Vec<Arc<DroppedTableInfo>>> {
+        self.do_action(FlightReq { req }).await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> common_exception::Result<PurgeDroppedTableReply> {
+        self.do_action(FlightReq { req }).await
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -99,6 +140,13 @@ impl MetaApi for MetaFlightClient {
         self.do_action(FlightReq { req }).await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply, ErrorCode> {
+        self.do_action(FlightReq { req }).await
+    }
+
     fn name(&self) -> String {
         "MetaFlightClient".to_string()
     }
@@ -27,6 +27,7 @@ use crate::state::RaftStateKey;
 use crate::state::RaftStateValue;
 use crate::state_machine::table_lookup::TableLookupValue;
 use crate::state_machine::ClientLastRespValue;
+use crate::state_machine::DroppedTableValue;
 use crate::state_machine::StateMachineMetaKey;
 use crate::state_machine::StateMachineMetaValue;
 use crate::state_machine::TableLookupKey;
@@ -133,3 +134,13 @@ impl SledKeySpace for TableLookup {
     type K = TableLookupKey;
     type V = SeqV<TableLookupValue>;
 }
+
+/// Tables that are dropped but not purged yet, by the name they are dropped with.
+pub struct DroppedTables {}
+
+impl SledKeySpace for DroppedTables {
+    const PREFIX: u8 = 13;
+    const NAME: &'static str = "dropped-tables";
+    type K = TableLookupKey;
+    type V = SeqV<DroppedTableValue>;
+}
@@ -20,6 +20,8 @@ pub use sm::StateMachine;
 pub use snapshot::Snapshot;
 pub use state_machine_meta::StateMachineMetaKey;
 pub use state_machine_meta::StateMachineMetaValue;
+pub use table_lookup::DroppedTable;
+pub use table_lookup::DroppedTableValue;
 pub use table_lookup::TableLookupKey;
 pub use table_lookup::TableLookupValue;
 
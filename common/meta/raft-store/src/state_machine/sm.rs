@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::convert::Infallible;
 use std::convert::TryInto;
 use std::fmt::Debug;
@@ -55,6 +56,7 @@ use crate::config::RaftConfig;
 use crate::sled_key_spaces::ClientLastResps;
 use crate::sled_key_spaces::DatabaseLookup;
 use crate::sled_key_spaces::Databases;
+use crate::sled_key_spaces::DroppedTables;
 use crate::sled_key_spaces::GenericKV;
 use crate::sled_key_spaces::Nodes;
 use crate::sled_key_spaces::Sequences;
@@ -63,6 +65,7 @@ use crate::sled_key_spaces::TableLookup;
 use crate::sled_key_spaces::Tables;
 use crate::state_machine::AppliedState;
 use crate::state_machine::ClientLastRespValue;
+use crate::state_machine::DroppedTable;
 use crate::state_machine::StateMachineMetaKey;
 use crate::state_machine::StateMachineMetaKey::Initialized;
 use crate::state_machine::StateMachineMetaKey::LastApplied;
@@ -566,6 +569,7 @@ impl StateMachine {
             Cmd::DropTable {
                 ref db_name,
                 ref table_name,
+                ref drop_on,
             } => {
                 let db_id = self.txn_get_database_id(db_name, txn_tree).map_err(|e| {
                     let e: ConflictableTransactionError<Infallible> = e.into();
@@ -601,28 +605,356 @@ impl StateMachine {
                     ErrorCode::from(e)
                 })?;
 
+                // the meta is kept until the dropped table is purged
+                let dropped_tree = txn_tree.key_space::<DroppedTables>();
+                let mut dropped = dropped_tree
+                    .get(&lookup_key)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?
+                    .map(|seq_v| seq_v.data)
+                    .unwrap_or_default();
+                dropped.0.push(DroppedTable {
+                    table_id,
+                    drop_on: *drop_on,
+                });
+                self.sub_txn_tree_upsert(
+                    &dropped_tree,
+                    &lookup_key,
+                    &MatchSeq::Any,
+                    Operation::Update(dropped),
+                    None,
+                )
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let prev = self
+                    .txn_get_table_meta_by_id(&table_id, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+                if prev.is_some() {
+                    self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
+                        .map_err(|e| {
+                            let e: ConflictableTransactionError<Infallible> = e.into();
+                            ErrorCode::from(e)
+                        })?;
+                }
+                tracing::debug!("applied drop Table: {} on {}", table_name, drop_on);
+                Ok(Change::new_with_id(table_id, prev, None).into())
+            }
+
+            Cmd::UndropTable {
+                ref db_name,
+                ref table_name,
+            } => {
+                let db_id = self.txn_get_database_id(db_name, txn_tree).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let db_id = match db_id {
+                    None => return Ok(Change::<TableMeta>::new(None, None).into()),
+                    Some(db_id) => db_id,
+                };
+
+                let lookup_key = TableLookupKey {
+                    database_id: db_id,
+                    table_name: table_name.to_string(),
+                };
+
+                let dropped_tree = txn_tree.key_space::<DroppedTables>();
+                let dropped = dropped_tree.get(&lookup_key).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let mut dropped = match dropped {
+                    Some(seq_v) if !seq_v.data.0.is_empty() => seq_v.data,
+                    _ => return Ok(Change::<TableMeta>::new(None, None).into()),
+                };
+                let table_id = dropped.0.last().unwrap().table_id;
+
+                let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+                let taken = table_lookup_tree.get(&lookup_key).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+                if let Some(taken) = taken {
+                    let taken_id = taken.data.0;
+                    let prev = self
+                        .txn_get_table_meta_by_id(&taken_id, txn_tree)
+                        .map_err(|e| {
+                            let e: ConflictableTransactionError<Infallible> = e.into();
+                            ErrorCode::from(e)
+                        })?;
+                    return Ok(AppliedState::TableMeta(Change::nochange_with_id(
+                        taken_id, prev,
+                    )));
+                }
+
+                dropped.0.pop();
+                let op = if dropped.0.is_empty() {
+                    Operation::Delete
+                } else {
+                    Operation::Update(dropped)
+                };
+                self.sub_txn_tree_upsert(&dropped_tree, &lookup_key, &MatchSeq::Any, op, None)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                self.sub_txn_tree_upsert(
+                    &table_lookup_tree,
+                    &lookup_key,
+                    &MatchSeq::Exact(0),
+                    Operation::Update(TableLookupValue(table_id)),
+                    None,
+                )
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                let result = self
+                    .txn_get_table_meta_by_id(&table_id, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                tracing::debug!("applied undrop Table: {}={}", table_name, table_id);
+                Ok(Change::new_with_id(table_id, None, result).into())
+            }
+
+            Cmd::PurgeDroppedTable {
+                ref db_id,
+                ref table_name,
+                ref table_id,
+            } => {
+                // the database is looked up by id, it may have been dropped after the table
+                let lookup_key = TableLookupKey {
+                    database_id: *db_id,
+                    table_name: table_name.to_string(),
+                };
+
+                let dropped_tree = txn_tree.key_space::<DroppedTables>();
+                let dropped = dropped_tree.get(&lookup_key).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let mut dropped = match dropped {
+                    Some(seq_v) if seq_v.data.0.iter().any(|t| t.table_id == *table_id) => {
+                        seq_v.data
+                    }
+                    _ => return Ok(Change::<TableMeta>::new(None, None).into()),
+                };
+
+                dropped.0.retain(|t| t.table_id != *table_id);
+                let op = if dropped.0.is_empty() {
+                    Operation::Delete
+                } else {
+                    Operation::Update(dropped)
+                };
+                self.sub_txn_tree_upsert(&dropped_tree, &lookup_key, &MatchSeq::Any, op, None)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
                 let tables = txn_tree.key_space::<Tables>();
                 let (prev, result) = self
+                    .sub_txn_tree_upsert(&tables, table_id, &MatchSeq::Any, Operation::Delete, None)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                tracing::debug!("applied purge Table: {}={}", table_name, table_id);
+                Ok(Change::new_with_id(*table_id, prev, result).into())
+            }
+
+            Cmd::RenameDatabase {
+                ref name,
+                ref new_name,
+            } => {
+                let db_lookup_tree = txn_tree.key_space::<DatabaseLookup>();
+                let prev = db_lookup_tree.get(name).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let prev = match prev {
+                    None => return Ok(AppliedState::DatabaseId(Change::new(None, None))),
+                    Some(prev) => prev,
+                };
+                let db_id = prev.data;
+
+                let taken = db_lookup_tree.get(new_name).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+                if taken.is_some() {
+                    return Ok(AppliedState::DatabaseId(Change::nochange_with_id(
+                        db_id,
+                        Some(prev),
+                    )));
+                }
+
+                // tables are looked up by database id, they follow the renamed database
+                self.sub_txn_tree_upsert(
+                    &db_lookup_tree,
+                    name,
+                    &MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                )
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+                let (_, result) = self
                     .sub_txn_tree_upsert(
-                        &tables,
-                        &table_id,
-                        &MatchSeq::Any,
-                        Operation::Delete,
+                        &db_lookup_tree,
+                        new_name,
+                        &MatchSeq::Exact(0),
+                        Operation::Update(db_id),
                         None,
                     )
                     .map_err(|e| {
                         let e: ConflictableTransactionError<Infallible> = e.into();
                         ErrorCode::from(e)
                     })?;
-                if prev.is_some() && result.is_none() {
-                    self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
+
+                self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                tracing::debug!(
+                    "applied rename Database: {}={} {:?}",
+                    name,
+                    new_name,
+                    result
+                );
+                Ok(AppliedState::DatabaseId(Change::new_with_id(
+                    db_id,
+                    Some(prev),
+                    result,
+                )))
+            }
+
+            Cmd::RenameTable {
+                ref db_name,
+                ref table_name,
+                ref new_db_name,
+                ref new_table_name,
+            } => {
+                let db_id = self.txn_get_database_id(db_name, txn_tree).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+                let new_db_id = self
+                    .txn_get_database_id(new_db_name, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                let (db_id, new_db_id) = match (db_id, new_db_id) {
+                    (Some(db_id), Some(new_db_id)) => (db_id, new_db_id),
+                    _ => return Ok(Change::<TableMeta>::new(None, None).into()),
+                };
+
+                let lookup_key = TableLookupKey {
+                    database_id: db_id,
+                    table_name: table_name.to_string(),
+                };
+                let new_lookup_key = TableLookupKey {
+                    database_id: new_db_id,
+                    table_name: new_table_name.to_string(),
+                };
+
+                let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+                let seq_table_id = table_lookup_tree.get(&lookup_key).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let table_id = match seq_table_id {
+                    None => return Ok(Change::<TableMeta>::new(None, None).into()),
+                    Some(seq_table_id) => seq_table_id.data.0,
+                };
+
+                let taken = table_lookup_tree.get(&new_lookup_key).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+                if taken.is_some() {
+                    let prev = self
+                        .txn_get_table_meta_by_id(&table_id, txn_tree)
                         .map_err(|e| {
                             let e: ConflictableTransactionError<Infallible> = e.into();
                             ErrorCode::from(e)
                         })?;
+                    return Ok(AppliedState::TableMeta(Change::nochange_with_id(
+                        table_id, prev,
+                    )));
                 }
-                tracing::debug!("applied drop Table: {} {:?}", table_name, result);
-                Ok(Change::new_with_id(table_id, prev, result).into())
+
+                self.sub_txn_tree_upsert(
+                    &table_lookup_tree,
+                    &lookup_key,
+                    &MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                )
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+                self.sub_txn_tree_upsert(
+                    &table_lookup_tree,
+                    &new_lookup_key,
+                    &MatchSeq::Exact(0),
+                    Operation::Update(TableLookupValue(table_id)),
+                    None,
+                )
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                tracing::debug!(
+                    "applied rename Table: {}-{}={}-{}",
+                    db_name,
+                    table_name,
+                    new_db_name,
+                    new_table_name
+                );
+
+                // bump the table version, so that a stale table info of the old name
+                // can not be committed to.
+                self.txn_update_table_meta(txn_tree, table_id, &MatchSeq::Any, |_| {})
             }
 
             Cmd::UpsertKV {
@@ -644,57 +976,83 @@ impl StateMachine {
             }
 
             Cmd::UpsertTableOptions(ref req) => {
-                let table_tree = txn_tree.key_space::<Tables>();
-                let prev = table_tree.get(&req.table_id).map_err(|e| {
-                    let e: ConflictableTransactionError<Infallible> = e.into();
-                    ErrorCode::from(e)
-                })?;
+                self.txn_update_table_meta(txn_tree, req.table_id, &req.seq, |table_meta| {
+                    Self::upsert_table_options(&mut table_meta.options, &req.options)
+                })
+            }
 
-                // Unlike other Cmd, prev to be None is not allowed for upsert-options.
-                let prev = prev.ok_or_else(|| {
-                    ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id))
-                })?;
+            Cmd::UpdateTableSchema(ref req) => {
+                self.txn_update_table_meta(txn_tree, req.table_id, &req.seq, |table_meta| {
+                    table_meta.schema = req.schema.clone();
+                    Self::upsert_table_options(&mut table_meta.options, &req.options)
+                })
+            }
+        }
+    }
 
-                if req.seq.match_seq(&prev).is_err() {
-                    let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
-                    return Ok(res);
-                }
+    /// Update the meta of a present table with `f`, if `seq` matches.
+    ///
+    /// With mismatched seq, it returns a unchanged state: (prev:TableMeta, prev:TableMeta)
+    fn txn_update_table_meta(
+        &self,
+        txn_tree: &TransactionSledTree,
+        table_id: u64,
+        seq: &MatchSeq,
+        f: impl FnOnce(&mut TableMeta),
+    ) -> common_exception::Result<AppliedState> {
+        let table_tree = txn_tree.key_space::<Tables>();
+        let prev = table_tree.get(&table_id).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
 
-                let meta = prev.meta.clone();
-                let mut table_meta = prev.data.clone();
-                let opts = &mut table_meta.options;
+        // Unlike other Cmd, prev to be None is not allowed for table updates.
+        let prev =
+            prev.ok_or_else(|| ErrorCode::UnknownTableId(format!("table_id:{}", table_id)))?;
 
-                for (k, opt_v) in &req.options {
-                    match opt_v {
-                        None => {
-                            opts.remove(k);
-                        }
-                        Some(v) => {
-                            opts.insert(k.to_string(), v.to_string());
-                        }
-                    }
-                }
+        if seq.match_seq(&prev).is_err() {
+            let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
+            return Ok(res);
+        }
 
-                let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree).map_err(|e| {
-                    let e: ConflictableTransactionError<Infallible> = e.into();
-                    ErrorCode::from(e)
-                })?;
-                let sv = SeqV {
-                    seq: new_seq,
-                    meta,
-                    data: table_meta,
-                };
+        let meta = prev.meta.clone();
+        let mut table_meta = prev.data.clone();
+        f(&mut table_meta);
 
-                table_tree.insert(&req.table_id, &sv).map_err(|e| {
-                    let e: ConflictableTransactionError<Infallible> = e.into();
-                    ErrorCode::from(e)
-                })?;
+        let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
+        let sv = SeqV {
+            seq: new_seq,
+            meta,
+            data: table_meta,
+        };
 
-                Ok(AppliedState::TableMeta(Change::new_with_id(
-                    req.table_id,
-                    Some(prev),
-                    Some(sv),
-                )))
+        table_tree.insert(&table_id, &sv).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
+
+        Ok(AppliedState::TableMeta(Change::new_with_id(
+            table_id,
+            Some(prev),
+            Some(sv),
+        )))
+    }
+
+    fn upsert_table_options(
+        opts: &mut HashMap<String, String>,
+        upserts: &HashMap<String, Option<String>>,
+    ) {
+        for (k, opt_v) in upserts {
+            match opt_v {
+                None => {
+                    opts.remove(k);
+                }
+                Some(v) => {
+                    opts.insert(k.to_string(), v.to_string());
+                }
             }
         }
     }
@@ -1095,4 +1453,8 @@ impl StateMachine {
     pub fn table_lookup(&self) -> AsKeySpace<TableLookup> {
         self.sm_tree.key_space()
     }
+
+    pub fn dropped_tables(&self) -> AsKeySpace<DroppedTables> {
+        self.sm_tree.key_space()
+    }
 }
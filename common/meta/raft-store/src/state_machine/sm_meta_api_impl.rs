@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_exception::ErrorCode;
 use common_meta_api::MetaApi;
@@ -29,14 +32,26 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReply;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -98,6 +113,40 @@ impl MetaApi for StateMachine {
         Ok(DropDatabaseReply {})
     }
 
+    async fn rename_database(
+        &self,
+        req: RenameDatabaseReq,
+    ) -> Result<RenameDatabaseReply, ErrorCode> {
+        let cmd = Cmd::RenameDatabase {
+            name: req.db.clone(),
+            new_name: req.new_db.clone(),
+        };
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+
+        if res.prev().is_none() {
+            if req.if_exists {
+                return Ok(RenameDatabaseReply {});
+            }
+            return Err(ErrorCode::UnknownDatabase(format!(
+                "database not found: {:}",
+                req.db
+            )));
+        }
+
+        if !res.changed() {
+            return Err(ErrorCode::DatabaseAlreadyExists(format!(
+                "{} database exists",
+                req.new_db
+            )));
+        }
+
+        Ok(RenameDatabaseReply {})
+    }
+
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, ErrorCode> {
         let db_id = self.get_database_id(&req.db_name)?;
         let seq_meta = self.get_database_meta_by_id(&db_id)?;
@@ -173,9 +222,15 @@ impl MetaApi for StateMachine {
         let table_name = &req.table;
         let if_exists = req.if_exists;
 
+        let drop_on = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
         let cr = Cmd::DropTable {
             db_name: db_name.clone(),
             table_name: table_name.clone(),
+            drop_on,
         };
 
         let res = self.sm_tree.txn(true, |t| {
@@ -195,6 +250,73 @@ impl MetaApi for StateMachine {
         Ok(DropTableReply {})
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, ErrorCode> {
+        self.get_database_id(&req.db)?;
+
+        let cr = Cmd::UndropTable {
+            db_name: req.db.clone(),
+            table_name: req.table.clone(),
+        };
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cr, &t).unwrap();
+            Ok(r)
+        })?;
+
+        if res.result().is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown dropped table: '{:}'",
+                req.table
+            )));
+        }
+
+        if !res.changed() {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.table
+            )));
+        }
+
+        Ok(UndropTableReply {})
+    }
+
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, ErrorCode> {
+        // an unknown database is reported as an unknown table by the state machine
+        self.get_database_id(&req.db)?;
+        self.get_database_id(&req.new_db)?;
+
+        let cr = Cmd::RenameTable {
+            db_name: req.db.clone(),
+            table_name: req.table.clone(),
+            new_db_name: req.new_db.clone(),
+            new_table_name: req.new_table.clone(),
+        };
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cr, &t).unwrap();
+            Ok(r)
+        })?;
+
+        if res.prev().is_none() {
+            if req.if_exists {
+                return Ok(RenameTableReply {});
+            }
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown table: '{:}'",
+                req.table
+            )));
+        }
+
+        if !res.changed() {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.new_table
+            )));
+        }
+
+        Ok(RenameTableReply {})
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, ErrorCode> {
         let db = &req.db_name;
         let table_name = &req.table_name;
@@ -264,6 +386,86 @@ impl MetaApi for StateMachine {
         Ok(tbls)
     }
 
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> Result<Vec<Arc<DroppedTableInfo>>, ErrorCode> {
+        // The names of the databases by id, a dropped database has no name any more.
+        let mut db_names = HashMap::new();
+        match &req.db_name {
+            Some(db_name) => {
+                db_names.insert(self.get_database_id(db_name)?, db_name.clone());
+            }
+            None => {
+                for r in self.database_lookup().range(..)? {
+                    let (db_name, seq_db_id) = r?;
+                    db_names.insert(seq_db_id.data, db_name);
+                }
+            }
+        }
+
+        let mut tbls = vec![];
+        let tables = self.tables();
+        let dropped_iter = self.dropped_tables().range(..)?;
+        for r in dropped_iter {
+            let (k, seq_dropped) = r?;
+
+            let db_name = match (db_names.get(&k.database_id), &req.db_name) {
+                (Some(db_name), _) => db_name.as_str(),
+                (None, None) => "",
+                (None, Some(_)) => continue,
+            };
+
+            for dropped in seq_dropped.data.0 {
+                let table_id = dropped.table_id;
+
+                let seq_table_meta = tables.get(&table_id)?.ok_or_else(|| {
+                    ErrorCode::IllegalMetaState(format!(" table of id {}, not found", table_id))
+                })?;
+
+                let table_info = TableInfo::new(
+                    db_name,
+                    &k.table_name,
+                    TableIdent::new(table_id, seq_table_meta.seq),
+                    seq_table_meta.data,
+                );
+
+                tbls.push(Arc::new(DroppedTableInfo {
+                    table_info,
+                    db_id: k.database_id,
+                    drop_on: dropped.drop_on,
+                }));
+            }
+        }
+
+        Ok(tbls)
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply, ErrorCode> {
+        let cr = Cmd::PurgeDroppedTable {
+            db_id: req.db_id,
+            table_name: req.table.clone(),
+            table_id: req.table_id,
+        };
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cr, &t).unwrap();
+            Ok(r)
+        })?;
+
+        if res.prev().is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown dropped table: '{:}' of id {}",
+                req.table, req.table_id
+            )));
+        }
+
+        Ok(PurgeDroppedTableReply {})
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -303,6 +505,29 @@ impl MetaApi for StateMachine {
         Ok(UpsertTableOptionReply {})
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply, ErrorCode> {
+        let cmd = Cmd::UpdateTableSchema(req.clone());
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let (prev, _result) = ch.unwrap();
+
+            return Err(ErrorCode::TableVersionMissMatch(format!(
+                "targeting version {:?}, current version {}",
+                req.seq, prev.seq,
+            )));
+        }
+
+        Ok(UpdateTableSchemaReply {})
+    }
+
     fn name(&self) -> String {
         "StateMachine".to_string()
     }
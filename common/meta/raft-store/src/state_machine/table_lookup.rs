@@ -72,3 +72,27 @@ impl fmt::Display for TableLookupValue {
         write!(f, "{}", self.0)
     }
 }
+
+/// A dropped table that is kept until it is purged.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct DroppedTable {
+    pub table_id: u64,
+
+    /// Unix timestamp in seconds when the table is dropped.
+    pub drop_on: u64,
+}
+
+/// The dropped tables of the same name in a database, the latest dropped is the last one.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct DroppedTableValue(pub Vec<DroppedTable>);
+
+impl fmt::Display for DroppedTableValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids = self
+            .0
+            .iter()
+            .map(|t| format!("{}@{}", t.table_id, t.drop_on))
+            .collect::<Vec<_>>();
+        write!(f, "[{}]", ids.join(","))
+    }
+}
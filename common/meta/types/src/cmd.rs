@@ -23,6 +23,7 @@ use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
 use crate::TableMeta;
+use crate::UpdateTableSchemaReq;
 use crate::UpsertTableOptionReq;
 
 /// A Cmd describes what a user want to do to raft state machine
@@ -49,8 +50,49 @@ pub enum Cmd {
         table_meta: TableMeta,
     },
 
-    /// Drop a table if absent
-    DropTable { db_name: String, table_name: String },
+    /// Drop a table if present.
+    ///
+    /// The table meta is kept as a dropped table, which can be undropped until it is purged.
+    /// `drop_on` is the unix timestamp in seconds when the table is dropped.
+    DropTable {
+        db_name: String,
+        table_name: String,
+        #[serde(default)]
+        drop_on: u64,
+    },
+
+    /// Restore the latest dropped table of the name.
+    ///
+    /// If there is no such dropped table it returns (None, None).
+    /// If the name is already taken it returns a unchanged state: (prev:TableMeta, prev:TableMeta).
+    /// Otherwise it returns (None, restored:TableMeta).
+    UndropTable { db_name: String, table_name: String },
+
+    /// Remove the meta of a dropped table permanently.
+    ///
+    /// It returns (prev:TableMeta, None) if the dropped table is present, otherwise (None, None).
+    PurgeDroppedTable {
+        db_id: u64,
+        table_name: String,
+        table_id: u64,
+    },
+
+    /// Rename a database if present.
+    ///
+    /// If `new_name` is already taken it returns a unchanged state: (prev:db_id, prev:db_id).
+    /// Otherwise it returns the lookup entry before and after renaming.
+    RenameDatabase { name: String, new_name: String },
+
+    /// Rename or move a table if present.
+    ///
+    /// The table keeps its id and meta, only the lookup entry is moved and the version bumped.
+    /// If the new name is already taken it returns a unchanged state: (prev:TableMeta, prev:TableMeta)
+    RenameTable {
+        db_name: String,
+        table_name: String,
+        new_db_name: String,
+        new_table_name: String,
+    },
 
     /// Update, remove or insert table options.
     ///
@@ -61,6 +103,11 @@ pub enum Cmd {
     /// Otherwise it returns the TableMeta before and after update.
     UpsertTableOptions(UpsertTableOptionReq),
 
+    /// Replace the schema of a table and upsert its options in one step.
+    ///
+    /// It requires a present table and behaves the same as `UpsertTableOptions` on mismatched seq.
+    UpdateTableSchema(UpdateTableSchemaReq),
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -104,8 +151,37 @@ impl fmt::Display for Cmd {
             Cmd::DropTable {
                 db_name,
                 table_name,
+                drop_on,
             } => {
-                write!(f, "delete_table:{}-{}", db_name, table_name)
+                write!(f, "delete_table:{}-{} on {}", db_name, table_name, drop_on)
+            }
+            Cmd::UndropTable {
+                db_name,
+                table_name,
+            } => {
+                write!(f, "undrop_table:{}-{}", db_name, table_name)
+            }
+            Cmd::PurgeDroppedTable {
+                db_id,
+                table_name,
+                table_id,
+            } => {
+                write!(f, "purge_table:{}-{}={}", db_id, table_name, table_id)
+            }
+            Cmd::RenameDatabase { name, new_name } => {
+                write!(f, "rename_db:{}={}", name, new_name)
+            }
+            Cmd::RenameTable {
+                db_name,
+                table_name,
+                new_db_name,
+                new_table_name,
+            } => {
+                write!(
+                    f,
+                    "rename_table:{}-{}={}-{}",
+                    db_name, table_name, new_db_name, new_table_name
+                )
             }
             Cmd::UpsertKV {
                 key,
@@ -126,6 +202,13 @@ impl fmt::Display for Cmd {
                     req.table_id, req.seq, req.options
                 )
             }
+            Cmd::UpdateTableSchema(req) => {
+                write!(
+                    f,
+                    "update-table-schema: table-id:{}({:?}) = {:?}, {:?}",
+                    req.table_id, req.seq, req.schema, req.options
+                )
+            }
         }
     }
 }
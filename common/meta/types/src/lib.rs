@@ -49,6 +49,8 @@ pub use database::DropDatabaseReply;
 pub use database::DropDatabaseReq;
 pub use database::GetDatabaseReq;
 pub use database::ListDatabaseReq;
+pub use database::RenameDatabaseReply;
+pub use database::RenameDatabaseReq;
 pub use errors::ConflictSeq;
 pub use kv_message::GetKVActionReply;
 pub use kv_message::MGetKVActionReply;
@@ -74,12 +76,22 @@ pub use table::CreateTableReply;
 pub use table::CreateTableReq;
 pub use table::DropTableReply;
 pub use table::DropTableReq;
+pub use table::DroppedTableInfo;
 pub use table::GetTableReq;
+pub use table::ListDroppedTableReq;
 pub use table::ListTableReq;
+pub use table::PurgeDroppedTableReply;
+pub use table::PurgeDroppedTableReq;
+pub use table::RenameTableReply;
+pub use table::RenameTableReq;
 pub use table::TableIdent;
 pub use table::TableInfo;
 pub use table::TableMeta;
 pub use table::TableNameIndent;
+pub use table::UndropTableReply;
+pub use table::UndropTableReq;
+pub use table::UpdateTableSchemaReply;
+pub use table::UpdateTableSchemaReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
 pub use user_auth::AuthType;
@@ -194,6 +194,39 @@ pub struct DropTableReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct DropTableReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UndropTableReq {
+    pub db: String,
+    pub table: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UndropTableReply {}
+
+/// Remove a dropped table permanently, once its retention period expires.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PurgeDroppedTableReq {
+    /// Id of the database the table is dropped from, which may be dropped as well.
+    pub db_id: u64,
+    pub table: String,
+    pub table_id: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PurgeDroppedTableReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableReq {
+    pub if_exists: bool,
+    pub db: String,
+    pub table: String,
+    pub new_db: String,
+    pub new_table: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReq {
     pub table_id: u64,
@@ -223,6 +256,36 @@ impl UpsertTableOptionReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReply {}
 
+/// Replace the schema of a table, e.g. by `ALTER TABLE`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableSchemaReq {
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub schema: Arc<DataSchema>,
+
+    /// Options to add or remove along with the new schema, in the same way as `UpsertTableOptionReq`.
+    pub options: HashMap<String, Option<String>>,
+}
+
+impl UpdateTableSchemaReq {
+    pub fn new(table_ident: &TableIdent, schema: Arc<DataSchema>) -> UpdateTableSchemaReq {
+        UpdateTableSchemaReq {
+            table_id: table_ident.table_id,
+            seq: MatchSeq::Exact(table_ident.version),
+            schema,
+            options: HashMap::new(),
+        }
+    }
+
+    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(key.into(), Some(value.into()));
+        self
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableSchemaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIndent,
@@ -272,3 +335,34 @@ impl ListTableReq {
         }
     }
 }
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ListDroppedTableReq {
+    /// None to list the dropped tables of all the databases, including the dropped ones.
+    pub db_name: Option<String>,
+}
+
+impl ListDroppedTableReq {
+    pub fn new(db_name: impl Into<String>) -> ListDroppedTableReq {
+        ListDroppedTableReq {
+            db_name: Some(db_name.into()),
+        }
+    }
+
+    pub fn all() -> ListDroppedTableReq {
+        ListDroppedTableReq { db_name: None }
+    }
+}
+
+/// A dropped table that is not purged yet.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct DroppedTableInfo {
+    /// The database name of `table_info` is empty if the database is dropped.
+    pub table_info: TableInfo,
+
+    /// Id of the database the table is dropped from.
+    pub db_id: u64,
+
+    /// Unix timestamp in seconds when the table is dropped.
+    pub drop_on: u64,
+}
@@ -19,6 +19,7 @@ mod plan_builder;
 mod plan_copy;
 mod plan_database_create;
 mod plan_database_drop;
+mod plan_database_rename;
 mod plan_describe_table;
 mod plan_display;
 mod plan_display_indent;
@@ -58,8 +59,11 @@ mod plan_sort;
 mod plan_stage;
 mod plan_statistics;
 mod plan_subqueries_set;
+mod plan_table_alter;
 mod plan_table_create;
 mod plan_table_drop;
+mod plan_table_rename;
+mod plan_table_undrop;
 mod plan_truncate_table;
 mod plan_use_database;
 mod plan_user_alter;
@@ -76,6 +80,7 @@ pub use plan_copy::CopyPlan;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
+pub use plan_database_rename::RenameDatabasePlan;
 pub use plan_describe_table::DescribeTablePlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
@@ -92,6 +97,7 @@ pub use plan_expression_common::expr_as_column_expr;
 pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_aggregate_exprs_in_expr;
+pub use plan_expression_common::find_array_join_exprs;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
@@ -139,9 +145,13 @@ pub use plan_stage::StageKind;
 pub use plan_stage::StagePlan;
 pub use plan_statistics::Statistics;
 pub use plan_subqueries_set::SubQueriesSetPlan;
+pub use plan_table_alter::AlterTableOperation;
+pub use plan_table_alter::AlterTablePlan;
 pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_drop::DropTablePlan;
+pub use plan_table_rename::RenameTablePlan;
+pub use plan_table_undrop::UndropTablePlan;
 pub use plan_truncate_table::TruncateTablePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
@@ -25,8 +25,10 @@ use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::GetFieldFunction;
 use lazy_static::lazy_static;
 
+use crate::get_scalar_function;
 use crate::PlanNode;
 
 lazy_static! {
@@ -297,7 +299,8 @@ impl Expression {
                 for arg in args {
                     arg_types.push(arg.to_data_type(input_schema)?);
                 }
-                let func = FunctionFactory::instance().get(op)?;
+                let params = Self::scalar_function_params(op, args);
+                let func = get_scalar_function(op, &params)?;
                 func.return_type(&arg_types)
             }
             Expression::AggregateFunction { .. } => {
@@ -312,6 +315,17 @@ impl Expression {
         }
     }
 
+    /// The parameters of the scalar function which are known by its constant arguments,
+    /// `get_field(struct, 'name')` is created with the name of the field.
+    pub fn scalar_function_params(op: &str, args: &[Expression]) -> Vec<DataValue> {
+        match args {
+            [_, Expression::Literal { value, .. }] if GetFieldFunction::is_get_field(op) => {
+                vec![value.clone()]
+            }
+            _ => vec![],
+        }
+    }
+
     pub fn to_aggregate_function(&self, schema: &DataSchemaRef) -> Result<AggregateFunctionRef> {
         match self {
             Expression::AggregateFunction {
@@ -22,6 +22,7 @@ use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::scalars::CastFunction;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::GetFieldFunction;
 
 #[derive(Debug, Clone)]
 pub enum ExpressionAction {
@@ -91,7 +92,7 @@ impl ActionFunction {
 
         match self.func_name.as_str() {
             "cast" => CastFunction::create(self.func_name.clone(), self.return_type.clone()),
-            _ => FunctionFactory::instance().get(&self.func_name),
+            _ => get_scalar_function(&self.func_name, &self.params),
         }
     }
 
@@ -109,3 +110,12 @@ impl ActionFunction {
         )
     }
 }
+
+/// The scalar functions are created by the factory, except `get_field` which is created with
+/// the name of the field, see `Expression::scalar_function_params`.
+pub fn get_scalar_function(op: &str, params: &[DataValue]) -> Result<Box<dyn Function>> {
+    match GetFieldFunction::is_get_field(op) {
+        true => GetFieldFunction::create_with_params(op, params),
+        false => FunctionFactory::instance().get(op),
+    }
+}
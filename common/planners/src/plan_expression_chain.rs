@@ -17,6 +17,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::FunctionFactory;
 
+use crate::get_scalar_function;
 use crate::ActionAlias;
 use crate::ActionConstant;
 use crate::ActionFunction;
@@ -151,7 +152,8 @@ impl ExpressionChain {
                     self.add_expr(expr)?;
                 }
 
-                let func = FunctionFactory::instance().get(op)?;
+                let params = Expression::scalar_function_params(op, args);
+                let func = get_scalar_function(op, &params)?;
                 let arg_types = args
                     .iter()
                     .map(|action| action.to_data_type(&self.schema))
@@ -161,7 +163,7 @@ impl ExpressionChain {
                     name: expr.column_name(),
                     func_name: op.clone(),
                     is_aggregated: false,
-                    params: vec![],
+                    params,
                     arg_names: args.iter().map(|action| action.column_name()).collect(),
                     arg_types: arg_types.clone(),
                     arg_fields: vec![],
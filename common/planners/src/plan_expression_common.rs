@@ -17,6 +17,7 @@ use std::collections::HashMap;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::ArrayJoinFunction;
 
 use crate::Expression;
 use crate::ExpressionVisitor;
@@ -49,6 +50,15 @@ pub fn find_aggregate_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
     })
 }
 
+/// Collect all deeply nested `arrayJoin` (or `unnest`) calls, they change the number of
+/// the rows so the analyzer only allows them in the projection.
+pub fn find_array_join_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| match nest_exprs {
+        Expression::ScalarFunction { op, .. } => ArrayJoinFunction::is_array_join(op),
+        _ => false,
+    })
+}
+
 /// Collect all arguments from aggregation function and append to this exprs
 /// [ColumnExpr(b), Aggr(sum(a, b))] ---> [ColumnExpr(b), ColumnExpr(a)]
 
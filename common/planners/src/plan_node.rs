@@ -21,6 +21,7 @@ use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::plan_user_stage_create::CreateUserStagePlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
@@ -43,6 +44,8 @@ use crate::LimitPlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameDatabasePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -51,6 +54,7 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
 
 #[allow(clippy::large_enum_variant)]
@@ -75,10 +79,14 @@ pub enum PlanNode {
     Explain(ExplainPlan),
     CreateDatabase(CreateDatabasePlan),
     DropDatabase(DropDatabasePlan),
+    RenameDatabase(RenameDatabasePlan),
     CreateTable(CreateTablePlan),
     DescribeTable(DescribeTablePlan),
     DropTable(DropTablePlan),
+    RenameTable(RenameTablePlan),
+    UndropTable(UndropTablePlan),
     TruncateTable(TruncateTablePlan),
+    AlterTable(AlterTablePlan),
     UseDatabase(UseDatabasePlan),
     SetVariable(SettingPlan),
     Insert(InsertPlan),
@@ -115,10 +123,14 @@ impl PlanNode {
             PlanNode::Explain(v) => v.schema(),
             PlanNode::CreateDatabase(v) => v.schema(),
             PlanNode::DropDatabase(v) => v.schema(),
+            PlanNode::RenameDatabase(v) => v.schema(),
             PlanNode::CreateTable(v) => v.schema(),
             PlanNode::DropTable(v) => v.schema(),
+            PlanNode::RenameTable(v) => v.schema(),
+            PlanNode::UndropTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
+            PlanNode::AlterTable(v) => v.schema(),
             PlanNode::SetVariable(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
             PlanNode::UseDatabase(v) => v.schema(),
@@ -156,10 +168,14 @@ impl PlanNode {
             PlanNode::Explain(_) => "ExplainPlan",
             PlanNode::CreateDatabase(_) => "CreateDatabasePlan",
             PlanNode::DropDatabase(_) => "DropDatabasePlan",
+            PlanNode::RenameDatabase(_) => "RenameDatabasePlan",
             PlanNode::CreateTable(_) => "CreateTablePlan",
             PlanNode::DescribeTable(_) => "DescribeTablePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
+            PlanNode::RenameTable(_) => "RenameTablePlan",
+            PlanNode::UndropTable(_) => "UndropTablePlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
+            PlanNode::AlterTable(_) => "AlterTablePlan",
             PlanNode::SetVariable(_) => "SetVariablePlan",
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
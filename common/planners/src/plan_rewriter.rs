@@ -25,6 +25,7 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
@@ -52,6 +53,8 @@ use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameDatabasePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -60,6 +63,7 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
@@ -106,12 +110,16 @@ pub trait PlanRewriter {
             PlanNode::Expression(plan) => self.rewrite_expression(plan),
             PlanNode::DescribeTable(plan) => self.rewrite_describe_table(plan),
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
+            PlanNode::RenameTable(plan) => self.rewrite_rename_table(plan),
+            PlanNode::UndropTable(plan) => self.rewrite_undrop_table(plan),
             PlanNode::DropDatabase(plan) => self.rewrite_drop_database(plan),
+            PlanNode::RenameDatabase(plan) => self.rewrite_rename_database(plan),
             PlanNode::Insert(plan) => self.rewrite_insert_into(plan),
             PlanNode::Copy(plan) => self.rewrite_copy(plan),
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
+            PlanNode::AlterTable(plan) => self.rewrite_alter_table(plan),
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
             PlanNode::CreateUser(plan) => self.create_user(plan),
             PlanNode::AlterUser(plan) => self.alter_user(plan),
@@ -334,10 +342,22 @@ pub trait PlanRewriter {
         Ok(PlanNode::DropTable(plan.clone()))
     }
 
+    fn rewrite_rename_table(&mut self, plan: &RenameTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::RenameTable(plan.clone()))
+    }
+
+    fn rewrite_undrop_table(&mut self, plan: &UndropTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::UndropTable(plan.clone()))
+    }
+
     fn rewrite_drop_database(&mut self, plan: &DropDatabasePlan) -> Result<PlanNode> {
         Ok(PlanNode::DropDatabase(plan.clone()))
     }
 
+    fn rewrite_rename_database(&mut self, plan: &RenameDatabasePlan) -> Result<PlanNode> {
+        Ok(PlanNode::RenameDatabase(plan.clone()))
+    }
+
     fn rewrite_insert_into(&mut self, plan: &InsertPlan) -> Result<PlanNode> {
         Ok(PlanNode::Insert(plan.clone()))
     }
@@ -354,6 +374,10 @@ pub trait PlanRewriter {
         Ok(PlanNode::TruncateTable(plan.clone()))
     }
 
+    fn rewrite_alter_table(&mut self, plan: &AlterTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTable(plan.clone()))
+    }
+
     fn rewrite_kill(&mut self, plan: &KillPlan) -> Result<PlanNode> {
         Ok(PlanNode::Kill(plan.clone()))
     }
@@ -35,6 +35,8 @@ pub struct SinkPlan {
     pub table_info: TableInfo,
     pub input: Arc<PlanNode>,
     pub cast_needed: bool,
+    /// The columns the input is casted to, the other columns of the table are filled by defaults
+    pub cast_schema: DataSchemaRef,
 }
 
 impl SinkPlan {
@@ -18,16 +18,23 @@ use common_datavalues::DataSchemaRef;
 use common_meta_types::CreateTableReq;
 use common_meta_types::TableMeta;
 
+use crate::PlanNode;
+
 pub type TableOptions = HashMap<String, String>;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct CreateTablePlan {
     pub if_not_exists: bool,
+    /// The table lives in the current session only and is never written to the meta service
+    pub temporary: bool,
     pub db: String,
     /// The table name
     pub table: String,
 
     pub table_meta: TableMeta,
+
+    /// The query of `CREATE TABLE ... AS SELECT`, its result is loaded into the new table
+    pub as_select: Option<Box<PlanNode>>,
 }
 
 impl From<CreateTablePlan> for CreateTableReq {
@@ -18,6 +18,7 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
@@ -43,6 +44,8 @@ use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameDatabasePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -51,6 +54,7 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
@@ -110,10 +114,14 @@ pub trait PlanVisitor {
             PlanNode::Explain(plan) => self.visit_explain(plan),
             PlanNode::CreateDatabase(plan) => self.visit_create_database(plan),
             PlanNode::DropDatabase(plan) => self.visit_drop_database(plan),
+            PlanNode::RenameDatabase(plan) => self.visit_rename_database(plan),
             PlanNode::CreateTable(plan) => self.visit_create_table(plan),
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
+            PlanNode::RenameTable(plan) => self.visit_rename_table(plan),
+            PlanNode::UndropTable(plan) => self.visit_undrop_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
+            PlanNode::AlterTable(plan) => self.visit_alter_table(plan),
             PlanNode::UseDatabase(plan) => self.visit_use_database(plan),
             PlanNode::SetVariable(plan) => self.visit_set_variable(plan),
             PlanNode::Stage(plan) => self.visit_stage(plan),
@@ -248,6 +256,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_rename_database(&mut self, _: &RenameDatabasePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_create_table(&mut self, _: &CreateTablePlan) -> Result<()> {
         Ok(())
     }
@@ -280,6 +292,14 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_rename_table(&mut self, _: &RenameTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_undrop_table(&mut self, _: &UndropTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_use_database(&mut self, _: &UseDatabasePlan) -> Result<()> {
         Ok(())
     }
@@ -304,6 +324,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_alter_table(&mut self, _: &AlterTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
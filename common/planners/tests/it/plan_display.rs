@@ -33,6 +33,7 @@ fn test_plan_display_indent() -> Result<()> {
 
     let plan_create = PlanNode::CreateTable(CreateTablePlan {
         if_not_exists: true,
+        temporary: false,
         db: "foo".into(),
         table: "bar".into(),
         table_meta: TableMeta {
@@ -40,6 +41,7 @@ fn test_plan_display_indent() -> Result<()> {
             engine: "JSON".to_string(),
             options,
         },
+        as_select: None,
     });
 
     assert_eq!(
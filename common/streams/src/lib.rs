@@ -20,6 +20,7 @@ mod stream_correct_with_schema;
 mod stream_datablock;
 mod stream_limit_by;
 mod stream_progress;
+mod stream_read_limits;
 mod stream_skip;
 mod stream_sort;
 mod stream_source;
@@ -34,6 +35,9 @@ pub use stream_correct_with_schema::CorrectWithSchemaStream;
 pub use stream_datablock::DataBlockStream;
 pub use stream_limit_by::LimitByStream;
 pub use stream_progress::ProgressStream;
+pub use stream_read_limits::ReadLimits;
+pub use stream_read_limits::ReadLimitsStream;
+pub use stream_read_limits::ReadOverflowMode;
 pub use stream_skip::SkipStream;
 pub use stream_sort::SortStream;
 pub use stream_source::SourceStream;
@@ -15,6 +15,7 @@
 mod source;
 mod source_csv;
 mod source_factory;
+mod source_json;
 mod source_parquet;
 mod source_values;
 
@@ -23,5 +24,8 @@ pub use source::Source;
 pub use source_csv::CsvSource;
 pub use source_factory::SourceFactory;
 pub use source_factory::SourceParams;
+pub use source_json::JsonSource;
+pub use source_parquet::parquet_leaf_arrays;
+pub use source_parquet::parquet_leaf_fields;
 pub use source_parquet::ParquetSource;
 pub use source_values::ValueSource;
@@ -21,6 +21,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::CsvSource;
+use crate::JsonSource;
 use crate::ParquetSource;
 use crate::Source;
 
@@ -55,6 +56,14 @@ impl SourceFactory {
                     params.max_block_size,
                 )?))
             }
+            "json" | "ndjson" => {
+                let reader = params.acc.get_input_stream(params.path, None)?;
+                Ok(Box::new(JsonSource::try_create(
+                    reader,
+                    params.schema,
+                    params.max_block_size,
+                )?))
+            }
             "parquet" => Ok(Box::new(ParquetSource::new(
                 params.acc,
                 params.path.to_owned(),
@@ -15,12 +15,18 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::read::decompress;
+use common_arrow::arrow::io::parquet::read::page_iter_to_array;
 use common_arrow::arrow::io::parquet::read::page_stream_to_array;
 use common_arrow::arrow::io::parquet::read::read_metadata_async;
 use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
 use common_arrow::parquet::read::get_page_stream;
+use common_arrow::parquet::read::Decompressor;
 use common_dal::DataAccessor;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::DataColumn;
@@ -90,30 +96,62 @@ impl Source for ParquetSource {
         }
         let col_num = self.projection.len();
         let row_group = self.row_group;
-        let cols = self
-            .projection
-            .clone()
-            .into_iter()
-            .map(|idx| (metadata.row_groups[row_group].column(idx).clone(), idx));
-
         let fields = self.arrow_table_schema.fields();
 
-        let stream = futures::stream::iter(cols).map(|(col_meta, idx)| {
+        // the nested structs are stored as their leaf columns, see `parquet_leaf_fields`
+        let mut leaf_starts = Vec::with_capacity(fields.len());
+        let mut leaf_start = 0;
+        for field in fields {
+            leaf_starts.push(leaf_start);
+            leaf_start += parquet_leaf_fields(field).len();
+        }
+
+        let cols = self.projection.clone().into_iter().map(|idx| {
+            let leaves = parquet_leaf_fields(&fields[idx])
+                .into_iter()
+                .enumerate()
+                .map(|(i, leaf)| {
+                    let col_meta = metadata.row_groups[row_group].column(leaf_starts[idx] + i);
+                    (col_meta.clone(), leaf)
+                })
+                .collect::<Vec<_>>();
+            (fields[idx].clone(), leaves)
+        });
+
+        let stream = futures::stream::iter(cols).map(|(field, leaves)| {
             let data_accessor = self.data_accessor.clone();
             let path = self.path.clone();
 
             async move {
-                let mut reader = data_accessor.get_input_stream(path.as_str(), None)?;
-                // TODO cache block column
-                let col_pages =
-                    get_page_stream(&col_meta, &mut reader, vec![], Arc::new(|_, _| true))
-                        .await
-                        .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
-                let pages =
-                    col_pages.map(|compressed_page| decompress(compressed_page?, &mut vec![]));
-                let array =
-                    page_stream_to_array(pages, &col_meta, fields[idx].data_type.clone()).await?;
-                let array: Arc<dyn common_arrow::arrow::array::Array> = array.into();
+                let mut arrays = Vec::with_capacity(leaves.len());
+                for (col_meta, leaf) in leaves {
+                    let mut reader = data_accessor.get_input_stream(path.as_str(), None)?;
+                    // TODO cache block column
+                    let col_pages =
+                        get_page_stream(&col_meta, &mut reader, vec![], Arc::new(|_, _| true))
+                            .await
+                            .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+                    let array: ArrayRef = match &leaf.data_type {
+                        // the dictionary page and the indices of LowCardinality are read into
+                        // the dictionary array directly, only the page iterator supports it
+                        ArrowDataType::Dictionary(_, _) => {
+                            let col_pages = col_pages.collect::<Vec<_>>().await;
+                            let mut pages = Decompressor::new(col_pages.into_iter(), vec![]);
+                            page_iter_to_array(&mut pages, &col_meta, leaf.data_type.clone())?
+                                .into()
+                        }
+                        data_type => {
+                            let pages = col_pages
+                                .map(|compressed_page| decompress(compressed_page?, &mut vec![]));
+                            page_stream_to_array(pages, &col_meta, data_type.clone())
+                                .await?
+                                .into()
+                        }
+                    };
+                    arrays.push(array);
+                }
+
+                let array = parquet_assemble_leaves(&field, &mut arrays.into_iter());
                 Ok::<_, ErrorCode>(DataColumn::Array(array.into_series()))
             }
         });
@@ -128,3 +166,56 @@ impl Source for ParquetSource {
         Ok(Some(block))
     }
 }
+
+/// The fields of the leaf columns in parquet, the nested structs (and the maps stored as
+/// the structs) are stored as their children named like `s.a`, the others are kept as they are.
+pub fn parquet_leaf_fields(field: &ArrowField) -> Vec<ArrowField> {
+    match field.data_type() {
+        ArrowDataType::Struct(children) => children
+            .iter()
+            .flat_map(|child| {
+                let child = ArrowField::new(
+                    &format!("{}.{}", field.name(), child.name()),
+                    child.data_type().clone(),
+                    child.is_nullable(),
+                );
+                parquet_leaf_fields(&child)
+            })
+            .collect(),
+        _ => vec![field.clone()],
+    }
+}
+
+/// The leaf arrays of the array in the same order of `parquet_leaf_fields`.
+pub fn parquet_leaf_arrays(array: &ArrayRef) -> Vec<ArrayRef> {
+    match array.as_any().downcast_ref::<StructArray>() {
+        Some(array) => array
+            .values()
+            .iter()
+            .flat_map(parquet_leaf_arrays)
+            .collect(),
+        None => vec![array.clone()],
+    }
+}
+
+/// Assembles the array of the field from its leaf arrays, the reverse of `parquet_leaf_arrays`.
+/// The structs are read back as non-null, as their validities are not stored.
+fn parquet_assemble_leaves(
+    field: &ArrowField,
+    leaves: &mut impl Iterator<Item = ArrayRef>,
+) -> ArrayRef {
+    match field.data_type() {
+        ArrowDataType::Struct(children) => {
+            let values = children
+                .iter()
+                .map(|child| parquet_assemble_leaves(child, leaves))
+                .collect();
+            Arc::new(StructArray::from_data(
+                field.data_type().clone(),
+                values,
+                None,
+            ))
+        }
+        _ => leaves.next().unwrap(),
+    }
+}
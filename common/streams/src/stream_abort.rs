@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
 
@@ -28,7 +31,9 @@ use crate::SendableDataBlockStream;
 pin_project! {
     pub struct AbortStream {
         #[pin]
-        input: Abortable<SendableDataBlockStream>
+        input: Abortable<SendableDataBlockStream>,
+        // Set if the stream is aborted because the query exceeds its execution time.
+        timeout: Option<Arc<AtomicBool>>,
     }
 }
 
@@ -37,8 +42,29 @@ impl AbortStream {
         let (handle, reg) = AbortHandle::new_pair();
         Ok((handle, Self {
             input: Abortable::new(input, reg),
+            timeout: None,
         }))
     }
+
+    /// Create an abortable stream which reports a timeout error instead of an abort error
+    /// if `timeout` is set when the stream is aborted.
+    pub fn try_create_with_timeout(
+        input: SendableDataBlockStream,
+        timeout: Arc<AtomicBool>,
+    ) -> Result<(AbortHandle, Self)> {
+        let (handle, reg) = AbortHandle::new_pair();
+        Ok((handle, Self {
+            input: Abortable::new(input, reg),
+            timeout: Some(timeout),
+        }))
+    }
+
+    fn is_timeout(timeout: &Option<Arc<AtomicBool>>) -> bool {
+        timeout
+            .as_ref()
+            .map(|timeout| timeout.load(Ordering::Acquire))
+            .unwrap_or(false)
+    }
 }
 
 impl Stream for AbortStream {
@@ -54,6 +80,11 @@ impl Stream for AbortStream {
         match this.input.poll_next(ctx) {
             Poll::Ready(None) => match is_aborted {
                 false => Poll::Ready(None),
+                true if Self::is_timeout(this.timeout) => {
+                    Poll::Ready(Some(Err(ErrorCode::QueryTimeout(
+                        "Aborted query, because it exceeds the max_execution_time",
+                    ))))
+                }
                 true => Poll::Ready(Some(Err(ErrorCode::AbortedQuery(
                     "Aborted query, because the server is shutting down or the query was killed",
                 )))),
@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_base::Progress;
+use common_base::ProgressValues;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::Stream;
+use pin_project_lite::pin_project;
+
+use crate::SendableDataBlockStream;
+
+/// What to do when a query reads more rows or bytes than allowed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReadOverflowMode {
+    /// Fail the query.
+    Throw,
+    /// Stop reading and return the partial result.
+    Break,
+}
+
+impl ReadOverflowMode {
+    pub fn try_from_str(mode: &str) -> Result<Self> {
+        match mode.to_lowercase().as_str() {
+            "throw" => Ok(ReadOverflowMode::Throw),
+            "break" => Ok(ReadOverflowMode::Break),
+            other => Err(ErrorCode::BadArguments(format!(
+                "Unknown read overflow mode: {}, expected 'throw' or 'break'",
+                other
+            ))),
+        }
+    }
+}
+
+/// The limits of the rows and bytes a query can read, 0 means no limit.
+#[derive(Clone, Debug)]
+pub struct ReadLimits {
+    pub max_rows_to_read: usize,
+    pub max_bytes_to_read: usize,
+    pub overflow_mode: ReadOverflowMode,
+}
+
+impl ReadLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_rows_to_read == 0 && self.max_bytes_to_read == 0
+    }
+
+    pub fn is_exceeded(&self, read_rows: usize, read_bytes: usize) -> bool {
+        (self.max_rows_to_read != 0 && read_rows > self.max_rows_to_read)
+            || (self.max_bytes_to_read != 0 && read_bytes > self.max_bytes_to_read)
+    }
+
+    /// Check the rows and bytes which are going to be read, only fails in `throw` mode.
+    pub fn check(&self, read_rows: usize, read_bytes: usize) -> Result<()> {
+        match self.overflow_mode {
+            ReadOverflowMode::Throw if self.is_exceeded(read_rows, read_bytes) => {
+                Err(ErrorCode::TooManyRowsOrBytesToRead(format!(
+                    "Limit for rows or bytes to read exceeded, rows: {} (max_rows_to_read: {}), bytes: {} (max_bytes_to_read: {})",
+                    read_rows, self.max_rows_to_read, read_bytes, self.max_bytes_to_read
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pin_project! {
+    /// Count the read rows and bytes into `progress`, which is shared by all the sources of the
+    /// query, and stop the stream once they exceed the limits.
+    pub struct ReadLimitsStream {
+        #[pin]
+        input: SendableDataBlockStream,
+        progress: Arc<Progress>,
+        limits: ReadLimits,
+        finished: bool,
+    }
+}
+
+impl ReadLimitsStream {
+    pub fn try_create(
+        input: SendableDataBlockStream,
+        progress: Arc<Progress>,
+        limits: ReadLimits,
+    ) -> Result<Self> {
+        Ok(Self {
+            input,
+            progress,
+            limits,
+            finished: false,
+        })
+    }
+}
+
+impl Stream for ReadLimitsStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.finished {
+            return Poll::Ready(None);
+        }
+
+        // Other sources of the query may have already read up to the limits.
+        let values = this.progress.get_values();
+        if this.limits.is_exceeded(values.read_rows, values.read_bytes) {
+            *this.finished = true;
+            return match this.limits.check(values.read_rows, values.read_bytes) {
+                Ok(_) => Poll::Ready(None),
+                Err(cause) => Poll::Ready(Some(Err(cause))),
+            };
+        }
+
+        match this.input.poll_next(ctx) {
+            Poll::Ready(Some(Ok(block))) => {
+                this.progress.incr(&ProgressValues {
+                    read_rows: block.num_rows(),
+                    read_bytes: block.memory_size(),
+                });
+
+                let values = this.progress.get_values();
+                match this.limits.check(values.read_rows, values.read_bytes) {
+                    Ok(_) => Poll::Ready(Some(Ok(block))),
+                    Err(cause) => {
+                        *this.finished = true;
+                        Poll::Ready(Some(Err(cause)))
+                    }
+                }
+            }
+            other => other,
+        }
+    }
+}
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 mod source;
+mod stream_abort;
 mod stream_cast;
 mod stream_datablock;
 mod stream_limit_by;
 mod stream_progress;
+mod stream_read_limits;
 mod stream_skip;
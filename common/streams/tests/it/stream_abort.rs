@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_streams::*;
+use futures::stream::StreamExt;
+
+fn create_stream() -> SendableDataBlockStream {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1i32, 2, 3])]);
+    Box::pin(DataBlockStream::create(schema, None, vec![block]))
+}
+
+#[tokio::test]
+async fn test_abort_stream() {
+    let (handle, mut stream) = AbortStream::try_create(create_stream()).unwrap();
+    handle.abort();
+
+    let res = stream.next().await.unwrap();
+    assert_eq!(res.unwrap_err().code(), ErrorCode::AbortedQuery("").code());
+}
+
+#[tokio::test]
+async fn test_abort_stream_with_timeout() {
+    // Aborted without timeout.
+    {
+        let timeout = Arc::new(AtomicBool::new(false));
+        let (handle, mut stream) =
+            AbortStream::try_create_with_timeout(create_stream(), timeout).unwrap();
+        handle.abort();
+
+        let res = stream.next().await.unwrap();
+        assert_eq!(res.unwrap_err().code(), ErrorCode::AbortedQuery("").code());
+    }
+
+    // Aborted by timeout.
+    {
+        let timeout = Arc::new(AtomicBool::new(false));
+        let (handle, mut stream) =
+            AbortStream::try_create_with_timeout(create_stream(), timeout.clone()).unwrap();
+        timeout.store(true, Ordering::Release);
+        handle.abort();
+
+        let res = stream.next().await.unwrap();
+        assert_eq!(res.unwrap_err().code(), ErrorCode::QueryTimeout("").code());
+    }
+
+    // Not aborted.
+    {
+        let timeout = Arc::new(AtomicBool::new(false));
+        let (_handle, stream) =
+            AbortStream::try_create_with_timeout(create_stream(), timeout).unwrap();
+
+        let blocks = stream.collect::<Vec<_>>().await;
+        assert_eq!(blocks.len(), 1);
+        assert!(blocks[0].is_ok());
+    }
+}
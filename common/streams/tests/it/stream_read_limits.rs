@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_base::Progress;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_streams::*;
+use futures::stream::StreamExt;
+
+fn create_stream() -> SendableDataBlockStream {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let blocks = (0..3)
+        .map(|i| DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![i, i, i])]))
+        .collect::<Vec<_>>();
+    Box::pin(DataBlockStream::create(schema, None, blocks))
+}
+
+#[tokio::test]
+async fn test_read_limits_stream_throw() {
+    let limits = ReadLimits {
+        max_rows_to_read: 4,
+        max_bytes_to_read: 0,
+        overflow_mode: ReadOverflowMode::Throw,
+    };
+    let progress = Arc::new(Progress::create());
+    let stream = ReadLimitsStream::try_create(create_stream(), progress, limits).unwrap();
+
+    let results = stream.collect::<Vec<_>>().await;
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert_eq!(
+        results[1].as_ref().unwrap_err().code(),
+        ErrorCode::TooManyRowsOrBytesToRead("").code()
+    );
+}
+
+#[tokio::test]
+async fn test_read_limits_stream_break() {
+    let limits = ReadLimits {
+        max_rows_to_read: 4,
+        max_bytes_to_read: 0,
+        overflow_mode: ReadOverflowMode::Break,
+    };
+    let progress = Arc::new(Progress::create());
+    let stream =
+        ReadLimitsStream::try_create(create_stream(), progress.clone(), limits.clone()).unwrap();
+
+    // The block exceeding the limits is returned, then the stream is stopped.
+    let results = stream.collect::<Vec<_>>().await;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|res| res.is_ok()));
+    assert_eq!(progress.get_values().read_rows, 6);
+
+    // Another source of the same query is stopped too.
+    let stream = ReadLimitsStream::try_create(create_stream(), progress, limits).unwrap();
+    let results = stream.collect::<Vec<_>>().await;
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_read_limits_check() {
+    let limits = ReadLimits {
+        max_rows_to_read: 0,
+        max_bytes_to_read: 100,
+        overflow_mode: ReadOverflowMode::Throw,
+    };
+    assert!(!limits.is_unlimited());
+    assert!(limits.check(1000, 100).is_ok());
+    assert!(limits.check(1, 101).is_err());
+
+    let limits = ReadLimits {
+        overflow_mode: ReadOverflowMode::Break,
+        ..limits
+    };
+    assert!(limits.check(1, 101).is_ok());
+
+    assert_eq!(
+        ReadOverflowMode::try_from_str("BREAK").unwrap(),
+        ReadOverflowMode::Break
+    );
+    assert!(ReadOverflowMode::try_from_str("wait").is_err());
+}
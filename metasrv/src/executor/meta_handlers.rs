@@ -15,6 +15,8 @@
 
 use std::convert::TryInto;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_exception::ErrorCode;
 use common_meta_api::MetaApi;
@@ -25,6 +27,11 @@ use common_meta_types::Cmd::CreateDatabase;
 use common_meta_types::Cmd::CreateTable;
 use common_meta_types::Cmd::DropDatabase;
 use common_meta_types::Cmd::DropTable;
+use common_meta_types::Cmd::PurgeDroppedTable;
+use common_meta_types::Cmd::RenameDatabase;
+use common_meta_types::Cmd::RenameTable;
+use common_meta_types::Cmd::UndropTable;
+use common_meta_types::Cmd::UpdateTableSchema;
 use common_meta_types::Cmd::UpsertTableOptions;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
@@ -36,14 +43,26 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::LogEntry;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReply;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -141,6 +160,48 @@ impl RequestHandler<FlightReq<DropDatabaseReq>> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<FlightReq<RenameDatabaseReq>> for ActionHandler {
+    async fn handle(
+        &self,
+        act: FlightReq<RenameDatabaseReq>,
+    ) -> common_exception::Result<RenameDatabaseReply> {
+        let req = act.req;
+        let cr = LogEntry {
+            txid: None,
+            cmd: RenameDatabase {
+                name: req.db.clone(),
+                new_name: req.new_db.clone(),
+            },
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if res.prev().is_none() {
+            if req.if_exists {
+                return Ok(RenameDatabaseReply {});
+            }
+            return Err(ErrorCode::UnknownDatabase(format!(
+                "database not found: {:}",
+                req.db
+            )));
+        }
+
+        if !res.changed() {
+            return Err(ErrorCode::DatabaseAlreadyExists(format!(
+                "{} database exists",
+                req.new_db
+            )));
+        }
+
+        Ok(RenameDatabaseReply {})
+    }
+}
+
 // table
 #[async_trait::async_trait]
 impl RequestHandler<FlightReq<CreateTableReq>> for ActionHandler {
@@ -196,12 +257,17 @@ impl RequestHandler<FlightReq<DropTableReq>> for ActionHandler {
         let db_name = &act.req.db;
         let table_name = &act.req.table;
         let if_exists = act.req.if_exists;
+        let drop_on = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
 
         let cr = LogEntry {
             txid: None,
             cmd: DropTable {
                 db_name: db_name.clone(),
                 table_name: table_name.clone(),
+                drop_on,
             },
         };
 
@@ -225,6 +291,103 @@ impl RequestHandler<FlightReq<DropTableReq>> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<FlightReq<UndropTableReq>> for ActionHandler {
+    async fn handle(
+        &self,
+        act: FlightReq<UndropTableReq>,
+    ) -> common_exception::Result<UndropTableReply> {
+        let req = act.req;
+
+        {
+            let sm = self.meta_node.get_state_machine().await;
+            sm.get_database_id(&req.db)?;
+        }
+
+        let cr = LogEntry {
+            txid: None,
+            cmd: UndropTable {
+                db_name: req.db.clone(),
+                table_name: req.table.clone(),
+            },
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if res.result().is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown dropped table: '{:}'",
+                req.table
+            )));
+        }
+
+        if !res.changed() {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.table
+            )));
+        }
+
+        Ok(UndropTableReply {})
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<FlightReq<RenameTableReq>> for ActionHandler {
+    async fn handle(
+        &self,
+        act: FlightReq<RenameTableReq>,
+    ) -> common_exception::Result<RenameTableReply> {
+        let req = act.req;
+
+        // an unknown database is reported as an unknown table by the state machine
+        {
+            let sm = self.meta_node.get_state_machine().await;
+            sm.get_database_id(&req.db)?;
+            sm.get_database_id(&req.new_db)?;
+        }
+
+        let cr = LogEntry {
+            txid: None,
+            cmd: RenameTable {
+                db_name: req.db.clone(),
+                table_name: req.table.clone(),
+                new_db_name: req.new_db.clone(),
+                new_table_name: req.new_table.clone(),
+            },
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if res.prev().is_none() {
+            if req.if_exists {
+                return Ok(RenameTableReply {});
+            }
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown table: '{:}'",
+                req.table
+            )));
+        }
+
+        if !res.changed() {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.new_table
+            )));
+        }
+
+        Ok(RenameTableReply {})
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<FlightReq<GetTableReq>> for ActionHandler {
     async fn handle(
@@ -278,6 +441,50 @@ impl RequestHandler<FlightReq<ListTableReq>> for ActionHandler {
         sm.list_tables(req.req).await
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<FlightReq<ListDroppedTableReq>> for ActionHandler {
+    async fn handle(
+        &self,
+        req: FlightReq<ListDroppedTableReq>,
+    ) -> common_exception::Result<Vec<Arc<DroppedTableInfo>>> {
+        let sm = self.meta_node.get_state_machine().await;
+        sm.list_dropped_tables(req.req).await
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<FlightReq<PurgeDroppedTableReq>> for ActionHandler {
+    async fn handle(
+        &self,
+        act: FlightReq<PurgeDroppedTableReq>,
+    ) -> common_exception::Result<PurgeDroppedTableReply> {
+        let req = act.req;
+        let cr = LogEntry {
+            txid: None,
+            cmd: PurgeDroppedTable {
+                db_id: req.db_id,
+                table_name: req.table.clone(),
+                table_id: req.table_id,
+            },
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if res.prev().is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown dropped table: '{:}' of id {}",
+                req.table, req.table_id
+            )));
+        }
+
+        Ok(PurgeDroppedTableReply {})
+    }
+}
 #[async_trait::async_trait]
 impl RequestHandler<FlightReq<UpsertTableOptionReq>> for ActionHandler {
     async fn handle(
@@ -309,3 +516,35 @@ impl RequestHandler<FlightReq<UpsertTableOptionReq>> for ActionHandler {
         Ok(UpsertTableOptionReply {})
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<FlightReq<UpdateTableSchemaReq>> for ActionHandler {
+    async fn handle(
+        &self,
+        req: FlightReq<UpdateTableSchemaReq>,
+    ) -> common_exception::Result<UpdateTableSchemaReply> {
+        let req = req.req;
+        let cr = LogEntry {
+            txid: None,
+            cmd: UpdateTableSchema(req.clone()),
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let (prev, _result) = ch.unwrap();
+
+            return Err(ErrorCode::TableVersionMissMatch(format!(
+                "targeting version {:?}, current version {}",
+                req.seq, prev.seq,
+            )));
+        }
+
+        Ok(UpdateTableSchemaReply {})
+    }
+}
@@ -19,10 +19,12 @@ pub use rpc::BroadcastAction;
 pub use rpc::CancelAction;
 pub use rpc::DatabendQueryFlightDispatcher;
 pub use rpc::DatabendQueryFlightService;
+pub use rpc::FetchProfileAction;
 pub use rpc::FlightAction;
 pub use rpc::FlightClient;
 pub use rpc::FlightTicket;
 pub use rpc::ShuffleAction;
+pub use rpc::StageProfile;
 pub use rpc::StreamTicket;
 pub use rpc_service::RpcService;
 
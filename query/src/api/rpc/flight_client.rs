@@ -22,12 +22,15 @@ use common_base::tokio::time::Duration;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::ToErrorCode;
 use common_streams::SendableDataBlockStream;
 use tonic::transport::channel::Channel;
 use tonic::Request;
 use tonic::Streaming;
 
+use crate::api::rpc::flight_actions::FetchProfileAction;
 use crate::api::rpc::flight_actions::FlightAction;
+use crate::api::rpc::flight_actions::StageProfile;
 use crate::api::rpc::flight_client_stream::FlightDataStream;
 use crate::api::rpc::flight_tickets::FlightTicket;
 
@@ -57,6 +60,18 @@ impl FlightClient {
         Ok(())
     }
 
+    /// Fetch the profiled pipelines of the stages the node ran for the query.
+    pub async fn fetch_profile(
+        &mut self,
+        query_id: String,
+        timeout: u64,
+    ) -> Result<Vec<StageProfile>> {
+        let action = FlightAction::FetchProfileAction(FetchProfileAction { query_id });
+        let body = self.do_action(action, timeout).await?;
+        serde_json::from_slice(&body)
+            .map_err_to_code(ErrorCode::BadBytes, || "Cannot deserialize StageProfile.")
+    }
+
     // Execute do_get.
     async fn do_get(&mut self, ticket: Ticket, timeout: u64) -> Result<Streaming<FlightData>> {
         let mut request = Request::new(ticket);
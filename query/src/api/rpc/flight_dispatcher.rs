@@ -28,11 +28,13 @@ use common_exception::ToErrorCode;
 use common_infallible::RwLock;
 use tokio_stream::StreamExt;
 
+use crate::api::rpc::flight_actions::StageProfile;
 use crate::api::rpc::flight_scatter::FlightScatter;
 use crate::api::rpc::flight_scatter_broadcast::BroadcastFlightScatter;
 use crate::api::rpc::flight_scatter_hash::HashFlightScatter;
 use crate::api::rpc::flight_tickets::StreamTicket;
 use crate::api::FlightAction;
+use crate::pipelines::processors::Pipeline;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
@@ -47,6 +49,7 @@ struct StreamInfo {
 pub struct DatabendQueryFlightDispatcher {
     streams: Arc<RwLock<HashMap<String, StreamInfo>>>,
     stages_notify: Arc<RwLock<HashMap<String, Arc<Notify>>>>,
+    profiles: Arc<RwLock<HashMap<String, Vec<StageProfile>>>>,
     abort: Arc<AtomicBool>,
 }
 
@@ -57,6 +60,7 @@ impl DatabendQueryFlightDispatcher {
         DatabendQueryFlightDispatcher {
             streams: Arc::new(RwLock::new(HashMap::new())),
             stages_notify: Arc::new(RwLock::new(HashMap::new())),
+            profiles: Arc::new(RwLock::new(HashMap::new())),
             abort: Arc::new(AtomicBool::new(false)),
         }
     }
@@ -83,6 +87,16 @@ impl DatabendQueryFlightDispatcher {
         }
     }
 
+    /// Take the profiled pipelines of the finished stages of the query.
+    pub fn take_profiles(&self, query_id: &str) -> Vec<StageProfile> {
+        self.profiles.write().remove(query_id).unwrap_or_default()
+    }
+
+    /// Drop the profiled pipelines of a query which is cancelled before fetching them.
+    pub fn remove_profiles(&self, query_id: &str) {
+        self.profiles.write().remove(query_id);
+    }
+
     pub async fn broadcast_action(&self, session: SessionRef, action: FlightAction) -> Result<()> {
         let query_id = action.get_query_id();
         let stage_id = action.get_stage_id();
@@ -124,6 +138,9 @@ impl DatabendQueryFlightDispatcher {
 
         let query_plan = action.get_plan();
         action_context.attach_query_plan(&query_plan);
+        if action.get_profile() {
+            action_context.enable_pipeline_profiling();
+        }
         let mut pipeline = pipeline_builder.build(&query_plan)?;
 
         let action_sinks = action.get_sinks();
@@ -133,6 +150,8 @@ impl DatabendQueryFlightDispatcher {
         assert_eq!(action_sinks.len(), 1);
         let stage_name = format!("{}/{}", action_query_id, action_stage_id);
         let stages_notify = self.stages_notify.clone();
+        let profiles = self.profiles.clone();
+        let profile = action.get_profile();
 
         let stream_name = format!("{}/{}", stage_name, action_sinks[0]);
         let tx_ref = self.streams.read().get(&stream_name).map(|x| x.tx.clone());
@@ -158,6 +177,13 @@ impl DatabendQueryFlightDispatcher {
                     }
                 }
             };
+
+            // Record before the sink is closed, the profile is fetched once the stream is finished.
+            // A cancelled query never fetches it, so it is not recorded at all.
+            if profile && !action_context.is_killed() {
+                record_profile(&profiles, action_query_id, action_stage_id, &pipeline);
+            }
+            drop(tx);
         })?;
         Ok(())
     }
@@ -176,6 +202,9 @@ impl DatabendQueryFlightDispatcher {
 
         let query_plan = action.get_plan();
         action_context.attach_query_plan(&query_plan);
+        if action.get_profile() {
+            action_context.enable_pipeline_profiling();
+        }
         let mut pipeline = pipeline_builder.build(&query_plan)?;
 
         let action_query_id = action.get_query_id();
@@ -205,6 +234,8 @@ impl DatabendQueryFlightDispatcher {
 
         let stage_name = format!("{}/{}", action_query_id, action_stage_id);
         let stages_notify = self.stages_notify.clone();
+        let profiles = self.profiles.clone();
+        let profile = action.get_profile();
 
         let flight_scatter = T::try_create(
             action.get_plan().schema(),
@@ -217,8 +248,9 @@ impl DatabendQueryFlightDispatcher {
             wait_start(stage_name, stages_notify).await;
 
             let sinks_tx_ref = &sinks_tx;
+            let pipeline_ref = &mut pipeline;
             let forward_blocks = async move {
-                let mut abortable_stream = pipeline.execute().await?;
+                let mut abortable_stream = pipeline_ref.execute().await?;
                 while let Some(item) = abortable_stream.next().await {
                     let forward_blocks = flight_scatter.execute(&item?)?;
 
@@ -245,6 +277,11 @@ impl DatabendQueryFlightDispatcher {
                     }
                 }
             }
+
+            if profile && !action_context.is_killed() {
+                record_profile(&profiles, action_query_id, action_stage_id, &pipeline);
+            }
+            drop(sinks_tx);
         })?;
 
         Ok(())
@@ -277,6 +314,24 @@ impl DatabendQueryFlightDispatcher {
     }
 }
 
+fn record_profile(
+    profiles: &RwLock<HashMap<String, Vec<StageProfile>>>,
+    query_id: String,
+    stage_id: String,
+    pipeline: &Pipeline,
+) {
+    let pipeline = format!("{:?}", pipeline)
+        .lines()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+
+    profiles
+        .write()
+        .entry(query_id)
+        .or_default()
+        .push(StageProfile { stage_id, pipeline });
+}
+
 async fn wait_start(stage_name: String, stages_notify: Arc<RwLock<HashMap<String, Arc<Notify>>>>) {
     let notify = {
         let stages_notify = stages_notify.read();
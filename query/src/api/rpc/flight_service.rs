@@ -30,6 +30,8 @@ use common_arrow::arrow_format::flight::data::Result as FlightResult;
 use common_arrow::arrow_format::flight::data::SchemaResult;
 use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_server::FlightService;
+use common_exception::ErrorCode;
+use common_exception::ToErrorCode;
 use tokio_stream::Stream;
 use tonic::Request;
 use tonic::Response as RawResponse;
@@ -143,8 +145,19 @@ impl FlightService for DatabendQueryFlightService {
                     session.force_kill_session();
                 }
 
+                // A failed or cancelled EXPLAIN ANALYZE never fetches its profiles.
+                self.dispatcher.remove_profiles(&session_id);
+
                 FlightResult { body: vec![] }
             }
+            FlightAction::FetchProfileAction(action) => {
+                let profiles = self.dispatcher.take_profiles(&action.query_id);
+                let body = serde_json::to_vec(&profiles)
+                    .map_err_to_code(ErrorCode::LogicalError, || {
+                        "Logical error: cannot serialize StageProfile."
+                    })?;
+                FlightResult { body }
+            }
             FlightAction::BroadcastAction(action) => {
                 let session_id = action.query_id.clone();
                 let is_aborted = self.dispatcher.is_aborted();
@@ -22,6 +22,7 @@ use common_tracing::init_tracing_with_file;
 use common_tracing::set_panic_hook;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
+use databend_query::catalogs::DroppedTableGC;
 use databend_query::configs::Config;
 use databend_query::metrics::MetricService;
 use databend_query::servers::ClickHouseHandler;
@@ -30,6 +31,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::sessions::SessionManager;
+use databend_query::storages::fuse::TtlGC;
 use log::info;
 
 #[databend_main]
@@ -161,6 +163,29 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         );
     }
 
+    // Dropped table GC.
+    {
+        if DroppedTableGC::create(session_manager.clone())
+            .start()
+            .is_some()
+        {
+            info!(
+                "Dropped table GC started, retention {} seconds",
+                conf.query.dropped_table_retention_secs
+            );
+        }
+    }
+
+    // Expired rows GC of the tables with TTL.
+    {
+        if TtlGC::create(session_manager.clone()).start().is_some() {
+            info!(
+                "TTL GC started, interval {} seconds",
+                conf.query.ttl_gc_interval_secs
+            );
+        }
+    }
+
     log::info!("Ready for connections.");
     shutdown_handle.wait_for_termination_request().await;
     log::info!("Shutdown server.");
@@ -27,14 +27,26 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReply;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -87,6 +99,11 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
+        self.query_backend(move |cli| async move { cli.rename_database(req).await })
+            .await
+    }
+
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>> {
         self.query_backend(move |cli| async move { cli.get_database(req).await })
             .await
@@ -108,6 +125,16 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        self.query_backend(move |cli| async move { cli.undrop_table(req).await })
+            .await
+    }
+
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        self.query_backend(move |cli| async move { cli.rename_table(req).await })
+            .await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>> {
         self.query_backend(move |cli| async move { cli.get_table(req).await })
             .await
@@ -118,6 +145,22 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> Result<Vec<Arc<DroppedTableInfo>>> {
+        self.query_backend(move |cli| async move { cli.list_dropped_tables(req).await })
+            .await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        self.query_backend(move |cli| async move { cli.purge_dropped_table(req).await })
+            .await
+    }
+
     async fn get_table_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
         self.query_backend(move |cli| async move { cli.get_table_by_id(table_id).await })
             .await
@@ -131,6 +174,14 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        self.query_backend(move |cli| async move { cli.update_table_schema(req).await })
+            .await
+    }
+
     fn name(&self) -> String {
         "meta-remote".to_owned()
     }
@@ -22,10 +22,20 @@ use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use dyn_clone::DynClone;
@@ -52,6 +62,8 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn drop_database(&self, req: DropDatabaseReq) -> Result<()>;
 
+    async fn rename_database(&self, req: RenameDatabaseReq) -> Result<()>;
+
     async fn exists_database(&self, db_name: &str) -> Result<bool> {
         match self.get_database(db_name).await {
             Ok(_) => Ok(true),
@@ -84,6 +96,24 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
 
+    // Restore the latest dropped table of the name.
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply>;
+
+    // Rename a table, it may be moved to another database.
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
+
+    // Get the dropped tables of a db that are not purged yet.
+    async fn list_dropped_tables(&self, db_name: &str) -> Result<Vec<Arc<DroppedTableInfo>>>;
+
+    // Get the dropped tables of all dbs that are not purged yet, including the dropped dbs.
+    async fn list_all_dropped_tables(&self) -> Result<Vec<Arc<DroppedTableInfo>>>;
+
+    // Remove the meta of a dropped table permanently.
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply>;
+
     // Check a db.table is exists or not.
     async fn exists_table(&self, db_name: &str, table_name: &str) -> Result<bool> {
         match self.get_table(db_name, table_name).await {
@@ -103,6 +133,11 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply>;
+
     ///
     /// Table function
     ///
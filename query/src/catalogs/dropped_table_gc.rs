@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::tokio;
+use common_base::tokio::task::JoinHandle;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::PurgeDroppedTableReq;
+
+use crate::catalogs::Catalog;
+use crate::sessions::SessionManager;
+
+/// Purges the dropped tables in the background, once their retention period expires.
+///
+/// A dropped table can be restored by `UNDROP TABLE` until it is purged.
+pub struct DroppedTableGC {
+    session_manager: Arc<SessionManager>,
+    retention_secs: u64,
+    interval: Duration,
+}
+
+impl DroppedTableGC {
+    pub fn create(session_manager: Arc<SessionManager>) -> DroppedTableGC {
+        let conf = session_manager.get_conf();
+        DroppedTableGC {
+            retention_secs: conf.query.dropped_table_retention_secs,
+            interval: Duration::from_secs(conf.query.dropped_table_gc_interval_secs),
+            session_manager,
+        }
+    }
+
+    /// Start purging periodically, it is disabled if the interval is 0.
+    pub fn start(self) -> Option<JoinHandle<()>> {
+        if self.interval.is_zero() {
+            return None;
+        }
+
+        Some(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(self.interval).await;
+                match self.purge_expired().await {
+                    Ok(0) => {}
+                    Ok(n) => log::info!("Purged {} expired dropped tables", n),
+                    Err(e) => log::error!("Purge expired dropped tables failure: {:?}", e),
+                }
+            }
+        }))
+    }
+
+    /// Purge the data and the meta of the expired dropped tables of all databases, including
+    /// the tables dropped from a database which is dropped since.
+    ///
+    /// The data is purged before the meta, if it fails the meta is kept and the purge of the
+    /// table is resumed in the next round. Every query node runs the GC, removing the files is
+    /// idempotent, and the meta service applies the purge of the meta once.
+    ///
+    /// Returns the number of purged tables.
+    pub async fn purge_expired(&self) -> Result<usize> {
+        let session = self.session_manager.create_session("DroppedTableGC")?;
+        let ctx = session.create_context().await?;
+        let catalog = ctx.get_catalog();
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| ErrorCode::LogicalError(e.to_string()))?
+            .as_secs();
+
+        let mut purged = 0;
+        for dropped in catalog.list_all_dropped_tables().await? {
+            if dropped.drop_on + self.retention_secs > now {
+                continue;
+            }
+
+            let table_info = &dropped.table_info;
+            let table = catalog.get_table_by_info(table_info)?;
+            let req = PurgeDroppedTableReq {
+                db_id: dropped.db_id,
+                table: table_info.name.clone(),
+                table_id: table_info.ident.table_id,
+            };
+            if let Err(e) = table.purge(ctx.clone()).await {
+                log::error!(
+                    "Purge data of dropped table {} failure, retry in the next round: {:?}",
+                    table_info.desc,
+                    e
+                );
+                continue;
+            }
+
+            match catalog.purge_dropped_table(req).await {
+                Ok(_) => purged += 1,
+                // purged by another query node
+                Err(e) if e.code() == ErrorCode::UnknownTableCode() => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(purged)
+    }
+}
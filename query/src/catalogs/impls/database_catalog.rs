@@ -23,10 +23,20 @@ use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -125,6 +135,19 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.drop_database(req).await
     }
 
+    async fn rename_database(&self, req: RenameDatabaseReq) -> Result<()> {
+        // rename db in BOTTOM layer only
+        for db in [&req.db, &req.new_db] {
+            if self.immutable_catalog.exists_database(db).await? {
+                return Err(ErrorCode::UnexpectedError(format!(
+                    "user can not rename {} database",
+                    db
+                )));
+            }
+        }
+        self.mutable_catalog.rename_database(req).await
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let res = self.immutable_catalog.get_table_by_info(table_info);
         match res {
@@ -195,6 +218,51 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        // undrop table in BOTTOM layer only
+        if self.immutable_catalog.exists_database(&req.db).await? {
+            return self.immutable_catalog.undrop_table(req).await;
+        }
+        self.mutable_catalog.undrop_table(req).await
+    }
+
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        // rename table in BOTTOM layer only
+        if self.immutable_catalog.exists_database(&req.db).await?
+            || self.immutable_catalog.exists_database(&req.new_db).await?
+        {
+            return self.immutable_catalog.rename_table(req).await;
+        }
+        self.mutable_catalog.rename_table(req).await
+    }
+
+    async fn list_dropped_tables(&self, db_name: &str) -> Result<Vec<Arc<DroppedTableInfo>>> {
+        let r = self.immutable_catalog.list_dropped_tables(db_name).await;
+        match r {
+            Ok(x) => Ok(x),
+            Err(e) => {
+                if e.code() == ErrorCode::UnknownDatabaseCode() {
+                    self.mutable_catalog.list_dropped_tables(db_name).await
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    async fn list_all_dropped_tables(&self) -> Result<Vec<Arc<DroppedTableInfo>>> {
+        // tables in the system databases are never dropped
+        self.mutable_catalog.list_all_dropped_tables().await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        // tables in the system databases are never dropped
+        self.mutable_catalog.purge_dropped_table(req).await
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -203,6 +271,14 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.upsert_table_option(req).await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        // update table schema in BOTTOM layer only
+        self.mutable_catalog.update_table_schema(req).await
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
@@ -23,10 +23,20 @@ use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -86,6 +96,10 @@ impl Catalog for ImmutableCatalog {
         Err(ErrorCode::UnImplement("Cannot drop system database"))
     }
 
+    async fn rename_database(&self, _req: RenameDatabaseReq) -> Result<()> {
+        Err(ErrorCode::UnImplement("Cannot rename system database"))
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let table_id = table_info.ident.table_id;
 
@@ -142,6 +156,37 @@ impl Catalog for ImmutableCatalog {
         )));
     }
 
+    async fn undrop_table(&self, _req: UndropTableReq) -> Result<UndropTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot undrop table in system database",
+        ))
+    }
+
+    async fn rename_table(&self, _req: RenameTableReq) -> Result<RenameTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot rename table in system database",
+        ))
+    }
+
+    async fn list_dropped_tables(&self, db_name: &str) -> Result<Vec<Arc<DroppedTableInfo>>> {
+        // ensure db exists, tables in system database are never dropped
+        let _db = self.get_database(db_name).await?;
+        Ok(vec![])
+    }
+
+    async fn list_all_dropped_tables(&self) -> Result<Vec<Arc<DroppedTableInfo>>> {
+        Ok(vec![])
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        _req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot purge table in system database",
+        ))
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -151,4 +196,14 @@ impl Catalog for ImmutableCatalog {
             req
         )))
     }
+
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        Err(ErrorCode::UnImplement(format!(
+            "Alter table not allowed for system database {:?}",
+            req
+        )))
+    }
 }
@@ -25,14 +25,25 @@ use common_meta_types::DatabaseInfo;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameDatabaseReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -172,6 +183,11 @@ impl Catalog for MutableCatalog {
         Ok(())
     }
 
+    async fn rename_database(&self, req: RenameDatabaseReq) -> Result<()> {
+        self.ctx.meta.rename_database(req).await?;
+        Ok(())
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let storage = self.ctx.storage_factory.clone();
         let ctx = StorageContext {
@@ -220,10 +236,46 @@ impl Catalog for MutableCatalog {
         self.ctx.meta.drop_table(req).await
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        self.ctx.meta.undrop_table(req).await
+    }
+
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        self.ctx.meta.rename_table(req).await
+    }
+
+    async fn list_dropped_tables(&self, db_name: &str) -> Result<Vec<Arc<DroppedTableInfo>>> {
+        self.ctx
+            .meta
+            .list_dropped_tables(ListDroppedTableReq::new(db_name))
+            .await
+    }
+
+    async fn list_all_dropped_tables(&self) -> Result<Vec<Arc<DroppedTableInfo>>> {
+        self.ctx
+            .meta
+            .list_dropped_tables(ListDroppedTableReq::all())
+            .await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        self.ctx.meta.purge_dropped_table(req).await
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply> {
         self.ctx.meta.upsert_table_option(req).await
     }
+
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        self.ctx.meta.update_table_schema(req).await
+    }
 }
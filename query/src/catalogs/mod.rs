@@ -15,6 +15,7 @@
 
 mod catalog;
 mod catalog_context;
+mod dropped_table_gc;
 mod table_id_ranges;
 mod table_memory_meta;
 
@@ -24,6 +25,7 @@ mod impls;
 pub use backends::MetaRemote;
 pub use catalog::Catalog;
 pub use catalog_context::CatalogContext;
+pub use dropped_table_gc::DroppedTableGC;
 pub use impls::DatabaseCatalog;
 pub use impls::ImmutableCatalog;
 pub use impls::MutableCatalog;
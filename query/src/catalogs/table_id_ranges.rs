@@ -26,3 +26,7 @@ pub const SYS_TBL_FUC_ID_END: u64 = SYS_TBL_FUNC_ID_BEGIN + 10000;
 // min id for system tables (inclusive)
 // max id for local tables is u64:MAX
 pub const LOCAL_TBL_ID_BEGIN: u64 = SYS_TBL_ID_END;
+
+// min id for session temporary tables (inclusive)
+// max id for temporary tables is u64:MAX
+pub const TEMP_TBL_ID_BEGIN: u64 = 1 << 63;
@@ -14,7 +14,12 @@
 
 mod hashtable;
 mod meta;
+mod result_cache;
 pub mod service;
 
 pub use hashtable::*;
 pub use meta::MetaClientProvider;
+pub use result_cache::QueryResultCache;
+pub use result_cache::QueryResultCacheEntry;
+pub use result_cache::QueryResultCacheKey;
+pub use result_cache::QueryResultCacheStream;
@@ -0,0 +1,262 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::convert::TryInto;
+use std::io::Read;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use common_arrow::arrow::io::flight::deserialize_batch;
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_cache::Cache;
+use common_cache::DefaultHashBuilder;
+use common_cache::LruCache;
+use common_cache::LruDiskCache;
+use common_cache::Meter;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::DataColumn;
+use common_datavalues::prelude::IntoSeries;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use prost::Message;
+
+use crate::common::result_cache::QueryResultCacheKey;
+use crate::configs::Config;
+
+/// A cached query result and the information shown in `system.query_cache`.
+pub struct QueryResultCacheEntry {
+    pub key: String,
+    pub query: String,
+    pub schema: DataSchemaRef,
+    pub result_rows: usize,
+    pub result_bytes: usize,
+    pub created_at: SystemTime,
+    pub expire_at: SystemTime,
+    pub hits: AtomicU64,
+    // None if the blocks are stored in the disk cache.
+    blocks: Option<Vec<DataBlock>>,
+}
+
+impl QueryResultCacheEntry {
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() >= self.expire_at
+    }
+
+    pub fn get_hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+struct ResultBytesMeter;
+
+impl Meter<String, Arc<QueryResultCacheEntry>> for ResultBytesMeter {
+    type Measure = usize;
+
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Arc<QueryResultCacheEntry>) -> usize
+    where String: Borrow<Q> {
+        v.result_bytes
+    }
+}
+
+type EntriesCache =
+    LruCache<String, Arc<QueryResultCacheEntry>, DefaultHashBuilder, ResultBytesMeter>;
+
+/// Result cache shared by all the sessions of the query node.
+///
+/// Entries are evicted by LRU when the cached bytes exceed `query_result_cache_max_bytes`, and
+/// are ignored once their TTL is reached.
+pub struct QueryResultCache {
+    max_bytes: u64,
+    entries: Mutex<EntriesCache>,
+    // Only set if the result blocks are stored on local disk.
+    disk_cache: Option<Mutex<LruDiskCache>>,
+}
+
+impl QueryResultCache {
+    pub fn create_global(conf: Config) -> Result<Arc<QueryResultCache>> {
+        let max_bytes = conf.query.query_result_cache_max_bytes;
+        let disk_cache = match conf.query.query_result_cache_storage.as_str() {
+            "memory" => None,
+            "disk" => {
+                let path = conf.query.query_result_cache_disk_path.clone();
+                let disk_cache = LruDiskCache::new(path, max_bytes)
+                    .map_err(|e| ErrorCode::InvalidConfig(e.to_string()))?;
+                Some(Mutex::new(disk_cache))
+            }
+            other => {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "Unknown query result cache storage: {}, expected 'memory' or 'disk'",
+                    other
+                )))
+            }
+        };
+
+        Ok(Arc::new(QueryResultCache {
+            max_bytes,
+            entries: Mutex::new(LruCache::with_meter(max_bytes, ResultBytesMeter)),
+            disk_cache,
+        }))
+    }
+
+    /// Get the cached result blocks of the key, if any and not expired.
+    pub fn get(&self, key: &QueryResultCacheKey) -> Result<Option<Vec<DataBlock>>> {
+        let entry = match self.entries.lock().get(&key.digest) {
+            None => return Ok(None),
+            Some(entry) => entry.clone(),
+        };
+
+        if entry.is_expired() {
+            self.entries.lock().pop(&key.digest);
+            self.remove_from_disk(&key.digest);
+            return Ok(None);
+        }
+
+        let blocks = match (&entry.blocks, &self.disk_cache) {
+            (Some(blocks), _) => blocks.clone(),
+            (None, Some(disk_cache)) => {
+                let reader = disk_cache.lock().get(&entry.key);
+                let mut bytes = vec![];
+                match reader {
+                    Ok(mut reader) => reader.read_to_end(&mut bytes)?,
+                    Err(_) => {
+                        // The file was evicted by the disk cache.
+                        self.entries.lock().pop(&entry.key);
+                        return Ok(None);
+                    }
+                };
+                Self::decode_blocks(&entry.schema, &bytes)?
+            }
+            (None, None) => return Ok(None),
+        };
+
+        entry.hits.fetch_add(1, Ordering::Relaxed);
+        Ok(Some(blocks))
+    }
+
+    /// Put the result blocks of the key, the result is dropped if it exceeds the cache capacity.
+    pub fn put(
+        &self,
+        key: &QueryResultCacheKey,
+        query: &str,
+        schema: DataSchemaRef,
+        blocks: Vec<DataBlock>,
+        ttl: Duration,
+    ) -> Result<()> {
+        let result_rows = blocks.iter().map(|b| b.num_rows()).sum();
+        let result_bytes: usize = blocks.iter().map(|b| b.memory_size()).sum();
+        if result_bytes as u64 > self.max_bytes {
+            return Ok(());
+        }
+
+        let blocks = match &self.disk_cache {
+            None => Some(blocks),
+            Some(disk_cache) => {
+                let bytes = Self::encode_blocks(blocks)?;
+                disk_cache
+                    .lock()
+                    .insert_bytes(&key.digest, &bytes)
+                    .map_err(|e| ErrorCode::UnexpectedError(e.to_string()))?;
+                None
+            }
+        };
+
+        let created_at = SystemTime::now();
+        let entry = QueryResultCacheEntry {
+            key: key.digest.clone(),
+            query: query.to_string(),
+            schema,
+            result_rows,
+            result_bytes,
+            created_at,
+            expire_at: created_at + ttl,
+            hits: AtomicU64::new(0),
+            blocks,
+        };
+
+        self.entries.lock().put(key.digest.clone(), Arc::new(entry));
+        Ok(())
+    }
+
+    /// All the live entries, the expired ones are purged.
+    pub fn entries(&self) -> Vec<Arc<QueryResultCacheEntry>> {
+        let mut entries = self.entries.lock();
+        let expired = entries
+            .iter()
+            .filter(|(_, entry)| entry.is_expired())
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+
+        for key in &expired {
+            entries.pop(key);
+            self.remove_from_disk(key);
+        }
+
+        entries.iter().map(|(_, entry)| entry.clone()).collect()
+    }
+
+    fn remove_from_disk(&self, key: &str) {
+        if let Some(disk_cache) = &self.disk_cache {
+            if let Err(cause) = disk_cache.lock().remove(key) {
+                log::warn!("Cannot remove query result cache file {}: {}", key, cause);
+            }
+        }
+    }
+
+    fn encode_blocks(blocks: Vec<DataBlock>) -> Result<Vec<u8>> {
+        let options = WriteOptions { compression: None };
+
+        let mut bytes = vec![];
+        for block in blocks {
+            let record_batch: RecordBatch = block.try_into()?;
+            let (_, flight_data) = serialize_batch(&record_batch, &options);
+            flight_data
+                .encode_length_delimited(&mut bytes)
+                .map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+        }
+        Ok(bytes)
+    }
+
+    fn decode_blocks(schema: &DataSchemaRef, mut bytes: &[u8]) -> Result<Vec<DataBlock>> {
+        let arrow_schema = Arc::new(schema.to_arrow());
+
+        let mut blocks = vec![];
+        while !bytes.is_empty() {
+            let flight_data = FlightData::decode_length_delimited(&mut bytes)
+                .map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+            let record_batch = deserialize_batch(
+                &flight_data,
+                arrow_schema.clone(),
+                true,
+                &Default::default(),
+            )?;
+
+            let columns = record_batch
+                .columns()
+                .iter()
+                .map(|column| DataColumn::Array(column.clone().into_series()))
+                .collect::<Vec<_>>();
+            blocks.push(DataBlock::create(schema.clone(), columns));
+        }
+        Ok(blocks)
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_planners::Expression;
+use common_planners::ExpressionVisitor;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Recursion;
+use sha2::Digest;
+
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+
+/// The key of a cached query result.
+///
+/// It is made of the normalized plan text, the current database and user of the session and the
+/// snapshot location of every fuse table the plan reads, so any commit to one of these tables
+/// produces a different key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryResultCacheKey {
+    pub digest: String,
+    pub snapshots: Vec<String>,
+}
+
+impl QueryResultCacheKey {
+    /// Build the key of an optimized plan.
+    ///
+    /// Returns None if the result of the plan can not be cached, e.g. it reads a system table
+    /// or calls a function which is not deterministic.
+    pub fn try_create(
+        plan: &PlanNode,
+        current_database: &str,
+        current_user: &str,
+    ) -> Result<Option<QueryResultCacheKey>> {
+        let mut visitor = CacheKeyVisitor {
+            cacheable: true,
+            snapshots: vec![],
+        };
+        visitor.visit_plan_node(plan)?;
+
+        if !visitor.cacheable {
+            return Ok(None);
+        }
+
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(format!("{:?}", plan).as_bytes());
+        // The names are length prefixed, so ("ab", "c") and ("a", "bc") are different.
+        for name in [current_database, current_user] {
+            hasher.update((name.len() as u64).to_le_bytes());
+            hasher.update(name.as_bytes());
+        }
+        for snapshot in &visitor.snapshots {
+            hasher.update(snapshot.as_bytes());
+        }
+
+        Ok(Some(QueryResultCacheKey {
+            digest: format!("{:x}", hasher.finalize()),
+            snapshots: visitor.snapshots,
+        }))
+    }
+}
+
+struct CacheKeyVisitor {
+    cacheable: bool,
+    snapshots: Vec<String>,
+}
+
+impl PlanVisitor for CacheKeyVisitor {
+    fn visit_expr(&mut self, expr: &Expression) -> Result<()> {
+        match expr {
+            Expression::Subquery { query_plan, .. } => self.visit_subquery_plan(query_plan),
+            Expression::ScalarSubquery { query_plan, .. } => self.visit_subquery_plan(query_plan),
+            _ => {
+                let deterministic = expr.accept(DeterministicVisitor {
+                    deterministic: true,
+                })?;
+                self.cacheable &= deterministic.deterministic;
+                Ok(())
+            }
+        }
+    }
+
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        let table_info = &plan.table_info;
+        match table_info.engine() {
+            "SystemOne" => Ok(()),
+            engine if engine.eq_ignore_ascii_case("FUSE") && plan.tbl_args.is_none() => {
+                let snapshot_loc = table_info.options().get(TBL_OPT_KEY_SNAPSHOT_LOC);
+                self.snapshots.push(format!(
+                    "{}:{}",
+                    table_info.desc,
+                    snapshot_loc.map(|s| s.as_str()).unwrap_or("")
+                ));
+                Ok(())
+            }
+            _ => {
+                self.cacheable = false;
+                Ok(())
+            }
+        }
+    }
+}
+
+struct DeterministicVisitor {
+    deterministic: bool,
+}
+
+impl ExpressionVisitor for DeterministicVisitor {
+    fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+        match expr {
+            Expression::ScalarFunction { op, .. }
+            | Expression::BinaryExpression { op, .. }
+            | Expression::UnaryExpression { op, .. } => {
+                // The functions unknown to the factory are not cached either.
+                let deterministic = FunctionFactory::instance()
+                    .get_features(op)
+                    .map_or(false, |features| features.is_deterministic);
+                match deterministic {
+                    true => Ok(Recursion::Continue(self)),
+                    false => Ok(Recursion::Stop(DeterministicVisitor {
+                        deterministic: false,
+                    })),
+                }
+            }
+            _ => Ok(Recursion::Continue(self)),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::common::result_cache::QueryResultCache;
+use crate::common::result_cache::QueryResultCacheKey;
+
+/// Pass through the result blocks and put them into the result cache once the stream is finished.
+///
+/// Nothing is cached if the stream fails.
+pub struct QueryResultCacheStream {
+    input: SendableDataBlockStream,
+    cache: Arc<QueryResultCache>,
+    key: QueryResultCacheKey,
+    query: String,
+    schema: DataSchemaRef,
+    ttl: Duration,
+    // None if an error happened or the stream is finished.
+    blocks: Option<Vec<DataBlock>>,
+}
+
+impl QueryResultCacheStream {
+    pub fn create(
+        input: SendableDataBlockStream,
+        cache: Arc<QueryResultCache>,
+        key: QueryResultCacheKey,
+        query: String,
+        schema: DataSchemaRef,
+        ttl: Duration,
+    ) -> QueryResultCacheStream {
+        QueryResultCacheStream {
+            input,
+            cache,
+            key,
+            query,
+            schema,
+            ttl,
+            blocks: Some(vec![]),
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Some(blocks) = self.blocks.take() {
+            let schema = self.schema.clone();
+            let res = self
+                .cache
+                .put(&self.key, &self.query, schema, blocks, self.ttl);
+
+            if let Err(cause) = res {
+                log::warn!("Cannot put query result into cache: {}", cause);
+            }
+        }
+    }
+}
+
+impl Stream for QueryResultCacheStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        match self.input.poll_next_unpin(ctx) {
+            Poll::Ready(Some(Ok(block))) => {
+                if let Some(blocks) = self.blocks.as_mut() {
+                    blocks.push(block.clone());
+                }
+                Poll::Ready(Some(Ok(block)))
+            }
+            Poll::Ready(Some(Err(cause))) => {
+                self.blocks = None;
+                Poll::Ready(Some(Err(cause)))
+            }
+            Poll::Ready(None) => {
+                self.finish();
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -33,6 +33,12 @@ pub const QUERY_HTTP_API_ADDRESS: &str = "QUERY_HTTP_API_ADDRESS";
 pub const QUERY_METRICS_API_ADDRESS: &str = "QUERY_METRIC_API_ADDRESS";
 pub const QUERY_WAIT_TIMEOUT_MILLS: &str = "QUERY_WAIT_TIMEOUT_MILLS";
 pub const QUERY_MAX_QUERY_LOG_SIZE: &str = "QUERY_MAX_QUERY_LOG_SIZE";
+pub const QUERY_RESULT_CACHE_STORAGE: &str = "QUERY_RESULT_CACHE_STORAGE";
+pub const QUERY_RESULT_CACHE_MAX_BYTES: &str = "QUERY_RESULT_CACHE_MAX_BYTES";
+pub const QUERY_RESULT_CACHE_DISK_PATH: &str = "QUERY_RESULT_CACHE_DISK_PATH";
+pub const QUERY_DROPPED_TABLE_RETENTION_SECS: &str = "QUERY_DROPPED_TABLE_RETENTION_SECS";
+pub const QUERY_DROPPED_TABLE_GC_INTERVAL_SECS: &str = "QUERY_DROPPED_TABLE_GC_INTERVAL_SECS";
+pub const QUERY_TTL_GC_INTERVAL_SECS: &str = "QUERY_TTL_GC_INTERVAL_SECS";
 const QUERY_API_TLS_SERVER_CERT: &str = "QUERY_API_TLS_SERVER_CERT";
 const QUERY_API_TLS_SERVER_KEY: &str = "QUERY_API_TLS_SERVER_KEY";
 const QUERY_API_TLS_SERVER_ROOT_CA_CERT: &str = "QUERY_API_TLS_SERVER_ROOT_CA_CERT";
@@ -230,6 +236,60 @@ pub struct QueryConfig {
     )]
     #[serde(default)]
     pub max_query_log_size: usize,
+
+    #[structopt(
+    long,
+    env = QUERY_RESULT_CACHE_STORAGE,
+    default_value = "memory",
+    help = "Where the query result cache keeps the results, 'memory' or 'disk'"
+    )]
+    #[serde(default)]
+    pub query_result_cache_storage: String,
+
+    #[structopt(
+    long,
+    env = QUERY_RESULT_CACHE_MAX_BYTES,
+    default_value = "268435456",
+    help = "Max bytes of the results kept by the query result cache"
+    )]
+    #[serde(default)]
+    pub query_result_cache_max_bytes: u64,
+
+    #[structopt(
+    long,
+    env = QUERY_RESULT_CACHE_DISK_PATH,
+    default_value = "_cache/query_result",
+    help = "Directory of the query result cache if the storage is 'disk'"
+    )]
+    #[serde(default)]
+    pub query_result_cache_disk_path: String,
+
+    #[structopt(
+    long,
+    env = QUERY_DROPPED_TABLE_RETENTION_SECS,
+    default_value = "86400",
+    help = "Seconds a dropped table is kept for UNDROP TABLE before it is purged"
+    )]
+    #[serde(default)]
+    pub dropped_table_retention_secs: u64,
+
+    #[structopt(
+    long,
+    env = QUERY_DROPPED_TABLE_GC_INTERVAL_SECS,
+    default_value = "600",
+    help = "Interval in seconds to purge the expired dropped tables, 0 to disable"
+    )]
+    #[serde(default)]
+    pub dropped_table_gc_interval_secs: u64,
+
+    #[structopt(
+    long,
+    env = QUERY_TTL_GC_INTERVAL_SECS,
+    default_value = "3600",
+    help = "Interval in seconds to remove the expired rows of the tables with TTL, 0 to disable"
+    )]
+    #[serde(default)]
+    pub ttl_gc_interval_secs: u64,
 }
 
 impl QueryConfig {
@@ -261,6 +321,12 @@ impl QueryConfig {
             table_engine_github_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10000,
+            query_result_cache_storage: "memory".to_string(),
+            query_result_cache_max_bytes: 256 * 1024 * 1024,
+            query_result_cache_disk_path: "_cache/query_result".to_string(),
+            dropped_table_retention_secs: 86400,
+            dropped_table_gc_interval_secs: 600,
+            ttl_gc_interval_secs: 3600,
         }
     }
 
@@ -388,6 +454,48 @@ impl QueryConfig {
             usize,
             QUERY_MAX_QUERY_LOG_SIZE
         );
+        env_helper!(
+            mut_config,
+            query,
+            query_result_cache_storage,
+            String,
+            QUERY_RESULT_CACHE_STORAGE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            query_result_cache_max_bytes,
+            u64,
+            QUERY_RESULT_CACHE_MAX_BYTES
+        );
+        env_helper!(
+            mut_config,
+            query,
+            query_result_cache_disk_path,
+            String,
+            QUERY_RESULT_CACHE_DISK_PATH
+        );
+        env_helper!(
+            mut_config,
+            query,
+            dropped_table_retention_secs,
+            u64,
+            QUERY_DROPPED_TABLE_RETENTION_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            dropped_table_gc_interval_secs,
+            u64,
+            QUERY_DROPPED_TABLE_GC_INTERVAL_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            ttl_gc_interval_secs,
+            u64,
+            QUERY_TTL_GC_INTERVAL_SECS
+        );
         env_helper!(
             mut_config,
             query,
@@ -17,6 +17,8 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_types::GrantObject;
 use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
 
 use crate::catalogs::Catalog;
 use crate::optimizers::Optimizers;
@@ -26,6 +28,34 @@ pub fn apply_plan_rewrite(mut optimizer: Optimizers, plan: &PlanNode) -> Result<
     optimizer.optimize(plan)
 }
 
+/// Check the estimated rows and bytes the plan reads against the read limits of the query.
+pub fn check_read_limits(ctx: &Arc<QueryContext>, plan: &PlanNode) -> Result<()> {
+    let read_limits = ctx.get_read_limits()?;
+    if read_limits.is_unlimited() {
+        return Ok(());
+    }
+
+    let mut visitor = ReadStatisticsVisitor {
+        read_rows: 0,
+        read_bytes: 0,
+    };
+    visitor.visit_plan_node(plan)?;
+    read_limits.check(visitor.read_rows, visitor.read_bytes)
+}
+
+struct ReadStatisticsVisitor {
+    read_rows: usize,
+    read_bytes: usize,
+}
+
+impl PlanVisitor for ReadStatisticsVisitor {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        self.read_rows += plan.statistics.read_rows;
+        self.read_bytes += plan.statistics.read_bytes;
+        Ok(())
+    }
+}
+
 pub async fn grant_object_exists_or_err(
     ctx: &Arc<QueryContext>,
     object: &GrantObject,
@@ -30,6 +30,7 @@ use nom::bytes::complete::tag;
 use nom::bytes::complete::take_until;
 use nom::IResult;
 
+use crate::interpreters::insert_stream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
@@ -86,9 +87,10 @@ impl Interpreter for CopyInterpreter {
             input_stream,
             self.ctx.progress_callback()?,
         )?);
+        let stream = insert_stream(progress_stream, false, self.plan.schema(), table.schema())?;
 
         let r = table
-            .append_data(self.ctx.clone(), progress_stream)
+            .append_data(self.ctx.clone(), stream)
             .await?
             .try_collect()
             .await?;
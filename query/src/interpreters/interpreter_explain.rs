@@ -21,8 +21,11 @@ use common_planners::ExplainPlan;
 use common_planners::ExplainType;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
 
 use crate::interpreters::interpreter_common::apply_plan_rewrite;
+use crate::interpreters::interpreter_common::check_read_limits;
+use crate::interpreters::plan_schedulers;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::optimizers::Optimizers;
@@ -50,6 +53,7 @@ impl Interpreter for ExplainInterpreter {
             ExplainType::Graph => self.explain_graph(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::Analyze => self.explain_analyze().await,
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -104,4 +108,61 @@ impl ExplainInterpreter {
         );
         Ok(DataBlock::create_by_array(schema, vec![formatted_pipeline]))
     }
+
+    async fn explain_analyze(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        self.ctx.enable_pipeline_profiling();
+        let optimizer = Optimizers::create(self.ctx.clone());
+        let plan = apply_plan_rewrite(optimizer, &self.explain.input)?;
+        check_read_limits(&self.ctx, &plan)?;
+
+        // Run the query to the end, the result is dropped.
+        let (pipeline, mut stream) =
+            plan_schedulers::schedule_query_with_pipeline(&self.ctx, &plan).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        drop(stream);
+
+        let mut lines = format!("{:?}", pipeline)
+            .lines()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+
+        // The stages run by the cluster nodes, collected over flight.
+        let cluster = self.ctx.get_cluster();
+        if !cluster.is_empty() {
+            let config = self.ctx.get_config();
+            let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+            for node in cluster.get_nodes() {
+                let mut flight_client = cluster.create_node_conn(&node.id, &config).await?;
+                let stages = flight_client
+                    .fetch_profile(self.ctx.get_id(), timeout)
+                    .await?;
+                for stage in stages {
+                    lines.push(format!("Stage {} on node {}", stage.stage_id, node.id));
+                    lines.extend(stage.pipeline.iter().map(|line| format!("  {}", line)));
+                }
+            }
+        }
+
+        for (worker, profile) in self.ctx.get_executor_profile().get_workers() {
+            let worker = match worker {
+                Some(worker) => format!("Worker {}", worker),
+                None => "Other tasks".to_string(),
+            };
+            lines.push(format!(
+                "{} (morsels: {}, stolen morsels: {}, blocks out: {}, rows out: {}, wall: {:?})",
+                worker,
+                profile.morsels,
+                profile.stolen_morsels,
+                profile.output_blocks,
+                profile.output_rows,
+                profile.elapsed
+            ));
+        }
+
+        let formatted = Series::new(lines.iter().map(|s| s.as_bytes()).collect::<Vec<_>>());
+        Ok(DataBlock::create_by_array(schema, vec![formatted]))
+    }
 }
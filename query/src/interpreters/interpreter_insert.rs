@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::InsertInputSource;
@@ -23,9 +24,11 @@ use common_streams::SendableDataBlockStream;
 use futures::TryStreamExt;
 
 use super::interpreter_insert_with_stream::SendableWithSchema;
+use crate::catalogs::TEMP_TBL_ID_BEGIN;
+use crate::interpreters::insert_stream;
+use crate::interpreters::interpreter_insert_into_views::InsertIntoMaterializedViews;
 use crate::interpreters::interpreter_insert_with_plan::InsertWithPlan;
 use crate::interpreters::interpreter_insert_with_stream::InsertWithStream;
-use crate::interpreters::AddOnStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
@@ -57,55 +60,75 @@ impl Interpreter for InsertInterpreter {
             .get_table(&plan.database_name, &plan.table_name)
             .await?;
 
-        let need_fill_missing_columns = table.schema() != self.plan.schema();
+        let views =
+            InsertIntoMaterializedViews::try_create(&self.ctx, &plan.database_name, &table).await?;
 
-        let append_logs = match &self.plan.source {
+        let append_logs: Vec<DataBlock> = match &self.plan.source {
             InsertInputSource::SelectPlan(plan_node) => {
                 let with_plan = InsertWithPlan::new(&self.ctx, &self.plan.schema, plan_node);
-                with_plan.execute(table.as_ref()).await
+                // Other nodes know nothing about a temporary table, so the query is not sunk
+                // into it.
+                let temporary = table.get_id() >= TEMP_TBL_ID_BEGIN;
+                if views.is_empty() && !temporary {
+                    with_plan
+                        .execute(table.as_ref())
+                        .await?
+                        .try_collect()
+                        .await?
+                } else if !temporary
+                    && views.can_push_appended()
+                    && !self.ctx.get_cluster().is_empty()
+                {
+                    // The sinks of every node append, then the views read back the blocks.
+                    let append_logs = with_plan
+                        .execute(table.as_ref())
+                        .await?
+                        .try_collect::<Vec<_>>()
+                        .await?;
+                    views.push_appended(&append_logs).await?;
+                    append_logs
+                } else {
+                    // The blocks are appended here, e.g. to be pushed through the views as they
+                    // are inserted, the select query itself is still distributed.
+                    let stream = with_plan.execute_to_stream(table.as_ref()).await?;
+                    let with_stream = InsertWithStream::new(&self.ctx, &table);
+                    with_stream
+                        .append_stream(views.push_blocks(stream))
+                        .await?
+                        .try_collect()
+                        .await?
+                }
             }
             InsertInputSource::Expressions(values_exprs) => {
                 let stream = values_exprs.to_stream(self.plan.schema.clone())?;
-                let stream = if need_fill_missing_columns {
-                    Box::pin(AddOnStream::try_create(
-                        stream,
-                        self.plan.schema(),
-                        table.schema(),
-                    )?)
-                } else {
-                    stream
-                };
+                let stream = insert_stream(stream, false, self.plan.schema(), table.schema())?;
 
                 let with_stream = InsertWithStream::new(&self.ctx, &table);
-                with_stream.append_stream(stream).await
+                with_stream
+                    .append_stream(views.push_blocks(stream))
+                    .await?
+                    .try_collect()
+                    .await?
             }
             InsertInputSource::StreamingWithFormat(_) => {
                 let stream = input_stream
                     .take()
                     .ok_or_else(|| ErrorCode::EmptyData("input stream not exist or consumed"))?;
-
-                let stream = if need_fill_missing_columns {
-                    Box::pin(AddOnStream::try_create(
-                        stream,
-                        self.plan.schema(),
-                        table.schema(),
-                    )?)
-                } else {
-                    stream
-                };
+                let stream = insert_stream(stream, false, self.plan.schema(), table.schema())?;
 
                 let with_stream = InsertWithStream::new(&self.ctx, &table);
-                with_stream.append_stream(stream).await
+                with_stream
+                    .append_stream(views.push_blocks(stream))
+                    .await?
+                    .try_collect()
+                    .await?
             }
-        }?;
+        };
         // feed back the append operation logs to table
         table
-            .commit(
-                self.ctx.clone(),
-                append_logs.try_collect().await?,
-                self.plan.overwrite,
-            )
+            .commit(self.ctx.clone(), append_logs, self.plan.overwrite)
             .await?;
+        views.commit().await?;
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_meta_types::MetaId;
+use common_meta_types::TableInfo;
+use common_planners::PlanNode;
+use common_planners::SinkPlan;
+use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+use sqlparser::ast::TableAlias;
+use sqlparser::ast::TableFactor;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::interpreter_common::apply_plan_rewrite;
+use crate::interpreters::plan_schedulers;
+use crate::optimizers::Optimizers;
+use crate::sessions::QueryContext;
+use crate::sessions::QueryContextShared;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+use crate::storages::fuse::FuseTable;
+use crate::storages::memory::MemoryTable;
+use crate::storages::view::materialized_view_ids;
+use crate::storages::view::MaterializedViewTable;
+use crate::storages::Table;
+
+/// Push the blocks inserted into a table through its materialized views, the results are
+/// appended to the target tables of the views.
+///
+/// The blocks are pushed as they are inserted, before the table is committed, so a view which
+/// fails to push fails the whole insert and nothing is committed. The targets are committed
+/// one by one after the table, see `commit` for a failure of them.
+pub struct InsertIntoMaterializedViews {
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    views: Vec<PushedView>,
+}
+
+struct PushedView {
+    view_id: MetaId,
+    query: DfQueryStatement,
+    target_name: String,
+    target: Arc<dyn Table>,
+    append_logs: Mutex<Vec<DataBlock>>,
+}
+
+impl InsertIntoMaterializedViews {
+    pub async fn try_create(
+        ctx: &Arc<QueryContext>,
+        database: &str,
+        table: &Arc<dyn Table>,
+    ) -> Result<Arc<InsertIntoMaterializedViews>> {
+        // The inserted blocks are bound in place of the table to be pushed, the sinks of the
+        // insert itself must not see them, so the views are pushed in a query of their own.
+        let shared =
+            QueryContextShared::try_create(ctx.get_config(), ctx.get_session(), ctx.get_cluster());
+        let push_ctx = QueryContext::from_shared(shared);
+
+        let catalog = ctx.get_catalog();
+        let mut views = vec![];
+        for view_id in materialized_view_ids(table.get_table_info())? {
+            // The views are looked up by id, they follow the table across renames.
+            let (ident, meta) = match catalog.get_table_meta_by_id(view_id).await {
+                Ok(view_meta) => view_meta,
+                Err(e) if e.code() == ErrorCode::UnknownTableCode() => continue,
+                Err(e) => return Err(e),
+            };
+            let view_info = TableInfo {
+                ident,
+                desc: format!("materialized view #{}", view_id),
+                name: view_id.to_string(),
+                meta: meta.as_ref().clone(),
+            };
+            let view = catalog.get_table_by_info(&view_info)?;
+            let view = view
+                .as_any()
+                .downcast_ref::<MaterializedViewTable>()
+                .ok_or_else(|| ErrorCode::LogicalError("Logical error, it's a bug."))?;
+
+            let (target_db, target_table) = view.get_target();
+            let target = push_ctx.get_table(target_db, target_table).await?;
+            views.push(PushedView {
+                view_id,
+                query: Self::view_query(view, database, table.name())?,
+                target_name: format!("{}.{}", target_db, target_table),
+                target,
+                append_logs: Mutex::new(vec![]),
+            });
+        }
+
+        Ok(Arc::new(InsertIntoMaterializedViews {
+            ctx: push_ctx,
+            table: table.clone(),
+            views,
+        }))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    /// Push each block of the stream through the views as it is inserted.
+    pub fn push_blocks(
+        self: &Arc<Self>,
+        input: SendableDataBlockStream,
+    ) -> SendableDataBlockStream {
+        if self.views.is_empty() {
+            return input;
+        }
+
+        let views = self.clone();
+        Box::pin(input.and_then(move |block| {
+            let views = views.clone();
+            async move {
+                views.push(vec![block.clone()]).await?;
+                Ok(block)
+            }
+        }))
+    }
+
+    /// Whether the views can read back the blocks appended by the sinks of the insert, which
+    /// allows the sinks to run on every node of the cluster.
+    pub fn can_push_appended(&self) -> bool {
+        self.table.as_any().is::<FuseTable>()
+    }
+
+    /// Push the blocks appended by the sinks of the insert through the views, block by block.
+    pub async fn push_appended(&self, append_logs: &[DataBlock]) -> Result<()> {
+        if self.views.is_empty() {
+            return Ok(());
+        }
+
+        let table = self
+            .table
+            .as_any()
+            .downcast_ref::<FuseTable>()
+            .ok_or_else(|| ErrorCode::LogicalError("Logical error, it's a bug."))?;
+        let mut appended = table.read_appended(self.ctx.clone(), append_logs)?;
+        while let Some(block) = appended.try_next().await? {
+            self.push(vec![block]).await?;
+        }
+        Ok(())
+    }
+
+    /// Commit the targets of the views, once the table is committed.
+    ///
+    /// The targets are committed one by one, if one of them fails, the inserted rows are kept
+    /// by the table and the targets committed before it, but not by the others. The error
+    /// tells which view failed.
+    pub async fn commit(&self) -> Result<()> {
+        for view in &self.views {
+            let append_logs = std::mem::take(&mut *view.append_logs.lock());
+            if append_logs.is_empty() {
+                continue;
+            }
+
+            if let Err(cause) = view
+                .target
+                .commit(self.ctx.clone(), append_logs, false)
+                .await
+            {
+                return Err(cause.add_message(format!(
+                    "The rows are inserted into {}, but materialized view #{} failed to commit them into {}, \
+                     only the views committed before it have them:",
+                    self.table.name(),
+                    view.view_id,
+                    view.target_name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    async fn push(&self, blocks: Vec<DataBlock>) -> Result<()> {
+        // The views read the inserted blocks in place of the whole table.
+        let table_info = self.table.get_table_info().clone();
+        let inserted = MemoryTable::create_with_blocks(table_info, blocks);
+        self.ctx.bind_table(Arc::new(inserted));
+
+        let mut res = Ok(());
+        for view in &self.views {
+            res = self.push_to_view(view).await.map_err(|cause| {
+                cause.add_message(format!(
+                    "Materialized view #{} failed to push the rows inserted into {}, nothing is inserted:",
+                    view.view_id,
+                    self.table.name()
+                ))
+            });
+            if res.is_err() {
+                break;
+            }
+        }
+
+        self.ctx.unbind_table(self.table.get_id());
+        res
+    }
+
+    async fn push_to_view(&self, view: &PushedView) -> Result<()> {
+        let ctx = QueryContext::new(self.ctx.clone());
+        let statement = DfStatement::Query(Box::new(view.query.clone()));
+        let select_plan = match PlanParser::build_plan(vec![statement], ctx.clone()).await? {
+            PlanNode::Select(select_plan) => select_plan,
+            other => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Materialized view #{} must be defined by a select query, but got {}",
+                    view.view_id,
+                    other.name()
+                )))
+            }
+        };
+
+        // The inserted blocks are only bound in this node, so the query is not distributed.
+        let optimized_plan = apply_plan_rewrite(
+            Optimizers::without_scatters(ctx.clone()),
+            &select_plan.input,
+        )?;
+        let sink = PlanNode::Sink(SinkPlan {
+            table_info: view.target.get_table_info().clone(),
+            input: Arc::new(optimized_plan),
+            cast_needed: select_plan.schema() != view.target.schema(),
+            cast_schema: view.target.schema(),
+        });
+
+        let append_logs = plan_schedulers::schedule_query(&ctx, &sink)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        view.append_logs.lock().extend(append_logs);
+        Ok(())
+    }
+
+    /// The query of the view reading the table under its current name, which may differ from
+    /// the one the view is defined with.
+    fn view_query(
+        view: &MaterializedViewTable,
+        database: &str,
+        table: &str,
+    ) -> Result<DfQueryStatement> {
+        let (mut statements, _) = DfParser::parse_sql(view.get_query())?;
+        let mut query = match statements.pop() {
+            Some(DfStatement::Query(query)) if statements.is_empty() => query,
+            _ => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Logical error, materialized view #{} must be defined by a single query, it's a bug.",
+                    view.get_id()
+                )))
+            }
+        };
+
+        if let Some(table_with_joins) = query.from.first_mut() {
+            if let TableFactor::Table { name, alias, .. } = &mut table_with_joins.relation {
+                // Keep the columns qualified by the former name resolvable.
+                if let Some(former) = name.0.last() {
+                    if alias.is_none() && former.value != table {
+                        *alias = Some(TableAlias {
+                            name: former.clone(),
+                            columns: vec![],
+                        });
+                    }
+                }
+                *name = ObjectName(vec![Ident::new(database), Ident::new(table)]);
+            }
+        }
+        Ok(*query)
+    }
+}
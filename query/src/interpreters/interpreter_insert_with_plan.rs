@@ -24,7 +24,9 @@ use common_planners::SinkPlan;
 use common_planners::StagePlan;
 use common_streams::SendableDataBlockStream;
 
+use crate::interpreters::insert_stream;
 use crate::interpreters::interpreter_common::apply_plan_rewrite;
+use crate::interpreters::interpreter_common::check_read_limits;
 use crate::interpreters::plan_schedulers;
 use crate::optimizers::Optimizers;
 use crate::sessions::QueryContext;
@@ -64,6 +66,27 @@ impl<'a> InsertWithPlan<'a> {
         }
     }
 
+    /// Execute the select query without sinks, the result is shaped into the blocks of `table`
+    /// as the sinks do, see `insert_stream`, it is left to the caller to append.
+    pub async fn execute_to_stream(
+        &self,
+        table: &dyn Table,
+    ) -> common_exception::Result<SendableDataBlockStream> {
+        if let PlanNode::Select(sel) = self.plan_node {
+            let cast_needed = self.check_schema_cast(sel)?;
+            let optimized_plan =
+                apply_plan_rewrite(Optimizers::create(self.ctx.clone()), &sel.input)?;
+            check_read_limits(self.ctx, &optimized_plan)?;
+            let stream = plan_schedulers::schedule_query(self.ctx, &optimized_plan).await?;
+            insert_stream(stream, cast_needed, self.schema.clone(), table.schema())
+        } else {
+            Err(ErrorCode::UnknownTypeOfQuery(format!(
+                "Unsupported select query plan for insert_into interpreter, {}",
+                self.plan_node.name()
+            )))
+        }
+    }
+
     fn rewrite_plan(
         &self,
         select_plan: &SelectPlan,
@@ -74,6 +97,7 @@ impl<'a> InsertWithPlan<'a> {
         // optimize and rewrite the SelectPlan.input
         let optimized_plan =
             apply_plan_rewrite(Optimizers::create(self.ctx.clone()), &select_plan.input)?;
+        check_read_limits(self.ctx, &optimized_plan)?;
 
         // rewrite the optimized the plan
         let rewritten_plan = match optimized_plan {
@@ -86,6 +110,7 @@ impl<'a> InsertWithPlan<'a> {
                     table_info: table_info.clone(),
                     input: prev_input,
                     cast_needed,
+                    cast_schema: self.schema.clone(),
                 });
                 PlanNode::Stage(StagePlan {
                     kind: r.kind,
@@ -100,6 +125,7 @@ impl<'a> InsertWithPlan<'a> {
                 table_info: table_info.clone(),
                 input: Arc::new(node),
                 cast_needed,
+                cast_schema: self.schema.clone(),
             }),
         };
         Ok(rewritten_plan)
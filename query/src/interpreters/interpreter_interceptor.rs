@@ -28,6 +28,7 @@ use crate::interpreters::InterpreterQueryLog;
 use crate::sessions::QueryContext;
 
 pub struct InterceptorInterpreter {
+    ctx: Arc<QueryContext>,
     inner: InterpreterPtr,
     query_log: InterpreterQueryLog,
     result_metric: Arc<Progress>,
@@ -36,6 +37,7 @@ pub struct InterceptorInterpreter {
 impl InterceptorInterpreter {
     pub fn create(ctx: Arc<QueryContext>, inner: InterpreterPtr, plan: PlanNode) -> Self {
         InterceptorInterpreter {
+            ctx: ctx.clone(),
             inner,
             query_log: InterpreterQueryLog::create(ctx, plan),
             result_metric: Arc::new(Progress::create()),
@@ -61,6 +63,7 @@ impl Interpreter for InterceptorInterpreter {
         &self,
         input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
+        self.ctx.start_execution_timer()?;
         let result_stream = self.inner.execute(input_stream).await?;
         let metric_stream =
             ProgressStream::try_create(result_stream, self.result_metric_callback()?)?;
@@ -13,15 +13,20 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
+use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 
 use super::interpreter_common::apply_plan_rewrite;
+use super::interpreter_common::check_read_limits;
+use crate::common::QueryResultCacheKey;
+use crate::common::QueryResultCacheStream;
 use crate::interpreters::plan_schedulers;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -60,6 +65,43 @@ impl Interpreter for SelectInterpreter {
     ) -> Result<SendableDataBlockStream> {
         // TODO: maybe panic?
         let optimized_plan = self.rewrite_plan()?;
-        plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await
+        check_read_limits(&self.ctx, &optimized_plan)?;
+
+        // A query under read limits may be cut short in break mode, so its
+        // result must neither be served from nor written to the cache.
+        let settings = self.ctx.get_settings();
+        if settings.get_enable_query_result_cache()? == 0
+            || !self.ctx.get_read_limits()?.is_unlimited()
+        {
+            return plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await;
+        }
+
+        let current_database = self.ctx.get_current_database();
+        let current_user = self.ctx.get_current_user()?.name;
+        let cache_key = match QueryResultCacheKey::try_create(
+            &optimized_plan,
+            &current_database,
+            &current_user,
+        )? {
+            None => return plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await,
+            Some(cache_key) => cache_key,
+        };
+
+        let schema = self.schema();
+        let cache = self.ctx.get_sessions_manager().get_query_result_cache();
+        if let Some(blocks) = cache.get(&cache_key)? {
+            return Ok(Box::pin(DataBlockStream::create(schema, None, blocks)));
+        }
+
+        let stream = plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await?;
+        let ttl = Duration::from_secs(settings.get_query_result_cache_ttl()?);
+        Ok(Box::pin(QueryResultCacheStream::create(
+            stream,
+            cache,
+            cache_key,
+            self.ctx.get_query_str(),
+            schema,
+            ttl,
+        )))
     }
 }
@@ -17,9 +17,12 @@ use std::sync::Arc;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::SettingPlan;
 use common_streams::DataBlockStream;
+use common_streams::ReadOverflowMode;
 use common_streams::SendableDataBlockStream;
 
 use crate::interpreters::Interpreter;
@@ -56,6 +59,20 @@ impl Interpreter for SettingInterpreter {
                     let threads: u64 = var.value.parse()?;
                     self.ctx.get_settings().set_max_threads(threads)?;
                 }
+                "timezone" => {
+                    var.value.parse::<Tz>().map_err(|_| {
+                        ErrorCode::BadArguments(format!("Unknown timezone '{}'", var.value))
+                    })?;
+                    self.ctx
+                        .get_settings()
+                        .update_settings(&var.variable, var.value)?;
+                }
+                "read_overflow_mode" => {
+                    ReadOverflowMode::try_from_str(&var.value)?;
+                    self.ctx
+                        .get_settings()
+                        .update_settings(&var.variable, var.value)?;
+                }
                 _ => {
                     self.ctx
                         .get_settings()
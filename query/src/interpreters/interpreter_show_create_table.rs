@@ -30,6 +30,12 @@ use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::partition::partition_expression;
+use crate::storages::fuse::ttl::ttl_expression;
+use crate::storages::fuse::TBL_OPT_KEY_PARTITION_BY;
+use crate::storages::fuse::TBL_OPT_KEY_TTL;
+use crate::storages::view::MaterializedViewTable;
+use crate::storages::view::ViewTable;
 
 pub struct ShowCreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -60,22 +66,47 @@ impl Interpreter for ShowCreateTableInterpreter {
         let engine = table.engine();
         let schema = table.schema();
 
-        let mut table_info = format!("CREATE TABLE `{}` (\n", name);
-        for field in schema.fields().iter() {
-            let column = format!("  `{}` {},\n", field.name(), field.data_type());
-            table_info.push_str(column.as_str());
-        }
-        let table_engine = format!(") ENGINE={}", engine);
-        table_info.push_str(table_engine.as_str());
-        table_info.push_str(
-            table
-                .options()
-                .iter()
-                .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
-                .collect::<Vec<_>>()
-                .join("")
-                .as_str(),
-        );
+        let table_info = if let Some(view) = table.as_any().downcast_ref::<ViewTable>() {
+            format!("CREATE VIEW `{}` AS {}", name, view.get_query())
+        } else if let Some(view) = table.as_any().downcast_ref::<MaterializedViewTable>() {
+            let (target_db, target_table) = view.get_target();
+            format!(
+                "CREATE MATERIALIZED VIEW `{}` TO `{}`.`{}` AS {}",
+                name,
+                target_db,
+                target_table,
+                view.get_query()
+            )
+        } else {
+            let mut table_info = format!("CREATE TABLE `{}` (\n", name);
+            for field in schema.fields().iter() {
+                let column = format!("  `{}` {},\n", field.name(), field.data_type());
+                table_info.push_str(column.as_str());
+            }
+            let table_engine = format!(") ENGINE={}", engine);
+            table_info.push_str(table_engine.as_str());
+            if let Some(expr) = partition_expression(table.get_table_info())? {
+                let partition_by = format!(" PARTITION BY {}", expr.column_name());
+                table_info.push_str(partition_by.as_str());
+            }
+            if let Some(expr) = ttl_expression(table.get_table_info())? {
+                let ttl = format!(" TTL {}", expr.column_name());
+                table_info.push_str(ttl.as_str());
+            }
+            table_info.push_str(
+                table
+                    .options()
+                    .iter()
+                    .filter(|(k, _)| {
+                        k.as_str() != TBL_OPT_KEY_PARTITION_BY && k.as_str() != TBL_OPT_KEY_TTL
+                    })
+                    .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                    .collect::<Vec<_>>()
+                    .join("")
+                    .as_str(),
+            );
+            table_info
+        };
 
         let show_fields = vec![
             DataField::new("Table", DataType::String, false),
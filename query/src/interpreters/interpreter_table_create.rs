@@ -12,17 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CreateTableReq;
+use common_meta_types::DropTableReq;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReq;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
 use common_planners::CreateTablePlan;
+use common_planners::PlanNode;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+use uuid::Uuid;
 
 use crate::catalogs::Catalog;
+use crate::catalogs::TEMP_TBL_ID_BEGIN;
+use crate::interpreters::interpreter_insert_with_plan::InsertWithPlan;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::memory::MemoryTable;
+use crate::storages::view::MaterializedViewTable;
+use crate::storages::view::MATERIALIZED_VIEW_ENGINE;
+use crate::storages::Table;
+
+static NEXT_TEMP_TBL_ID: AtomicU64 = AtomicU64::new(TEMP_TBL_ID_BEGIN);
 
 pub struct CreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -30,6 +51,32 @@ pub struct CreateTableInterpreter {
 }
 
 impl CreateTableInterpreter {
+    /// The inserts into the source table find the view through the index kept on the table,
+    /// the view is dropped if it can't be indexed, otherwise it would never be pushed to.
+    async fn attach_materialized_view(&self) -> Result<()> {
+        let catalog = self.ctx.get_catalog();
+        let plan = &self.plan;
+
+        let table = catalog.get_table(&plan.db, &plan.table).await?;
+        let view = match table.as_any().downcast_ref::<MaterializedViewTable>() {
+            Some(view) => view,
+            None => return Ok(()),
+        };
+
+        let res = view.attach_to_source(&self.ctx).await;
+        if res.is_err() {
+            let req = DropTableReq {
+                if_exists: true,
+                db: plan.db.clone(),
+                table: plan.table.clone(),
+            };
+            if let Err(cause) = catalog.drop_table(req).await {
+                tracing::warn!("drop materialized view {} failure: {}", plan.table, cause);
+            }
+        }
+        res
+    }
+
     pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTablePlan) -> Result<InterpreterPtr> {
         Ok(Arc::new(CreateTableInterpreter { ctx, plan }))
     }
@@ -45,8 +92,22 @@ impl Interpreter for CreateTableInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let catalog = self.ctx.get_catalog();
-        catalog.create_table(self.plan.clone().into()).await?;
+        match &self.plan.as_select {
+            _ if self.plan.temporary => self.create_temporary_table().await?,
+            Some(select_plan) => self.create_table_as_select(select_plan).await?,
+            None => {
+                let catalog = self.ctx.get_catalog();
+                catalog.create_table(self.plan.clone().into()).await?;
+                if self
+                    .plan
+                    .table_meta
+                    .engine
+                    .eq_ignore_ascii_case(MATERIALIZED_VIEW_ENGINE)
+                {
+                    self.attach_materialized_view().await?;
+                }
+            }
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -55,3 +116,148 @@ impl Interpreter for CreateTableInterpreter {
         )))
     }
 }
+
+impl CreateTableInterpreter {
+    /// A temporary table is a memory table kept by the session, the meta service never sees it.
+    async fn create_temporary_table(&self) -> Result<()> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog();
+        if !catalog.exists_database(&plan.db).await? {
+            return Err(ErrorCode::UnknownDatabase(format!(
+                "Unknown database {}",
+                plan.db
+            )));
+        }
+
+        let session = self.ctx.get_session();
+        if session.get_temporary_table(&plan.db, &plan.table).is_some() {
+            return match plan.if_not_exists {
+                true => Ok(()),
+                false => Err(ErrorCode::TableAlreadyExists(format!(
+                    "temporary table exists: {}",
+                    plan.table
+                ))),
+            };
+        }
+
+        let table_id = NEXT_TEMP_TBL_ID.fetch_add(1, Ordering::Relaxed);
+        let table_info = TableInfo::new(
+            &plan.db,
+            &plan.table,
+            TableIdent::new(table_id, 0),
+            plan.table_meta.clone(),
+        );
+        let table: Arc<dyn Table> = Arc::new(MemoryTable::create_with_blocks(table_info, vec![]));
+
+        if let Some(select_plan) = &plan.as_select {
+            let schema = table.schema();
+            let with_plan = InsertWithPlan::new(&self.ctx, &schema, select_plan);
+            // Other nodes know nothing about the table, so the query is not sunk into it.
+            let stream = with_plan.execute_to_stream(table.as_ref()).await?;
+            let append_logs = table.append_data(self.ctx.clone(), stream).await?;
+            table
+                .commit(self.ctx.clone(), append_logs.try_collect().await?, false)
+                .await?;
+        }
+
+        // The table is only published once it is loaded, same as `CREATE TABLE ... AS SELECT`.
+        if !session.add_temporary_table(&plan.db, table) && !plan.if_not_exists {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "temporary table exists: {}",
+                plan.table
+            )));
+        }
+        Ok(())
+    }
+
+    /// The table is created and loaded under a staging name first, then renamed to the
+    /// requested name once the data is committed. Thus the table becomes visible with all
+    /// of its data, or not at all.
+    async fn create_table_as_select(&self, select_plan: &PlanNode) -> Result<()> {
+        let catalog = self.ctx.get_catalog();
+        let plan = &self.plan;
+
+        if catalog.exists_table(&plan.db, &plan.table).await? {
+            return match plan.if_not_exists {
+                true => Ok(()),
+                false => Err(ErrorCode::TableAlreadyExists(format!(
+                    "table exists: {}",
+                    plan.table
+                ))),
+            };
+        }
+
+        let staging_name = format!("__ctas_{}_{}", plan.table, Uuid::new_v4().to_simple());
+        let mut req: CreateTableReq = plan.clone().into();
+        req.if_not_exists = false;
+        req.table = staging_name.clone();
+        catalog.create_table(req).await?;
+
+        let table = catalog.get_table(&plan.db, &staging_name).await?;
+        let res = self.load_and_publish(table.clone(), select_plan).await;
+        if res.is_err() {
+            if let Err(cause) = self.discard_staging_table(table.as_ref()).await {
+                tracing::warn!("discard staging table {} failure: {}", staging_name, cause);
+            }
+        }
+        res
+    }
+
+    async fn load_and_publish(&self, table: Arc<dyn Table>, select_plan: &PlanNode) -> Result<()> {
+        let plan = &self.plan;
+        let schema = table.schema();
+
+        let with_plan = InsertWithPlan::new(&self.ctx, &schema, select_plan);
+        let append_logs = with_plan.execute(table.as_ref()).await?;
+        table
+            .commit(self.ctx.clone(), append_logs.try_collect().await?, false)
+            .await?;
+
+        let catalog = self.ctx.get_catalog();
+        catalog
+            .rename_table(RenameTableReq {
+                if_exists: false,
+                db: plan.db.clone(),
+                table: table.name().to_string(),
+                new_db: plan.db.clone(),
+                new_table: plan.table.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Drop the staging table and purge whatever has been written to it.
+    async fn discard_staging_table(&self, table: &dyn Table) -> Result<()> {
+        let catalog = self.ctx.get_catalog();
+        let db = self.plan.db.clone();
+        let table_id = table.get_id();
+
+        catalog
+            .drop_table(DropTableReq {
+                if_exists: true,
+                db: db.clone(),
+                table: table.name().to_string(),
+            })
+            .await?;
+
+        // The staging table may have been committed, reload it to see its latest data.
+        for dropped in catalog.list_dropped_tables(&db).await? {
+            let table_info = &dropped.table_info;
+            if table_info.ident.table_id != table_id {
+                continue;
+            }
+
+            let dropped_table = catalog.get_table_by_info(table_info)?;
+            dropped_table.purge(self.ctx.clone()).await?;
+            catalog
+                .purge_dropped_table(PurgeDroppedTableReq {
+                    db_id: dropped.db_id,
+                    table: table_info.name.clone(),
+                    table_id,
+                })
+                .await?;
+            break;
+        }
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::DropTablePlan;
 use common_streams::DataBlockStream;
@@ -23,6 +24,7 @@ use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::view::MaterializedViewTable;
 
 pub struct DropTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -45,8 +47,18 @@ impl Interpreter for DropTableInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let catalog = self.ctx.get_catalog();
-        catalog.drop_table(self.plan.clone().into()).await?;
+        let plan = &self.plan;
+        let session = self.ctx.get_session();
+
+        // The temporary table shadows the one in the catalog, so it is dropped first.
+        if session
+            .drop_temporary_table(&plan.db, &plan.table)
+            .is_none()
+        {
+            let catalog = self.ctx.get_catalog();
+            self.detach_materialized_view().await?;
+            catalog.drop_table(plan.clone().into()).await?;
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -55,3 +67,21 @@ impl Interpreter for DropTableInterpreter {
         )))
     }
 }
+
+impl DropTableInterpreter {
+    /// Stop pushing the inserts of the source table through the view before it is dropped.
+    async fn detach_materialized_view(&self) -> Result<()> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog();
+        let table = match catalog.get_table(&plan.db, &plan.table).await {
+            Ok(table) => table,
+            Err(e) if e.code() == ErrorCode::UnknownTableCode() => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        match table.as_any().downcast_ref::<MaterializedViewTable>() {
+            Some(view) => view.detach_from_source(&self.ctx).await,
+            None => Ok(()),
+        }
+    }
+}
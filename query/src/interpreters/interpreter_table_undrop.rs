@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::UndropTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::view::MaterializedViewTable;
+
+pub struct UndropTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: UndropTablePlan,
+}
+
+impl UndropTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: UndropTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(UndropTableInterpreter { ctx, plan }))
+    }
+}
+
+impl UndropTableInterpreter {
+    /// The data of the table whose retention period has expired may be being purged by the GC,
+    /// see `DroppedTableGC`, so it can't be restored any more.
+    async fn check_not_expired(&self) -> Result<()> {
+        let conf = self.ctx.get_config();
+        if conf.query.dropped_table_gc_interval_secs == 0 {
+            return Ok(());
+        }
+
+        let dropped = self
+            .ctx
+            .get_catalog()
+            .list_dropped_tables(&self.plan.db)
+            .await?;
+        let drop_on = dropped
+            .iter()
+            .filter(|dropped| dropped.table_info.name == self.plan.table)
+            .map(|dropped| dropped.drop_on)
+            .max();
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| ErrorCode::LogicalError(e.to_string()))?
+            .as_secs();
+        match drop_on {
+            Some(drop_on) if drop_on + conf.query.dropped_table_retention_secs <= now => {
+                Err(ErrorCode::UnknownTable(format!(
+                    "Dropped table {}.{} is expired, it can not be restored",
+                    self.plan.db, self.plan.table
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for UndropTableInterpreter {
+    fn name(&self) -> &str {
+        "UndropTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let catalog = self.ctx.get_catalog();
+        self.check_not_expired().await?;
+        catalog.undrop_table(self.plan.clone().into()).await?;
+
+        // The inserts of the source table are pushed through the view again.
+        let table = catalog.get_table(&self.plan.db, &self.plan.table).await?;
+        if let Some(view) = table.as_any().downcast_ref::<MaterializedViewTable>() {
+            view.attach_to_source(&self.ctx).await?;
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -17,11 +17,13 @@ mod interpreter_common;
 mod interpreter_copy;
 mod interpreter_database_create;
 mod interpreter_database_drop;
+mod interpreter_database_rename;
 mod interpreter_describe_table;
 mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_grant_privilege;
 mod interpreter_insert;
+mod interpreter_insert_into_views;
 mod interpreter_insert_with_plan;
 mod interpreter_insert_with_stream;
 mod interpreter_interceptor;
@@ -32,8 +34,11 @@ mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_create_table;
 mod interpreter_stage_create;
+mod interpreter_table_alter;
 mod interpreter_table_create;
 mod interpreter_table_drop;
+mod interpreter_table_rename;
+mod interpreter_table_undrop;
 mod interpreter_truncate_table;
 mod interpreter_use_database;
 mod interpreter_user_alter;
@@ -41,12 +46,15 @@ mod interpreter_user_create;
 mod interpreter_user_drop;
 mod plan_schedulers;
 mod stream_addon;
+mod stream_insert;
+mod stream_not_null;
 
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_copy::CopyInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
+pub use interpreter_database_rename::RenameDatabaseInterpreter;
 pub use interpreter_describe_table::DescribeTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
@@ -62,12 +70,19 @@ pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_create_table::ShowCreateTableInterpreter;
 pub use interpreter_stage_create::CreatStageInterpreter;
+pub use interpreter_table_alter::AlterTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_table_rename::RenameTableInterpreter;
+pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_truncate_table::TruncateTableInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreatUserInterpreter;
 pub use interpreter_user_drop::DropUserInterpreter;
 pub use plan_schedulers::PlanScheduler;
+pub use plan_schedulers::Scheduled;
+pub use plan_schedulers::ScheduledStream;
 pub use stream_addon::AddOnStream;
+pub use stream_insert::insert_stream;
+pub use stream_not_null::NotNullStream;
@@ -21,5 +21,6 @@ mod plan_scheduler_stream;
 pub use plan_scheduler::PlanScheduler;
 pub use plan_scheduler_error::handle_error;
 pub use plan_scheduler_query::schedule_query;
+pub use plan_scheduler_query::schedule_query_with_pipeline;
 pub use plan_scheduler_stream::Scheduled;
 pub use plan_scheduler_stream::ScheduledStream;
@@ -171,6 +171,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
             scatters_expression: stage.scatters_expr.clone(),
+            profile: self.query_context.is_pipeline_profiling(),
         }
     }
 
@@ -211,6 +212,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
             scatters_expression: stage.scatters_expr.clone(),
+            profile: self.query_context.is_pipeline_profiling(),
         }
     }
 
@@ -254,6 +256,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: vec![self.cluster_nodes[self.local_pos].clone()],
             scatters_expression: stage.scatters_expr.clone(),
+            profile: self.query_context.is_pipeline_profiling(),
         }
     }
 
@@ -435,6 +438,7 @@ impl PlanScheduler {
             query_id: self.query_context.get_id(),
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
+            profile: self.query_context.is_pipeline_profiling(),
         }
     }
 
@@ -823,6 +827,7 @@ impl PlanScheduler {
             table_info: plan.table_info.clone(),
             input: Arc::new(self.nodes_plan[self.local_pos].clone()),
             cast_needed: plan.cast_needed,
+            cast_schema: plan.cast_schema.clone(),
         })
     }
 
@@ -832,6 +837,7 @@ impl PlanScheduler {
                 table_info: plan.table_info.clone(),
                 input: Arc::new(self.nodes_plan[index].clone()),
                 cast_needed: plan.cast_needed,
+                cast_schema: plan.cast_schema.clone(),
             })
         }
     }
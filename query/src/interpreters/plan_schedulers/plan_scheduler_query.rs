@@ -23,6 +23,7 @@ use crate::interpreters::plan_schedulers;
 use crate::interpreters::plan_schedulers::Scheduled;
 use crate::interpreters::plan_schedulers::ScheduledStream;
 use crate::interpreters::PlanScheduler;
+use crate::pipelines::processors::Pipeline;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::QueryContext;
 
@@ -30,6 +31,16 @@ pub async fn schedule_query(
     ctx: &Arc<QueryContext>,
     plan: &PlanNode,
 ) -> Result<SendableDataBlockStream> {
+    let (_, stream) = schedule_query_with_pipeline(ctx, plan).await?;
+    Ok(stream)
+}
+
+/// Schedule the query and also return the local pipeline, used by EXPLAIN ANALYZE to display
+/// the pipeline once the stream is consumed.
+pub async fn schedule_query_with_pipeline(
+    ctx: &Arc<QueryContext>,
+    plan: &PlanNode,
+) -> Result<(Pipeline, SendableDataBlockStream)> {
     let scheduler = PlanScheduler::try_create(ctx.clone())?;
     let scheduled_tasks = scheduler.reschedule(plan)?;
     let remote_stage_actions = scheduled_tasks.get_tasks()?;
@@ -50,7 +61,10 @@ pub async fn schedule_query(
     let mut in_local_pipeline = pipeline_builder.build(&scheduled_tasks.get_local_task())?;
 
     match in_local_pipeline.execute().await {
-        Ok(stream) => Ok(ScheduledStream::create(ctx.clone(), scheduled, stream)),
+        Ok(stream) => Ok((
+            in_local_pipeline,
+            ScheduledStream::create(ctx.clone(), scheduled, stream),
+        )),
         Err(error) => {
             plan_schedulers::handle_error(ctx, scheduled, timeout).await;
             Err(error)
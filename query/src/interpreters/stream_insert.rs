@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_functions::scalars::CastFunction;
+use common_streams::CastStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::AddOnStream;
+use crate::interpreters::NotNullStream;
+
+/// Shape the blocks of an insert into the blocks of the table: they are casted to `cast_schema`
+/// if needed, the columns of the table missing from `cast_schema` are filled with their
+/// defaults, and the NULLs of the NOT NULL columns are rejected.
+///
+/// It is shared by the sinks and the inserts appending on their own.
+pub fn insert_stream(
+    input: SendableDataBlockStream,
+    cast_needed: bool,
+    cast_schema: DataSchemaRef,
+    table_schema: DataSchemaRef,
+) -> Result<SendableDataBlockStream> {
+    let mut stream = input;
+    if cast_needed {
+        let mut functions = Vec::with_capacity(cast_schema.fields().len());
+        for field in cast_schema.fields() {
+            let cast_function =
+                CastFunction::create("cast".to_string(), field.data_type().clone())?;
+            functions.push(cast_function);
+        }
+        stream = Box::pin(CastStream::try_create(
+            stream,
+            cast_schema.clone(),
+            functions,
+        )?);
+    }
+
+    if cast_schema != table_schema {
+        stream = Box::pin(AddOnStream::try_create(
+            stream,
+            cast_schema,
+            table_schema.clone(),
+        )?);
+    }
+
+    Ok(Box::pin(NotNullStream::try_create(stream, table_schema)?))
+}
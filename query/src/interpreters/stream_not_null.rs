@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::DataColumn;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+/// Reject the blocks with NULLs in the columns which are not nullable
+pub struct NotNullStream {
+    input: SendableDataBlockStream,
+    not_null_columns: Vec<String>,
+}
+
+impl NotNullStream {
+    pub fn try_create(input: SendableDataBlockStream, schema: DataSchemaRef) -> Result<Self> {
+        let not_null_columns = schema
+            .fields()
+            .iter()
+            .filter(|f| !f.is_nullable())
+            .map(|f| f.name().clone())
+            .collect();
+
+        Ok(NotNullStream {
+            input,
+            not_null_columns,
+        })
+    }
+
+    #[inline]
+    fn check_not_null(&self, block: DataBlock) -> Result<DataBlock> {
+        for name in &self.not_null_columns {
+            let has_null = match block.try_column_by_name(name)? {
+                DataColumn::Array(series) => series.null_count() > 0,
+                DataColumn::Constant(value, size) => *size > 0 && value.is_null(),
+            };
+
+            if has_null {
+                return Err(ErrorCode::NullValueInNotNullColumn(format!(
+                    "Cannot insert NULL into column `{}` which is NOT NULL",
+                    name
+                )));
+            }
+        }
+        Ok(block)
+    }
+}
+
+impl Stream for NotNullStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.input.poll_next_unpin(ctx).map(|x| match x {
+            Some(Ok(v)) => Some(self.check_not_null(v)),
+            other => other,
+        })
+    }
+}
@@ -97,6 +97,29 @@ impl PlanRewriter for TopNPushDownImpl {
         plan_node
     }
 
+    fn rewrite_projection(&mut self, plan: &ProjectionPlan) -> Result<PlanNode> {
+        // The arrayJoin changes the number of rows, the top n can't be pushed through it.
+        if !find_array_join_exprs(&plan.expr).is_empty() {
+            self.limit = None;
+        }
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_exprs = self.rewrite_exprs(&new_input.schema(), &plan.expr)?;
+        PlanBuilder::from(&new_input).project(&new_exprs)?.build()
+    }
+
+    fn rewrite_expression(&mut self, plan: &ExpressionPlan) -> Result<PlanNode> {
+        if !find_array_join_exprs(&plan.exprs).is_empty() {
+            self.limit = None;
+        }
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_exprs = self.rewrite_exprs(&new_input.schema(), &plan.exprs)?;
+        PlanBuilder::from(&new_input)
+            .expression(&new_exprs, &plan.desc)?
+            .build()
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         // push the limit and order_by down to read_source_plan
         if let Some(n) = self.limit {
@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use common_infallible::Mutex;
+
+/// What a pipeline worker has done during the query.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkerProfile {
+    /// Partitions read from the local queue or the injector.
+    pub morsels: usize,
+    /// Partitions stolen from the local queue of another worker.
+    pub stolen_morsels: usize,
+    pub output_blocks: usize,
+    pub output_rows: usize,
+    /// Wall time spent pulling the blocks of the worker.
+    pub elapsed: Duration,
+}
+
+/// Executor-level profile of a query, shown by EXPLAIN ANALYZE.
+///
+/// The tasks which run outside of any pipeline worker are accounted to the worker `None`.
+#[derive(Default)]
+pub struct ExecutorProfile {
+    workers: Mutex<BTreeMap<Option<usize>, WorkerProfile>>,
+}
+
+impl ExecutorProfile {
+    pub fn create() -> ExecutorProfile {
+        ExecutorProfile::default()
+    }
+
+    pub fn record_morsel(&self, worker: Option<usize>, stolen: bool) {
+        let mut workers = self.workers.lock();
+        let profile = workers.entry(worker).or_default();
+        match stolen {
+            true => profile.stolen_morsels += 1,
+            false => profile.morsels += 1,
+        }
+    }
+
+    pub fn record_output(&self, worker: Option<usize>, rows: usize, elapsed: Duration) {
+        let mut workers = self.workers.lock();
+        let profile = workers.entry(worker).or_default();
+        profile.output_blocks += 1;
+        profile.output_rows += rows;
+        profile.elapsed += elapsed;
+    }
+
+    pub fn get_workers(&self) -> Vec<(Option<usize>, WorkerProfile)> {
+        let workers = self.workers.lock();
+        workers
+            .iter()
+            .map(|(worker, profile)| (*worker, profile.clone()))
+            .collect()
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod executor_profile;
+mod morsel_queue;
 mod pipe;
 mod pipeline;
 mod pipeline_builder;
@@ -21,7 +23,13 @@ mod processor;
 mod processor_empty;
 mod processor_merge;
 mod processor_mixed;
+mod processor_profiling;
 
+pub use executor_profile::ExecutorProfile;
+pub use executor_profile::WorkerProfile;
+pub use morsel_queue::current_worker;
+pub use morsel_queue::run_as_worker;
+pub use morsel_queue::MorselQueue;
 pub use pipe::Pipe;
 pub use pipeline::Pipeline;
 pub use pipeline_builder::PipelineBuilder;
@@ -30,3 +38,5 @@ pub use processor::Processor;
 pub use processor_empty::EmptyProcessor;
 pub use processor_merge::MergeProcessor;
 pub use processor_mixed::MixedProcessor;
+pub use processor_profiling::ProcessorProfile;
+pub use processor_profiling::ProfilingProcessor;
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::iter;
+
+use common_base::tokio;
+use common_infallible::Mutex;
+use common_planners::Part;
+use common_planners::Partitions;
+use crossbeam::deque::Injector;
+use crossbeam::deque::Steal;
+use crossbeam::deque::Stealer;
+use crossbeam::deque::Worker;
+
+use crate::pipelines::processors::ExecutorProfile;
+
+tokio::task_local! {
+    static PIPELINE_WORKER: usize;
+}
+
+/// Run the future as the pipeline worker `worker`.
+///
+/// The partitions popped inside the future are taken from the local queue of the worker first.
+pub async fn run_as_worker<F: Future>(worker: usize, future: F) -> F::Output {
+    PIPELINE_WORKER.scope(worker, future).await
+}
+
+/// The index of the pipeline worker running the current task, if any.
+pub fn current_worker() -> Option<usize> {
+    PIPELINE_WORKER.try_with(|worker| *worker).ok()
+}
+
+/// Work-stealing queue of the partitions (morsels) to be read by the sources of a query.
+///
+/// New partitions are pushed into a global injector, each worker refills its local queue with a
+/// batch from the injector, and steals from the other workers once the injector is drained, so a
+/// worker which has been given cheap partitions keeps busy until all the partitions are read.
+pub struct MorselQueue {
+    injector: Injector<Part>,
+    locals: Vec<Mutex<Worker<Part>>>,
+    stealers: Vec<Stealer<Part>>,
+}
+
+impl MorselQueue {
+    pub fn create(workers: usize) -> MorselQueue {
+        let locals = (0..workers.max(1))
+            .map(|_| Worker::new_fifo())
+            .collect::<Vec<_>>();
+        let stealers = locals.iter().map(|local| local.stealer()).collect();
+
+        MorselQueue {
+            injector: Injector::new(),
+            locals: locals.into_iter().map(Mutex::new).collect(),
+            stealers,
+        }
+    }
+
+    pub fn push_partitions(&self, partitions: Partitions) {
+        for part in partitions {
+            self.injector.push(part);
+        }
+    }
+
+    /// Pop at most `num` partitions for the current worker, stealing from the others if needed.
+    pub fn pop_partitions(&self, num: usize, profile: &ExecutorProfile) -> Partitions {
+        let worker = current_worker().map(|worker| worker % self.locals.len());
+
+        let mut partitions = Vec::with_capacity(num);
+        while partitions.len() < num {
+            match self.pop(worker) {
+                None => break,
+                Some((part, stolen)) => {
+                    profile.record_morsel(worker, stolen);
+                    partitions.push(part);
+                }
+            }
+        }
+        partitions
+    }
+
+    fn pop(&self, worker: Option<usize>) -> Option<(Part, bool)> {
+        match worker {
+            None => self.steal(None).map(|part| (part, false)),
+            Some(worker) => {
+                let local = self.locals[worker].lock();
+                if let Some(part) = local.pop() {
+                    return Some((part, false));
+                }
+
+                // Refill the local queue from the injector before stealing from the others.
+                let injected = iter::repeat_with(|| self.injector.steal_batch_and_pop(&local))
+                    .find(|steal| !steal.is_retry())
+                    .and_then(|steal| steal.success());
+
+                match injected {
+                    Some(part) => Some((part, false)),
+                    None => self.steal(Some(worker)).map(|part| (part, true)),
+                }
+            }
+        }
+    }
+
+    fn steal(&self, worker: Option<usize>) -> Option<Part> {
+        iter::repeat_with(|| {
+            self.injector.steal().or_else(|| {
+                self.stealers
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| Some(*index) != worker)
+                    .map(|(_, stealer)| stealer.steal())
+                    .collect::<Steal<Part>>()
+            })
+        })
+        .find(|steal| !steal.is_retry())
+        .and_then(|steal| steal.success())
+    }
+}
@@ -22,6 +22,7 @@ use super::MixedProcessor;
 use crate::pipelines::processors::MergeProcessor;
 use crate::pipelines::processors::Pipe;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::processors::ProfilingProcessor;
 use crate::sessions::QueryContext;
 
 pub struct Pipeline {
@@ -60,6 +61,7 @@ impl Pipeline {
     }
 
     pub fn add_source(&mut self, source: Arc<dyn Processor>) -> Result<()> {
+        let source = self.profiled(source);
         if self.pipes.first().is_none() {
             let mut first = Pipe::create();
             first.add(source);
@@ -87,7 +89,7 @@ impl Pipeline {
         for x in last_pipe.processors() {
             let mut p = f()?;
             p.connect_to(x.clone())?;
-            new_pipe.add(Arc::from(p));
+            new_pipe.add(self.profiled(Arc::from(p)));
         }
         self.pipes.push(new_pipe);
         Ok(())
@@ -109,7 +111,7 @@ impl Pipeline {
                 merge.connect_to(x.clone())?;
             }
             let mut new_pipe = Pipe::create();
-            new_pipe.add(Arc::from(merge));
+            new_pipe.add(self.profiled(Arc::from(merge)));
             self.pipes.push(new_pipe);
         }
         Ok(())
@@ -142,14 +144,22 @@ impl Pipeline {
         let mut new_pipe = Pipe::create();
         for _i in 0..n - 1 {
             let processor = processor.share()?;
-            new_pipe.add(Arc::from(processor));
+            new_pipe.add(self.profiled(Arc::from(processor)));
         }
-        new_pipe.add(Arc::from(processor));
+        new_pipe.add(self.profiled(Arc::from(processor)));
         self.pipes.push(new_pipe);
 
         Ok(())
     }
 
+    // Wrap the processor to collect its runtime statistics if the query is profiled.
+    fn profiled(&self, processor: Arc<dyn Processor>) -> Arc<dyn Processor> {
+        match self.ctx.is_pipeline_profiling() {
+            true => Arc::new(ProfilingProcessor::create(processor)),
+            false => processor,
+        }
+    }
+
     pub async fn execute(&mut self) -> Result<SendableDataBlockStream> {
         if self.last_pipe()?.nums() > 1 {
             self.merge_processor()?;
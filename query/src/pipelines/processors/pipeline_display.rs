@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::fmt;
 use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Duration;
 
+use crate::pipelines::processors::Pipe;
 use crate::pipelines::processors::Pipeline;
 
 impl Pipeline {
@@ -44,6 +48,16 @@ impl Pipeline {
 
                     match processor.name() {
                         "EmptyProcessor" => write!(f, "")?,
+                        // The merge added by the execution of the pipeline outputs the result.
+                        "MergeProcessor" if index == 0 => {
+                            let post_pipe = self.0.pipe_by_index(self.0.pipes().len() - 2);
+                            write!(
+                                f,
+                                "Merge ({} × {} processors) to (Result × 1)",
+                                post_pipe.name(),
+                                post_pipe.nums(),
+                            )?;
+                        }
                         "MergeProcessor" => {
                             let mut pipes = self.0.pipes();
                             pipes.reverse();
@@ -122,6 +136,8 @@ impl Pipeline {
                         }
                     }
 
+                    write_profile(f, pipe)?;
+
                     index += 1;
                     Result::<bool, fmt::Error>::Ok(true)
                 })?;
@@ -150,6 +166,49 @@ impl Pipeline {
     }
 }
 
+// Append the runtime statistics of the pipe, if the pipeline was profiled.
+fn write_profile(f: &mut fmt::Formatter, pipe: &Pipe) -> fmt::Result {
+    let profiles = pipe
+        .processors()
+        .iter()
+        .filter_map(|processor| processor.profile())
+        .collect::<Vec<_>>();
+
+    if profiles.is_empty() {
+        return Ok(());
+    }
+
+    let rows_out: usize = profiles.iter().map(|p| p.output_rows()).sum();
+    let bytes_out: usize = profiles.iter().map(|p| p.output_bytes()).sum();
+    let wall: Duration = profiles.iter().map(|p| p.wall_time()).sum();
+    let cpu: Duration = profiles.iter().map(|p| p.cpu_time()).sum();
+
+    // The processors of a mixed pipe share their inputs, count each input once.
+    let mut visited = HashSet::new();
+    let mut rows_in = None;
+    for processor in pipe.processors() {
+        for input in processor.inputs() {
+            if !visited.insert(Arc::as_ptr(&input) as *const () as usize) {
+                continue;
+            }
+            if let Some(profile) = input.profile() {
+                *rows_in.get_or_insert(0) += profile.output_rows();
+            }
+        }
+    }
+
+    write!(
+        f,
+        " (rows in: {}, rows out: {}, bytes out: {}, wall: {:?}, cpu: {:?})",
+        // The sources have no profiled input, they read what they output.
+        rows_in.unwrap_or(rows_out),
+        rows_out,
+        bytes_out,
+        wall,
+        cpu
+    )
+}
+
 impl fmt::Debug for Pipeline {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.display_indent().fmt(f)
@@ -18,6 +18,8 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_streams::SendableDataBlockStream;
 
+use crate::pipelines::processors::ProcessorProfile;
+
 /// Formatter settings for PlanStep debug.
 pub struct FormatterSettings {
     pub ways: usize,
@@ -42,6 +44,11 @@ pub trait Processor: Sync + Send {
     /// Reference used for downcast.
     fn as_any(&self) -> &dyn Any;
 
+    /// Runtime statistics, only collected when the pipeline is profiled.
+    fn profile(&self) -> Option<Arc<ProcessorProfile>> {
+        None
+    }
+
     /// Execute the processor.
     async fn execute(&self) -> Result<SendableDataBlockStream>;
 }
@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_base::tokio::sync::mpsc;
 use common_base::TrySpawn;
@@ -25,6 +26,7 @@ use log::error;
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_stream::StreamExt;
 
+use crate::pipelines::processors::run_as_worker;
 use crate::pipelines::processors::Processor;
 use crate::sessions::QueryContext;
 
@@ -49,11 +51,14 @@ impl MergeProcessor {
             ));
         }
 
+        // Each input is pulled by its own pipeline worker task, the tasks are scheduled by the
+        // work-stealing runtime of the query and steal the partitions of each other.
         let (sender, receiver) = mpsc::channel::<Result<DataBlock>>(len);
         for i in 0..len {
             let processor = self.inputs[i].clone();
             let sender = sender.clone();
-            self.ctx.try_spawn(async move {
+            let profile = self.ctx.get_executor_profile();
+            self.ctx.try_spawn(run_as_worker(i, async move {
                 let mut stream = match processor.execute().await {
                     Err(e) => {
                         if let Err(error) = sender.send(Result::Err(e)).await {
@@ -64,9 +69,16 @@ impl MergeProcessor {
                     Ok(stream) => stream,
                 };
 
-                while let Some(item) = stream.next().await {
+                loop {
+                    let instant = Instant::now();
+                    let item = match stream.next().await {
+                        None => break,
+                        Some(item) => item,
+                    };
+
                     match item {
                         Ok(item) => {
+                            profile.record_output(Some(i), item.num_rows(), instant.elapsed());
                             if let Err(error) = sender.send(Ok(item)).await {
                                 // Stop pulling data
                                 error!("Merge processor cannot push data: {}", error);
@@ -82,7 +94,7 @@ impl MergeProcessor {
                         }
                     }
                 }
-            })?;
+            }))?;
         }
         Ok(Box::pin(ReceiverStream::new(receiver)))
     }
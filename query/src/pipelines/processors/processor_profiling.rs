@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cell::Cell;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use cpu_time::ThreadTime;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::pipelines::processors::Processor;
+
+thread_local! {
+    // Wall and cpu nanoseconds spent by the nested polls of the current poll.
+    static CHILDREN_TIME: Cell<(u64, u64)> = Cell::new((0, 0));
+}
+
+/// Runtime statistics of one processor, collected for EXPLAIN ANALYZE.
+#[derive(Default)]
+pub struct ProcessorProfile {
+    output_rows: AtomicUsize,
+    output_bytes: AtomicUsize,
+    output_blocks: AtomicUsize,
+    wall_ns: AtomicU64,
+    cpu_ns: AtomicU64,
+}
+
+impl ProcessorProfile {
+    pub fn output_rows(&self) -> usize {
+        self.output_rows.load(Ordering::Relaxed)
+    }
+
+    pub fn output_bytes(&self) -> usize {
+        self.output_bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn output_blocks(&self) -> usize {
+        self.output_blocks.load(Ordering::Relaxed)
+    }
+
+    /// Wall time spent in the processor itself, without the time of its inputs.
+    pub fn wall_time(&self) -> Duration {
+        Duration::from_nanos(self.wall_ns.load(Ordering::Relaxed))
+    }
+
+    /// CPU time spent in the processor itself, without the time of its inputs.
+    pub fn cpu_time(&self) -> Duration {
+        Duration::from_nanos(self.cpu_ns.load(Ordering::Relaxed))
+    }
+}
+
+/// Wraps a processor and records what its output stream produces and how long it takes.
+pub struct ProfilingProcessor {
+    inner: Arc<dyn Processor>,
+    profile: Arc<ProcessorProfile>,
+}
+
+impl ProfilingProcessor {
+    pub fn create(inner: Arc<dyn Processor>) -> Self {
+        ProfilingProcessor {
+            inner,
+            profile: Arc::new(ProcessorProfile::default()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ProfilingProcessor {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        Err(ErrorCode::LogicalError(
+            "Cannot call ProfilingProcessor connect_to",
+        ))
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        self.inner.inputs()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.inner.as_any()
+    }
+
+    fn profile(&self) -> Option<Arc<ProcessorProfile>> {
+        Some(self.profile.clone())
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let input = self.inner.execute().await?;
+        Ok(Box::pin(ProfilingStream {
+            input,
+            profile: self.profile.clone(),
+        }))
+    }
+}
+
+struct ProfilingStream {
+    input: SendableDataBlockStream,
+    profile: Arc<ProcessorProfile>,
+}
+
+impl Stream for ProfilingStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let parent_children_time = CHILDREN_TIME.with(|t| t.replace((0, 0)));
+        let start_wall = Instant::now();
+        let start_cpu = ThreadTime::now();
+
+        let res = self.input.poll_next_unpin(ctx);
+
+        let wall = start_wall.elapsed().as_nanos() as u64;
+        let cpu = start_cpu.elapsed().as_nanos() as u64;
+        let (children_wall, children_cpu) = CHILDREN_TIME
+            .with(|t| t.replace((parent_children_time.0 + wall, parent_children_time.1 + cpu)));
+
+        let profile = &self.profile;
+        profile
+            .wall_ns
+            .fetch_add(wall.saturating_sub(children_wall), Ordering::Relaxed);
+        profile
+            .cpu_ns
+            .fetch_add(cpu.saturating_sub(children_cpu), Ordering::Relaxed);
+
+        if let Poll::Ready(Some(Ok(block))) = &res {
+            profile
+                .output_rows
+                .fetch_add(block.num_rows(), Ordering::Relaxed);
+            profile
+                .output_bytes
+                .fetch_add(block.memory_size(), Ordering::Relaxed);
+            profile.output_blocks.fetch_add(1, Ordering::Relaxed);
+        }
+
+        res
+    }
+}
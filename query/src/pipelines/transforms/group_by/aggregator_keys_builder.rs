@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use common_datablocks::GlobalDictionary;
 use common_datablocks::HashMethod;
 use common_datablocks::HashMethodFixedKeys;
 use common_datavalues::arrays::ArrayBuilder;
@@ -19,6 +22,7 @@ use common_datavalues::arrays::PrimitiveArrayBuilder;
 use common_datavalues::arrays::StringArrayBuilder;
 use common_datavalues::prelude::*;
 use common_datavalues::DFPrimitiveType;
+use common_infallible::RwLock;
 
 use crate::pipelines::transforms::group_by::keys_ref::KeysRef;
 
@@ -67,3 +71,19 @@ impl KeysArrayBuilder<KeysRef> for SerializedKeysArrayBuilder {
         }
     }
 }
+
+pub struct LowCardinalityKeysArrayBuilder {
+    pub keys: Vec<u32>,
+    pub dictionary: Arc<RwLock<GlobalDictionary>>,
+}
+
+impl KeysArrayBuilder<u32> for LowCardinalityKeysArrayBuilder {
+    fn finish(self) -> Series {
+        self.dictionary.read().to_array(&self.keys).into_series()
+    }
+
+    #[inline]
+    fn append_value(&mut self, v: &u32) {
+        self.keys.push(*v)
+    }
+}
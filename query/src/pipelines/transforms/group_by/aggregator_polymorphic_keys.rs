@@ -18,6 +18,7 @@ use common_datablocks::HashMethodKeysU16;
 use common_datablocks::HashMethodKeysU32;
 use common_datablocks::HashMethodKeysU64;
 use common_datablocks::HashMethodKeysU8;
+use common_datablocks::HashMethodLowCardinality;
 use common_datablocks::HashMethodSerializer;
 use common_datavalues::arrays::PrimitiveArrayBuilder;
 use common_datavalues::arrays::StringArrayBuilder;
@@ -25,6 +26,7 @@ use common_datavalues::arrays::StringArrayBuilder;
 use crate::common::HashTable;
 use crate::pipelines::transforms::group_by::aggregator_keys_builder::FixedKeysArrayBuilder;
 use crate::pipelines::transforms::group_by::aggregator_keys_builder::KeysArrayBuilder;
+use crate::pipelines::transforms::group_by::aggregator_keys_builder::LowCardinalityKeysArrayBuilder;
 use crate::pipelines::transforms::group_by::aggregator_keys_builder::SerializedKeysArrayBuilder;
 use crate::pipelines::transforms::group_by::aggregator_state::LongerFixedKeysAggregatorState;
 use crate::pipelines::transforms::group_by::aggregator_state::SerializedKeysAggregatorState;
@@ -154,3 +156,21 @@ impl PolymorphicKeysHelper<HashMethodSerializer> for HashMethodSerializer {
         }
     }
 }
+
+impl PolymorphicKeysHelper<HashMethodLowCardinality> for HashMethodLowCardinality {
+    type State = LongerFixedKeysAggregatorState<u32>;
+    fn aggregate_state(&self) -> Self::State {
+        LongerFixedKeysAggregatorState::<u32> {
+            area: Bump::new(),
+            data: HashTable::create(),
+        }
+    }
+
+    type ArrayBuilder = LowCardinalityKeysArrayBuilder;
+    fn state_array_builder(&self, capacity: usize) -> Self::ArrayBuilder {
+        LowCardinalityKeysArrayBuilder {
+            keys: Vec::with_capacity(capacity),
+            dictionary: self.dictionary.clone(),
+        }
+    }
+}
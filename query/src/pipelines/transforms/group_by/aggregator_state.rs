@@ -18,6 +18,7 @@ use std::intrinsics::likely;
 use bumpalo::Bump;
 use common_datablocks::HashMethod;
 use common_datablocks::HashMethodFixedKeys;
+use common_datablocks::HashMethodLowCardinality;
 use common_datablocks::HashMethodSerializer;
 use common_datavalues::DFPrimitiveType;
 use common_functions::aggregates::StateAddr;
@@ -210,6 +211,40 @@ where
     }
 }
 
+impl AggregatorState<HashMethodLowCardinality> for LongerFixedKeysAggregatorState<u32> {
+    type Key = u32;
+    type Entity = KeyValueEntity<u32, usize>;
+    type Iterator = HashMapIterator<u32, usize>;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline(always)]
+    fn iter(&self) -> Self::Iterator {
+        self.data.iter()
+    }
+
+    #[inline(always)]
+    fn alloc_layout(&self, params: &AggregatorParams) -> StateAddr {
+        let place: StateAddr = self.area.alloc_layout(params.layout).into();
+
+        for idx in 0..params.offsets_aggregate_states.len() {
+            let aggr_state = params.offsets_aggregate_states[idx];
+            let aggr_state_place = place.next(aggr_state);
+            params.aggregate_functions[idx].init_state(aggr_state_place);
+        }
+
+        place
+    }
+
+    #[inline(always)]
+    fn entity(&mut self, key: &u32, inserted: &mut bool) -> *mut Self::Entity {
+        self.data.insert_key(key, inserted)
+    }
+}
+
 pub struct SerializedKeysAggregatorState {
     pub keys_area: Bump,
     pub state_area: Bump,
@@ -17,11 +17,15 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::columns::DataColumn;
+use common_datavalues::prelude::DataColumnCommon;
 use common_datavalues::prelude::DataColumnWithField;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::ArrayJoinFunction;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
 use common_planners::ExpressionChain;
@@ -87,7 +91,7 @@ impl ExpressionExecutor {
             column_map.insert(f.name(), column);
         }
 
-        let rows = block.num_rows();
+        let mut rows = block.num_rows();
         for action in self.chain.actions.iter() {
             if let ExpressionAction::Alias(alias) = action {
                 if let Some(v) = alias_action_map.get_mut(alias.arg_name.as_str()) {
@@ -123,6 +127,24 @@ impl ExpressionExecutor {
                         arg_columns.push(column);
                     }
 
+                    // arrayJoin expands the rows, the columns evaluated before are replicated
+                    if ArrayJoinFunction::is_array_join(&f.func_name) {
+                        let (column, indices) =
+                            Self::expand_array(arg_columns[0].column(), &f.return_type)?;
+                        for column in column_map.values_mut() {
+                            let replicated = Self::replicate_column(column.column(), &indices)?;
+                            *column = DataColumnWithField::new(replicated, column.field().clone());
+                        }
+                        rows = indices.len();
+
+                        let column = DataColumnWithField::new(
+                            column,
+                            DataField::new(&f.name, f.return_type.clone(), f.is_nullable),
+                        );
+                        column_map.insert(f.name.as_str(), column);
+                        continue;
+                    }
+
                     let func = f.to_function()?;
                     let column = func.eval(&arg_columns, rows)?;
 
@@ -135,6 +157,14 @@ impl ExpressionExecutor {
                 }
                 ExpressionAction::Constant(constant) => {
                     let column = DataColumn::Constant(constant.value.clone(), rows);
+                    // DataValue can't keep LowCardinality, the folded constants are encoded
+                    let column = match &constant.data_type {
+                        DataType::LowCardinality(_) => column
+                            .to_array()?
+                            .cast_with_type(&constant.data_type)?
+                            .into(),
+                        _ => column,
+                    };
 
                     let column = DataColumnWithField::new(
                         column,
@@ -189,4 +219,38 @@ impl ExpressionExecutor {
             project_columns,
         ))
     }
+
+    /// Flattens the arrays into the elements, returns the elements and the row of each element.
+    fn expand_array(
+        column: &DataColumn,
+        element_type: &DataType,
+    ) -> Result<(DataColumn, Vec<usize>)> {
+        let array = column.to_array()?;
+        let mut elements = vec![];
+        let mut indices = vec![];
+        for (row, values) in array.list()?.into_iter().enumerate() {
+            if let Some(values) = values {
+                indices.extend(std::iter::repeat(row).take(values.len()));
+                elements.push(DataColumn::Array(values));
+            }
+        }
+
+        let elements = match elements.is_empty() {
+            true => DataValue::try_into_data_array(&[], element_type)?.into(),
+            false => DataColumnCommon::concat(&elements)?,
+        };
+        Ok((elements, indices))
+    }
+
+    fn replicate_column(column: &DataColumn, indices: &[usize]) -> Result<DataColumn> {
+        match column {
+            DataColumn::Array(array) => {
+                let array = array.take_iter(&mut indices.iter().copied())?;
+                Ok(DataColumn::Array(array))
+            }
+            DataColumn::Constant(value, _) => {
+                Ok(DataColumn::Constant(value.clone(), indices.len()))
+            }
+        }
+    }
 }
@@ -244,6 +244,9 @@ impl Processor for GroupByFinalTransform {
                     HashMethodKind::KeysU64(hash_method) => {
                         apply! { hash_method , &DFUInt64Array, u64, RwLock<HashMap<u64, usize, ahash::RandomState>> }
                     }
+                    HashMethodKind::LowCardinality(hash_method) => {
+                        apply! { hash_method , &DFDictionaryArray, dictionary, RwLock<HashMap<u32, usize, ahash::RandomState>> }
+                    }
                 }
             }};
         }
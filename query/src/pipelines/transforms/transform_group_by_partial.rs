@@ -137,6 +137,7 @@ impl Processor for GroupByPartialTransform {
             HashMethodKind::KeysU32(method) => self.aggregate(method, group_cols).await,
             HashMethodKind::KeysU64(method) => self.aggregate(method, group_cols).await,
             HashMethodKind::Serializer(method) => self.aggregate(method, group_cols).await,
+            HashMethodKind::LowCardinality(method) => self.aggregate(method, group_cols).await,
         }
     }
 }
@@ -17,14 +17,12 @@ use std::sync::Arc;
 
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
-use common_functions::scalars::CastFunction;
 use common_meta_types::TableInfo;
-use common_streams::CastStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 
 use crate::catalogs::Catalog;
-use crate::interpreters::AddOnStream;
+use crate::interpreters::insert_stream;
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
 use crate::sessions::QueryContext;
@@ -34,7 +32,7 @@ pub struct SinkTransform {
     table_info: TableInfo,
     input: Arc<dyn Processor>,
     cast_needed: bool,
-    input_schema: DataSchemaRef,
+    cast_schema: DataSchemaRef,
 }
 
 impl SinkTransform {
@@ -42,14 +40,14 @@ impl SinkTransform {
         ctx: Arc<QueryContext>,
         table_info: TableInfo,
         cast_needed: bool,
-        input_schema: DataSchemaRef,
+        cast_schema: DataSchemaRef,
     ) -> Self {
         Self {
             ctx,
             table_info,
             input: Arc::new(EmptyProcessor::create()),
             cast_needed,
-            input_schema,
+            cast_schema,
         }
     }
     fn table_info(&self) -> &TableInfo {
@@ -78,33 +76,13 @@ impl Processor for SinkTransform {
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         tracing::debug!("executing sink transform");
-        let tbl = self
-            .ctx
-            .get_catalog()
-            .get_table_by_info(self.table_info())?;
-        let mut upstream = self.input.execute().await?;
-        let output_schema = self.table_info.schema();
-        if self.cast_needed {
-            let mut functions = Vec::with_capacity(output_schema.fields().len());
-            for field in output_schema.fields() {
-                let cast_function =
-                    CastFunction::create("cast".to_string(), field.data_type().clone())?;
-                functions.push(cast_function);
-            }
-            upstream = Box::pin(CastStream::try_create(
-                upstream,
-                output_schema.clone(),
-                functions,
-            )?);
-        };
-
-        if self.input_schema != self.table_info.schema() {
-            upstream = Box::pin(AddOnStream::try_create(
-                upstream,
-                output_schema,
-                self.table_info.schema(),
-            )?)
-        }
+        let tbl = self.ctx.get_table_by_info(self.table_info())?;
+        let upstream = insert_stream(
+            self.input.execute().await?,
+            self.cast_needed,
+            self.cast_schema.clone(),
+            self.table_info.schema(),
+        )?;
 
         tbl.append_data(self.ctx.clone(), upstream).await
     }
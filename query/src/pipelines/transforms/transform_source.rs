@@ -43,10 +43,11 @@ impl SourceTransform {
         let table_stream = table.read(self.ctx.clone(), &self.source_plan);
         let progress_stream =
             ProgressStream::try_create(table_stream.await?, self.ctx.progress_callback()?)?;
+        let limited_stream = self
+            .ctx
+            .try_create_read_limited(Box::pin(progress_stream))?;
 
-        Ok(Box::pin(
-            self.ctx.try_create_abortable(Box::pin(progress_stream))?,
-        ))
+        Ok(Box::pin(self.ctx.try_create_abortable(limited_stream)?))
     }
 }
 
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use std::borrow::Cow;
+use std::sync::Arc;
 
 use chrono::Date;
 use chrono::DateTime;
 use chrono_tz::Tz;
+use common_arrow::arrow::array::ArrayRef;
 use common_base::ProgressValues;
 use common_clickhouse_srv::connection::Connection;
 use common_clickhouse_srv::errors::Error as CHError;
@@ -31,6 +33,7 @@ use common_clickhouse_srv::types::DateTimeType;
 use common_clickhouse_srv::types::SqlType;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::exception::QUERY_TIMEOUT;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::channel::mpsc::Receiver;
@@ -143,9 +146,17 @@ impl<'a> QueryWriter<'a> {
     }
 }
 
+// ClickHouse TIMEOUT_EXCEEDED, so that the clients recognize the timeout of the query.
+const CLICKHOUSE_TIMEOUT_EXCEEDED: u32 = 159;
+
 pub fn to_clickhouse_err(res: ErrorCode) -> common_clickhouse_srv::errors::Error {
+    let code = match res.code() {
+        code if code == QUERY_TIMEOUT => CLICKHOUSE_TIMEOUT_EXCEEDED,
+        code => code as u32,
+    };
+
     common_clickhouse_srv::errors::Error::Server(ServerError {
-        code: res.code() as u32,
+        code,
         name: "DB:Exception".to_string(),
         message: res.message(),
         stack_trace: res.backtrace_str(),
@@ -277,7 +288,50 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
     Ok(DataBlock::create_by_array(schema, arrays))
 }
 
+// the maps are sent as the arrays of the key-value tuples, like the native maps of clickhouse
+fn map_to_clickhouse_column(
+    key_type: &DataType,
+    value_type: &DataType,
+    column: &Series,
+) -> Result<ArcColumnData> {
+    let lists = column.tuple()?.inner().values();
+    let mut ends = vec![];
+    let mut inner = Vec::with_capacity(2);
+    for (list, field) in lists.iter().zip([
+        DataField::new("key", key_type.clone(), false),
+        DataField::new("value", value_type.clone(), true),
+    ]) {
+        let list = list
+            .as_any()
+            .downcast_ref::<LargeListArray>()
+            .ok_or_else(|| ErrorCode::BadDataValueType("Map entries must be lists"))?;
+
+        // the lists may be sliced, the offsets are rebased to the first one
+        let offsets = list.offsets();
+        let start = offsets[0] as usize;
+        let end = offsets[offsets.len() - 1] as usize;
+        ends = offsets
+            .iter()
+            .skip(1)
+            .map(|offset| (*offset as usize - start) as u64)
+            .collect();
+
+        let values: ArrayRef = Arc::from(list.values().slice(start, end - start));
+        inner.push(to_clickhouse_column(&field, &values.into_series())?);
+    }
+
+    let inner = Vec::column_from::<ArcColumnWrapper>(inner);
+    Ok(<(ArcColumnData, Vec<u64>)>::column_from::<ArcColumnWrapper>((inner, ends)))
+}
+
 fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnData> {
+    // LowCardinality is sent as its strings
+    if let DataType::LowCardinality(inner) = field.data_type() {
+        let field = DataField::new(field.name(), inner.as_ref().clone(), field.is_nullable());
+        let column = column.dictionary()?.decode().into_series();
+        return to_clickhouse_column(&field, &column);
+    }
+
     let is_nullable = field.is_nullable();
     let utc: Tz = "UTC".parse().unwrap();
     let result = match is_nullable {
@@ -331,7 +385,7 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
             DataType::Float64 => {
                 Vec::column_from::<ArcColumnWrapper>(column.f64()?.collect_values())
             }
-            DataType::String => {
+            DataType::String | DataType::Variant => {
                 Vec::column_from::<ArcColumnWrapper>(column.string()?.collect_values())
             }
             DataType::Boolean => {
@@ -343,6 +397,25 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
 
                 Vec::column_from::<ArcColumnWrapper>(v)
             }
+            // the chrono values are sent as DateTime32 by clickhouse-srv, send them as strings
+            DataType::DateTime64(precision, tz) => {
+                let tz = parse_timezone(tz)?;
+                let c: Vec<Option<String>> = column
+                    .i64()?
+                    .into_iter()
+                    .map(|x| x.map(|v| datetime64_to_string(*v, *precision, &tz)))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(c)
+            }
+            // the decimals of clickhouse-srv have at most 64 bits, send them as strings
+            DataType::Decimal(_, scale) => {
+                let c: Vec<Option<String>> = column
+                    .decimal()?
+                    .into_iter()
+                    .map(|x| x.map(|v| decimal_to_string(*v, *scale)))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(c)
+            }
             DataType::Struct(fields) => Vec::column_from::<ArcColumnWrapper>(
                 fields
                     .iter()
@@ -353,6 +426,20 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
                     })
                     .collect::<Result<Vec<_>>>()?,
             ),
+            DataType::Map(key_type, value_type) => {
+                map_to_clickhouse_column(key_type, value_type, column)?
+            }
+            // the arrays are sent as their texts, like `[1, 2]`
+            DataType::List(_) => {
+                let serializer = field.data_type().create_serializer();
+                let texts = serializer.serialize_column(&column.clone().into())?;
+                let c: Vec<Option<String>> = texts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, v)| (!column.is_null(i)).then(|| v))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(c)
+            }
             _ => {
                 return Err(ErrorCode::BadDataValueType(format!(
                     "Unsupported column type:{:?}",
@@ -423,7 +510,7 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
             DataType::Float64 => Vec::column_from::<ArcColumnWrapper>(
                 column.f64()?.inner().values().as_slice().to_vec(),
             ),
-            DataType::String => {
+            DataType::String | DataType::Variant => {
                 let vs: Vec<&[u8]> = column.string()?.into_no_null_iter().collect();
                 Vec::column_from::<ArcColumnWrapper>(vs)
             }
@@ -438,6 +525,23 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
             DataType::Interval(_) => Vec::column_from::<ArcColumnWrapper>(
                 column.i64()?.inner().values().as_slice().to_vec(),
             ),
+            DataType::DateTime64(precision, tz) => {
+                let tz = parse_timezone(tz)?;
+                let c: Vec<String> = column
+                    .i64()?
+                    .into_no_null_iter()
+                    .map(|v| datetime64_to_string(*v, *precision, &tz))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(c)
+            }
+            DataType::Decimal(_, scale) => {
+                let c: Vec<String> = column
+                    .decimal()?
+                    .into_no_null_iter()
+                    .map(|v| decimal_to_string(*v, *scale))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(c)
+            }
             DataType::Struct(fields) => Vec::column_from::<ArcColumnWrapper>(
                 fields
                     .iter()
@@ -448,6 +552,14 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
                     })
                     .collect::<Result<Vec<_>>>()?,
             ),
+            DataType::Map(key_type, value_type) => {
+                map_to_clickhouse_column(key_type, value_type, column)?
+            }
+            DataType::List(_) => {
+                let serializer = field.data_type().create_serializer();
+                let c: Vec<String> = serializer.serialize_column(&column.clone().into())?;
+                Vec::column_from::<ArcColumnWrapper>(c)
+            }
             _ => {
                 return Err(ErrorCode::BadDataValueType(format!(
                     "Unsupported column type:{:?}",
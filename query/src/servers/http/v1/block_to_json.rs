@@ -15,11 +15,20 @@
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::arrays::DFDecimalArray;
+use common_datavalues::arrays::DFInt64Array;
+use common_datavalues::arrays::DFListArray;
 use common_datavalues::arrays::DFPrimitiveArray;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
+use common_datavalues::datetime64_to_string;
+use common_datavalues::decimal_to_string;
+use common_datavalues::parse_timezone;
+use common_datavalues::series::IntoSeries;
+use common_datavalues::series::Series;
 use common_datavalues::DFPrimitiveType;
 use common_datavalues::DataType;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use serde::Serialize;
@@ -92,6 +101,136 @@ where
         .collect()
 }
 
+// the decimals are strings to keep all the digits
+fn decimal_array_to_string_array(array: &DFDecimalArray, scale: usize) -> Vec<JsonValue> {
+    array
+        .into_iter()
+        .map(|o| o.map(|x| decimal_to_string(*x, scale)))
+        .map(to_json_value)
+        .collect()
+}
+
+fn decimal_array_to_string_array_not_null(array: &DFDecimalArray, scale: usize) -> Vec<JsonValue> {
+    array
+        .into_no_null_iter()
+        .map(|x| decimal_to_string(*x, scale))
+        .map(to_json_value)
+        .collect()
+}
+
+fn datetime64_array_to_string_array(
+    array: &DFInt64Array,
+    precision: u32,
+    tz: &Option<String>,
+) -> Result<Vec<JsonValue>> {
+    let tz = parse_timezone(tz)?;
+    Ok(array
+        .into_iter()
+        .map(|o| o.map(|x| datetime64_to_string(*x, precision, &tz)))
+        .map(to_json_value)
+        .collect())
+}
+
+fn datetime64_array_to_string_array_not_null(
+    array: &DFInt64Array,
+    precision: u32,
+    tz: &Option<String>,
+) -> Result<Vec<JsonValue>> {
+    let tz = parse_timezone(tz)?;
+    Ok(array
+        .into_no_null_iter()
+        .map(|x| datetime64_to_string(*x, precision, &tz))
+        .map(to_json_value)
+        .collect())
+}
+
+// the elements are formatted by their serializers, the numbers and booleans are kept as JSON values
+fn list_array_to_json(array: &DFListArray, element_type: &DataType) -> Result<Vec<JsonValue>> {
+    let serializer = element_type.create_serializer();
+    array
+        .into_iter()
+        .map(|values| match values {
+            None => Ok(JsonValue::Null),
+            Some(values) => {
+                let texts = serializer.serialize_column(&values.clone().into())?;
+                let elements = texts.into_iter().enumerate().map(|(i, text)| {
+                    if values.is_null(i) {
+                        return JsonValue::Null;
+                    }
+                    match element_type {
+                        DataType::Boolean => JsonValue::Bool(text == "1"),
+                        t if t.is_numeric() && !t.is_decimal() => {
+                            serde_json::from_str(&text).unwrap_or(JsonValue::String(text))
+                        }
+                        _ => JsonValue::String(text),
+                    }
+                });
+                Ok(JsonValue::Array(elements.collect()))
+            }
+        })
+        .collect()
+}
+
+// the structs and the maps are formatted as JSON objects, the values are formatted like the arrays
+fn nested_array_to_json(series: &Series, data_type: &DataType) -> Result<Vec<JsonValue>> {
+    (0..series.len())
+        .map(|row| match series.is_null(row) {
+            true => Ok(JsonValue::Null),
+            false => value_to_json(data_type, &series.try_get(row)?),
+        })
+        .collect()
+}
+
+fn value_to_json(data_type: &DataType, value: &DataValue) -> Result<JsonValue> {
+    if value.is_null() {
+        return Ok(JsonValue::Null);
+    }
+
+    match (data_type, value) {
+        (DataType::Struct(fields), DataValue::Struct(values)) => {
+            let mut object = serde_json::Map::with_capacity(fields.len());
+            for (field, value) in fields.iter().zip(values.iter()) {
+                object.insert(
+                    field.name().to_string(),
+                    value_to_json(field.data_type(), value)?,
+                );
+            }
+            Ok(JsonValue::Object(object))
+        }
+        (DataType::Map(key_type, value_type), DataValue::Struct(entries)) => {
+            let mut object = serde_json::Map::new();
+            if let [DataValue::List(Some(keys), _), DataValue::List(Some(values), _)] =
+                entries.as_slice()
+            {
+                let serializer = key_type.create_serializer();
+                for (key, value) in keys.iter().zip(values.iter()) {
+                    object.insert(
+                        serializer.serialize_value(key)?,
+                        value_to_json(value_type, value)?,
+                    );
+                }
+            }
+            Ok(JsonValue::Object(object))
+        }
+        (DataType::List(field), DataValue::List(Some(values), _)) => Ok(JsonValue::Array(
+            values
+                .iter()
+                .map(|value| value_to_json(field.data_type(), value))
+                .collect::<Result<Vec<_>>>()?,
+        )),
+        _ => {
+            let text = data_type.create_serializer().serialize_value(value)?;
+            Ok(match data_type {
+                DataType::Boolean => JsonValue::Bool(text == "1"),
+                t if t.is_numeric() && !t.is_decimal() => {
+                    serde_json::from_str(&text).unwrap_or(JsonValue::String(text))
+                }
+                _ => JsonValue::String(text),
+            })
+        }
+    }
+}
+
 fn bad_type(data_type: &DataType) -> ErrorCode {
     ErrorCode::BadDataValueType(format!("Unsupported column type:{:?}", data_type))
 }
@@ -104,7 +243,13 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
         let series = column.to_array()?;
 
         let field = block.schema().field(col_index);
-        let data_type = field.data_type();
+        // LowCardinality is written as its strings
+        let (series, data_type) = match field.data_type() {
+            DataType::LowCardinality(inner) => {
+                (series.dictionary()?.decode().into_series(), inner.as_ref())
+            }
+            data_type => (series, data_type),
+        };
         let json_column: Vec<JsonValue> = match field.is_nullable() {
             true => match data_type {
                 DataType::Int8 => primitive_array_to_json(series.i8()?),
@@ -117,7 +262,7 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                 DataType::UInt64 => primitive_array_to_json(series.u64()?),
                 DataType::Float32 => primitive_array_to_json(series.f32()?),
                 DataType::Float64 => primitive_array_to_json(series.f64()?),
-                DataType::String => series
+                DataType::String | DataType::Variant => series
                     .string()?
                     .collect_values()
                     .iter()
@@ -129,6 +274,16 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                 DataType::Date32 => date_array_to_string_array(series.i32()?, DATE_FMT),
                 // TODO(youngsofun): add time zone?
                 DataType::DateTime32(_) => date_array_to_string_array(series.i32()?, TIME_FMT),
+                DataType::DateTime64(precision, tz) => {
+                    datetime64_array_to_string_array(series.i64()?, *precision, tz)?
+                }
+                DataType::Decimal(_, scale) => {
+                    decimal_array_to_string_array(series.decimal()?, *scale)
+                }
+                DataType::List(field) => list_array_to_json(series.list()?, field.data_type())?,
+                DataType::Struct(_) | DataType::Map(_, _) => {
+                    nested_array_to_json(&series, data_type)?
+                }
                 // TODO(youngsofun): support other DataType
                 _ => return Err(bad_type(data_type)),
             },
@@ -148,7 +303,7 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                     .into_no_null_iter()
                     .map(to_json_value)
                     .collect(),
-                DataType::String => series
+                DataType::String | DataType::Variant => series
                     .string()?
                     .into_no_null_iter()
                     .map(|v| String::from_utf8(v.to_vec()).unwrap())
@@ -159,6 +314,16 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                 DataType::DateTime32(_) => {
                     date_array_to_string_array_not_null(series.i32()?, TIME_FMT)
                 }
+                DataType::DateTime64(precision, tz) => {
+                    datetime64_array_to_string_array_not_null(series.i64()?, *precision, tz)?
+                }
+                DataType::Decimal(_, scale) => {
+                    decimal_array_to_string_array_not_null(series.decimal()?, *scale)
+                }
+                DataType::List(field) => list_array_to_json(series.list()?, field.data_type())?,
+                DataType::Struct(_) | DataType::Map(_, _) => {
+                    nested_array_to_json(&series, data_type)?
+                }
                 _ => return Err(bad_type(data_type)),
             },
         };
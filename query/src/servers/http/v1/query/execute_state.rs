@@ -172,6 +172,7 @@ impl ExecuteState {
         }));
 
         let executor_clone = executor.clone();
+        let ctx = context.clone();
         context
             .try_spawn(async move {
                 loop {
@@ -180,7 +181,11 @@ impl ExecuteState {
                             Ok(block) => tokio::select! {
                                 _ = block_tx.send(block) => { },
                                 _ = abort_rx.recv() => {
-                                    Executor::stop(&executor, Err(ErrorCode::AbortedQuery("query aborted")), true).await;
+                                    let err = match ctx.is_execution_timeout() {
+                                        true => ErrorCode::QueryTimeout("query exceeds the max_execution_time"),
+                                        false => ErrorCode::AbortedQuery("query aborted"),
+                                    };
+                                    Executor::stop(&executor, Err(err), true).await;
                                     break;
                                 },
                             },
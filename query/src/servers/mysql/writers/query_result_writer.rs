@@ -14,6 +14,8 @@
 
 use chrono_tz::Tz;
 use common_datablocks::DataBlock;
+use common_datavalues::decimal_to_string;
+use common_datavalues::parse_timezone;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
@@ -21,6 +23,7 @@ use common_datavalues::DataValue;
 use common_datavalues::DateConverter;
 use common_exception::exception::ABORT_QUERY;
 use common_exception::exception::ABORT_SESSION;
+use common_exception::exception::QUERY_TIMEOUT;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use msql_srv::*;
@@ -72,13 +75,20 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
                 DataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                 DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::String | DataType::Variant | DataType::LowCardinality(_) => {
+                    Ok(ColumnType::MYSQL_TYPE_VARCHAR)
+                }
                 DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
                 DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::DateTime32(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::DateTime32(_) | DataType::DateTime64(_, _) => {
+                    Ok(ColumnType::MYSQL_TYPE_DATETIME)
+                }
                 DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Struct(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Decimal(_, _) => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
+                DataType::Struct(_) | DataType::List(_) | DataType::Map(_, _) => {
+                    Ok(ColumnType::MYSQL_TYPE_VARCHAR)
+                }
                 _ => Err(ErrorCode::UnImplement(format!(
                     "Unsupported column type:{:?}",
                     field.data_type()
@@ -163,10 +173,27 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     let tz: Tz = tz.parse().unwrap();
                                     row_writer.write_col(v.to_date_time(&tz).naive_local())?
                                 }
-                                (DataType::String, DataValue::String(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Struct(_), DataValue::Struct(_)) => {
+                                (
+                                    DataType::DateTime64(precision, tz),
+                                    DataValue::Int64(Some(v)),
+                                ) => {
+                                    let tz = parse_timezone(tz)?;
+                                    row_writer.write_col(
+                                        v.to_date_time64(*precision, &tz).naive_local(),
+                                    )?
+                                }
+                                (
+                                    DataType::String
+                                    | DataType::Variant
+                                    | DataType::LowCardinality(_),
+                                    DataValue::String(Some(v)),
+                                ) => row_writer.write_col(v)?,
+                                (DataType::Decimal(_, scale), DataValue::Decimal128(Some(v))) => {
+                                    row_writer.write_col(decimal_to_string(v, *scale))?
+                                }
+                                (DataType::Struct(_), DataValue::Struct(_))
+                                | (DataType::Map(_, _), DataValue::Struct(_))
+                                | (DataType::List(_), DataValue::List(_, _)) => {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
@@ -189,7 +216,12 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
     }
 
     fn err(error: &ErrorCode, writer: QueryResultWriter<'a, W>) -> Result<()> {
-        if error.code() != ABORT_QUERY && error.code() != ABORT_SESSION {
+        if error.code() == QUERY_TIMEOUT {
+            writer.error(
+                ErrorKind::ER_QUERY_INTERRUPTED,
+                format!("{}", error).as_bytes(),
+            )?;
+        } else if error.code() != ABORT_QUERY && error.code() != ABORT_SESSION {
             log::error!("OnQuery Error: {:?}", error);
             writer.error(ErrorKind::ER_UNKNOWN_ERROR, format!("{}", error).as_bytes())?;
         } else {
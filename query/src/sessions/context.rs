@@ -12,13 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::VecDeque;
 use std::future::Future;
 use std::net::SocketAddr;
 use std::str::FromStr;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::Ordering::Acquire;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::tokio::task::JoinHandle;
 use common_base::ProgressCallback;
@@ -35,13 +35,17 @@ use common_dal::S3;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
+use common_meta_types::MetaId;
+use common_meta_types::TableInfo;
 use common_meta_types::UserInfo;
-use common_planners::Part;
 use common_planners::Partitions;
 use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_streams::AbortStream;
+use common_streams::ReadLimits;
+use common_streams::ReadLimitsStream;
+use common_streams::ReadOverflowMode;
 use common_streams::SendableDataBlockStream;
 
 use crate::catalogs::Catalog;
@@ -49,6 +53,8 @@ use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
 use crate::configs::AzureStorageBlobConfig;
 use crate::configs::Config;
+use crate::pipelines::processors::ExecutorProfile;
+use crate::pipelines::processors::MorselQueue;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
@@ -59,7 +65,7 @@ use crate::storages::Table;
 pub struct QueryContext {
     version: String,
     statistics: Arc<RwLock<Statistics>>,
-    partition_queue: Arc<RwLock<VecDeque<Part>>>,
+    partition_queue: Arc<MorselQueue>,
     shared: Arc<QueryContextShared>,
 }
 
@@ -73,9 +79,10 @@ impl QueryContext {
 
         log::info!("Create DatabendQueryContext");
 
+        let max_threads = shared.get_settings().get_max_threads().unwrap_or(1);
         Arc::new(QueryContext {
             statistics: Arc::new(RwLock::new(Statistics::default())),
-            partition_queue: Arc::new(RwLock::new(VecDeque::new())),
+            partition_queue: Arc::new(MorselQueue::create(max_threads as usize)),
             version: format!(
                 "DatabendQuery v-{}",
                 *crate::configs::DATABEND_COMMIT_VERSION
@@ -92,12 +99,11 @@ impl QueryContext {
         &self,
         plan: &ReadDataSourcePlan,
     ) -> Result<Arc<dyn Table>> {
-        let catalog = self.get_catalog();
-
         if plan.tbl_args.is_none() {
-            catalog.get_table_by_info(&plan.table_info)
+            self.get_table_by_info(&plan.table_info)
         } else {
-            Ok(catalog
+            Ok(self
+                .get_catalog()
                 .get_table_function(&plan.table_info.name, plan.tbl_args.clone())?
                 .as_table())
         }
@@ -122,28 +128,33 @@ impl QueryContext {
     }
 
     // Steal n partitions from the partition pool by the pipeline worker.
-    // This also can steal the partitions from distributed node.
+    // The partitions of the worker's local queue are taken first, then the ones of the others.
     pub fn try_get_partitions(&self, num: usize) -> Result<Partitions> {
-        let mut partitions = vec![];
-        for _ in 0..num {
-            match self.partition_queue.write().pop_back() {
-                None => break,
-                Some(partition) => {
-                    partitions.push(partition);
-                }
-            }
-        }
-        Ok(partitions)
+        let profile = &self.shared.executor_profile;
+        Ok(self.partition_queue.pop_partitions(num, profile))
     }
 
     // Update the context partition pool from the pipeline builder.
     pub fn try_set_partitions(&self, partitions: Partitions) -> Result<()> {
-        for part in partitions {
-            self.partition_queue.write().push_back(part);
-        }
+        self.partition_queue.push_partitions(partitions);
         Ok(())
     }
 
+    pub fn get_executor_profile(&self) -> Arc<ExecutorProfile> {
+        self.shared.executor_profile.clone()
+    }
+
+    /// Collect the runtime statistics of the processors of the pipelines built from now on.
+    pub fn enable_pipeline_profiling(&self) {
+        self.shared
+            .pipeline_profiling
+            .store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_pipeline_profiling(&self) -> bool {
+        self.shared.pipeline_profiling.load(Ordering::Relaxed)
+    }
+
     pub fn try_get_statistics(&self) -> Result<Statistics> {
         let statistics = self.statistics.read();
         Ok((*statistics).clone())
@@ -185,16 +196,84 @@ impl QueryContext {
         self.shared.get_table(database, table).await
     }
 
+    /// Bind a table in place of the table with the same id, see `QueryContextShared::bind_table`.
+    pub fn bind_table(&self, table: Arc<dyn Table>) {
+        self.shared.bind_table(table)
+    }
+
+    pub fn unbind_table(&self, table_id: MetaId) {
+        self.shared.unbind_table(table_id)
+    }
+
+    /// Mark the view as being expanded, see `QueryContextShared::enter_view`.
+    pub fn enter_view(&self, view_id: MetaId) -> bool {
+        self.shared.enter_view(view_id)
+    }
+
+    pub fn leave_view(&self, view_id: MetaId) {
+        self.shared.leave_view(view_id)
+    }
+
+    /// Get the table of the info, the bound and temporary tables take precedence over the catalog.
+    pub fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+        self.shared.get_table_by_info(table_info)
+    }
+
     pub fn get_id(&self) -> String {
         self.shared.init_query_id.as_ref().read().clone()
     }
 
     pub fn try_create_abortable(&self, input: SendableDataBlockStream) -> Result<AbortStream> {
-        let (abort_handle, abort_stream) = AbortStream::try_create(input)?;
+        let timeout = self.shared.execution_timeout.clone();
+        let (abort_handle, abort_stream) = AbortStream::try_create_with_timeout(input, timeout)?;
         self.shared.add_source_abort_handle(abort_handle);
         Ok(abort_stream)
     }
 
+    /// The `max_rows_to_read` and `max_bytes_to_read` limits of the query.
+    pub fn get_read_limits(&self) -> Result<ReadLimits> {
+        let settings = self.get_settings();
+        Ok(ReadLimits {
+            max_rows_to_read: settings.get_max_rows_to_read()? as usize,
+            max_bytes_to_read: settings.get_max_bytes_to_read()? as usize,
+            overflow_mode: ReadOverflowMode::try_from_str(&settings.get_read_overflow_mode()?)?,
+        })
+    }
+
+    /// Count the rows and bytes read by the source into the read limits of the query.
+    pub fn try_create_read_limited(
+        &self,
+        input: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        let limits = self.get_read_limits()?;
+        if limits.is_unlimited() {
+            return Ok(input);
+        }
+
+        let progress = self.shared.read_limits_progress.clone();
+        Ok(Box::pin(ReadLimitsStream::try_create(
+            input, progress, limits,
+        )?))
+    }
+
+    /// Start the `max_execution_time` timer of the query, if the setting is not 0.
+    pub fn start_execution_timer(&self) -> Result<()> {
+        let max_execution_time = self.get_settings().get_max_execution_time()?;
+        if max_execution_time > 0 {
+            let max_execution_time = Duration::from_secs(max_execution_time);
+            self.shared.start_execution_timer(max_execution_time);
+        }
+        Ok(())
+    }
+
+    pub fn is_execution_timeout(&self) -> bool {
+        self.shared.is_execution_timeout()
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.shared.is_killed()
+    }
+
     pub fn get_current_database(&self) -> String {
         self.shared.get_current_database()
     }
@@ -325,6 +404,7 @@ impl QueryContextShared {
         if self.ref_count.fetch_sub(1, Ordering::Release) == 1 {
             std::sync::atomic::fence(Acquire);
             log::info!("Destroy DatabendQueryContext");
+            self.stop_execution_timer();
             self.session.destroy_context_shared();
         }
     }
@@ -14,9 +14,15 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_base::tokio;
+use common_base::tokio::task::JoinHandle;
 use common_base::Progress;
 use common_base::Runtime;
 use common_dal::DalContext;
@@ -24,6 +30,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::Mutex;
 use common_infallible::RwLock;
+use common_meta_types::MetaId;
+use common_meta_types::TableInfo;
 use common_meta_types::UserInfo;
 use common_planners::PlanNode;
 use futures::future::AbortHandle;
@@ -33,6 +41,7 @@ use crate::catalogs::Catalog;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
 use crate::configs::Config;
+use crate::pipelines::processors::ExecutorProfile;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::Session;
 use crate::sessions::Settings;
@@ -52,17 +61,25 @@ type DatabaseAndTable = (String, String);
 pub struct QueryContextShared {
     pub conf: Config,
     pub(in crate::sessions) progress: Arc<Progress>,
+    pub(in crate::sessions) read_limits_progress: Arc<Progress>,
+    pub(in crate::sessions) executor_profile: Arc<ExecutorProfile>,
+    pub(in crate::sessions) pipeline_profiling: Arc<AtomicBool>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
     pub(in crate::sessions) cluster_cache: Arc<Cluster>,
     pub(in crate::sessions) sources_abort_handle: Arc<RwLock<Vec<AbortHandle>>>,
+    pub(in crate::sessions) killed: Arc<AtomicBool>,
+    pub(in crate::sessions) execution_timeout: Arc<AtomicBool>,
+    pub(in crate::sessions) execution_timer: Arc<Mutex<Option<JoinHandle<()>>>>,
     pub(in crate::sessions) ref_count: Arc<AtomicUsize>,
     pub(in crate::sessions) subquery_index: Arc<AtomicUsize>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) http_query: Arc<RwLock<Option<HttpQueryHandle>>>,
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
+    pub(in crate::sessions) bound_tables: Arc<Mutex<HashMap<MetaId, Arc<dyn Table>>>>,
+    pub(in crate::sessions) expanding_views: Arc<Mutex<HashSet<MetaId>>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
 }
 
@@ -76,21 +93,31 @@ impl QueryContextShared {
             conf,
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             progress: Arc::new(Progress::create()),
+            read_limits_progress: Arc::new(Progress::create()),
+            executor_profile: Arc::new(ExecutorProfile::create()),
+            pipeline_profiling: Arc::new(AtomicBool::new(false)),
             session,
             cluster_cache,
             runtime: Arc::new(RwLock::new(None)),
             sources_abort_handle: Arc::new(RwLock::new(Vec::new())),
+            killed: Arc::new(AtomicBool::new(false)),
+            execution_timeout: Arc::new(AtomicBool::new(false)),
+            execution_timer: Arc::new(Mutex::new(None)),
             ref_count: Arc::new(AtomicUsize::new(0)),
             subquery_index: Arc::new(AtomicUsize::new(1)),
             running_query: Arc::new(RwLock::new(None)),
             http_query: Arc::new(RwLock::new(None)),
             running_plan: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
+            bound_tables: Arc::new(Mutex::new(HashMap::new())),
+            expanding_views: Arc::new(Mutex::new(HashSet::new())),
             dal_ctx: Arc::new(Default::default()),
         })
     }
 
+    /// Abort the sources of the query, the sources created after the kill are aborted at once.
     pub fn kill(&self) {
+        self.killed.store(true, Ordering::Release);
         let mut sources_abort_handle = self.sources_abort_handle.write();
 
         while let Some(source_abort_handle) = sources_abort_handle.pop() {
@@ -105,6 +132,46 @@ impl QueryContextShared {
         // TODO: Wait for the query to be processed (write out the last error)
     }
 
+    /// Kill the query once it has been executed for longer than `max_execution_time`.
+    ///
+    /// The sources report a timeout error instead of an abort error. The remote fragments are
+    /// cancelled by the `CancelAction` which the `ScheduledStream` sends when it is dropped
+    /// before its end.
+    pub fn start_execution_timer(self: &Arc<Self>, max_execution_time: Duration) {
+        let shared = Arc::downgrade(self);
+        let timer = tokio::spawn(async move {
+            tokio::time::sleep(max_execution_time).await;
+
+            if let Some(shared) = shared.upgrade() {
+                log::warn!(
+                    "Kill query {}, because it exceeds the max_execution_time {:?}",
+                    shared.init_query_id.read(),
+                    max_execution_time
+                );
+                shared.execution_timeout.store(true, Ordering::Release);
+                shared.kill();
+            }
+        });
+
+        if let Some(old_timer) = self.execution_timer.lock().replace(timer) {
+            old_timer.abort();
+        }
+    }
+
+    pub fn stop_execution_timer(&self) {
+        if let Some(timer) = self.execution_timer.lock().take() {
+            timer.abort();
+        }
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.killed.load(Ordering::Acquire)
+    }
+
+    pub fn is_execution_timeout(&self) -> bool {
+        self.execution_timeout.load(Ordering::Acquire)
+    }
+
     pub fn get_cluster(&self) -> Arc<Cluster> {
         self.cluster_cache.clone()
     }
@@ -130,20 +197,28 @@ impl QueryContextShared {
     }
 
     pub async fn get_table(&self, database: &str, table: &str) -> Result<Arc<dyn Table>> {
+        // A temporary table of the session shadows the one with the same name in the catalog.
+        if let Some(table) = self.session.get_temporary_table(database, table) {
+            return Ok(table);
+        }
+
         // Always get same table metadata in the same query
         let table_meta_key = (database.to_string(), table.to_string());
 
         let already_in_cache = { self.tables_refs.lock().contains_key(&table_meta_key) };
 
-        match already_in_cache {
-            false => self.get_table_to_cache(database, table).await,
-            true => Ok(self
+        let table = match already_in_cache {
+            false => self.get_table_to_cache(database, table).await?,
+            true => self
                 .tables_refs
                 .lock()
                 .get(&table_meta_key)
                 .ok_or_else(|| ErrorCode::LogicalError("Logical error, it's a bug."))?
-                .clone()),
-        }
+                .clone(),
+        };
+
+        // A table bound to the query shadows the one in the catalog.
+        Ok(self.get_bound_table(table.get_id()).unwrap_or(table))
     }
 
     async fn get_table_to_cache(&self, database: &str, table: &str) -> Result<Arc<dyn Table>> {
@@ -159,6 +234,42 @@ impl QueryContextShared {
         }
     }
 
+    /// Bind a table in place of the table with the same id until it is unbound, e.g. the blocks
+    /// of an insert which are pushed through the materialized views of the table.
+    pub fn bind_table(&self, table: Arc<dyn Table>) {
+        self.bound_tables.lock().insert(table.get_id(), table);
+    }
+
+    pub fn unbind_table(&self, table_id: MetaId) {
+        self.bound_tables.lock().remove(&table_id);
+    }
+
+    pub fn get_bound_table(&self, table_id: MetaId) -> Option<Arc<dyn Table>> {
+        self.bound_tables.lock().get(&table_id).cloned()
+    }
+
+    /// Mark the view as being expanded, returns false if it is already, i.e. it references
+    /// itself through the views it is defined by.
+    pub fn enter_view(&self, view_id: MetaId) -> bool {
+        self.expanding_views.lock().insert(view_id)
+    }
+
+    pub fn leave_view(&self, view_id: MetaId) {
+        self.expanding_views.lock().remove(&view_id);
+    }
+
+    pub fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+        let table_id = table_info.ident.table_id;
+        if let Some(table) = self.get_bound_table(table_id) {
+            return Ok(table);
+        }
+
+        match self.session.get_temporary_table_by_id(table_id) {
+            Some(table) => Ok(table),
+            None => self.get_catalog().get_table_by_info(table_info),
+        }
+    }
+
     /// Init runtime when first get
     pub fn try_get_runtime(&self) -> Result<Arc<Runtime>> {
         let mut query_runtime = self.runtime.write();
@@ -197,7 +308,11 @@ impl QueryContextShared {
 
     pub fn add_source_abort_handle(&self, handle: AbortHandle) {
         let mut sources_abort_handle = self.sources_abort_handle.write();
-        sources_abort_handle.push(handle);
+        // Checked with the lock held, so the handle is either aborted here or by kill().
+        match self.is_killed() {
+            true => handle.abort(),
+            false => sources_abort_handle.push(handle),
+        }
     }
 }
 
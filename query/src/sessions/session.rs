@@ -20,6 +20,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_macros::MallocSizeOf;
 use common_mem_allocator::malloc_size;
+use common_meta_types::MetaId;
 use common_meta_types::UserInfo;
 use futures::channel::*;
 
@@ -30,6 +31,7 @@ use crate::sessions::MutableStatus;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::sessions::Settings;
+use crate::storages::Table;
 use crate::users::UserApiProvider;
 
 #[derive(Clone, MallocSizeOf)]
@@ -179,4 +181,21 @@ impl Session {
     pub fn get_memory_usage(self: &Arc<Self>) -> usize {
         malloc_size(self)
     }
+
+    /// Add a table which lives in this session only, returns false if it already exists.
+    pub fn add_temporary_table(self: &Arc<Self>, db: &str, table: Arc<dyn Table>) -> bool {
+        self.mutable_state.add_temporary_table(db, table)
+    }
+
+    pub fn get_temporary_table(self: &Arc<Self>, db: &str, table: &str) -> Option<Arc<dyn Table>> {
+        self.mutable_state.get_temporary_table(db, table)
+    }
+
+    pub fn get_temporary_table_by_id(self: &Arc<Self>, table_id: MetaId) -> Option<Arc<dyn Table>> {
+        self.mutable_state.get_temporary_table_by_id(table_id)
+    }
+
+    pub fn drop_temporary_table(self: &Arc<Self>, db: &str, table: &str) -> Option<Arc<dyn Table>> {
+        self.mutable_state.remove_temporary_table(db, table)
+    }
 }
@@ -57,6 +57,7 @@ impl Session {
         if self.ref_count.fetch_sub(1, Ordering::Release) == 1 {
             std::sync::atomic::fence(Acquire);
             log::debug!("Destroy session {}", self.id);
+            self.mutable_state.clear_temporary_tables();
             self.sessions.destroy_session(&self.id);
         }
     }
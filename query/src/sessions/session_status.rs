@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -20,11 +22,13 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_infallible::RwLock;
 use common_macros::MallocSizeOf;
+use common_meta_types::MetaId;
 use common_meta_types::UserInfo;
 use futures::channel::oneshot::Sender;
 
 use crate::sessions::context_shared::QueryContextShared;
 use crate::sessions::Settings;
+use crate::storages::Table;
 
 #[derive(MallocSizeOf)]
 pub struct MutableStatus {
@@ -39,6 +43,8 @@ pub struct MutableStatus {
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     #[ignore_malloc_size_of = "insignificant"]
     context_shared: RwLock<Option<Arc<QueryContextShared>>>,
+    #[ignore_malloc_size_of = "insignificant"]
+    temporary_tables: RwLock<HashMap<(String, String), Arc<dyn Table>>>,
 }
 
 impl MutableStatus {
@@ -51,6 +57,7 @@ impl MutableStatus {
             session_settings: RwLock::new(Settings::try_create()?.as_ref().clone()),
             io_shutdown_tx: Default::default(),
             context_shared: Default::default(),
+            temporary_tables: Default::default(),
         })
     }
 
@@ -134,4 +141,38 @@ impl MutableStatus {
         let mut lock = self.context_shared.write();
         lock.take()
     }
+
+    // Add a temporary table, return false if the name is already taken.
+    pub fn add_temporary_table(&self, db: &str, table: Arc<dyn Table>) -> bool {
+        let key = (db.to_string(), table.name().to_string());
+        let mut lock = self.temporary_tables.write();
+        match lock.entry(key) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(v) => {
+                v.insert(table);
+                true
+            }
+        }
+    }
+
+    pub fn get_temporary_table(&self, db: &str, table: &str) -> Option<Arc<dyn Table>> {
+        let lock = self.temporary_tables.read();
+        lock.get(&(db.to_string(), table.to_string())).cloned()
+    }
+
+    pub fn get_temporary_table_by_id(&self, table_id: MetaId) -> Option<Arc<dyn Table>> {
+        let lock = self.temporary_tables.read();
+        lock.values().find(|t| t.get_id() == table_id).cloned()
+    }
+
+    pub fn remove_temporary_table(&self, db: &str, table: &str) -> Option<Arc<dyn Table>> {
+        let mut lock = self.temporary_tables.write();
+        lock.remove(&(db.to_string(), table.to_string()))
+    }
+
+    // Drop all the temporary tables, their data is released with them.
+    pub fn clear_temporary_tables(&self) {
+        let mut lock = self.temporary_tables.write();
+        lock.clear();
+    }
 }
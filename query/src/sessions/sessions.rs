@@ -30,6 +30,7 @@ use futures::StreamExt;
 
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::common::QueryResultCache;
 use crate::configs::Config;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session::Session;
@@ -42,6 +43,7 @@ pub struct SessionManager {
     pub(in crate::sessions) catalog: Arc<DatabaseCatalog>,
     pub(in crate::sessions) user: Arc<UserApiProvider>,
     pub(in crate::sessions) http_query_manager: Arc<HttpQueryManager>,
+    pub(in crate::sessions) query_result_cache: Arc<QueryResultCache>,
 
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
@@ -59,6 +61,8 @@ impl SessionManager {
 
         let http_query_manager = HttpQueryManager::create_global(conf.clone()).await?;
 
+        let query_result_cache = QueryResultCache::create_global(conf.clone())?;
+
         let max_active_sessions = conf.query.max_active_sessions as usize;
         Ok(Arc::new(SessionManager {
             catalog,
@@ -66,6 +70,7 @@ impl SessionManager {
             discovery,
             user,
             http_query_manager,
+            query_result_cache,
             max_sessions: max_active_sessions,
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_active_sessions))),
         }))
@@ -83,6 +88,10 @@ impl SessionManager {
         self.http_query_manager.clone()
     }
 
+    pub fn get_query_result_cache(self: &Arc<Self>) -> Arc<QueryResultCache> {
+        self.query_result_cache.clone()
+    }
+
     // Get the user api provider.
     pub fn get_user_manager(self: &Arc<Self>) -> Arc<UserApiProvider> {
         self.user.clone()
@@ -31,7 +31,14 @@ impl Settings {
         ("max_threads", u64, 16, "The maximum number of threads to execute the request. By default, it is determined automatically."),
         ("flight_client_timeout", u64, 60, "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds"),
         ("min_distributed_rows", u64, 100000000, "Minimum distributed read rows. In cluster mode, when read rows exceeds this value, the local table converted to distributed query."),
-        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query.")
+        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query."),
+        ("enable_query_result_cache", u64, 0, "Enable the query result cache, 1 for enable, 0 for disable. By default, it is 0."),
+        ("query_result_cache_ttl", u64, 300, "Max duration in seconds a cached query result can be reused. By default, it is 300 seconds."),
+        ("max_execution_time", u64, 0, "Maximum query execution time in seconds, 0 means no limit. By default, it is 0."),
+        ("max_rows_to_read", u64, 0, "Maximum rows a query can read from the tables, 0 means no limit. By default, it is 0."),
+        ("max_bytes_to_read", u64, 0, "Maximum bytes a query can read from the tables, 0 means no limit. By default, it is 0."),
+        ("read_overflow_mode", String, "throw", "What to do when max_rows_to_read or max_bytes_to_read is exceeded, 'throw' to fail the query, 'break' to return the partial result. By default, it is 'throw'."),
+        ("timezone", String, "UTC", "The timezone of the session, the date time values without timezone are converted in it. By default, it is 'UTC'.")
     }
 
     pub fn try_create() -> Result<Arc<Settings>> {
@@ -214,7 +221,6 @@ impl SettingsBase {
         )))
     }
 
-    #[allow(unused)]
     pub fn try_set_string(&self, key: &'static str, val: &str, desc: &str) -> Result<()> {
         let mut settings = self.settings.write();
         let default_value = val;
@@ -227,8 +233,7 @@ impl SettingsBase {
         Ok(())
     }
 
-    #[allow(unused)]
-    pub fn try_update_string(&self, key: &'static str, val: &str) -> Result<()> {
+    pub fn try_update_string(&self, key: &'static str, val: String) -> Result<()> {
         let mut settings = self.settings.write();
         let setting_val = settings
             .get(key)
@@ -245,8 +250,7 @@ impl SettingsBase {
         Ok(())
     }
 
-    #[allow(unused)]
-    pub fn try_get_string(&self, key: &str) -> Result<Vec<u8>> {
+    pub fn try_get_string(&self, key: &str) -> Result<String> {
         let settings = self.settings.read();
         let setting_val = settings
             .get(key)
@@ -254,7 +258,7 @@ impl SettingsBase {
 
         if let DataValue::Struct(values) = setting_val {
             if let DataValue::String(Some(result)) = values[0].clone() {
-                return Ok(result);
+                return String::from_utf8(result).map_err(ErrorCode::from);
             }
         }
 
@@ -17,6 +17,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use sqlparser::ast::DataType as SQLDataType;
 
+use crate::sql::DfParser;
+
 pub struct SQLCommon;
 
 impl SQLCommon {
@@ -31,12 +33,21 @@ impl SQLCommon {
             SQLDataType::Varchar(_) => Ok(DataType::String),
             SQLDataType::String => Ok(DataType::String),
             SQLDataType::Text => Ok(DataType::String),
-            SQLDataType::Decimal(_, _) => Ok(DataType::Float64),
+            SQLDataType::Decimal(precision, scale) => create_decimal_type(
+                precision.unwrap_or(10) as usize,
+                scale.unwrap_or(0) as usize,
+            ),
             SQLDataType::Float(_) => Ok(DataType::Float32),
             SQLDataType::Real | SQLDataType::Double => Ok(DataType::Float64),
             SQLDataType::Boolean => Ok(DataType::Boolean),
             SQLDataType::Date => Ok(DataType::Date16),
             SQLDataType::Timestamp => Ok(DataType::DateTime32(None)),
+            SQLDataType::Array(inner) => {
+                let inner = SQLCommon::make_data_type(inner)?;
+                Ok(DataType::List(Box::new(DataField::new(
+                    "item", inner, true,
+                ))))
+            }
 
             //custom types for databend
             // Custom(ObjectName([Ident { value: "uint8", quote_style: None }])
@@ -58,6 +69,52 @@ impl SQLCommon {
                     "DATE32" => Ok(DataType::Date32),
                     "DATETIME" => Ok(DataType::DateTime32(None)),
                     "DATETIME32" => Ok(DataType::DateTime32(None)),
+                    // DateTime64(precision[, 'timezone']), the precision is 3 by default
+                    "DATETIME64" => {
+                        let precision = match obj.0.get(1) {
+                            Some(precision) => precision.value.parse::<u32>().map_err(|_| {
+                                ErrorCode::IllegalDataType(format!(
+                                    "Illegal precision {} of DateTime64",
+                                    precision.value
+                                ))
+                            })?,
+                            None => 3,
+                        };
+                        let tz = obj.0.get(2).map(|tz| tz.value.clone());
+                        create_datetime64_type(precision, tz)
+                    }
+                    "VARIANT" | "JSON" => Ok(DataType::Variant),
+                    // Map(K, V), the keys can't be NULL
+                    "MAP" if obj.0.len() == 3 => {
+                        let key = SQLCommon::make_nested_type(&obj.0[1].value)?;
+                        let value = SQLCommon::make_nested_type(&obj.0[2].value)?;
+                        Ok(DataType::Map(Box::new(key), Box::new(value)))
+                    }
+                    // LowCardinality(T), only the strings are dictionary encoded
+                    "LOWCARDINALITY" if obj.0.len() == 2 => {
+                        match SQLCommon::make_nested_type(&obj.0[1].value)? {
+                            DataType::String => {
+                                Ok(DataType::LowCardinality(Box::new(DataType::String)))
+                            }
+                            inner => Result::Err(ErrorCode::IllegalDataType(format!(
+                                "LowCardinality({}) is not supported, only String is allowed",
+                                inner
+                            ))),
+                        }
+                    }
+                    // Struct(a T, b U, ...), the members are nullable like the columns
+                    "STRUCT" | "TUPLE" if obj.0.len() > 1 => {
+                        let fields = obj.0[1..]
+                            .iter()
+                            .map(|member| {
+                                let (name, data_type) =
+                                    DfParser::parse_struct_member(&member.value)?;
+                                let data_type = SQLCommon::make_data_type(&data_type)?;
+                                Ok(DataField::new(&name.value, data_type, true))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        Ok(DataType::Struct(fields))
+                    }
                     "SIGNED" => Ok(DataType::Int64),
                     "UNSIGNED" => Ok(DataType::UInt64),
 
@@ -73,4 +130,9 @@ impl SQLCommon {
             ))),
         }
     }
+
+    fn make_nested_type(sql: &str) -> Result<DataType> {
+        let data_type = DfParser::parse_nested_data_type(sql)?;
+        SQLCommon::make_data_type(&data_type)
+    }
 }
@@ -32,6 +32,7 @@ use metrics::histogram;
 use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOptionDef;
+use sqlparser::ast::DataType;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::SqlOption;
@@ -49,22 +50,29 @@ use sqlparser::tokenizer::Tokenizer;
 use sqlparser::tokenizer::Whitespace;
 
 use super::statements::DfCopy;
+use crate::sql::statements::DfAlterTable;
+use crate::sql::statements::DfAlterTableOperation;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCompactTable;
 use crate::sql::statements::DfCreateDatabase;
+use crate::sql::statements::DfCreateMaterializedView;
 use crate::sql::statements::DfCreateStage;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfCreateView;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUser;
+use crate::sql::statements::DfDropView;
 use crate::sql::statements::DfExplain;
 use crate::sql::statements::DfGrantObject;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRenameDatabase;
+use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfRevokeStatement;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateTable;
@@ -75,6 +83,7 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUndropTable;
 use crate::sql::statements::DfUseDatabase;
 use crate::sql::DfHint;
 use crate::sql::DfStatement;
@@ -102,12 +111,232 @@ impl<'a> DfParser<'a> {
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
         let tokens = tokenizer.tokenize()?;
+        let tokens = DfParser::rewrite_variant_access(tokens);
+        let tokens = DfParser::rewrite_array_literal(tokens);
+        let tokens = DfParser::rewrite_regexp_operator(tokens);
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
         })
     }
 
+    /// Rewrite the variant access `v:a.b[0]` into `get_path(v, 'a.b[0]')`,
+    /// which is unknown to the sql parser. The path must follow the colon without whitespaces.
+    fn rewrite_variant_access(tokens: Vec<Token>) -> Vec<Token> {
+        let mut output: Vec<Token> = Vec::with_capacity(tokens.len());
+        let mut tokens = tokens.into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            let is_access = token == Token::Colon
+                && matches!(output.last(), Some(Token::Word(_)))
+                && matches!(tokens.peek(), Some(Token::Word(_)) | Some(Token::LBracket));
+            if !is_access {
+                output.push(token);
+                continue;
+            }
+
+            // The variant column, like `v` or `t.v`
+            let mut start = output.len() - 1;
+            while start >= 2
+                && output[start - 1] == Token::Period
+                && matches!(output[start - 2], Token::Word(_))
+            {
+                start -= 2;
+            }
+            let column = output.split_off(start);
+
+            let mut path = String::new();
+            let mut in_bracket = false;
+            while let Some(token) = tokens.peek() {
+                match (token, in_bracket) {
+                    (Token::Word(w), false) if path.is_empty() || path.ends_with('.') => {
+                        path.push_str(&w.value)
+                    }
+                    (Token::Period, false) if !path.is_empty() && !path.ends_with('.') => {
+                        path.push('.')
+                    }
+                    (Token::LBracket, false) => {
+                        in_bracket = true;
+                        path.push('[');
+                    }
+                    (Token::Number(n, _), true) => path.push_str(n),
+                    (Token::SingleQuotedString(s), true) => path.push_str(&format!("'{}'", s)),
+                    (Token::RBracket, true) => {
+                        in_bracket = false;
+                        path.push(']');
+                    }
+                    _ => break,
+                }
+                tokens.next();
+            }
+
+            output.push(Token::make_word("get_path", None));
+            output.push(Token::LParen);
+            output.extend(column);
+            output.push(Token::Comma);
+            output.push(Token::SingleQuotedString(path));
+            output.push(Token::RParen);
+        }
+        output
+    }
+
+    /// Rewrite the array literals `ARRAY[1, 2]` and `[1, 2]` into the function call `array(1, 2)`.
+    /// A bracket following an identifier or a closing bracket is kept as the subscript operator.
+    fn rewrite_array_literal(tokens: Vec<Token>) -> Vec<Token> {
+        let mut output: Vec<Token> = Vec::with_capacity(tokens.len());
+        // Whether the opening brackets were rewritten into the array literals
+        let mut brackets: Vec<bool> = vec![];
+        let mut tokens = tokens.into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            match token {
+                Token::Word(ref w)
+                    if w.quote_style.is_none()
+                        && w.value.eq_ignore_ascii_case("array")
+                        && tokens.peek() == Some(&Token::LBracket) =>
+                {
+                    tokens.next();
+                    brackets.push(true);
+                    output.push(Token::make_word("array", None));
+                    output.push(Token::LParen);
+                }
+                Token::LBracket => {
+                    let prev = output
+                        .iter()
+                        .rposition(|t| !matches!(t, Token::Whitespace(_)));
+                    if DfParser::is_array_literal_start(prev.map(|pos| &output[pos])) {
+                        brackets.push(true);
+                        output.push(Token::make_word("array", None));
+                        output.push(Token::LParen);
+                    } else if let Some(start) =
+                        prev.and_then(|pos| DfParser::subscript_operand_start(&output, pos))
+                    {
+                        // the subscript `a[x]` is rewritten into `arrayElement(a, x)`
+                        let operand = output.split_off(start);
+                        brackets.push(true);
+                        output.push(Token::make_word("arrayElement", None));
+                        output.push(Token::LParen);
+                        output.extend(operand);
+                        output.push(Token::Comma);
+                    } else {
+                        brackets.push(false);
+                        output.push(Token::LBracket);
+                    }
+                }
+                Token::RBracket => match brackets.pop() {
+                    Some(true) => output.push(Token::RParen),
+                    _ => output.push(Token::RBracket),
+                },
+                token => output.push(token),
+            }
+        }
+        output
+    }
+
+    /// Rewrite the operators `a [NOT] REGEXP b` and `a [NOT] RLIKE b` into `a ~ b` and `a !~ b`,
+    /// which are resolved to the regexp functions by the analyzer.
+    fn rewrite_regexp_operator(tokens: Vec<Token>) -> Vec<Token> {
+        let mut output: Vec<Token> = Vec::with_capacity(tokens.len());
+
+        for token in tokens {
+            let is_operator = matches!(&token, Token::Word(w) if w.quote_style.is_none()
+                && (w.value.eq_ignore_ascii_case("regexp") || w.value.eq_ignore_ascii_case("rlike")));
+            if !is_operator {
+                output.push(token);
+                continue;
+            }
+
+            let prev = output
+                .iter()
+                .rposition(|t| !matches!(t, Token::Whitespace(_)));
+            match prev.map(|pos| (pos, &output[pos])) {
+                Some((pos, Token::Word(w)))
+                    if w.quote_style.is_none() && w.keyword == Keyword::NOT =>
+                {
+                    output[pos] = Token::ExclamationMarkTilde;
+                }
+                Some((_, prev)) if DfParser::is_operand_end(prev) => output.push(Token::Tilde),
+                _ => output.push(token),
+            }
+        }
+        output
+    }
+
+    fn is_operand_end(token: &Token) -> bool {
+        match token {
+            Token::Word(w) => {
+                w.quote_style.is_some()
+                    || !matches!(
+                        w.keyword,
+                        Keyword::SELECT
+                            | Keyword::FROM
+                            | Keyword::WHERE
+                            | Keyword::HAVING
+                            | Keyword::BY
+                            | Keyword::ON
+                            | Keyword::AS
+                            | Keyword::DISTINCT
+                            | Keyword::AND
+                            | Keyword::OR
+                            | Keyword::CASE
+                            | Keyword::WHEN
+                            | Keyword::THEN
+                            | Keyword::ELSE
+                    )
+            }
+            Token::RParen
+            | Token::RBracket
+            | Token::Number(_, _)
+            | Token::SingleQuotedString(_) => true,
+            _ => false,
+        }
+    }
+
+    /// The start of the subscripted column `a` or `t.a` which ends at the position.
+    fn subscript_operand_start(tokens: &[Token], end: usize) -> Option<usize> {
+        let is_identifier = |token: &Token| match token {
+            Token::Word(w) => w.quote_style.is_some() || w.keyword == Keyword::NoKeyword,
+            _ => false,
+        };
+
+        if !is_identifier(&tokens[end]) {
+            return None;
+        }
+
+        let mut start = end;
+        while start >= 2 && tokens[start - 1] == Token::Period && is_identifier(&tokens[start - 2])
+        {
+            start -= 2;
+        }
+        Some(start)
+    }
+
+    fn is_array_literal_start(prev: Option<&Token>) -> bool {
+        match prev {
+            None => true,
+            Some(Token::Word(w)) => {
+                w.quote_style.is_none()
+                    && matches!(
+                        w.keyword,
+                        Keyword::SELECT
+                            | Keyword::WHERE
+                            | Keyword::HAVING
+                            | Keyword::AND
+                            | Keyword::OR
+                            | Keyword::NOT
+                            | Keyword::WHEN
+                            | Keyword::THEN
+                            | Keyword::ELSE
+                    )
+            }
+            Some(Token::RParen)
+            | Some(Token::RBracket)
+            | Some(Token::Number(_, _))
+            | Some(Token::SingleQuotedString(_)) => false,
+            Some(_) => true,
+        }
+    }
+
     /// Parse a SQL statement and produce a set of statements with dialect
     pub fn parse_sql(sql: &str) -> Result<(Vec<DfStatement>, Vec<DfHint>), ErrorCode> {
         let dialect = &GenericDialect {};
@@ -190,6 +419,10 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         self.parse_drop()
                     }
+                    Keyword::RENAME => {
+                        self.parser.next_token();
+                        self.parse_rename()
+                    }
                     Keyword::EXPLAIN => {
                         self.parser.next_token();
                         self.parse_explain()
@@ -197,6 +430,9 @@ impl<'a> DfParser<'a> {
                     Keyword::SHOW => {
                         self.parser.next_token();
                         if self.consume_token("TABLES") {
+                            if self.consume_token("HISTORY") {
+                                return self.parse_show_tables_history();
+                            }
                             let tok = self.parser.next_token();
                             match &tok {
                                 Token::EOF | Token::SemiColon => {
@@ -253,6 +489,10 @@ impl<'a> DfParser<'a> {
                         "USE" => self.parse_use_database(),
                         "KILL" => self.parse_kill_query(),
                         "COMPACT" => self.parse_compact(),
+                        "UNDROP" => {
+                            self.parser.next_token();
+                            self.parse_undrop()
+                        }
                         _ => self.expected("Keyword", self.parser.peek_token()),
                     },
                     _ => self.expected("an SQL statement", Token::Word(w)),
@@ -331,6 +571,10 @@ impl<'a> DfParser<'a> {
                     self.parser.next_token();
                     ExplainType::Graph
                 }
+                "ANALYZE" => {
+                    self.parser.next_token();
+                    ExplainType::Analyze
+                }
                 _ => ExplainType::Syntax,
             },
             _ => ExplainType::Syntax,
@@ -436,9 +680,117 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // The parameters of the custom types, like DateTime64(6, 'Asia/Shanghai'), are unknown to
+    // the sql parser, they are kept as the extra parts of the custom type name.
+    // The element type of Array(T) is parsed recursively.
+    fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
+        match self.parser.parse_data_type()? {
+            DataType::Custom(name)
+                if name.0.len() == 1
+                    && name.0[0].value.eq_ignore_ascii_case("array")
+                    && self.parser.consume_token(&Token::LParen) =>
+            {
+                let inner = self.parse_data_type()?;
+                self.parser.expect_token(&Token::RParen)?;
+                Ok(DataType::Array(Box::new(inner)))
+            }
+            // Map(K, V), the nested types are kept as their texts in the custom type
+            DataType::Custom(mut name)
+                if name.0.len() == 1
+                    && name.0[0].value.eq_ignore_ascii_case("map")
+                    && self.parser.consume_token(&Token::LParen) =>
+            {
+                let key = self.parse_data_type()?;
+                self.parser.expect_token(&Token::Comma)?;
+                let value = self.parse_data_type()?;
+                self.parser.expect_token(&Token::RParen)?;
+                name.0.push(Ident::new(DfParser::data_type_sql(&key)));
+                name.0.push(Ident::new(DfParser::data_type_sql(&value)));
+                Ok(DataType::Custom(name))
+            }
+            // LowCardinality(T), the inner type is kept as its text in the custom type
+            DataType::Custom(mut name)
+                if name.0.len() == 1
+                    && name.0[0].value.eq_ignore_ascii_case("lowcardinality")
+                    && self.parser.consume_token(&Token::LParen) =>
+            {
+                let inner = self.parse_data_type()?;
+                self.parser.expect_token(&Token::RParen)?;
+                name.0.push(Ident::new(DfParser::data_type_sql(&inner)));
+                Ok(DataType::Custom(name))
+            }
+            // Struct(a T, b U), each member is kept as `name type` in the custom type
+            DataType::Custom(mut name)
+                if name.0.len() == 1
+                    && (name.0[0].value.eq_ignore_ascii_case("struct")
+                        || name.0[0].value.eq_ignore_ascii_case("tuple"))
+                    && self.parser.consume_token(&Token::LParen) =>
+            {
+                loop {
+                    let member = self.parser.parse_identifier()?;
+                    let data_type = self.parse_data_type()?;
+                    name.0.push(Ident::new(format!(
+                        "{} {}",
+                        member,
+                        DfParser::data_type_sql(&data_type)
+                    )));
+                    if !self.parser.consume_token(&Token::Comma) {
+                        break;
+                    }
+                }
+                self.parser.expect_token(&Token::RParen)?;
+                Ok(DataType::Custom(name))
+            }
+            DataType::Custom(mut name) if self.parser.consume_token(&Token::LParen) => {
+                loop {
+                    match self.parser.next_token() {
+                        Token::Number(n, _) => name.0.push(Ident::new(n)),
+                        Token::SingleQuotedString(s) => name.0.push(Ident::with_quote('\'', s)),
+                        unexpected => return self.expected("type parameter", unexpected),
+                    }
+                    if !self.parser.consume_token(&Token::Comma) {
+                        break;
+                    }
+                }
+                self.parser.expect_token(&Token::RParen)?;
+                Ok(DataType::Custom(name))
+            }
+            data_type => Ok(data_type),
+        }
+    }
+
+    /// The SQL text of the parsed data type, which can be parsed again by `parse_data_type`.
+    fn data_type_sql(data_type: &DataType) -> String {
+        match data_type {
+            DataType::Array(inner) => format!("Array({})", DfParser::data_type_sql(inner)),
+            DataType::Custom(name) if name.0.len() > 1 => {
+                let params = name.0[1..]
+                    .iter()
+                    .map(|param| param.to_string())
+                    .collect::<Vec<_>>();
+                format!("{}({})", name.0[0], params.join(", "))
+            }
+            data_type => data_type.to_string(),
+        }
+    }
+
+    /// Parse the nested type of the composite types, like the `K` of `Map(K, V)`.
+    pub fn parse_nested_data_type(sql: &str) -> Result<DataType, ParserError> {
+        let mut parser = DfParser::new(sql)?;
+        parser.parse_data_type()
+    }
+
+    /// Parse the member `name type` of the struct types.
+    pub fn parse_struct_member(sql: &str) -> Result<(Ident, DataType), ParserError> {
+        let mut parser = DfParser::new(sql)?;
+        let name = parser.parser.parse_identifier()?;
+        let data_type = parser.parse_data_type()?;
+        Ok((name, data_type))
+    }
+
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
         let name = self.parser.parse_identifier()?;
-        let data_type = self.parser.parse_data_type()?;
+        let data_type = self.parse_data_type()?;
         let collation = if self.parser.parse_keyword(Keyword::COLLATE) {
             Some(self.parser.parse_object_name()?)
         } else {
@@ -476,11 +828,18 @@ impl<'a> DfParser<'a> {
                 //TODO:make stage to sql parser keyword
                 if w.value.to_uppercase() == "STAGE" {
                     self.parse_create_stage()
+                } else if w.value.to_uppercase() == "MATERIALIZED" {
+                    self.parse_create_materialized_view()
                 } else {
                     match w.keyword {
-                        Keyword::TABLE => self.parse_create_table(),
+                        Keyword::TABLE => self.parse_create_table(false),
+                        Keyword::TEMPORARY => {
+                            self.parser.expect_keyword(Keyword::TABLE)?;
+                            self.parse_create_table(true)
+                        }
                         Keyword::DATABASE => self.parse_create_database(),
                         Keyword::USER => self.parse_create_user(),
+                        Keyword::VIEW => self.parse_create_view(),
                         _ => self.expected("create statement", Token::Word(w)),
                     }
                 }
@@ -493,12 +852,80 @@ impl<'a> DfParser<'a> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::USER => self.parse_alter_user(),
+                Keyword::TABLE => self.parse_alter_table(),
+                Keyword::DATABASE => self.parse_alter_database(),
                 _ => self.expected("alter statement", Token::Word(w)),
             },
             unexpected => self.expected("alter statement", unexpected),
         }
     }
 
+    fn parse_alter_table(&mut self) -> Result<DfStatement, ParserError> {
+        let table_name = self.parser.parse_object_name()?;
+        let operation = if self.parser.parse_keyword(Keyword::ADD) {
+            let _ = self.parser.parse_keyword(Keyword::COLUMN);
+            DfAlterTableOperation::AddColumn(self.parse_column_def()?)
+        } else if self
+            .parser
+            .parse_keywords(&[Keyword::DROP, Keyword::PARTITION])
+        {
+            DfAlterTableOperation::DropPartition(self.parser.parse_expr()?)
+        } else if self.parser.parse_keyword(Keyword::DROP) {
+            let _ = self.parser.parse_keyword(Keyword::COLUMN);
+            DfAlterTableOperation::DropColumn(self.parser.parse_identifier()?)
+        } else if self
+            .parser
+            .parse_keywords(&[Keyword::RENAME, Keyword::COLUMN])
+        {
+            let old_name = self.parser.parse_identifier()?;
+            self.parser.expect_keyword(Keyword::TO)?;
+            let new_name = self.parser.parse_identifier()?;
+            DfAlterTableOperation::RenameColumn { old_name, new_name }
+        } else if self.consume_token("MODIFY") {
+            let _ = self.parser.parse_keyword(Keyword::COLUMN);
+            DfAlterTableOperation::ModifyColumn(self.parse_column_def()?)
+        } else {
+            return self.expected(
+                "ADD, DROP, RENAME COLUMN or MODIFY after ALTER TABLE",
+                self.parser.peek_token(),
+            );
+        };
+
+        Ok(DfStatement::AlterTable(DfAlterTable {
+            name: table_name,
+            operation,
+        }))
+    }
+
+    fn parse_alter_database(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let db_name = self.parser.parse_object_name()?;
+        self.parser
+            .expect_keywords(&[Keyword::RENAME, Keyword::TO])?;
+        let new_db_name = self.parser.parse_object_name()?;
+
+        Ok(DfStatement::RenameDatabase(DfRenameDatabase {
+            if_exists,
+            name: db_name,
+            new_name: new_db_name,
+        }))
+    }
+
+    /// Rename table.
+    fn parse_rename(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let table_name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::TO)?;
+        let new_table_name = self.parser.parse_object_name()?;
+
+        Ok(DfStatement::RenameTable(DfRenameTable {
+            if_exists,
+            name: table_name,
+            new_name: new_table_name,
+        }))
+    }
+
     fn parse_create_database(&mut self) -> Result<DfStatement, ParserError> {
         let if_not_exists =
             self.parser
@@ -529,6 +956,7 @@ impl<'a> DfParser<'a> {
                 Keyword::DATABASE => self.parse_drop_database(),
                 Keyword::TABLE => self.parse_drop_table(),
                 Keyword::USER => self.parse_drop_user(),
+                Keyword::VIEW => self.parse_drop_view(),
                 _ => self.expected("drop statement", Token::Word(w)),
             },
             unexpected => self.expected("drop statement", unexpected),
@@ -561,6 +989,44 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropTable(drop))
     }
 
+    /// Drop view.
+    fn parse_drop_view(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let view_name = self.parser.parse_object_name()?;
+
+        let drop = DfDropView {
+            if_exists,
+            name: view_name,
+        };
+
+        Ok(DfStatement::DropView(drop))
+    }
+
+    /// Undrop table.
+    fn parse_undrop(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let table_name = self.parser.parse_object_name()?;
+
+        Ok(DfStatement::UndropTable(DfUndropTable { name: table_name }))
+    }
+
+    // Parse 'SHOW TABLES HISTORY [FROM | IN db]'.
+    fn parse_show_tables_history(&mut self) -> Result<DfStatement, ParserError> {
+        let tok = self.parser.next_token();
+        match &tok {
+            Token::EOF | Token::SemiColon => {
+                Ok(DfStatement::ShowTables(DfShowTables::History(None)))
+            }
+            Token::Word(w) => match w.keyword {
+                Keyword::FROM | Keyword::IN => Ok(DfStatement::ShowTables(DfShowTables::History(
+                    Some(self.parser.parse_object_name()?),
+                ))),
+                _ => self.expected("from or in", tok),
+            },
+            _ => self.expected("from or in", tok),
+        }
+    }
+
     // Parse 'use database' db name.
     fn parse_use_database(&mut self) -> Result<DfStatement, ParserError> {
         if !self.consume_token("USE") {
@@ -836,7 +1302,7 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateStage(create))
     }
 
-    fn parse_create_table(&mut self) -> Result<DfStatement, ParserError> {
+    fn parse_create_table(&mut self, temporary: bool) -> Result<DfStatement, ParserError> {
         let if_not_exists =
             self.parser
                 .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
@@ -854,23 +1320,93 @@ impl<'a> DfParser<'a> {
             return parser_err!("mix create table like statement and column definition.");
         }
 
-        let engine = self.parse_table_engine()?;
+        // Temporary tables live in the memory of the session.
+        let engine = match temporary {
+            true => self.parse_table_engine("MEMORY")?,
+            false => self.parse_table_engine("FUSE")?,
+        };
+
+        let mut partition_by = None;
+        if self
+            .parser
+            .parse_keywords(&[Keyword::PARTITION, Keyword::BY])
+        {
+            partition_by = Some(self.parser.parse_expr()?);
+        }
+
+        let mut ttl = None;
+        if self.consume_token("TTL") {
+            ttl = Some(self.parser.parse_expr()?);
+        }
 
         // parse table options: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
         let options = self.parse_options()?;
 
+        // Parse the query to load the table from. This is for create table as select statement.
+        // https://dev.mysql.com/doc/refman/8.0/en/create-table-select.html
+        let mut query = None;
+        if self.parser.parse_keyword(Keyword::AS) {
+            if table_like.is_some() {
+                return parser_err!("mix create table like statement and select statement.");
+            }
+            let native_query = self.parser.parse_query()?;
+            query = Some(Box::new(DfQueryStatement::try_from(native_query)?));
+        }
+
         let create = DfCreateTable {
             if_not_exists,
+            temporary,
             name: table_name,
             columns,
             engine,
+            partition_by,
+            ttl,
             options,
             like: table_like,
+            query,
         };
 
         Ok(DfStatement::CreateTable(create))
     }
 
+    fn parse_create_view(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let view_name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::AS)?;
+        let query = Box::new(self.parser.parse_query()?);
+
+        let create = DfCreateView {
+            if_not_exists,
+            name: view_name,
+            query,
+        };
+
+        Ok(DfStatement::CreateView(create))
+    }
+
+    fn parse_create_materialized_view(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::VIEW)?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let view_name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::TO)?;
+        let target = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::AS)?;
+        let query = Box::new(self.parser.parse_query()?);
+
+        let create = DfCreateMaterializedView {
+            if_not_exists,
+            name: view_name,
+            target,
+            query,
+        };
+
+        Ok(DfStatement::CreateMaterializedView(create))
+    }
+
     fn parse_database_engine(&mut self) -> Result<String, ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
@@ -882,10 +1418,10 @@ impl<'a> DfParser<'a> {
     }
 
     /// Parses the set of valid formats
-    fn parse_table_engine(&mut self) -> Result<String, ParserError> {
+    fn parse_table_engine(&mut self, default_engine: &str) -> Result<String, ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
-            return Ok("FUSE".to_string());
+            return Ok(default_engine.to_string());
         }
 
         self.parser.expect_token(&Token::Eq)?;
@@ -901,6 +1437,12 @@ impl<'a> DfParser<'a> {
                     let show_create_table = DfShowCreateTable { name: table_name };
                     Ok(DfStatement::ShowCreateTable(show_create_table))
                 }
+                Keyword::VIEW => {
+                    let view_name = self.parser.parse_object_name()?;
+
+                    let show_create_view = DfShowCreateTable { name: view_name };
+                    Ok(DfStatement::ShowCreateTable(show_create_view))
+                }
                 _ => self.expected("show create statement", Token::Word(w)),
             },
             unexpected => self.expected("show create statement", unexpected),
@@ -1065,6 +1607,12 @@ impl<'a> DfParser<'a> {
     fn parse_options(&mut self) -> Result<Vec<SqlOption>, ParserError> {
         let mut options = vec![];
         loop {
+            // The options end where the query of create table as select statement starts.
+            if let Token::Word(w) = self.parser.peek_token() {
+                if w.keyword == Keyword::AS {
+                    break;
+                }
+            }
             let name = self.parser.parse_identifier();
             if name.is_err() {
                 break;
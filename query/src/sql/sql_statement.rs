@@ -20,21 +20,27 @@ use nom::character::complete::multispace1;
 use nom::IResult;
 
 use super::statements::DfCopy;
+use crate::sql::statements::DfAlterTable;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCompactTable;
 use crate::sql::statements::DfCreateDatabase;
+use crate::sql::statements::DfCreateMaterializedView;
 use crate::sql::statements::DfCreateStage;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfCreateView;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUser;
+use crate::sql::statements::DfDropView;
 use crate::sql::statements::DfExplain;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRenameDatabase;
+use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfRevokeStatement;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateTable;
@@ -45,6 +51,7 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUndropTable;
 use crate::sql::statements::DfUseDatabase;
 
 /// Tokens parsed by `DFParser` are converted into these values.
@@ -58,6 +65,7 @@ pub enum DfStatement {
     ShowDatabases(DfShowDatabases),
     CreateDatabase(DfCreateDatabase),
     DropDatabase(DfDropDatabase),
+    RenameDatabase(DfRenameDatabase),
     UseDatabase(DfUseDatabase),
 
     // Tables.
@@ -66,9 +74,17 @@ pub enum DfStatement {
     CreateTable(DfCreateTable),
     DescribeTable(DfDescribeTable),
     DropTable(DfDropTable),
+    RenameTable(DfRenameTable),
+    UndropTable(DfUndropTable),
     TruncateTable(DfTruncateTable),
+    AlterTable(DfAlterTable),
     CompactTable(DfCompactTable),
 
+    // Views.
+    CreateView(DfCreateView),
+    DropView(DfDropView),
+    CreateMaterializedView(DfCreateMaterializedView),
+
     // Settings.
     ShowSettings(DfShowSettings),
 
@@ -15,6 +15,10 @@
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+use common_datavalues::prelude::create_datetime64_type;
+use common_datavalues::prelude::parse_variant_path;
+use common_datavalues::prelude::DATETIME64_MAX_PRECISION;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
@@ -62,6 +66,9 @@ impl ExpressionAnalyzer {
                 ExprRPNItem::Subquery(v) => self.analyze_scalar_subquery(v, &mut stack).await?,
                 ExprRPNItem::Cast(v) => self.analyze_cast(v, &mut stack)?,
                 ExprRPNItem::Between(negated) => self.analyze_between(*negated, &mut stack)?,
+                ExprRPNItem::InSubquery(v, negated) => {
+                    self.analyze_in_subquery(v, *negated, &mut stack).await?
+                }
             }
         }
 
@@ -131,6 +138,22 @@ impl ExpressionAnalyzer {
     }
 
     fn function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+        if info.name.eq_ignore_ascii_case("toDateTime64") {
+            return self.to_datetime64(args);
+        }
+
+        // The variant access `v:a.b[0]` is parsed as get_path(v, 'a.b[0]'), check the path early.
+        if info.name.eq_ignore_ascii_case("get_path") {
+            if let Some(Expression::Literal {
+                value: DataValue::String(Some(path)),
+                ..
+            }) = args.get(1)
+            {
+                parse_variant_path(&String::from_utf8_lossy(path))
+                    .map_err(|e| ErrorCode::SyntaxException(e.message()))?;
+            }
+        }
+
         let query_context = self.context.clone();
         let context_args = ContextFunction::build_args_from_ctx(&info.name, query_context)?;
 
@@ -153,6 +176,72 @@ impl ExpressionAnalyzer {
         }
     }
 
+    // toDateTime64(expr[, precision[, 'timezone']]) is the cast to DateTime64(precision, timezone).
+    fn to_datetime64(&self, args: &[Expression]) -> Result<Expression> {
+        if args.is_empty() || args.len() > 3 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function toDateTime64 expect 1 to 3 arguments, but got {}",
+                args.len()
+            )));
+        }
+
+        let precision = match args.get(1) {
+            None => 3,
+            Some(Expression::Literal { value, .. }) => match value.as_u64() {
+                Ok(precision) if precision <= DATETIME64_MAX_PRECISION as u64 => precision as u32,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The precision of toDateTime64 must be between 0 and {}, but got {}",
+                        DATETIME64_MAX_PRECISION, value
+                    )))
+                }
+            },
+            Some(other) => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "The precision of toDateTime64 must be a constant, but got {:?}",
+                    other
+                )))
+            }
+        };
+
+        let tz = match args.get(2) {
+            None => None,
+            Some(Expression::Literal { value, .. }) => Some(
+                String::from_utf8(value.as_string()?)
+                    .map_err(|_| ErrorCode::BadBytes("The timezone of toDateTime64 is not utf8"))?,
+            ),
+            Some(other) => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "The timezone of toDateTime64 must be a constant, but got {:?}",
+                    other
+                )))
+            }
+        };
+
+        let data_type = create_datetime64_type(precision, tz)?;
+        Ok(Expression::Cast {
+            expr: Box::new(args[0].clone()),
+            data_type: self.with_session_timezone(data_type)?,
+        })
+    }
+
+    // The DateTime64 without timezone is converted in the timezone of the session.
+    fn with_session_timezone(
+        &self,
+        data_type: common_datavalues::DataType,
+    ) -> Result<common_datavalues::DataType> {
+        match data_type {
+            common_datavalues::DataType::DateTime64(precision, None) => {
+                let tz = self.context.get_settings().get_timezone()?;
+                match tz.eq_ignore_ascii_case("UTC") {
+                    true => Ok(common_datavalues::DataType::DateTime64(precision, None)),
+                    false => Ok(common_datavalues::DataType::DateTime64(precision, Some(tz))),
+                }
+            }
+            other => Ok(other),
+        }
+    }
+
     fn aggr_function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
         let mut parameters = Vec::with_capacity(info.parameters.len());
 
@@ -216,6 +305,29 @@ impl ExpressionAnalyzer {
         Ok(())
     }
 
+    async fn analyze_in_subquery(
+        &self,
+        subquery: &Query,
+        negated: bool,
+        args: &mut Vec<Expression>,
+    ) -> Result<()> {
+        let expression = match args.pop() {
+            None => return Err(ErrorCode::LogicalError("In operator must be one children.")),
+            Some(expression) => expression,
+        };
+
+        let subquery = self.analyze_subquery(subquery).await?;
+        args.push(Expression::ScalarFunction {
+            op: String::from(if negated {
+                "not_in_subquery"
+            } else {
+                "in_subquery"
+            }),
+            args: vec![expression, subquery],
+        });
+        Ok(())
+    }
+
     async fn analyze_subquery(&self, subquery: &Query) -> Result<Expression> {
         let statement = DfQueryStatement::try_from(subquery.clone())?;
 
@@ -281,7 +393,7 @@ impl ExpressionAnalyzer {
             Some(inner_expr) => {
                 args.push(Expression::Cast {
                     expr: Box::new(inner_expr),
-                    data_type: data_type.clone(),
+                    data_type: self.with_session_timezone(data_type.clone())?,
                 });
                 Ok(())
             }
@@ -335,6 +447,7 @@ enum ExprRPNItem {
     Subquery(Box<Query>),
     Cast(common_datavalues::DataType),
     Between(bool),
+    InSubquery(Box<Query>, bool),
 }
 
 impl ExprRPNItem {
@@ -415,6 +528,22 @@ impl ExprRPNBuilder {
                 high,
             } => self.visit_between(expr, negated, low, high),
             Expr::Tuple(exprs) => self.visit_tuple(exprs),
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => self.visit_in_list(expr, list, *negated),
+            Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => self.visit_in_subquery(expr, subquery, *negated),
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => self.visit_case(operand, conditions, results, else_result),
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -524,7 +653,15 @@ impl ExprRPNBuilder {
     fn visit_binary_expr(&mut self, left: &Expr, op: &BinaryOperator, right: &Expr) -> Result<()> {
         self.visit(left)?;
         self.visit(right)?;
-        self.rpn.push(ExprRPNItem::binary_operator(op.to_string()));
+        match op {
+            BinaryOperator::PGRegexMatch => self
+                .rpn
+                .push(ExprRPNItem::binary_operator("REGEXP".to_string())),
+            BinaryOperator::PGRegexNotMatch => self
+                .rpn
+                .push(ExprRPNItem::binary_operator("NOT REGEXP".to_string())),
+            _ => self.rpn.push(ExprRPNItem::binary_operator(op.to_string())),
+        }
         Ok(())
     }
 
@@ -542,6 +679,69 @@ impl ExprRPNBuilder {
         Ok(())
     }
 
+    fn visit_in_list(&mut self, expr: &Expr, list: &[Expr], negated: bool) -> Result<()> {
+        if list.is_empty() {
+            return Err(ErrorCode::SyntaxException(
+                "The list of IN must have at least one element.",
+            ));
+        }
+
+        self.visit(expr)?;
+        for value in list {
+            self.visit(value)?;
+        }
+
+        let name = String::from(if negated { "not in" } else { "in" });
+        self.rpn.push(ExprRPNItem::function(name, list.len() + 1));
+        Ok(())
+    }
+
+    fn visit_in_subquery(&mut self, expr: &Expr, subquery: &Query, negated: bool) -> Result<()> {
+        self.visit(expr)?;
+        self.rpn
+            .push(ExprRPNItem::InSubquery(Box::new(subquery.clone()), negated));
+        Ok(())
+    }
+
+    // CASE [operand] WHEN c1 THEN r1 WHEN c2 THEN r2 ELSE r3 END is multiIf(c1, r1, c2, r2, r3),
+    // the operand is compared with each condition, the types of the results are aggregated.
+    fn visit_case(
+        &mut self,
+        operand: &Option<Box<Expr>>,
+        conditions: &[Expr],
+        results: &[Expr],
+        else_result: &Option<Box<Expr>>,
+    ) -> Result<()> {
+        if conditions.is_empty() || conditions.len() != results.len() {
+            return Err(ErrorCode::SyntaxException(
+                "Case must have at least one WHEN and a THEN for each WHEN.",
+            ));
+        }
+
+        for (condition, result) in conditions.iter().zip(results.iter()) {
+            match operand {
+                None => self.visit(condition)?,
+                Some(operand) => {
+                    self.visit(operand)?;
+                    self.visit(condition)?;
+                    self.rpn
+                        .push(ExprRPNItem::binary_operator(String::from("=")));
+                }
+            };
+            self.visit(result)?;
+        }
+
+        match else_result {
+            None => self.rpn.push(ExprRPNItem::Value(Value::Null)),
+            Some(else_result) => self.visit(else_result)?,
+        };
+
+        let name = String::from("multiIf");
+        self.rpn
+            .push(ExprRPNItem::function(name, conditions.len() * 2 + 1));
+        Ok(())
+    }
+
     fn visit_position(&mut self, substr_expr: &Expr, str_expr: &Expr) -> Result<()> {
         self.visit(substr_expr)?;
         self.visit(str_expr)?;
@@ -17,21 +17,27 @@ pub mod query;
 mod analyzer_expr;
 mod analyzer_statement;
 mod analyzer_value_expr;
+mod statement_alter_table;
 mod statement_alter_user;
 mod statement_compact_table;
 mod statement_copy;
 mod statement_create_database;
+mod statement_create_materialized_view;
 mod statement_create_stage;
 mod statement_create_table;
 mod statement_create_user;
+mod statement_create_view;
 mod statement_describe_table;
 mod statement_drop_database;
 mod statement_drop_table;
 mod statement_drop_user;
+mod statement_drop_view;
 mod statement_explain;
 mod statement_grant;
 mod statement_insert;
 mod statement_kill;
+mod statement_rename_database;
+mod statement_rename_table;
 mod statement_revoke;
 mod statement_select;
 mod statement_select_convert;
@@ -44,6 +50,7 @@ mod statement_show_settings;
 mod statement_show_tables;
 mod statement_show_users;
 mod statement_truncate_table;
+mod statement_undrop_table;
 mod statement_use_database;
 
 pub use analyzer_statement::AnalyzableStatement;
@@ -51,22 +58,29 @@ pub use analyzer_statement::AnalyzedResult;
 pub use analyzer_statement::QueryAnalyzeState;
 pub use analyzer_statement::QueryRelation;
 pub use query::QueryASTIR;
+pub use statement_alter_table::DfAlterTable;
+pub use statement_alter_table::DfAlterTableOperation;
 pub use statement_alter_user::DfAlterUser;
 pub use statement_compact_table::DfCompactTable;
 pub use statement_copy::DfCopy;
 pub use statement_create_database::DfCreateDatabase;
+pub use statement_create_materialized_view::DfCreateMaterializedView;
 pub use statement_create_stage::DfCreateStage;
 pub use statement_create_table::DfCreateTable;
 pub use statement_create_user::DfCreateUser;
+pub use statement_create_view::DfCreateView;
 pub use statement_describe_table::DfDescribeTable;
 pub use statement_drop_database::DfDropDatabase;
 pub use statement_drop_table::DfDropTable;
 pub use statement_drop_user::DfDropUser;
+pub use statement_drop_view::DfDropView;
 pub use statement_explain::DfExplain;
 pub use statement_grant::DfGrantObject;
 pub use statement_grant::DfGrantStatement;
 pub use statement_insert::DfInsertStatement;
 pub use statement_kill::DfKillStatement;
+pub use statement_rename_database::DfRenameDatabase;
+pub use statement_rename_table::DfRenameTable;
 pub use statement_revoke::DfRevokeStatement;
 pub use statement_select::DfQueryStatement;
 pub use statement_set_variable::DfSetVariable;
@@ -78,4 +92,5 @@ pub use statement_show_settings::DfShowSettings;
 pub use statement_show_tables::DfShowTables;
 pub use statement_show_users::DfShowUsers;
 pub use statement_truncate_table::DfTruncateTable;
+pub use statement_undrop_table::DfUndropTable;
 pub use statement_use_database::DfUseDatabase;
@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
@@ -57,6 +59,13 @@ impl QueryASTIRVisitor<QualifiedRewriter> for QualifiedRewriter {
 
             match projection_expr {
                 Expression::Wildcard => Self::expand_wildcard(data, &mut new_exprs),
+                Expression::QualifiedColumn(names) => {
+                    // the struct field access is named as it's written
+                    match Self::rewrite_qualified_column(data, names)? {
+                        column @ Expression::Column(_) => new_exprs.push(column),
+                        expr => new_exprs.push(Expression::Alias(names.join("."), Box::new(expr))),
+                    }
+                }
                 _ => {
                     Self::visit_recursive_expr(projection_expr, data)?;
                     new_exprs.push(projection_expr.clone());
@@ -105,35 +114,86 @@ impl QualifiedRewriter {
     }
 
     fn rewrite_qualified_column(&self, ref_names: &[String]) -> Result<Expression> {
-        match self.best_match_table(ref_names) {
-            None => Err(ErrorCode::UnknownColumn(format!(
-                "Unknown column {}",
-                ref_names.join(".")
-            ))),
+        let (column, data_type, field_names) = match self.best_match_table(ref_names) {
+            // column.field_a.field_b
+            None => match self.find_column_in_tables(&ref_names[0])? {
+                Some((column, data_type)) => (column, data_type, &ref_names[1..]),
+                None => {
+                    return Err(ErrorCode::UnknownColumn(format!(
+                        "Unknown column {}",
+                        ref_names.join(".")
+                    )));
+                }
+            },
             Some((pos, table_ref)) => {
                 let column_name = &ref_names[pos..];
-                match column_name.len() {
-                    1 => Self::find_column(&table_ref, &column_name[0]),
-                    // TODO: column.field_a.field_b => GetField(field_b, GetField(field_a, column))
-                    _ => Err(ErrorCode::SyntaxException(
-                        "Unsupported complex type field access",
-                    )),
-                }
+                let (column, data_type) = Self::find_column(&table_ref, &column_name[0])?;
+                (column, data_type, &column_name[1..])
             }
+        };
+
+        Self::rewrite_struct_field(column, data_type, field_names)
+    }
+
+    /// column.field_a.field_b => get_field(get_field(column, 'field_a'), 'field_b')
+    fn rewrite_struct_field(
+        mut expr: Expression,
+        mut data_type: DataType,
+        field_names: &[String],
+    ) -> Result<Expression> {
+        for field_name in field_names {
+            let field = match &data_type {
+                DataType::Struct(fields) => fields.iter().find(|f| f.name() == field_name),
+                _ => None,
+            };
+
+            let field = match field {
+                Some(field) => field.clone(),
+                None => {
+                    return Err(ErrorCode::UnknownColumn(format!(
+                        "Unknown field {} of {:?}",
+                        field_name, data_type
+                    )));
+                }
+            };
+
+            data_type = field.data_type().clone();
+            expr = Expression::create_scalar_function("get_field", vec![
+                expr,
+                Expression::create_literal(DataValue::String(Some(field_name.as_bytes().to_vec()))),
+            ]);
         }
+
+        Ok(expr)
     }
 
-    fn find_column(table_desc: &JoinedTableDesc, name: &str) -> Result<Expression> {
+    fn find_column_in_tables(&self, name: &str) -> Result<Option<(Expression, DataType)>> {
+        let mut columns = self
+            .tables_schema
+            .get_tables_desc()
+            .iter()
+            .filter_map(|table_desc| Self::find_column(table_desc, name).ok());
+
+        match (columns.next(), columns.next()) {
+            (Some(_), Some(_)) => Err(ErrorCode::SyntaxException(format!(
+                "Column {} is ambiguous, it's in more than one of the joined tables",
+                name
+            ))),
+            (column, _) => Ok(column),
+        }
+    }
+
+    fn find_column(table_desc: &JoinedTableDesc, name: &str) -> Result<(Expression, DataType)> {
         let name_parts = table_desc.get_name_parts();
         for column_desc in table_desc.get_columns_desc() {
             if column_desc.short_name == name {
+                let data_type = column_desc.data_type.clone();
                 return match column_desc.is_ambiguity {
-                    true => Ok(Expression::Column(format!(
-                        "{}.{}",
-                        name_parts.join("."),
-                        name
-                    ))),
-                    false => Ok(Expression::Column(name.to_string())),
+                    true => Ok((
+                        Expression::Column(format!("{}.{}", name_parts.join("."), name)),
+                        data_type,
+                    )),
+                    false => Ok((Expression::Column(name.to_string()), data_type)),
                 };
             }
         }
@@ -32,6 +32,10 @@ use crate::sql::statements::query::query_schema_joined::JoinedSchema;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+use crate::storages::view::MaterializedViewTable;
+use crate::storages::view::ViewTable;
 
 pub struct JoinedSchemaAnalyzer {
     ctx: Arc<QueryContext>,
@@ -96,7 +100,21 @@ impl JoinedSchemaAnalyzer {
     async fn table(&self, item: &TableRPNItem) -> Result<JoinedSchema> {
         // TODO(Winter): await query_context.get_table
         let (database, table) = self.resolve_table(&item.name)?;
-        let read_table = self.ctx.get_table(&database, &table).await?;
+        let mut read_table = self.ctx.get_table(&database, &table).await?;
+
+        // The materialized view is read from its target table.
+        if let Some(view) = read_table.as_any().downcast_ref::<MaterializedViewTable>() {
+            let (target_db, target_table) = view.get_target();
+            read_table = self.ctx.get_table(target_db, target_table).await?;
+        }
+
+        if let Some(view) = read_table.as_any().downcast_ref::<ViewTable>() {
+            let name_prefix = match &item.alias {
+                None => vec![database, table],
+                Some(table_alias) => vec![table_alias.name.value.clone()],
+            };
+            return self.view(view, name_prefix).await;
+        }
 
         match &item.alias {
             None => {
@@ -110,6 +128,45 @@ impl JoinedSchemaAnalyzer {
         }
     }
 
+    // The view is expanded as a subquery.
+    async fn view(&self, view: &ViewTable, name_prefix: Vec<String>) -> Result<JoinedSchema> {
+        // A view may be replaced to reference itself, directly or through other views.
+        if !self.ctx.enter_view(view.get_id()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "View {} references itself",
+                view.name()
+            )));
+        }
+
+        let expanded = self.expand_view(view, name_prefix).await;
+        self.ctx.leave_view(view.get_id());
+        expanded
+    }
+
+    async fn expand_view(
+        &self,
+        view: &ViewTable,
+        name_prefix: Vec<String>,
+    ) -> Result<JoinedSchema> {
+        let (mut statements, _) = DfParser::parse_sql(view.get_query())?;
+        let subquery = match statements.pop() {
+            Some(DfStatement::Query(subquery)) if statements.is_empty() => subquery,
+            _ => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Logical error, view {} must be defined by a single query, it's a bug.",
+                    view.name()
+                )))
+            }
+        };
+
+        match subquery.analyze(self.ctx.clone()).await? {
+            AnalyzedResult::SelectQuery(state) => JoinedSchema::from_subquery(state, name_prefix),
+            _ => Err(ErrorCode::LogicalError(
+                "Logical error, view analyzed data must be SelectQuery, it's a bug.",
+            )),
+        }
+    }
+
     async fn table_function(&self, item: &TableFunctionRPNItem) -> Result<JoinedSchema> {
         if item.name.0.len() >= 2 {
             return Result::Err(ErrorCode::BadArguments(
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::AlterTableOperation;
+use common_planners::AlterTablePlan;
+use common_planners::Expression;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ColumnDef;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use super::analyzer_expr::ExpressionAnalyzer;
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfCreateTable;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfAlterTableOperation {
+    AddColumn(ColumnDef),
+    DropColumn(Ident),
+    RenameColumn { old_name: Ident, new_name: Ident },
+    ModifyColumn(ColumnDef),
+    DropPartition(Expr),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterTable {
+    pub name: ObjectName,
+    pub operation: DfAlterTableOperation,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfAlterTable {
+    #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (db, table) = self.resolve_table(ctx.clone())?;
+        let expr_analyzer = ExpressionAnalyzer::create(ctx);
+        let operation = match &self.operation {
+            DfAlterTableOperation::AddColumn(column) => AlterTableOperation::AddColumn(
+                DfCreateTable::column_field(&expr_analyzer, column).await?,
+            ),
+            DfAlterTableOperation::DropColumn(name) => {
+                AlterTableOperation::DropColumn(name.value.clone())
+            }
+            DfAlterTableOperation::RenameColumn { old_name, new_name } => {
+                AlterTableOperation::RenameColumn {
+                    old_name: old_name.value.clone(),
+                    new_name: new_name.value.clone(),
+                }
+            }
+            DfAlterTableOperation::ModifyColumn(column) => AlterTableOperation::ModifyColumn(
+                DfCreateTable::column_field(&expr_analyzer, column).await?,
+            ),
+            DfAlterTableOperation::DropPartition(partition) => {
+                match expr_analyzer.analyze(partition).await? {
+                    Expression::Literal { value, .. } => AlterTableOperation::DropPartition(value),
+                    _ => {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "Partition must be a literal value, but got {}",
+                            partition
+                        )))
+                    }
+                }
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterTable(
+            AlterTablePlan {
+                db,
+                table,
+                operation,
+            },
+        ))))
+    }
+}
+
+impl DfAlterTable {
+    fn resolve_table(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let DfAlterTable {
+            name: ObjectName(idents),
+            ..
+        } = self;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Alter table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Alter table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableMeta;
+use common_planners::CreateTablePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
+use sqlparser::ast::SetExpr;
+use sqlparser::ast::TableFactor;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfCreateView;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+use crate::storages::view::MATERIALIZED_VIEW_ENGINE;
+use crate::storages::view::MATERIALIZED_VIEW_SOURCE_TABLE_ID_KEY;
+use crate::storages::view::MATERIALIZED_VIEW_TARGET_DATABASE_KEY;
+use crate::storages::view::MATERIALIZED_VIEW_TARGET_TABLE_KEY;
+use crate::storages::view::VIEW_QUERY_KEY;
+use crate::storages::Table;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateMaterializedView {
+    pub if_not_exists: bool,
+    /// View name
+    pub name: ObjectName,
+    /// The table which the result of the query is appended to
+    pub target: ObjectName,
+    pub query: Box<Query>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateMaterializedView {
+    #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let if_not_exists = self.if_not_exists;
+        let (db, table) = Self::resolve_name(&self.name, ctx.clone())?;
+        let (target_db, target_table) = Self::resolve_name(&self.target, ctx.clone())?;
+
+        let mut query = self.query.as_ref().clone();
+        DfCreateView::qualify_query(&mut query, &ctx.get_current_database());
+        let (source_db, source_table) = Self::resolve_source(&query)?;
+
+        // Both of the tables must exist by now, the source is kept by id to survive renames.
+        let source = ctx.get_table(&source_db, &source_table).await?;
+        let target = ctx.get_table(&target_db, &target_table).await?;
+
+        let statement = DfQueryStatement::try_from(query.clone())?;
+        let plan =
+            PlanParser::build_plan(vec![DfStatement::Query(Box::new(statement))], ctx).await?;
+
+        let schema = plan.schema();
+        if schema.fields().len() < target.schema().fields().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Fields in materialized view query is less than the target table {}.{}",
+                target_db, target_table
+            )));
+        }
+
+        let mut options = HashMap::new();
+        options.insert(VIEW_QUERY_KEY.to_string(), query.to_string());
+        options.insert(
+            MATERIALIZED_VIEW_SOURCE_TABLE_ID_KEY.to_string(),
+            source.get_id().to_string(),
+        );
+        options.insert(MATERIALIZED_VIEW_TARGET_DATABASE_KEY.to_string(), target_db);
+        options.insert(MATERIALIZED_VIEW_TARGET_TABLE_KEY.to_string(), target_table);
+        let table_meta = TableMeta {
+            schema,
+            engine: MATERIALIZED_VIEW_ENGINE.to_string(),
+            options,
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateTable(CreateTablePlan {
+                if_not_exists,
+                temporary: false,
+                db,
+                table,
+                table_meta,
+                as_select: None,
+            }),
+        )))
+    }
+}
+
+impl DfCreateMaterializedView {
+    fn resolve_name(name: &ObjectName, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let idents = &name.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException(
+                "Materialized view name is empty",
+            )),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Materialized view name must be [`db`].`table`",
+            )),
+        }
+    }
+
+    /// The inserted blocks are pushed through the query, so it must read exactly one table.
+    fn resolve_source(query: &Query) -> Result<(String, String)> {
+        if let SetExpr::Select(select) = &query.body {
+            if let [table_with_joins] = select.from.as_slice() {
+                if let TableFactor::Table { name, args, .. } = &table_with_joins.relation {
+                    if table_with_joins.joins.is_empty() && args.is_empty() && name.0.len() == 2 {
+                        return Ok((name.0[0].value.clone(), name.0[1].value.clone()));
+                    }
+                }
+            }
+        }
+
+        Err(ErrorCode::BadArguments(
+            "Materialized view query must select from exactly one table",
+        ))
+    }
+}
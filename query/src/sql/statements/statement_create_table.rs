@@ -26,6 +26,7 @@ use common_planners::PlanNode;
 use common_tracing::tracing;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOption;
+use sqlparser::ast::Expr;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::SqlOption;
 
@@ -33,35 +34,69 @@ use super::analyzer_expr::ExpressionAnalyzer;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
+use crate::storages::fuse::ttl::TtlEvaluator;
+use crate::storages::fuse::TBL_OPT_KEY_PARTITION_BY;
+use crate::storages::fuse::TBL_OPT_KEY_TTL;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
     pub if_not_exists: bool,
+    /// The table lives in the session only
+    pub temporary: bool,
     /// Table name
     pub name: ObjectName,
     pub columns: Vec<ColumnDef>,
     pub engine: String,
+    /// The expression of "partition by", only for the fuse engine
+    pub partition_by: Option<Expr>,
+    /// The expression of "ttl", the time at which the rows expire, only for the fuse engine
+    pub ttl: Option<Expr>,
     pub options: Vec<SqlOption>,
 
     // The table name after "create .. like" statement.
     pub like: Option<ObjectName>,
+
+    // The query after "create .. as select" statement.
+    pub query: Option<Box<DfQueryStatement>>,
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfCreateTable {
     #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
-        let table_meta = self.table_meta(ctx.clone()).await?;
+        if self.temporary && !self.engine.eq_ignore_ascii_case("MEMORY") {
+            return Err(ErrorCode::BadArguments(format!(
+                "Temporary table only supports the Memory engine, but got {}",
+                self.engine
+            )));
+        }
+
+        let as_select = match &self.query {
+            Some(query) => {
+                let statement = DfStatement::Query(query.clone());
+                let plan = PlanParser::build_plan(vec![statement], ctx.clone()).await?;
+                Some(Box::new(plan))
+            }
+            None => None,
+        };
+
+        let table_meta = self.table_meta(ctx.clone(), &as_select).await?;
         let if_not_exists = self.if_not_exists;
+        let temporary = self.temporary;
         let (db, table) = Self::resolve_table(ctx, &self.name)?;
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateTable(CreateTablePlan {
                 if_not_exists,
+                temporary,
                 db,
                 table,
                 table_meta,
+                as_select,
             }),
         )))
     }
@@ -96,10 +131,48 @@ impl DfCreateTable {
             .collect()
     }
 
-    async fn table_meta(&self, ctx: Arc<QueryContext>) -> Result<TableMeta> {
+    async fn table_meta(
+        &self,
+        ctx: Arc<QueryContext>,
+        as_select: &Option<Box<PlanNode>>,
+    ) -> Result<TableMeta> {
         let engine = self.engine.clone();
-        let schema = self.table_schema(ctx).await?;
-        let options = self.table_options();
+        let schema = self.table_schema(ctx.clone(), as_select).await?;
+        let mut options = self.table_options();
+
+        if let Some(partition_by) = &self.partition_by {
+            if !engine.eq_ignore_ascii_case("FUSE") {
+                return Err(ErrorCode::BadArguments(format!(
+                    "PARTITION BY is only supported by the Fuse engine, but got {}",
+                    engine
+                )));
+            }
+
+            let expr = ExpressionAnalyzer::create(ctx.clone())
+                .analyze(partition_by)
+                .await?;
+            // make sure the expression can be evaluated against the columns of the table
+            expr.to_data_type(&schema)?;
+            options.insert(
+                TBL_OPT_KEY_PARTITION_BY.to_string(),
+                serde_json::to_string(&expr)?,
+            );
+        }
+
+        if let Some(ttl) = &self.ttl {
+            if !engine.eq_ignore_ascii_case("FUSE") {
+                return Err(ErrorCode::BadArguments(format!(
+                    "TTL is only supported by the Fuse engine, but got {}",
+                    engine
+                )));
+            }
+
+            let expr = ExpressionAnalyzer::create(ctx).analyze(ttl).await?;
+            // make sure the expression gives the expiration time of the rows
+            TtlEvaluator::try_create(schema.clone(), &expr)?;
+            options.insert(TBL_OPT_KEY_TTL.to_string(), serde_json::to_string(&expr)?);
+        }
+
         Ok(TableMeta {
             schema,
             engine,
@@ -107,7 +180,11 @@ impl DfCreateTable {
         })
     }
 
-    async fn table_schema(&self, ctx: Arc<QueryContext>) -> Result<DataSchemaRef> {
+    async fn table_schema(
+        &self,
+        ctx: Arc<QueryContext>,
+        as_select: &Option<Box<PlanNode>>,
+    ) -> Result<DataSchemaRef> {
         match &self.like {
             // For create table like statement, for example 'CREATE TABLE test2 LIKE db1.test1',
             // we use the original table's schema.
@@ -120,33 +197,43 @@ impl DfCreateTable {
                 let origin_table = ctx.get_table(&origin_db_name, &origin_table_name).await?;
                 Ok(origin_table.schema())
             }
+            // For create table as select statement without column definitions,
+            // the schema is inferred from the query.
+            None if self.columns.is_empty() && as_select.is_some() => {
+                Ok(as_select.as_ref().unwrap().schema())
+            }
             None => {
                 let expr_analyzer = ExpressionAnalyzer::create(ctx);
                 let mut fields = Vec::with_capacity(self.columns.len());
 
                 for column in &self.columns {
-                    let mut nullable = true;
-                    let mut default_expr = None;
-                    for opt in &column.options {
-                        match &opt.option {
-                            ColumnOption::NotNull => {
-                                nullable = false;
-                            }
-                            ColumnOption::Default(expr) => {
-                                let expr = expr_analyzer.analyze(expr).await?;
-                                default_expr = Some(serde_json::to_vec(&expr)?);
-                            }
-                            _ => {}
-                        }
-                    }
-                    let field = SQLCommon::make_data_type(&column.data_type).map(|data_type| {
-                        DataField::new(&column.name.value, data_type, nullable)
-                            .with_default_expr(default_expr)
-                    })?;
-                    fields.push(field);
+                    fields.push(Self::column_field(&expr_analyzer, column).await?);
                 }
                 Ok(DataSchemaRefExt::create(fields))
             }
         }
     }
+
+    pub(crate) async fn column_field(
+        expr_analyzer: &ExpressionAnalyzer,
+        column: &ColumnDef,
+    ) -> Result<DataField> {
+        let mut nullable = true;
+        let mut default_expr = None;
+        for opt in &column.options {
+            match &opt.option {
+                ColumnOption::NotNull => {
+                    nullable = false;
+                }
+                ColumnOption::Default(expr) => {
+                    let expr = expr_analyzer.analyze(expr).await?;
+                    default_expr = Some(serde_json::to_vec(&expr)?);
+                }
+                _ => {}
+            }
+        }
+        SQLCommon::make_data_type(&column.data_type).map(|data_type| {
+            DataField::new(&column.name.value, data_type, nullable).with_default_expr(default_expr)
+        })
+    }
 }
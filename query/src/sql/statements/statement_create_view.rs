@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableMeta;
+use common_planners::CreateTablePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::FunctionArg;
+use sqlparser::ast::Ident;
+use sqlparser::ast::JoinConstraint;
+use sqlparser::ast::JoinOperator;
+use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
+use sqlparser::ast::Select;
+use sqlparser::ast::SelectItem;
+use sqlparser::ast::SetExpr;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+use crate::storages::view::VIEW_ENGINE;
+use crate::storages::view::VIEW_QUERY_KEY;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateView {
+    pub if_not_exists: bool,
+    /// View name
+    pub name: ObjectName,
+    pub query: Box<Query>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateView {
+    #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let if_not_exists = self.if_not_exists;
+        let (db, table) = self.resolve_view(ctx.clone())?;
+
+        // The view may be referenced from any database, so bind its tables to the
+        // current database now.
+        let mut query = self.query.as_ref().clone();
+        Self::qualify_query(&mut query, &ctx.get_current_database());
+
+        let statement = DfQueryStatement::try_from(query.clone())?;
+        let plan =
+            PlanParser::build_plan(vec![DfStatement::Query(Box::new(statement))], ctx).await?;
+
+        let mut options = HashMap::new();
+        options.insert(VIEW_QUERY_KEY.to_string(), query.to_string());
+        let table_meta = TableMeta {
+            schema: plan.schema(),
+            engine: VIEW_ENGINE.to_string(),
+            options,
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateTable(CreateTablePlan {
+                if_not_exists,
+                temporary: false,
+                db,
+                table,
+                table_meta,
+                as_select: None,
+            }),
+        )))
+    }
+}
+
+impl DfCreateView {
+    fn resolve_view(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let idents = &self.name.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Create view name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Create view name must be [`db`].`view`",
+            )),
+        }
+    }
+
+    /// Prefix the unqualified table names of the query with `database`, including the ones of
+    /// the subqueries in any expression.
+    pub(crate) fn qualify_query(query: &mut Query, database: &str) {
+        Self::qualify_set_expr(&mut query.body, database);
+        for order_by in query.order_by.iter_mut() {
+            Self::qualify_expr(&mut order_by.expr, database);
+        }
+        if let Some(limit) = &mut query.limit {
+            Self::qualify_expr(limit, database);
+        }
+        if let Some(offset) = &mut query.offset {
+            Self::qualify_expr(&mut offset.value, database);
+        }
+    }
+
+    fn qualify_set_expr(set_expr: &mut SetExpr, database: &str) {
+        match set_expr {
+            SetExpr::Select(select) => Self::qualify_select(select, database),
+            SetExpr::Query(query) => Self::qualify_query(query, database),
+            SetExpr::SetOperation { left, right, .. } => {
+                Self::qualify_set_expr(left, database);
+                Self::qualify_set_expr(right, database);
+            }
+            _ => {}
+        }
+    }
+
+    fn qualify_select(select: &mut Select, database: &str) {
+        for table_with_joins in select.from.iter_mut() {
+            Self::qualify_table_with_joins(table_with_joins, database);
+        }
+        for item in select.projection.iter_mut() {
+            match item {
+                SelectItem::UnnamedExpr(expr) => Self::qualify_expr(expr, database),
+                SelectItem::ExprWithAlias { expr, .. } => Self::qualify_expr(expr, database),
+                _ => {}
+            }
+        }
+        if let Some(selection) = &mut select.selection {
+            Self::qualify_expr(selection, database);
+        }
+        for expr in select.group_by.iter_mut() {
+            Self::qualify_expr(expr, database);
+        }
+        if let Some(having) = &mut select.having {
+            Self::qualify_expr(having, database);
+        }
+    }
+
+    fn qualify_table_with_joins(table_with_joins: &mut TableWithJoins, database: &str) {
+        Self::qualify_table_factor(&mut table_with_joins.relation, database);
+        for join in table_with_joins.joins.iter_mut() {
+            Self::qualify_table_factor(&mut join.relation, database);
+            match &mut join.join_operator {
+                JoinOperator::Inner(JoinConstraint::On(expr))
+                | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                | JoinOperator::RightOuter(JoinConstraint::On(expr))
+                | JoinOperator::FullOuter(JoinConstraint::On(expr)) => {
+                    Self::qualify_expr(expr, database)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn qualify_table_factor(table_factor: &mut TableFactor, database: &str) {
+        match table_factor {
+            // Table functions, such as numbers(10), are not bound to any database.
+            TableFactor::Table { name, args, .. } if name.0.len() == 1 && args.is_empty() => {
+                name.0.insert(0, Ident::new(database));
+            }
+            TableFactor::Derived { subquery, .. } => Self::qualify_query(subquery, database),
+            TableFactor::NestedJoin(table_with_joins) => {
+                Self::qualify_table_with_joins(table_with_joins, database)
+            }
+            _ => {}
+        }
+    }
+
+    fn qualify_expr(expr: &mut Expr, database: &str) {
+        match expr {
+            Expr::Subquery(subquery) | Expr::Exists(subquery) => {
+                Self::qualify_query(subquery, database)
+            }
+            Expr::InSubquery { expr, subquery, .. } => {
+                Self::qualify_expr(expr, database);
+                Self::qualify_query(subquery, database);
+            }
+            Expr::Nested(expr)
+            | Expr::IsNull(expr)
+            | Expr::IsNotNull(expr)
+            | Expr::UnaryOp { expr, .. }
+            | Expr::Cast { expr, .. } => Self::qualify_expr(expr, database),
+            Expr::BinaryOp { left, right, .. } => {
+                Self::qualify_expr(left, database);
+                Self::qualify_expr(right, database);
+            }
+            Expr::Position {
+                substr_expr,
+                str_expr,
+            } => {
+                Self::qualify_expr(substr_expr, database);
+                Self::qualify_expr(str_expr, database);
+            }
+            Expr::Substring {
+                expr,
+                substring_from,
+                substring_for,
+            } => {
+                Self::qualify_expr(expr, database);
+                for expr in substring_from.iter_mut().chain(substring_for.iter_mut()) {
+                    Self::qualify_expr(expr, database);
+                }
+            }
+            Expr::Between {
+                expr, low, high, ..
+            } => {
+                Self::qualify_expr(expr, database);
+                Self::qualify_expr(low, database);
+                Self::qualify_expr(high, database);
+            }
+            Expr::InList { expr, list, .. } => {
+                Self::qualify_expr(expr, database);
+                for expr in list.iter_mut() {
+                    Self::qualify_expr(expr, database);
+                }
+            }
+            Expr::Tuple(exprs) => {
+                for expr in exprs.iter_mut() {
+                    Self::qualify_expr(expr, database);
+                }
+            }
+            Expr::Function(function) => {
+                for arg in function.args.iter_mut() {
+                    match arg {
+                        FunctionArg::Named { arg, .. } => Self::qualify_expr(arg, database),
+                        FunctionArg::Unnamed(expr) => Self::qualify_expr(expr, database),
+                    }
+                }
+            }
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                for expr in operand.iter_mut().chain(else_result.iter_mut()) {
+                    Self::qualify_expr(expr, database);
+                }
+                for expr in conditions.iter_mut().chain(results.iter_mut()) {
+                    Self::qualify_expr(expr, database);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_planners::expand_aggregate_arg_exprs;
 use common_planners::find_aggregate_exprs;
 use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_array_join_exprs;
 use common_planners::rebase_expr;
 use common_planners::Expression;
 use common_tracing::tracing;
@@ -87,6 +88,7 @@ impl DfQueryStatement {
 
         if let Some(predicate) = &ir.filter_predicate {
             Self::verify_no_aggregate(predicate, "filter")?;
+            Self::verify_no_array_join(predicate, "filter")?;
             analyze_state.filter = Some(predicate.clone());
         }
 
@@ -94,6 +96,7 @@ impl DfQueryStatement {
 
         // Allow `SELECT name FROM system.databases HAVING name = 'xxx'`
         if let Some(predicate) = &ir.having_predicate {
+            Self::verify_no_array_join(predicate, "having")?;
             analyze_state.having = Some(rebase_expr(predicate, &analyze_state.expressions)?);
         }
 
@@ -105,6 +108,7 @@ impl DfQueryStatement {
                     nulls_first,
                     origin_expr,
                 } => {
+                    Self::verify_array_join_in_projection(expr, &ir.projection_expressions)?;
                     analyze_state.add_expression(expr);
                     analyze_state.order_by_expressions.push(Expression::Sort {
                         expr: Box::new(rebase_expr(expr, &analyze_state.expressions)?),
@@ -125,6 +129,14 @@ impl DfQueryStatement {
         }
 
         if !ir.aggregate_expressions.is_empty() || !ir.group_by_expressions.is_empty() {
+            for expression in &ir.group_by_expressions {
+                Self::verify_no_array_join(expression, "group by")?;
+            }
+
+            for expression in &ir.aggregate_expressions {
+                Self::verify_no_array_join(expression, "aggregate functions")?;
+            }
+
             // Rebase expressions using aggregate expressions and group by expressions
             let mut expressions = Vec::with_capacity(analyze_state.expressions.len());
             for expression in &analyze_state.expressions {
@@ -189,6 +201,32 @@ impl DfQueryStatement {
             ))),
         }
     }
+
+    // arrayJoin expands the rows of the block, it's only evaluated with the projection
+    fn verify_no_array_join(expr: &Expression, info: &str) -> Result<()> {
+        match find_array_join_exprs(&[expr.clone()]).first() {
+            None => Ok(()),
+            Some(array_join) => Err(ErrorCode::SyntaxException(format!(
+                "{} cannot contain {}, it's only allowed in the projection",
+                info,
+                array_join.column_name()
+            ))),
+        }
+    }
+
+    fn verify_array_join_in_projection(expr: &Expression, projection: &[Expression]) -> Result<()> {
+        let projected = find_array_join_exprs(projection);
+        for array_join in find_array_join_exprs(&[expr.clone()]) {
+            if !projected.contains(&array_join) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "order by cannot contain {} which is not in the projection",
+                    array_join.column_name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl DfQueryStatement {
@@ -22,6 +22,7 @@ use common_planners::VarValue;
 use common_tracing::tracing;
 use sqlparser::ast::Ident;
 use sqlparser::ast::SetVariableValue;
+use sqlparser::ast::Value;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
@@ -59,6 +60,9 @@ impl DfSetVariable {
             variable,
             value: match value {
                 sqlparser::ast::SetVariableValue::Ident(v) => v.value.clone(),
+                sqlparser::ast::SetVariableValue::Literal(Value::SingleQuotedString(v)) => {
+                    v.clone()
+                }
                 sqlparser::ast::SetVariableValue::Literal(v) => v.to_string(),
             },
         }
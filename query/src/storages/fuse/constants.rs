@@ -16,6 +16,11 @@
 pub const TBL_OPT_KEY_SNAPSHOT_LOC: &str = "SNAPSHOT_LOC";
 pub const TBL_OPT_KEY_CHUNK_BLOCK_NUM: &str = "CHUNK_BLOCK_NUM";
 pub const TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "BLOCK_SIZE_THRESHOLD";
+// comma separated ids of the columns, in the order of the fields of the table schema
+pub const TBL_OPT_KEY_COLUMN_IDS: &str = "COLUMN_IDS";
+pub const TBL_OPT_KEY_NEXT_COLUMN_ID: &str = "NEXT_COLUMN_ID";
+pub const TBL_OPT_KEY_PARTITION_BY: &str = "PARTITION_BY";
+pub const TBL_OPT_KEY_TTL: &str = "TTL";
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
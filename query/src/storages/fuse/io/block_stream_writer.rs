@@ -27,8 +27,10 @@ use futures::TryStreamExt;
 
 use super::block_writer;
 use crate::storages::fuse::io::locations::gen_block_location;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::partition::BlockPartitioner;
 use crate::storages::fuse::statistics::StatisticsAccumulator;
 
 pub type SegmentInfoStream =
@@ -41,8 +43,10 @@ impl BlockStreamWriter {
         data_accessor: Arc<dyn DataAccessor>,
         stream: SendableDataBlockStream,
         data_schema: Arc<DataSchema>,
+        col_ids: Vec<ColumnId>,
         chunk_block_num: usize,
         block_size_threshold: usize,
+        partitioner: Option<BlockPartitioner>,
     ) -> SegmentInfoStream {
         let s = stream! {
             // filter out empty blocks
@@ -55,10 +59,22 @@ impl BlockStreamWriter {
             while let Some(item) = stream.next().await {
                 match item.map_err(|TryChunksError(_, e)| e) {
                     Err(e) => yield(Err(e)),
-                    Ok(blocks) => {
-                        let seg = Self::generate_segment(data_accessor.clone(), data_schema.clone(), blocks, block_size_threshold).await;
-                        yield(seg);
-                    }
+                    Ok(blocks) => match &partitioner {
+                        None => {
+                            let seg = Self::generate_segment(data_accessor.clone(), data_schema.clone(), col_ids.clone(), blocks, block_size_threshold).await;
+                            yield(seg);
+                        }
+                        // one segment for each partition of the chunk
+                        Some(partitioner) => match partitioner.partition(blocks) {
+                            Err(e) => yield(Err(e)),
+                            Ok(partitions) => {
+                                for (partition, blocks) in partitions {
+                                    let seg = Self::generate_segment(data_accessor.clone(), data_schema.clone(), col_ids.clone(), blocks, block_size_threshold).await;
+                                    yield(seg.map(|seg| SegmentInfo { partition: Some(partition), ..seg }));
+                                }
+                            }
+                        },
+                    },
                 }
             }
         };
@@ -68,12 +84,13 @@ impl BlockStreamWriter {
     pub async fn generate_segment(
         data_accessor: Arc<dyn DataAccessor>,
         data_schema: Arc<DataSchema>,
+        col_ids: Vec<ColumnId>,
         blocks: Vec<DataBlock>,
         block_size_threshold: usize,
     ) -> Result<SegmentInfo> {
         // re-shape the blocks
         let blocks = Self::reshape_blocks(blocks, block_size_threshold)?;
-        let mut acc = StatisticsAccumulator::new();
+        let mut acc = StatisticsAccumulator::with_column_ids(col_ids);
 
         for block in blocks.into_iter() {
             let partial_acc = acc.begin(&block)?;
@@ -95,6 +112,7 @@ impl BlockStreamWriter {
                 compressed_byte_size: acc.file_size,
                 col_stats: summary,
             },
+            partition: None,
         };
         Ok(seg)
     }
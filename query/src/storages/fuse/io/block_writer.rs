@@ -13,6 +13,8 @@
 //  limitations under the License.
 //
 
+use std::sync::Arc;
+
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
@@ -23,6 +25,8 @@ use common_dal::DataAccessor;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_streams::parquet_leaf_arrays;
+use common_streams::parquet_leaf_fields;
 
 pub async fn write_block(
     arrow_schema: &ArrowSchema,
@@ -36,15 +40,30 @@ pub async fn write_block(
         compression: Compression::Lz4, // let's begin with lz4
         version: Version::V2,
     };
+    // the nested structs are written as their leaf columns
+    let leaf_fields = arrow_schema
+        .fields()
+        .iter()
+        .flat_map(parquet_leaf_fields)
+        .collect::<Vec<_>>();
+    let leaf_schema = ArrowSchema::new(leaf_fields);
+
     let batch = RecordBatch::try_from(block)?;
-    let encodings: Vec<_> = arrow_schema
+    let leaf_arrays = batch
+        .columns()
+        .iter()
+        .flat_map(parquet_leaf_arrays)
+        .collect::<Vec<_>>();
+    let batch = RecordBatch::try_new(Arc::new(leaf_schema.clone()), leaf_arrays)?;
+
+    let encodings: Vec<_> = leaf_schema
         .fields()
         .iter()
         .map(|f| col_encoding(&f.data_type))
         .collect();
 
     let iter = vec![Ok(batch)];
-    let row_groups = RowGroupIterator::try_new(iter.into_iter(), arrow_schema, options, encodings)?;
+    let row_groups = RowGroupIterator::try_new(iter.into_iter(), &leaf_schema, options, encodings)?;
     let parquet_schema = row_groups.parquet_schema().clone();
 
     // PutObject in S3 need to know the content-length in advance
@@ -75,7 +94,12 @@ pub async fn write_block(
     Ok(len)
 }
 
-fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
+fn col_encoding(data_type: &ArrowDataType) -> Encoding {
+    // LowCardinality columns keep their dictionaries in parquet
+    if let ArrowDataType::Dictionary(_, _) = data_type {
+        return Encoding::RleDictionary;
+    }
+
     // Although encoding does work, parquet2 has not implemented decoding of DeltaLengthByteArray yet, we fallback to Plain
     // From parquet2: Decoding "DeltaLengthByteArray"-encoded required V2 pages is not yet implemented for Binary.
     //
@@ -15,6 +15,8 @@
 
 use std::collections::HashMap;
 
+use common_datavalues::DataType;
+
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::Location;
 use crate::storages::index::ColumnStatistics;
@@ -25,8 +27,21 @@ pub struct BlockMeta {
     /// Pointer of the data Block
     pub row_count: u64,
     pub block_size: u64,
+    /// Size of the block file, 0 for blocks written before it was recorded
+    #[serde(default)]
+    pub file_size: u64,
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub location: BlockLocation,
+
+    /// Ids of the columns stored in the block, in the order they are stored
+    ///
+    /// Empty for blocks written before column ids were tracked, their columns are
+    /// the ones of the table schema, with ids 0..n.
+    #[serde(default)]
+    pub col_ids: Vec<ColumnId>,
+    /// Types of the columns stored in the block, aligned with `col_ids`
+    #[serde(default)]
+    pub col_types: Vec<DataType>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
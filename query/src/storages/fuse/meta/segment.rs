@@ -13,6 +13,8 @@
 //  limitations under the License.
 //
 
+use common_datavalues::DataValue;
+
 use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::Statistics;
 
@@ -26,4 +28,9 @@ pub struct SegmentInfo {
 
     /// summary statistics
     pub summary: Statistics,
+
+    /// The value of the partition expression of all the rows in this segment,
+    /// None if the table is not partitioned
+    #[serde(default)]
+    pub partition: Option<DataValue>,
 }
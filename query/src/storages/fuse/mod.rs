@@ -16,12 +16,17 @@ mod constants;
 pub mod io;
 pub mod meta;
 mod operations;
+pub mod partition;
 pub mod pruning;
 pub mod statistics;
 mod table;
 mod table_functions;
+pub mod ttl;
+mod ttl_gc;
 
 pub use constants::*;
+pub use table::column_ids;
 pub use table::FuseTable;
 pub use table_functions::FuseHistoryTable;
 pub use table_functions::FUSE_FUNC_HIST;
+pub use ttl_gc::TtlGC;
@@ -0,0 +1,343 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_arrow::arrow::compute::cast::can_cast_types;
+use common_datavalues::get_physical_arrow_type;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UpdateTableSchemaReq;
+use common_planners::AlterTableOperation;
+use common_planners::AlterTablePlan;
+use uuid::Uuid;
+
+use crate::catalogs::Catalog;
+use crate::optimizers::RequireColumnsVisitor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_IDS;
+use crate::storages::fuse::TBL_OPT_KEY_NEXT_COLUMN_ID;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_alter_table(
+        &self,
+        ctx: Arc<QueryContext>,
+        alter_plan: AlterTablePlan,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let mut fields = schema.fields().clone();
+        let mut col_ids = self.column_ids()?;
+        let mut next_column_id = self.next_column_id()?;
+        let partition_columns = match self.partition_expression()? {
+            Some(expr) => RequireColumnsVisitor::collect_columns_from_expr(&expr)?,
+            None => HashSet::new(),
+        };
+        let ttl_columns = match self.ttl_expression()? {
+            Some(expr) => RequireColumnsVisitor::collect_columns_from_expr(&expr)?,
+            None => HashSet::new(),
+        };
+
+        match alter_plan.operation {
+            AlterTableOperation::AddColumn(field) => {
+                Self::check_column_absent(&schema, field.name())?;
+                fields.push(field);
+                col_ids.push(next_column_id);
+                next_column_id += 1;
+            }
+            AlterTableOperation::DropColumn(name) => {
+                let idx = Self::column_index(&schema, &name)?;
+                Self::check_not_partition_column(&partition_columns, &name)?;
+                Self::check_not_ttl_column(&ttl_columns, &name)?;
+                if fields.len() == 1 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Can not drop column {}, it is the only column of the table",
+                        name
+                    )));
+                }
+                fields.remove(idx);
+                col_ids.remove(idx);
+            }
+            AlterTableOperation::RenameColumn { old_name, new_name } => {
+                let idx = Self::column_index(&schema, &old_name)?;
+                Self::check_column_absent(&schema, &new_name)?;
+                Self::check_not_partition_column(&partition_columns, &old_name)?;
+                Self::check_not_ttl_column(&ttl_columns, &old_name)?;
+                let field = &fields[idx];
+                fields[idx] =
+                    DataField::new(&new_name, field.data_type().clone(), field.is_nullable())
+                        .with_default_expr(field.default_expr().clone());
+            }
+            AlterTableOperation::ModifyColumn(field) => {
+                // the column keeps its id, blocks are casted to the new type when they are read
+                let idx = Self::column_index(&schema, field.name())?;
+                Self::check_not_partition_column(&partition_columns, field.name())?;
+                Self::check_not_ttl_column(&ttl_columns, field.name())?;
+                Self::check_castable(&fields[idx], &field)?;
+                if fields[idx].is_nullable() && !field.is_nullable() {
+                    let snapshot = self.table_snapshot(ctx.as_ref()).await?;
+                    Self::check_no_nulls(snapshot.as_ref(), col_ids[idx], &fields[idx], &field)?;
+                }
+                fields[idx] = field;
+            }
+            AlterTableOperation::DropPartition(partition) => {
+                return self.do_drop_partition(ctx, &partition).await;
+            }
+        }
+
+        let new_schema = DataSchemaRefExt::create(fields);
+        let ids = col_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut req = UpdateTableSchemaReq::new(&self.table_info.ident, new_schema.clone())
+            .with_option(TBL_OPT_KEY_COLUMN_IDS, ids)
+            .with_option(TBL_OPT_KEY_NEXT_COLUMN_ID, next_column_id.to_string());
+
+        if let Some(snapshot) = self.table_snapshot(ctx.as_ref()).await? {
+            let snapshot_loc = self
+                .evolve_snapshot(ctx.as_ref(), snapshot, &new_schema, &col_ids)
+                .await?;
+            req = req.with_option(TBL_OPT_KEY_SNAPSHOT_LOC, snapshot_loc);
+        }
+
+        // TODO backoff retry
+        ctx.get_catalog().update_table_schema(req).await?;
+        Ok(())
+    }
+
+    /// Writes a new snapshot of the table for the new schema.
+    ///
+    /// The column layout is recorded for the blocks written before column ids were tracked,
+    /// and the statistics which do not describe the columns of the new schema (dropped or
+    /// modified ones) are removed, so that they are not used for pruning any more.
+    async fn evolve_snapshot(
+        &self,
+        ctx: &QueryContext,
+        snapshot: TableSnapshot,
+        new_schema: &DataSchemaRef,
+        new_col_ids: &[ColumnId],
+    ) -> Result<String> {
+        let da = ctx.get_data_accessor()?;
+        let col_ids = self.column_ids()?;
+        let col_types = Self::column_types(&self.table_info.schema());
+        let old_types = col_ids
+            .iter()
+            .cloned()
+            .zip(col_types.iter().cloned())
+            .collect::<HashMap<_, _>>();
+        let new_types = new_col_ids
+            .iter()
+            .cloned()
+            .zip(Self::column_types(new_schema))
+            .collect::<HashMap<_, _>>();
+
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        for seg_loc in snapshot.segments.iter() {
+            let mut segment: SegmentInfo = io::read_obj(da.as_ref(), seg_loc.as_str()).await?;
+            let mut changed = false;
+
+            for block in segment.blocks.iter_mut() {
+                if block.col_ids.is_empty() {
+                    block.col_ids = col_ids.clone();
+                    block.col_types = col_types.clone();
+                    changed = true;
+                }
+
+                let (ids, types) = (&block.col_ids, &block.col_types);
+                let num_stats = block.col_stats.len();
+                block
+                    .col_stats
+                    .retain(|id, _| match ids.iter().position(|v| v == id) {
+                        Some(pos) => new_types.get(id) == Some(&types[pos]),
+                        None => false,
+                    });
+                changed |= block.col_stats.len() != num_stats;
+            }
+
+            let blocks = &segment.blocks;
+            let num_stats = segment.summary.col_stats.len();
+            segment
+                .summary
+                .col_stats
+                .retain(|id, _| blocks.iter().all(|b| b.col_stats.contains_key(id)));
+            changed |= segment.summary.col_stats.len() != num_stats;
+
+            if changed {
+                let new_seg_loc = io::gen_segment_info_location();
+                da.put(&new_seg_loc, serde_json::to_vec(&segment)?).await?;
+                segments.push(new_seg_loc);
+            } else {
+                segments.push(seg_loc.clone());
+            }
+        }
+
+        let mut summary = snapshot.summary;
+        summary
+            .col_stats
+            .retain(|id, _| old_types.get(id).is_some() && old_types.get(id) == new_types.get(id));
+
+        let new_snapshot = TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(snapshot.snapshot_id),
+            schema: new_schema.as_ref().clone(),
+            summary,
+            segments,
+        };
+        let snapshot_loc =
+            io::snapshot_location(new_snapshot.snapshot_id.to_simple().to_string().as_str());
+        da.put(&snapshot_loc, serde_json::to_vec(&new_snapshot)?)
+            .await?;
+        Ok(snapshot_loc)
+    }
+
+    fn column_types(schema: &DataSchema) -> Vec<DataType> {
+        schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect()
+    }
+
+    fn column_index(schema: &DataSchema, name: &str) -> Result<usize> {
+        schema
+            .column_with_name(name)
+            .map(|(idx, _)| idx)
+            .ok_or_else(|| ErrorCode::UnknownColumn(format!("Unknown column {}", name)))
+    }
+
+    fn check_not_partition_column(partition_columns: &HashSet<String>, name: &str) -> Result<()> {
+        match partition_columns.contains(name) {
+            true => Err(ErrorCode::BadArguments(format!(
+                "Column {} is used by the partition expression",
+                name
+            ))),
+            false => Ok(()),
+        }
+    }
+
+    fn check_not_ttl_column(ttl_columns: &HashSet<String>, name: &str) -> Result<()> {
+        match ttl_columns.contains(name) {
+            true => Err(ErrorCode::BadArguments(format!(
+                "Column {} is used by the TTL expression",
+                name
+            ))),
+            false => Ok(()),
+        }
+    }
+
+    fn check_castable(old_field: &DataField, new_field: &DataField) -> Result<()> {
+        let old_type = old_field.data_type();
+        let new_type = new_field.data_type();
+        if old_type == new_type {
+            return Ok(());
+        }
+
+        // blocks are casted by arrow when they are read, the values it fails to cast become
+        // NULL silently, so only the casts that never fail are allowed
+        let from = old_type.to_arrow();
+        let to = new_type.to_arrow();
+        if !can_cast_types(get_physical_arrow_type(&from), get_physical_arrow_type(&to))
+            || !Self::is_lossless_cast(old_type, new_type)
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "Can not modify column {} from {} to {}",
+                old_field.name(),
+                old_type,
+                new_type
+            )));
+        }
+        Ok(())
+    }
+
+    /// The casts which never fail: widening the numbers, or the numbers to strings.
+    fn is_lossless_cast(from: &DataType, to: &DataType) -> bool {
+        let from = from.remove_low_cardinality();
+        let to = to.remove_low_cardinality();
+        if from == to {
+            return true;
+        }
+
+        let size = |data_type: &DataType| data_type.numeric_byte_size().unwrap_or(0);
+        match (&from, &to) {
+            (f, t) if f.is_unsigned_integer() && t.is_unsigned_integer() => size(t) > size(f),
+            (f, t) if f.is_unsigned_integer() && t.is_signed_integer() => size(t) > size(f),
+            (f, t) if f.is_signed_integer() && t.is_signed_integer() => size(t) > size(f),
+            (f, DataType::Float32) if f.is_integer() => size(f) <= 2,
+            (f, DataType::Float64) => f.is_integer() || f.is_floating(),
+            (f, DataType::String) => f.is_integer() || f.is_floating() || f == &DataType::Boolean,
+            _ => false,
+        }
+    }
+
+    /// Makes sure the column holds no NULL before it becomes NOT NULL.
+    ///
+    /// The null count of the snapshot is only kept while all the blocks have statistics of
+    /// the column with its current type, without it the column can not be checked.
+    fn check_no_nulls(
+        snapshot: Option<&TableSnapshot>,
+        col_id: ColumnId,
+        old_field: &DataField,
+        new_field: &DataField,
+    ) -> Result<()> {
+        let snapshot = match snapshot {
+            Some(snapshot) if snapshot.summary.row_count > 0 => snapshot,
+            _ => return Ok(()),
+        };
+
+        if old_field.data_type() != new_field.data_type() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Can not change the type of column {} and make it NOT NULL at once",
+                old_field.name()
+            )));
+        }
+
+        match snapshot.summary.col_stats.get(&col_id) {
+            Some(stats) if stats.null_count == 0 => Ok(()),
+            Some(stats) => Err(ErrorCode::BadArguments(format!(
+                "Can not make column {} NOT NULL, it has {} NULL values",
+                old_field.name(),
+                stats.null_count
+            ))),
+            None => Err(ErrorCode::BadArguments(format!(
+                "Can not make column {} NOT NULL, its NULL values are not counted",
+                old_field.name()
+            ))),
+        }
+    }
+
+    fn check_column_absent(schema: &DataSchema, name: &str) -> Result<()> {
+        match schema.has_field(name) {
+            true => Err(ErrorCode::BadArguments(format!(
+                "Column {} already exists",
+                name
+            ))),
+            false => Ok(()),
+        }
+    }
+}
@@ -25,6 +25,7 @@ use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
 use crate::storages::fuse::io::BlockStreamWriter;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::partition::BlockPartitioner;
 use crate::storages::fuse::FuseTable;
 use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
 use crate::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
@@ -47,14 +48,24 @@ impl FuseTable {
             DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
         );
 
+        let partitioner = match self.partition_expression()? {
+            Some(expr) => Some(BlockPartitioner::try_create(
+                self.table_info.schema(),
+                &expr,
+            )?),
+            None => None,
+        };
+
         let da = ctx.get_data_accessor()?;
 
         let mut segment_stream = BlockStreamWriter::write_block_stream(
             da.clone(),
             stream,
             self.table_info.schema().clone(),
+            self.column_ids()?,
             chunk_block_num,
             block_size_threshold,
+            partitioner,
         )
         .await;
 
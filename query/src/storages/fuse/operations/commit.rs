@@ -26,6 +26,7 @@ use uuid::Uuid;
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::Statistics;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
@@ -45,9 +46,11 @@ impl FuseTable {
         // TODO OCC retry & resolves conflicts if applicable
 
         let prev = self.table_snapshot(ctx.as_ref()).await?;
+        let col_ids = self.column_ids()?;
         let new_snapshot = if overwrite {
             let schema = self.table_info.meta.schema.as_ref().clone();
-            let (segments, summary) = Self::merge_append_operations(&schema, operation_log)?;
+            let (segments, summary) =
+                Self::merge_append_operations(&schema, &col_ids, operation_log)?;
             TableSnapshot {
                 snapshot_id: Uuid::new_v4(),
                 prev_snapshot_id: prev.as_ref().map(|v| v.snapshot_id),
@@ -56,7 +59,12 @@ impl FuseTable {
                 segments,
             }
         } else {
-            Self::merge_table_operations(self.table_info.meta.schema.as_ref(), prev, operation_log)?
+            Self::merge_table_operations(
+                self.table_info.meta.schema.as_ref(),
+                &col_ids,
+                prev,
+                operation_log,
+            )?
         };
 
         let uuid = new_snapshot.snapshot_id;
@@ -71,15 +79,16 @@ impl FuseTable {
 
     fn merge_table_operations(
         schema: &DataSchema,
+        col_ids: &[ColumnId],
         prev: Option<TableSnapshot>,
         ops: TableOperationLog,
     ) -> Result<TableSnapshot> {
         // 1. merge operations(appends, currently)
-        let (mut segs, stats) = Self::merge_append_operations(schema, ops)?;
+        let (mut segs, stats) = Self::merge_append_operations(schema, col_ids, ops)?;
 
         // 2. merge stats with previous snapshot, if any
         let stats = if let Some(TableSnapshot { summary, .. }) = &prev {
-            statistics::merge_statistics(schema, &stats, summary)?
+            statistics::merge_statistics(schema, col_ids, &stats, summary)?
         } else {
             stats
         };
@@ -122,6 +131,7 @@ impl FuseTable {
 
     pub fn merge_append_operations(
         schema: &DataSchema,
+        col_ids: &[ColumnId],
         append_log_entries: Vec<AppendOperationLogEntry>,
     ) -> Result<(Vec<String>, Statistics)> {
         let (s, seg_locs) = append_log_entries.iter().try_fold(
@@ -136,8 +146,11 @@ impl FuseTable {
                 acc.block_count += stats.block_count;
                 acc.uncompressed_byte_size += stats.uncompressed_byte_size;
                 acc.compressed_byte_size += stats.compressed_byte_size;
-                acc.col_stats =
-                    statistics::reduce_block_stats(&[&acc.col_stats, &stats.col_stats], schema)?;
+                acc.col_stats = statistics::reduce_block_stats(
+                    &[&acc.col_stats, &stats.col_stats],
+                    schema,
+                    col_ids,
+                )?;
                 seg_acc.push(loc.clone());
                 Ok::<_, ErrorCode>((acc, seg_acc))
             },
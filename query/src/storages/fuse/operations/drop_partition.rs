@@ -0,0 +1,97 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::sync::Arc;
+
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UpsertTableOptionReq;
+use uuid::Uuid;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::partition::BlockPartitioner;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+
+impl FuseTable {
+    /// Drops the segments of the partition from a new snapshot, no block is rewritten.
+    #[inline]
+    pub async fn do_drop_partition(
+        &self,
+        ctx: Arc<QueryContext>,
+        partition: &DataValue,
+    ) -> Result<()> {
+        let partition_by = self.partition_expression()?.ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Can not drop partition, table {} is not partitioned",
+                self.table_info.name
+            ))
+        })?;
+        let schema = self.table_info.schema();
+        let partitioner = BlockPartitioner::try_create(schema.clone(), &partition_by)?;
+        let partition = partitioner.cast_value(partition)?;
+
+        let prev_snapshot = match self.table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let da = ctx.get_data_accessor()?;
+        let col_ids = self.column_ids()?;
+        let mut segments = Vec::with_capacity(prev_snapshot.segments.len());
+        let mut summary = Statistics::default();
+        for seg_loc in prev_snapshot.segments.iter() {
+            let segment: SegmentInfo = io::read_obj(da.as_ref(), seg_loc.as_str()).await?;
+            if segment.partition.as_ref() != Some(&partition) {
+                summary =
+                    statistics::merge_statistics(&schema, &col_ids, &summary, &segment.summary)?;
+                segments.push(seg_loc.clone());
+            }
+        }
+
+        if segments.len() == prev_snapshot.segments.len() {
+            return Ok(());
+        }
+
+        let new_snapshot = TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(prev_snapshot.snapshot_id),
+            schema: schema.as_ref().clone(),
+            summary,
+            segments,
+        };
+        let new_snapshot_loc =
+            io::snapshot_location(new_snapshot.snapshot_id.to_simple().to_string().as_str());
+        let bytes = serde_json::to_vec(&new_snapshot)?;
+        da.put(&new_snapshot_loc, bytes).await?;
+
+        let catalog = ctx.get_catalog();
+        // TODO backoff retry
+        catalog
+            .upsert_table_option(UpsertTableOptionReq::new(
+                &self.table_info.ident,
+                TBL_OPT_KEY_SNAPSHOT_LOC,
+                new_snapshot_loc,
+            ))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchema;
+use common_exception::Result;
+use common_meta_types::UpsertTableOptionReq;
+use common_planners::Part;
+use futures::TryStreamExt;
+use uuid::Uuid;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::operations::PartInfo;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::ttl::TtlEvaluator;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+
+impl FuseTable {
+    /// Removes the rows whose TTL expired at `now`, in seconds since UNIX epoch.
+    ///
+    /// The blocks are judged by the statistics of the TTL column: the fully expired ones are
+    /// dropped from the snapshot, only the partially expired ones are read and rewritten.
+    /// Returns the number of removed rows.
+    pub async fn do_expire(&self, ctx: Arc<QueryContext>, now: i64) -> Result<u64> {
+        let ttl = match self.ttl_expression()? {
+            Some(ttl) => ttl,
+            None => return Ok(0),
+        };
+        let prev_snapshot = match self.table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(0),
+        };
+
+        let schema = self.table_info.schema();
+        let col_ids = self.column_ids()?;
+        let evaluator = TtlEvaluator::try_create(schema.clone(), &ttl)?;
+        let ttl_col_id = col_ids[schema.index_of(evaluator.column_name())?];
+        let da = ctx.get_data_accessor()?;
+
+        let mut expired_rows = 0;
+        let mut segments = Vec::with_capacity(prev_snapshot.segments.len());
+        let mut summary = Statistics::default();
+        for seg_loc in prev_snapshot.segments.iter() {
+            let segment: SegmentInfo = io::read_obj(da.as_ref(), seg_loc.as_str()).await?;
+            let num_blocks = segment.blocks.len();
+            let mut blocks = Vec::with_capacity(num_blocks);
+            let mut changed = false;
+
+            for block in segment.blocks.iter() {
+                // blocks without the statistics of the column are kept
+                let expire_range = match block.col_stats.get(&ttl_col_id) {
+                    None => None,
+                    Some(stats) => evaluator.expire_range(stats)?,
+                };
+
+                match expire_range {
+                    Some((_, max)) if max <= now => {
+                        expired_rows += block.row_count;
+                        changed = true;
+                    }
+                    Some((min, _)) if min <= now => {
+                        let data = self.read_block(ctx.clone(), block).await?;
+                        let data = evaluator.filter_expired(&data, now)?;
+                        expired_rows += block.row_count - data.num_rows() as u64;
+                        changed = true;
+                        if data.num_rows() > 0 {
+                            let seg = BlockStreamWriter::generate_segment(
+                                da.clone(),
+                                schema.clone(),
+                                col_ids.clone(),
+                                vec![data],
+                                0,
+                            )
+                            .await?;
+                            blocks.extend(seg.blocks);
+                        }
+                    }
+                    _ => blocks.push(block.clone()),
+                }
+            }
+
+            if !changed {
+                summary =
+                    statistics::merge_statistics(&schema, &col_ids, &summary, &segment.summary)?;
+                segments.push(seg_loc.clone());
+                continue;
+            }
+
+            if !blocks.is_empty() {
+                let segment = SegmentInfo {
+                    summary: Self::blocks_summary(&schema, &col_ids, &blocks)?,
+                    blocks,
+                    partition: segment.partition,
+                };
+                let new_seg_loc = io::gen_segment_info_location();
+                da.put(&new_seg_loc, serde_json::to_vec(&segment)?).await?;
+                summary =
+                    statistics::merge_statistics(&schema, &col_ids, &summary, &segment.summary)?;
+                segments.push(new_seg_loc);
+            }
+        }
+
+        if expired_rows == 0 {
+            return Ok(0);
+        }
+
+        let new_snapshot = TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(prev_snapshot.snapshot_id),
+            schema: schema.as_ref().clone(),
+            summary,
+            segments,
+        };
+        let new_snapshot_loc =
+            io::snapshot_location(new_snapshot.snapshot_id.to_simple().to_string().as_str());
+        let bytes = serde_json::to_vec(&new_snapshot)?;
+        da.put(&new_snapshot_loc, bytes).await?;
+
+        let catalog = ctx.get_catalog();
+        // TODO backoff retry
+        catalog
+            .upsert_table_option(UpsertTableOptionReq::new(
+                &self.table_info.ident,
+                TBL_OPT_KEY_SNAPSHOT_LOC,
+                new_snapshot_loc,
+            ))
+            .await?;
+        Ok(expired_rows)
+    }
+
+    async fn read_block(&self, ctx: Arc<QueryContext>, block: &BlockMeta) -> Result<DataBlock> {
+        let part = Part {
+            name: PartInfo::from_block_meta(block).encode()?,
+            version: 0,
+        };
+        let schema = self.table_info.schema();
+        let table_schema = Arc::new(DataSchema::from(schema.to_arrow()));
+        let projection = (0..schema.fields().len()).collect::<Vec<_>>();
+        let stream = Self::read_part(
+            ctx.get_data_accessor()?,
+            &part,
+            &table_schema,
+            &schema,
+            &self.column_ids()?,
+            &projection,
+        )?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        DataBlock::concat_blocks(&blocks)
+    }
+
+    fn blocks_summary(
+        schema: &DataSchema,
+        col_ids: &[ColumnId],
+        blocks: &[BlockMeta],
+    ) -> Result<Statistics> {
+        let col_stats = blocks.iter().map(|b| &b.col_stats).collect::<Vec<_>>();
+        Ok(Statistics {
+            row_count: blocks.iter().map(|b| b.row_count).sum(),
+            block_count: blocks.len() as u64,
+            uncompressed_byte_size: blocks.iter().map(|b| b.block_size).sum(),
+            compressed_byte_size: blocks.iter().map(|b| b.file_size).sum(),
+            col_stats: statistics::reduce_block_stats(&col_stats, schema, col_ids)?,
+        })
+    }
+}
@@ -12,12 +12,18 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod alter_table;
 mod append;
 mod commit;
+mod drop_partition;
+mod expire;
 mod operation_log;
+mod part_info;
+mod purge;
 mod read;
 mod read_plan;
 mod truncate;
 
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
+pub use part_info::PartInfo;
@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_planners::Part;
+
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+
+/// What a reader needs to know about a block, carried by `Part::name`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PartInfo {
+    pub location: String,
+    /// Ids of the columns stored in the block, empty if they are the ones of the table schema
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub col_ids: Vec<ColumnId>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub col_types: Vec<DataType>,
+}
+
+impl PartInfo {
+    pub fn from_block_meta(block_meta: &BlockMeta) -> Self {
+        PartInfo {
+            location: block_meta.location.location.clone(),
+            col_ids: block_meta.col_ids.clone(),
+            col_types: block_meta.col_types.clone(),
+        }
+    }
+
+    pub fn encode(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn decode(part: &Part) -> Result<PartInfo> {
+        Ok(serde_json::from_str(part.name.as_str())?)
+    }
+}
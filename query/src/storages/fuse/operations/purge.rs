@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    /// Removes the blocks, segments and snapshots of all the snapshots of the table.
+    ///
+    /// It is only safe to be called on a dropped table, whose retention period has expired.
+    pub async fn do_purge(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        let da = ctx.get_data_accessor()?;
+
+        let mut snapshot_locs = vec![];
+        let mut segment_locs = HashSet::new();
+        let mut location = self.snapshot_loc();
+        while let Some(loc) = location {
+            let snapshot: TableSnapshot = match io::read_obj(da.as_ref(), &loc).await {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    // an earlier purge was interrupted after removing the older snapshots
+                    tracing::warn!("snapshot {} of table is not readable: {}", loc, e);
+                    break;
+                }
+            };
+            segment_locs.extend(snapshot.segments);
+            location = snapshot
+                .prev_snapshot_id
+                .map(|id| io::snapshot_location(id.to_simple().to_string().as_str()));
+            snapshot_locs.push(loc);
+        }
+
+        for segment_loc in segment_locs {
+            if let Ok(segment) = io::read_obj::<SegmentInfo>(da.as_ref(), &segment_loc).await {
+                for block in segment.blocks {
+                    da.remove(&block.location.location).await?;
+                }
+            }
+            da.remove(&segment_loc).await?;
+        }
+
+        // the snapshots are removed at last and from the oldest, the meta of the table is only
+        // purged after it, so an interrupted purge is resumed from the remaining snapshots
+        for snapshot_loc in snapshot_locs.into_iter().rev() {
+            da.remove(&snapshot_loc).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -13,18 +13,30 @@
 //  limitations under the License.
 //
 
+use std::convert::TryFrom;
 use std::sync::Arc;
 
 use async_stream::stream;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
 use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::Result;
 use common_planners::Extras;
+use common_planners::Part;
 use common_streams::ParquetSource;
 use common_streams::SendableDataBlockStream;
 use common_streams::Source;
 use futures::StreamExt;
+use futures::TryStreamExt;
 
+use crate::interpreters::AddOnStream;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::operations::PartInfo;
 use crate::storages::fuse::FuseTable;
 
 impl FuseTable {
@@ -65,26 +77,183 @@ impl FuseTable {
         let da = ctx.get_data_accessor()?;
         let arrow_schema = self.table_info.schema().to_arrow();
         let table_schema = Arc::new(DataSchema::from(arrow_schema));
+        // the schema with default expressions, to fill the columns missing in old blocks
+        let full_schema = self.table_info.schema();
+        let col_ids = self.column_ids()?;
 
         let mut iter = futures::stream::iter(iter);
         let stream = stream! {
             while let Some(part) = iter.next().await {
-                let mut source = ParquetSource::new(
+                let part_stream = Self::read_part(
                     da.clone(),
-                    part.name.clone(),
-                    table_schema.clone(),
-                    projection.clone(),
+                    &part,
+                    &table_schema,
+                    &full_schema,
+                    &col_ids,
+                    &projection,
                 );
-                loop {
-                    let block = source.read().await;
-                    match block {
-                        Ok(None) => break,
-                        Ok(Some(b)) =>  yield(Ok(b)),
-                        Err(e) => yield(Err(e)),
+                match part_stream {
+                    Err(e) => yield(Err(e)),
+                    Ok(mut part_stream) => {
+                        while let Some(block) = part_stream.next().await {
+                            yield(block);
+                        }
                     }
                 }
             }
         };
         Ok(Box::pin(stream))
     }
+
+    /// Read the blocks of the append logs of this table, i.e. the blocks which are appended by
+    /// the sinks of an insert, whether they are committed or not.
+    pub fn read_appended(
+        &self,
+        ctx: Arc<QueryContext>,
+        append_logs: &[DataBlock],
+    ) -> Result<SendableDataBlockStream> {
+        let col_ids = self.column_ids()?;
+        let mut parts = vec![];
+        for append_log in append_logs {
+            let log_entry = AppendOperationLogEntry::try_from(append_log)?;
+            let (_, mut log_parts) =
+                Self::to_partitions(&log_entry.segment_info.blocks, &col_ids, None)?;
+            parts.append(&mut log_parts);
+        }
+
+        let da = ctx.get_data_accessor()?;
+        let arrow_schema = self.table_info.schema().to_arrow();
+        let table_schema = Arc::new(DataSchema::from(arrow_schema));
+        let full_schema = self.table_info.schema();
+        let projection = (0..full_schema.fields().len()).collect::<Vec<usize>>();
+
+        let stream = futures::stream::iter(parts)
+            .map(move |part| {
+                Self::read_part(
+                    da.clone(),
+                    &part,
+                    &table_schema,
+                    &full_schema,
+                    &col_ids,
+                    &projection,
+                )
+            })
+            .try_flatten();
+        Ok(Box::pin(stream))
+    }
+
+    pub(crate) fn read_part(
+        da: Arc<dyn DataAccessor>,
+        part: &Part,
+        table_schema: &DataSchemaRef,
+        full_schema: &DataSchemaRef,
+        col_ids: &[ColumnId],
+        projection: &[usize],
+    ) -> Result<SendableDataBlockStream> {
+        let part_info = PartInfo::decode(part)?;
+        let same_layout = part_info.col_ids.is_empty()
+            || (part_info.col_ids == col_ids
+                && part_info
+                    .col_types
+                    .iter()
+                    .zip(full_schema.fields())
+                    .all(|(data_type, field)| data_type == field.data_type()));
+
+        if same_layout {
+            let source = ParquetSource::new(
+                da,
+                part_info.location,
+                table_schema.clone(),
+                projection.to_vec(),
+            );
+            return Ok(Self::source_stream(source));
+        }
+
+        // The block was written with another schema, its columns are looked up by id:
+        // dropped columns are skipped, modified ones are casted and missing ones are filled
+        // with their default values.
+        let block_fields = part_info
+            .col_ids
+            .iter()
+            .zip(part_info.col_types.iter())
+            .map(
+                |(id, data_type)| match col_ids.iter().position(|v| v == id) {
+                    Some(idx) => {
+                        let field = table_schema.field(idx);
+                        DataField::new(field.name(), data_type.clone(), field.is_nullable())
+                    }
+                    None => DataField::new(&format!("_dropped_{}", id), data_type.clone(), true),
+                },
+            )
+            .collect::<Vec<_>>();
+        let block_schema = DataSchemaRefExt::create(block_fields);
+
+        let mut block_projection = projection
+            .iter()
+            .filter_map(|idx| part_info.col_ids.iter().position(|v| *v == col_ids[*idx]))
+            .collect::<Vec<_>>();
+        if block_projection.is_empty() {
+            // read one column anyway, which gives the number of rows to fill
+            block_projection.push(0);
+        }
+
+        // columns of the block which are still in the table, with their current types
+        let read_fields = block_projection
+            .iter()
+            .map(|idx| {
+                let field = block_schema.field(*idx);
+                match full_schema.column_with_name(field.name()) {
+                    Some((_, table_field)) => table_field.clone(),
+                    None => field.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let casts = block_projection
+            .iter()
+            .zip(read_fields.iter())
+            .map(|(idx, field)| block_schema.field(*idx).data_type() != field.data_type())
+            .collect::<Vec<_>>();
+        let read_schema = DataSchemaRefExt::create(read_fields);
+        let output_schema = Arc::new(full_schema.project(projection.to_vec()));
+
+        let source = ParquetSource::new(da, part_info.location, block_schema, block_projection);
+        let mut stream = Self::source_stream(source);
+        if casts.iter().any(|cast| *cast) {
+            let cast_schema = read_schema.clone();
+            stream = Box::pin(stream.map(move |block| {
+                let block = block?;
+                let columns = block
+                    .columns()
+                    .iter()
+                    .zip(cast_schema.fields())
+                    .zip(casts.iter())
+                    .map(|((column, field), cast)| match cast {
+                        true => column.cast_with_type(field.data_type()),
+                        false => Ok(column.clone()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataBlock::create(cast_schema.clone(), columns))
+            }));
+        }
+
+        Ok(Box::pin(AddOnStream::try_create(
+            stream,
+            read_schema,
+            output_schema,
+        )?))
+    }
+
+    fn source_stream(mut source: ParquetSource) -> SendableDataBlockStream {
+        let stream = stream! {
+            loop {
+                let block = source.read().await;
+                match block {
+                    Ok(None) => break,
+                    Ok(Some(b)) =>  yield(Ok(b)),
+                    Err(e) => yield(Err(e)),
+                }
+            }
+        };
+        Box::pin(stream)
+    }
 }
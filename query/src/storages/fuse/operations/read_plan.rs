@@ -24,6 +24,8 @@ use common_planners::Statistics;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::operations::PartInfo;
 use crate::storages::fuse::pruning::apply_block_pruning;
 use crate::storages::fuse::FuseTable;
 
@@ -39,9 +41,18 @@ impl FuseTable {
             Some(snapshot) => {
                 let da = ctx.get_data_accessor()?;
                 let schema = self.table_info.schema();
-                let block_metas = apply_block_pruning(&snapshot, schema, &push_downs, da).await?;
-                let (statistics, parts) = Self::to_partitions(&block_metas, push_downs);
-                Ok((statistics, parts))
+                let col_ids = self.column_ids()?;
+                let partition_by = self.partition_expression()?;
+                let block_metas = apply_block_pruning(
+                    &snapshot,
+                    schema,
+                    &col_ids,
+                    partition_by.as_ref(),
+                    &push_downs,
+                    da,
+                )
+                .await?;
+                Self::to_partitions(&block_metas, &col_ids, push_downs)
             }
             None => Ok((Statistics::default(), vec![])),
         }
@@ -49,15 +60,19 @@ impl FuseTable {
 
     pub fn to_partitions(
         blocks_metas: &[BlockMeta],
+        col_ids: &[ColumnId],
         push_downs: Option<Extras>,
-    ) -> (Statistics, Partitions) {
-        let proj_cols =
-            push_downs.and_then(|extras| extras.projection.map(HashSet::<usize>::from_iter));
-        blocks_metas.iter().fold(
+    ) -> Result<(Statistics, Partitions)> {
+        let proj_cols = push_downs.and_then(|extras| {
+            extras
+                .projection
+                .map(|proj| proj.iter().map(|idx| col_ids[*idx]).collect::<HashSet<_>>())
+        });
+        blocks_metas.iter().try_fold(
             (Statistics::default(), Partitions::default()),
             |(mut stats, mut parts), block_meta| {
                 parts.push(Part {
-                    name: block_meta.location.location.clone(),
+                    name: PartInfo::from_block_meta(block_meta).encode()?,
                     version: 0,
                 });
 
@@ -68,14 +83,14 @@ impl FuseTable {
                         stats.read_bytes += block_meta
                             .col_stats
                             .iter()
-                            .filter(|(cid, _)| proj.contains(&(**cid as usize)))
+                            .filter(|(cid, _)| proj.contains(cid))
                             .map(|(_, col_stats)| col_stats.in_memory_size)
                             .sum::<u64>() as usize
                     }
                     None => stats.read_bytes += block_meta.block_size as usize,
                 }
 
-                (stats, parts)
+                Ok((stats, parts))
             },
         )
     }
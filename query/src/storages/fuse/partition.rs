@@ -0,0 +1,158 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodKind;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableInfo;
+use common_planners::Expression;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::storages::fuse::TBL_OPT_KEY_PARTITION_BY;
+
+/// The partition expression of a fuse table, None if the table is not partitioned.
+///
+/// It is kept in the table options as the serialized `Expression`, like the default
+/// expressions of the columns.
+pub fn partition_expression(table_info: &TableInfo) -> Result<Option<Expression>> {
+    match table_info.options().get(TBL_OPT_KEY_PARTITION_BY) {
+        None => Ok(None),
+        Some(v) => serde_json::from_str(v).map(Some).map_err(|e| {
+            ErrorCode::LogicalError(format!("invalid {}: {}", TBL_OPT_KEY_PARTITION_BY, e))
+        }),
+    }
+}
+
+/// Splits blocks by the value of the partition expression, so that every segment of a
+/// partitioned table holds the rows of exactly one partition.
+#[derive(Clone)]
+pub struct BlockPartitioner {
+    expr: Expression,
+    data_type: DataType,
+    keys_schema: DataSchemaRef,
+    executor: Arc<ExpressionExecutor>,
+}
+
+impl BlockPartitioner {
+    pub fn try_create(schema: DataSchemaRef, expr: &Expression) -> Result<Self> {
+        let field = expr.to_data_field(&schema)?;
+        let keys_schema = DataSchemaRefExt::create(vec![field.clone()]);
+        let executor = ExpressionExecutor::try_create(
+            "partition expression executor",
+            schema,
+            keys_schema.clone(),
+            vec![expr.clone()],
+            false,
+        )?;
+
+        Ok(Self {
+            expr: expr.clone(),
+            data_type: field.data_type().clone(),
+            keys_schema,
+            executor: Arc::new(executor),
+        })
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Groups the rows of the blocks by partition, in the order the partitions are met.
+    pub fn partition(&self, blocks: Vec<DataBlock>) -> Result<Vec<(DataValue, Vec<DataBlock>)>> {
+        // The method only depends on the type of the partition, its keys are comparable across
+        // the blocks.
+        let column_names = [self.expr.column_name()];
+        let keys_block = DataBlock::empty_with_schema(self.keys_schema.clone());
+        match DataBlock::choose_hash_method(&keys_block, &column_names)? {
+            HashMethodKind::Serializer(method) => self.partition_by(&method, blocks),
+            HashMethodKind::KeysU8(method) => self.partition_by(&method, blocks),
+            HashMethodKind::KeysU16(method) => self.partition_by(&method, blocks),
+            HashMethodKind::KeysU32(method) => self.partition_by(&method, blocks),
+            HashMethodKind::KeysU64(method) => self.partition_by(&method, blocks),
+            HashMethodKind::LowCardinality(method) => self.partition_by(&method, blocks),
+        }
+    }
+
+    fn partition_by<Method: HashMethod>(
+        &self,
+        method: &Method,
+        blocks: Vec<DataBlock>,
+    ) -> Result<Vec<(DataValue, Vec<DataBlock>)>> {
+        let column_names = [self.expr.column_name()];
+        let mut partitions: Vec<(DataValue, Vec<DataBlock>)> = vec![];
+        // index of each partition by its key, the key of the NULL partition is None
+        let mut partition_indices: HashMap<Option<Method::HashKey>, usize> = HashMap::new();
+
+        for block in blocks {
+            let keys_block = self.executor.execute(&block)?;
+            let column = keys_block.try_column_by_name(&column_names[0])?;
+            let group_indices = method.group_by_get_indices(&keys_block, &column_names)?;
+
+            // The keys ignore the validity of the rows, so the NULL rows are taken apart.
+            let nulls = column.to_array()?;
+            let mut groups = Vec::with_capacity(group_indices.len() + 1);
+            let mut null_rows = vec![];
+            for (key, (rows, _)) in group_indices {
+                let rows = match nulls.null_count() {
+                    0 => rows,
+                    _ => {
+                        let (null, rows): (Vec<u32>, Vec<u32>) = rows
+                            .into_iter()
+                            .partition(|row| nulls.is_null(*row as usize));
+                        null_rows.extend(null);
+                        rows
+                    }
+                };
+                if !rows.is_empty() {
+                    groups.push((Some(key), rows));
+                }
+            }
+            if !null_rows.is_empty() {
+                null_rows.sort_unstable();
+                groups.push((None, null_rows));
+            }
+            groups.sort_by_key(|(_, rows)| rows[0]);
+
+            let scatter = groups.len() > 1;
+            for (key, rows) in groups {
+                let partition = match scatter {
+                    true => DataBlock::block_take_by_indices(&block, &[], &rows)?,
+                    false => block.clone(),
+                };
+                match partition_indices.get(&key) {
+                    Some(idx) => partitions[*idx].1.push(partition),
+                    None => {
+                        partition_indices.insert(key, partitions.len());
+                        partitions.push((column.try_get(rows[0] as usize)?, vec![partition]));
+                    }
+                }
+            }
+        }
+        Ok(partitions)
+    }
+
+    /// Casts a value given by the user, e.g. in `DROP PARTITION`, to the type of the partitions.
+    pub fn cast_value(&self, value: &DataValue) -> Result<DataValue> {
+        DataColumn::Constant(value.clone(), 1)
+            .cast_with_type(&self.data_type)?
+            .try_get(0)
+    }
+}
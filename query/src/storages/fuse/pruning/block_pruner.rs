@@ -13,11 +13,18 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_dal::DataAccessor;
+use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
 use common_exception::Result;
+use common_planners::col;
+use common_planners::ExprRewriter;
+use common_planners::Expression;
 use common_planners::Extras;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -25,17 +32,25 @@ use futures::TryStreamExt;
 use crate::storages::fuse::io;
 use crate::storages::fuse::io::snapshot_location;
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::index::BlockStatistics;
+use crate::storages::index::ColumnStatistics;
 use crate::storages::index::RangeFilter;
 
+/// Name of the column which stands for the partition expression in the filters
+const PARTITION_COLUMN_NAME: &str = "_partition";
+
 pub struct BlockPruner {
     table_snapshot_loc: String,
     da: Arc<dyn DataAccessor>,
 }
 
 type Pred = Box<dyn Fn(&BlockStatistics) -> Result<bool> + Send + Sync + Unpin>;
+type PartitionPred =
+    Box<dyn Fn(&BlockStatistics, &DataValue) -> Result<bool> + Send + Sync + Unpin>;
+
 impl BlockPruner {
     pub fn new(table_snapshot: &TableSnapshot, da: Arc<dyn DataAccessor>) -> Self {
         Self {
@@ -49,15 +64,51 @@ impl BlockPruner {
     pub async fn apply(
         &self,
         schema: DataSchemaRef,
+        col_ids: &[ColumnId],
+        partition_by: Option<&Expression>,
         push_down: &Option<Extras>,
     ) -> Result<Vec<BlockMeta>> {
-        let block_pred: Pred = match push_down {
+        // statistics are keyed by column id, while the range filter refers to the
+        // columns by their index in the schema
+        let col_indices = Arc::new(
+            col_ids
+                .iter()
+                .enumerate()
+                .map(|(idx, col_id)| (*col_id, idx as ColumnId))
+                .collect::<HashMap<_, _>>(),
+        );
+        let index_stats = move |v: &BlockStatistics| {
+            v.iter()
+                .filter_map(|(col_id, col_stats)| {
+                    col_indices.get(col_id).map(|idx| (*idx, col_stats.clone()))
+                })
+                .collect::<BlockStatistics>()
+        };
+
+        let (block_pred, partition_pred): (Pred, Option<PartitionPred>) = match push_down {
             Some(exprs) if !exprs.filters.is_empty() => {
                 // for the time being, we only handle the first expr
-                let verifiable_expression = RangeFilter::try_create(&exprs.filters[0], schema)?;
-                Box::new(move |v: &BlockStatistics| verifiable_expression.eval(v))
+                let filter = &exprs.filters[0];
+                let verifiable_expression = RangeFilter::try_create(filter, schema.clone())?;
+                let partition_pred = match partition_by {
+                    None => None,
+                    Some(partition_by) => Some(Self::partition_pred(
+                        filter,
+                        partition_by,
+                        schema,
+                        index_stats.clone(),
+                    )?),
+                };
+
+                let block_pred: Pred = Box::new(move |v: &BlockStatistics| {
+                    verifiable_expression.eval(&index_stats(v))
+                });
+                (block_pred, partition_pred)
+            }
+            _ => {
+                let block_pred: Pred = Box::new(|_: &BlockStatistics| Ok(true));
+                (block_pred, None)
             }
-            _ => Box::new(|_: &BlockStatistics| Ok(true)),
         };
 
         let snapshot: TableSnapshot =
@@ -72,7 +123,7 @@ impl BlockPruner {
         let res = futures::stream::iter(segment_locs)
             .map(|seg_loc| async {
                 let segment_info: SegmentInfo = io::read_obj(self.da.as_ref(), seg_loc).await?;
-                Self::filter_segment(segment_info, &block_pred)
+                Self::filter_segment(segment_info, &block_pred, &partition_pred)
             })
             // configuration of the max size of buffered futures
             .buffered(std::cmp::min(10, segment_num))
@@ -84,8 +135,55 @@ impl BlockPruner {
         Ok(res.collect())
     }
 
+    /// A whole partition is pruned by the filter in which the partition expression is
+    /// replaced with a column, whose minimum and maximum are the value of the partition.
+    fn partition_pred(
+        filter: &Expression,
+        partition_by: &Expression,
+        schema: DataSchemaRef,
+        index_stats: impl Fn(&BlockStatistics) -> BlockStatistics + Send + Sync + Unpin + 'static,
+    ) -> Result<PartitionPred> {
+        let partition_field = DataField::new(
+            PARTITION_COLUMN_NAME,
+            partition_by.to_data_type(&schema)?,
+            true,
+        );
+        let partition_idx = schema.fields().len() as ColumnId;
+        let mut fields = schema.fields().clone();
+        fields.push(partition_field);
+
+        let filter = filter
+            .clone()
+            .rewrite(&mut PartitionRewriter { partition_by })?;
+        let verifiable_expression =
+            RangeFilter::try_create(&filter, DataSchemaRefExt::create(fields))?;
+
+        Ok(Box::new(
+            move |v: &BlockStatistics, partition: &DataValue| {
+                let mut stats = index_stats(v);
+                stats.insert(partition_idx, ColumnStatistics {
+                    min: partition.clone(),
+                    max: partition.clone(),
+                    null_count: partition.is_null() as u64,
+                    in_memory_size: 0,
+                });
+                verifiable_expression.eval(&stats)
+            },
+        ))
+    }
+
     #[inline]
-    fn filter_segment(segment_info: SegmentInfo, pred: &Pred) -> Result<Vec<BlockMeta>> {
+    fn filter_segment(
+        segment_info: SegmentInfo,
+        pred: &Pred,
+        partition_pred: &Option<PartitionPred>,
+    ) -> Result<Vec<BlockMeta>> {
+        if let (Some(partition_pred), Some(partition)) = (partition_pred, &segment_info.partition) {
+            if !partition_pred(&segment_info.summary.col_stats, partition)? {
+                return Ok(vec![]);
+            }
+        }
+
         if pred(&segment_info.summary.col_stats)? {
             let block_num = segment_info.blocks.len();
             segment_info.blocks.into_iter().try_fold(
@@ -103,13 +201,28 @@ impl BlockPruner {
     }
 }
 
+struct PartitionRewriter<'a> {
+    partition_by: &'a Expression,
+}
+
+impl<'a> ExprRewriter for PartitionRewriter<'a> {
+    fn mutate(&mut self, expr: Expression) -> Result<Expression> {
+        match &expr == self.partition_by {
+            true => Ok(col(PARTITION_COLUMN_NAME)),
+            false => Ok(expr),
+        }
+    }
+}
+
 pub async fn apply_block_pruning(
     table_snapshot: &TableSnapshot,
     schema: DataSchemaRef,
+    col_ids: &[ColumnId],
+    partition_by: Option<&Expression>,
     push_down: &Option<Extras>,
     data_accessor: Arc<dyn DataAccessor>,
 ) -> Result<Vec<BlockMeta>> {
     BlockPruner::new(table_snapshot, data_accessor)
-        .apply(schema, push_down)
+        .apply(schema, col_ids, partition_by, push_down)
         .await
 }
@@ -17,7 +17,10 @@ use std::collections::HashMap;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::DataColumn;
+use common_datavalues::prelude::IntoSeries;
 use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
 
 use crate::storages::fuse::meta::BlockLocation;
 use crate::storages::fuse::meta::BlockMeta;
@@ -33,6 +36,8 @@ pub struct StatisticsAccumulator {
     pub summary_block_count: u64,
     pub in_memory_size: u64,
     pub file_size: u64,
+    /// Ids of the columns of the blocks, 0..n if empty
+    pub col_ids: Vec<ColumnId>,
 }
 
 impl StatisticsAccumulator {
@@ -40,6 +45,13 @@ impl StatisticsAccumulator {
         Default::default()
     }
 
+    pub fn with_column_ids(col_ids: Vec<ColumnId>) -> Self {
+        Self {
+            col_ids,
+            ..Default::default()
+        }
+    }
+
     pub fn begin(mut self, block: &DataBlock) -> common_exception::Result<PartiallyAccumulated> {
         let row_count = block.num_rows() as u64;
         let block_in_memory_size = block.memory_size() as u64;
@@ -47,18 +59,39 @@ impl StatisticsAccumulator {
         self.summary_block_count += 1;
         self.summary_row_count += row_count;
         self.in_memory_size += block_in_memory_size;
-        let block_stats = Self::acc_columns(block)?;
+        let col_ids = self.block_column_ids(block.num_columns());
+        let block_stats = Self::acc_columns(block)?
+            .into_iter()
+            .map(|(idx, col_stats)| (col_ids[idx as usize], col_stats))
+            .collect::<BlockStatistics>();
         self.blocks_statistics.push(block_stats.clone());
+        let col_types = block
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
         Ok(PartiallyAccumulated {
             accumulator: self,
             block_row_count: block.num_rows() as u64,
             block_size: block.memory_size() as u64,
             block_column_statistics: block_stats,
+            block_col_ids: col_ids,
+            block_col_types: col_types,
         })
     }
 
     pub fn summary(&self, schema: &DataSchema) -> common_exception::Result<BlockStatistics> {
-        super::reduce_block_stats(&self.blocks_statistics, schema)
+        let col_ids = self.block_column_ids(schema.fields().len());
+        super::reduce_block_stats(&self.blocks_statistics, schema, &col_ids)
+    }
+
+    fn block_column_ids(&self, num_columns: usize) -> Vec<ColumnId> {
+        if self.col_ids.is_empty() {
+            (0..num_columns as ColumnId).collect()
+        } else {
+            self.col_ids.clone()
+        }
     }
 
     pub fn acc_columns(data_block: &DataBlock) -> common_exception::Result<BlockStatistics> {
@@ -66,15 +99,21 @@ impl StatisticsAccumulator {
             .into_iter()
             .zip(data_block.columns().iter())
             .map(|(idx, col)| {
-                let min = match col {
-                    DataColumn::Array(s) => s.min(),
-                    DataColumn::Constant(v, _) => Ok(v.clone()),
-                }?;
-
-                let max = match col {
-                    DataColumn::Array(s) => s.max(),
-                    DataColumn::Constant(v, _) => Ok(v.clone()),
-                }?;
+                let (min, max) = match (col, col.data_type()) {
+                    // the nested types have no natural order, their min/max are kept as NULLs
+                    (
+                        _,
+                        data_type @ (DataType::List(_) | DataType::Struct(_) | DataType::Map(_, _)),
+                    ) => (DataValue::from(&data_type), DataValue::from(&data_type)),
+                    // the bounds of LowCardinality are taken from the dictionary values,
+                    // which may be looser than the rows but never need decoding the rows
+                    (DataColumn::Array(s), DataType::LowCardinality(_)) => {
+                        let values = s.dictionary()?.values().into_series();
+                        (values.min()?, values.max()?)
+                    }
+                    (DataColumn::Array(s), _) => (s.min()?, s.max()?),
+                    (DataColumn::Constant(v, _), _) => (v.clone(), v.clone()),
+                };
 
                 let null_count = match col {
                     DataColumn::Array(s) => s.null_count(),
@@ -107,6 +146,8 @@ pub struct PartiallyAccumulated {
     block_row_count: u64,
     block_size: u64,
     block_column_statistics: HashMap<ColumnId, ColumnStatistics>,
+    block_col_ids: Vec<ColumnId>,
+    block_col_types: Vec<DataType>,
 }
 
 impl PartiallyAccumulated {
@@ -120,7 +161,10 @@ impl PartiallyAccumulated {
             },
             row_count: self.block_row_count,
             block_size: self.block_size,
+            file_size,
             col_stats: self.block_column_statistics,
+            col_ids: self.block_col_ids,
+            col_types: self.block_col_types,
         };
         stats.blocks_metas.push(block_meta);
         self.accumulator
@@ -18,6 +18,8 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
 use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
 use common_exception::Result;
 
 use crate::storages::fuse::meta::ColumnId;
@@ -25,9 +27,13 @@ use crate::storages::fuse::meta::Statistics;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
 
+/// Reduces the statistics of the columns of `schema`, whose ids are `col_ids`.
+///
+/// Statistics of columns which are not in the schema (dropped ones) are discarded.
 pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
     stats: &[T],
     schema: &DataSchema,
+    col_ids: &[ColumnId],
 ) -> Result<BlockStatistics> {
     let len = stats.len();
 
@@ -68,19 +74,23 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
-            // TODO panic
-            let data_type = schema.field((*id) as usize).data_type();
+            let data_type = match col_ids.iter().position(|col_id| col_id == id) {
+                Some(idx) => schema.field(idx).data_type(),
+                None => return Ok(acc),
+            };
 
             // TODO
             // for some data types, we shall balance the accuracy and the length
             // e.g. for a string col, which max value is "abcdef....", we record the max as something like "b"
-            let min =
-                common_datavalues::DataValue::try_into_data_array(min_stats.as_slice(), data_type)?
-                    .min()?;
-
-            let max =
-                common_datavalues::DataValue::try_into_data_array(max_stats.as_slice(), data_type)?
-                    .max()?;
+            let (min, max) = match data_type {
+                DataType::List(_) | DataType::Struct(_) | DataType::Map(_, _) => {
+                    (DataValue::from(data_type), DataValue::from(data_type))
+                }
+                _ => (
+                    DataValue::try_into_data_array(min_stats.as_slice(), data_type)?.min()?,
+                    DataValue::try_into_data_array(max_stats.as_slice(), data_type)?.max()?,
+                ),
+            };
 
             acc.insert(*id, ColumnStatistics {
                 min,
@@ -92,13 +102,18 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
         })
 }
 
-pub fn merge_statistics(schema: &DataSchema, l: &Statistics, r: &Statistics) -> Result<Statistics> {
+pub fn merge_statistics(
+    schema: &DataSchema,
+    col_ids: &[ColumnId],
+    l: &Statistics,
+    r: &Statistics,
+) -> Result<Statistics> {
     let s = Statistics {
         row_count: l.row_count + r.row_count,
         block_count: l.block_count + r.block_count,
         uncompressed_byte_size: l.uncompressed_byte_size + r.uncompressed_byte_size,
         compressed_byte_size: l.compressed_byte_size + r.compressed_byte_size,
-        col_stats: reduce_block_stats(&[&l.col_stats, &r.col_stats], schema)?,
+        col_stats: reduce_block_stats(&[&l.col_stats, &r.col_stats], schema, col_ids)?,
     };
     Ok(s)
 }
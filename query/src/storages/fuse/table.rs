@@ -18,8 +18,11 @@ use std::convert::TryFrom;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_planners::AlterTablePlan;
+use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
@@ -30,8 +33,13 @@ use futures::StreamExt;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::partition;
+use crate::storages::fuse::ttl;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_IDS;
+use crate::storages::fuse::TBL_OPT_KEY_NEXT_COLUMN_ID;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 use crate::storages::StorageContext;
 use crate::storages::Table;
@@ -115,6 +123,14 @@ impl Table for FuseTable {
     ) -> Result<()> {
         self.do_truncate(ctx, truncate_plan).await
     }
+
+    async fn alter_table(&self, ctx: Arc<QueryContext>, alter_plan: AlterTablePlan) -> Result<()> {
+        self.do_alter_table(ctx, alter_plan).await
+    }
+
+    async fn purge(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        self.do_purge(ctx).await
+    }
 }
 
 impl FuseTable {
@@ -133,4 +149,43 @@ impl FuseTable {
             Ok(None)
         }
     }
+
+    pub(crate) fn column_ids(&self) -> Result<Vec<ColumnId>> {
+        column_ids(&self.table_info)
+    }
+
+    pub(crate) fn partition_expression(&self) -> Result<Option<Expression>> {
+        partition::partition_expression(&self.table_info)
+    }
+
+    pub(crate) fn ttl_expression(&self) -> Result<Option<Expression>> {
+        ttl::ttl_expression(&self.table_info)
+    }
+
+    pub(crate) fn next_column_id(&self) -> Result<ColumnId> {
+        match self.table_info.options().get(TBL_OPT_KEY_NEXT_COLUMN_ID) {
+            None => Ok(self.table_info.schema().fields().len() as ColumnId),
+            Some(v) => v.parse::<ColumnId>().map_err(|e| {
+                ErrorCode::LogicalError(format!("invalid {}: {}", TBL_OPT_KEY_NEXT_COLUMN_ID, e))
+            }),
+        }
+    }
+}
+
+/// Ids of the columns of a fuse table, in the same order as the fields of its schema.
+///
+/// The ids are kept stable across `ALTER TABLE`, blocks refer to their columns by id.
+pub fn column_ids(table_info: &TableInfo) -> Result<Vec<ColumnId>> {
+    match table_info.options().get(TBL_OPT_KEY_COLUMN_IDS) {
+        None => Ok((0..table_info.schema().fields().len() as ColumnId).collect()),
+        Some(ids) if ids.is_empty() => Ok(vec![]),
+        Some(ids) => ids
+            .split(',')
+            .map(|id| {
+                id.parse::<ColumnId>().map_err(|e| {
+                    ErrorCode::LogicalError(format!("invalid {}: {}", TBL_OPT_KEY_COLUMN_IDS, e))
+                })
+            })
+            .collect(),
+    }
 }
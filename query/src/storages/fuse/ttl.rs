@@ -0,0 +1,154 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableInfo;
+use common_planners::Expression;
+
+use crate::optimizers::RequireColumnsVisitor;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::storages::fuse::TBL_OPT_KEY_TTL;
+use crate::storages::index::ColumnStatistics;
+
+const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+/// The TTL expression of a fuse table, None if its rows never expire.
+pub fn ttl_expression(table_info: &TableInfo) -> Result<Option<Expression>> {
+    match table_info.options().get(TBL_OPT_KEY_TTL) {
+        None => Ok(None),
+        Some(v) => serde_json::from_str(v)
+            .map(Some)
+            .map_err(|e| ErrorCode::LogicalError(format!("invalid {}: {}", TBL_OPT_KEY_TTL, e))),
+    }
+}
+
+/// Evaluates the time at which rows expire, e.g. `ts + INTERVAL 90 DAY`.
+///
+/// The expression refers to exactly one column and is expected to grow with it, so that
+/// the expiration of a block is bounded by the one of the minimum and the maximum value.
+#[derive(Clone)]
+pub struct TtlEvaluator {
+    column: DataField,
+    expr: Expression,
+    data_type: DataType,
+    executor: Arc<ExpressionExecutor>,
+}
+
+impl TtlEvaluator {
+    pub fn try_create(schema: DataSchemaRef, expr: &Expression) -> Result<Self> {
+        let columns = RequireColumnsVisitor::collect_columns_from_expr(expr)?;
+        if columns.len() != 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "TTL expression must refer to exactly one column, but got {:?}",
+                expr
+            )));
+        }
+
+        let column = schema
+            .field_with_name(columns.iter().next().unwrap())?
+            .clone();
+        let input_schema = DataSchemaRefExt::create(vec![column.clone()]);
+        let field = expr.to_data_field(&input_schema)?;
+        let data_type = field.data_type().clone();
+        if !matches!(
+            data_type,
+            DataType::Date16
+                | DataType::Date32
+                | DataType::DateTime32(_)
+                | DataType::DateTime64(_, _)
+        ) {
+            return Err(ErrorCode::BadArguments(format!(
+                "TTL expression must be a Date or DateTime, but got {:?}",
+                data_type
+            )));
+        }
+
+        let executor = ExpressionExecutor::try_create(
+            "ttl expression executor",
+            input_schema,
+            DataSchemaRefExt::create(vec![field]),
+            vec![expr.clone()],
+            false,
+        )?;
+
+        Ok(Self {
+            column,
+            expr: expr.clone(),
+            data_type,
+            executor: Arc::new(executor),
+        })
+    }
+
+    /// The column which the expression refers to.
+    pub fn column_name(&self) -> &str {
+        self.column.name()
+    }
+
+    /// The range of the expiration times of the rows described by the statistics of the
+    /// column, in seconds since UNIX epoch. None if the column has only nulls.
+    pub fn expire_range(&self, stats: &ColumnStatistics) -> Result<Option<(i64, i64)>> {
+        if stats.min.is_null() || stats.max.is_null() {
+            return Ok(None);
+        }
+
+        let values = [stats.min.clone(), stats.max.clone()];
+        let array = DataValue::try_into_data_array(&values, self.column.data_type())?;
+        let expire_at = self.expire_at(array.into())?;
+        Ok(Some((expire_at[0], expire_at[1])))
+    }
+
+    /// Removes the rows of the block which expire at `now`, in seconds since UNIX epoch.
+    pub fn filter_expired(&self, block: &DataBlock, now: i64) -> Result<DataBlock> {
+        let column = block.try_column_by_name(self.column.name())?.clone();
+        let alive = self
+            .expire_at(column)?
+            .into_iter()
+            .map(|expire_at| expire_at > now)
+            .collect::<Vec<_>>();
+        DataBlock::filter_block(block, Series::new(alive))
+    }
+
+    // The rows with a null expiration time never expire.
+    fn expire_at(&self, column: DataColumn) -> Result<Vec<i64>> {
+        let input = DataBlock::create(DataSchemaRefExt::create(vec![self.column.clone()]), vec![
+            column,
+        ]);
+        let values = self
+            .executor
+            .execute(&input)?
+            .try_column_by_name(&self.expr.column_name())?
+            .to_values()?;
+
+        let unit = match self.data_type {
+            DataType::DateTime32(_) => 1,
+            _ => SECONDS_PER_DAY,
+        };
+        values
+            .iter()
+            .map(|v| match (v.is_null(), &self.data_type) {
+                (true, _) => Ok(i64::MAX),
+                (false, DataType::DateTime64(precision, _)) => {
+                    Ok(v.as_i64()?.div_euclid(datetime64_factor(*precision)))
+                }
+                (false, _) => Ok(v.as_i64()? * unit),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::tokio;
+use common_base::tokio::task::JoinHandle;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::catalogs::Catalog;
+use crate::clusters::Cluster;
+use crate::sessions::SessionManager;
+use crate::storages::fuse::FuseTable;
+
+/// Removes the expired rows of the fuse tables with TTL in the background.
+pub struct TtlGC {
+    session_manager: Arc<SessionManager>,
+    interval: Duration,
+}
+
+impl TtlGC {
+    pub fn create(session_manager: Arc<SessionManager>) -> TtlGC {
+        let conf = session_manager.get_conf();
+        TtlGC {
+            interval: Duration::from_secs(conf.query.ttl_gc_interval_secs),
+            session_manager,
+        }
+    }
+
+    /// Start removing periodically, it is disabled if the interval is 0.
+    pub fn start(self) -> Option<JoinHandle<()>> {
+        if self.interval.is_zero() {
+            return None;
+        }
+
+        Some(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(self.interval).await;
+                match self.expire().await {
+                    Ok(0) => {}
+                    Ok(n) => log::info!("Removed {} expired rows", n),
+                    Err(e) => log::error!("Remove expired rows failure: {:?}", e),
+                }
+            }
+        }))
+    }
+
+    /// Remove the expired rows of the tables of all databases.
+    ///
+    /// A table failing to expire is logged and skipped, it is retried in the next pass.
+    /// Returns the number of removed rows.
+    pub async fn expire(&self) -> Result<u64> {
+        let session = self.session_manager.create_session("TtlGC")?;
+        let ctx = session.create_context().await?;
+        if !Self::runs_on(ctx.get_cluster().as_ref()) {
+            return Ok(0);
+        }
+
+        let catalog = ctx.get_catalog();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| ErrorCode::LogicalError(e.to_string()))?
+            .as_secs() as i64;
+
+        let mut expired = 0;
+        for database in catalog.list_databases().await? {
+            let tables = match catalog.list_tables(database.name()).await {
+                Ok(tables) => tables,
+                Err(e) => {
+                    log::error!("List tables of {} failure: {:?}", database.name(), e);
+                    continue;
+                }
+            };
+
+            for table in tables {
+                if let Some(fuse_table) = table.as_any().downcast_ref::<FuseTable>() {
+                    match fuse_table.do_expire(ctx.clone(), now).await {
+                        Ok(n) => expired += n,
+                        Err(e) => log::error!(
+                            "Remove expired rows of {}.{} failure: {:?}",
+                            database.name(),
+                            table.name(),
+                            e
+                        ),
+                    }
+                }
+            }
+        }
+
+        Ok(expired)
+    }
+
+    /// Only the node with the smallest id of the cluster removes the expired rows.
+    ///
+    /// Two nodes may both remove while the cluster changes, then the snapshot of a table is
+    /// committed by only one of them: the commit checks the version of the table, the other
+    /// one fails on this table and finds nothing left to expire in the next pass.
+    pub fn runs_on(cluster: &Cluster) -> bool {
+        let local_id = cluster.local_id();
+        cluster
+            .get_nodes()
+            .iter()
+            .map(|node| &node.id)
+            .min()
+            .map_or(true, |id| *id == local_id)
+    }
+}
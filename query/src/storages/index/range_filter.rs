@@ -74,20 +74,18 @@ impl RangeFilter {
         })
     }
 
+    /// Returns false if the block can be pruned.
+    ///
+    /// A block without the statistics of some column, e.g. one written before the column
+    /// was added or modified, is never pruned.
     pub fn eval(&self, stats: &BlockStatistics) -> Result<bool> {
-        let columns = self
-            .stat_columns
-            .iter()
-            .map(|c| {
-                let stat = stats.get(&c.column_id).ok_or_else(|| {
-                    ErrorCode::UnknownException(format!(
-                        "Unable to get the colStats by ColumnId: {}",
-                        c.column_id
-                    ))
-                })?;
-                c.apply_stat_value(stat)?.to_array()
-            })
-            .collect::<Result<Vec<_>>>()?;
+        let mut columns = Vec::with_capacity(self.stat_columns.len());
+        for c in &self.stat_columns {
+            match stats.get(&c.column_id) {
+                None => return Ok(true),
+                Some(stat) => columns.push(c.apply_stat_value(stat)?.to_array()?),
+            }
+        }
         let data_block = DataBlock::create_by_array(self.schema.clone(), columns);
         let executed_data_block = self.executor.execute(&data_block)?;
 
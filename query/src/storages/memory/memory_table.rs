@@ -58,6 +58,12 @@ impl MemoryTable {
         let table = Self { table_info, blocks };
         Ok(Box::new(table))
     }
+
+    /// Create a memory table over the given blocks, which are not kept in the storage.
+    pub fn create_with_blocks(table_info: TableInfo, blocks: Vec<DataBlock>) -> Self {
+        let blocks = Arc::new(RwLock::new(blocks));
+        Self { table_info, blocks }
+    }
 }
 
 #[async_trait::async_trait]
@@ -20,6 +20,7 @@ pub mod memory;
 pub mod null;
 pub mod parquet;
 pub mod system;
+pub mod view;
 
 mod storage_context;
 mod storage_factory;
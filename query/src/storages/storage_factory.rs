@@ -27,6 +27,10 @@ use crate::storages::github;
 use crate::storages::memory::MemoryTable;
 use crate::storages::null::NullTable;
 use crate::storages::parquet::ParquetTable;
+use crate::storages::view::MaterializedViewTable;
+use crate::storages::view::ViewTable;
+use crate::storages::view::MATERIALIZED_VIEW_ENGINE;
+use crate::storages::view::VIEW_ENGINE;
 use crate::storages::StorageContext;
 use crate::storages::Table;
 
@@ -94,6 +98,15 @@ impl StorageFactory {
         // Register FUSE table engine.
         creators.insert("FUSE".to_string(), Arc::new(FuseTable::try_create));
 
+        // Register VIEW table engine.
+        creators.insert(VIEW_ENGINE.to_string(), Arc::new(ViewTable::try_create));
+
+        // Register MATERIALIZED_VIEW table engine.
+        creators.insert(
+            MATERIALIZED_VIEW_ENGINE.to_string(),
+            Arc::new(MaterializedViewTable::try_create),
+        );
+
         StorageFactory {
             creators: RwLock::new(creators),
         }
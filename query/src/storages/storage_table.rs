@@ -22,6 +22,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::MetaId;
 use common_meta_types::TableInfo;
+use common_planners::AlterTablePlan;
 use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Part;
@@ -122,4 +123,23 @@ pub trait Table: Sync + Send {
             self.name()
         )))
     }
+
+    async fn alter_table(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _alter_plan: AlterTablePlan,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "alter table for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Remove the data of a dropped table permanently.
+    ///
+    /// Tables that do not keep data in the storage have nothing to remove.
+    async fn purge(&self, _ctx: Arc<QueryContext>) -> Result<()> {
+        Ok(())
+    }
 }
@@ -32,6 +32,9 @@ impl SystemDatabase {
             Arc::new(system::CreditsTable::create(sys_db_meta.next_id())),
             Arc::new(system::SettingsTable::create(sys_db_meta.next_id())),
             Arc::new(system::TablesTable::create(sys_db_meta.next_id())),
+            Arc::new(system::TablesWithHistoryTable::create(
+                sys_db_meta.next_id(),
+            )),
             Arc::new(system::ClustersTable::create(sys_db_meta.next_id())),
             Arc::new(system::DatabasesTable::create(sys_db_meta.next_id())),
             Arc::new(system::TracingTable::create(sys_db_meta.next_id())),
@@ -41,6 +44,7 @@ impl SystemDatabase {
             Arc::new(system::ColumnsTable::create(sys_db_meta.next_id())),
             Arc::new(system::UsersTable::create(sys_db_meta.next_id())),
             Arc::new(system::QueryLogTable::create(sys_db_meta.next_id())),
+            Arc::new(system::QueryCacheTable::create(sys_db_meta.next_id())),
         ];
 
         for tbl in table_list.into_iter() {
@@ -23,9 +23,11 @@ mod functions_table;
 mod metrics_table;
 mod one_table;
 mod processes_table;
+mod query_cache_table;
 mod query_log_table;
 mod settings_table;
 mod tables_table;
+mod tables_with_history_table;
 mod tracing_table;
 mod tracing_table_stream;
 mod users_table;
@@ -41,9 +43,11 @@ pub use functions_table::FunctionsTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
+pub use query_cache_table::QueryCacheTable;
 pub use query_log_table::QueryLogTable;
 pub use settings_table::SettingsTable;
 pub use tables_table::TablesTable;
+pub use tables_with_history_table::TablesWithHistoryTable;
 pub use tracing_table::TracingTable;
 pub use tracing_table_stream::TracingTableStream;
 pub use users_table::UsersTable;
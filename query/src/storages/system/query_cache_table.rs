@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+pub struct QueryCacheTable {
+    table_info: TableInfo,
+}
+
+impl QueryCacheTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("key", DataType::String, false),
+            DataField::new("query", DataType::String, false),
+            DataField::new("result_rows", DataType::UInt64, false),
+            DataField::new("result_bytes", DataType::UInt64, false),
+            DataField::new("hits", DataType::UInt64, false),
+            DataField::new("created_at", DataType::DateTime32(None), false),
+            DataField::new("expire_at", DataType::DateTime32(None), false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_cache'".to_string(),
+            name: "query_cache".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryCache".to_string(),
+                ..Default::default()
+            },
+        };
+        QueryCacheTable { table_info }
+    }
+
+    fn to_seconds(time: &SystemTime) -> u32 {
+        time.duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as u32)
+            .unwrap_or(0)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryCacheTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let cache = ctx.get_sessions_manager().get_query_result_cache();
+        let entries = cache.entries();
+
+        let mut keys = Vec::with_capacity(entries.len());
+        let mut queries = Vec::with_capacity(entries.len());
+        let mut result_rows = Vec::with_capacity(entries.len());
+        let mut result_bytes = Vec::with_capacity(entries.len());
+        let mut hits = Vec::with_capacity(entries.len());
+        let mut created_at = Vec::with_capacity(entries.len());
+        let mut expire_at = Vec::with_capacity(entries.len());
+
+        for entry in &entries {
+            keys.push(entry.key.as_bytes());
+            queries.push(entry.query.as_bytes());
+            result_rows.push(entry.result_rows as u64);
+            result_bytes.push(entry.result_bytes as u64);
+            hits.push(entry.get_hits());
+            created_at.push(QueryCacheTable::to_seconds(&entry.created_at));
+            expire_at.push(QueryCacheTable::to_seconds(&entry.expire_at));
+        }
+
+        let schema = self.table_info.schema();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(keys),
+            Series::new(queries),
+            Series::new(result_rows),
+            Series::new(result_bytes),
+            Series::new(hits),
+            Series::new(created_at),
+            Series::new(expire_at),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The tables of all databases, along with the dropped ones that are not purged yet.
+///
+/// `dropped_on` is NULL for a table that is not dropped.
+pub struct TablesWithHistoryTable {
+    table_info: TableInfo,
+}
+
+impl TablesWithHistoryTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", DataType::String, false),
+            DataField::new("name", DataType::String, false),
+            DataField::new("engine", DataType::String, false),
+            DataField::new("dropped_on", DataType::DateTime32(None), true),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tables_with_history'".to_string(),
+            name: "tables_with_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTablesWithHistory".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        TablesWithHistoryTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for TablesWithHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let catalog = ctx.get_catalog();
+        let databases = catalog.list_databases().await?;
+
+        let mut database_tables = vec![];
+        for database in databases {
+            let name = database.name();
+            for table in catalog.list_tables(name).await? {
+                let table_info = table.get_table_info().clone();
+                database_tables.push((name.to_string(), table_info, None));
+            }
+            for dropped in catalog.list_dropped_tables(name).await? {
+                let dropped_on = Some(dropped.drop_on as u32);
+                database_tables.push((name.to_string(), dropped.table_info.clone(), dropped_on));
+            }
+        }
+
+        let databases: Vec<&[u8]> = database_tables
+            .iter()
+            .map(|(d, _, _)| d.as_bytes())
+            .collect();
+        let names: Vec<&[u8]> = database_tables
+            .iter()
+            .map(|(_, v, _)| v.name.as_bytes())
+            .collect();
+        let engines: Vec<&[u8]> = database_tables
+            .iter()
+            .map(|(_, v, _)| v.engine().as_bytes())
+            .collect();
+        let dropped_ons: Vec<Option<u32>> = database_tables.iter().map(|(_, _, d)| *d).collect();
+
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(databases),
+            Series::new(names),
+            Series::new(engines),
+            Series::new(dropped_ons),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
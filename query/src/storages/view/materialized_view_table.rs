@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MetaId;
+use common_meta_types::TableInfo;
+use common_meta_types::UpsertTableOptionReq;
+use common_planners::ReadDataSourcePlan;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::view::VIEW_QUERY_KEY;
+use crate::storages::StorageContext;
+use crate::storages::Table;
+
+pub const MATERIALIZED_VIEW_ENGINE: &str = "MATERIALIZED_VIEW";
+
+/// The option key of the id of the table whose inserts are pushed through a materialized view,
+/// the view stays attached to the table across renames.
+pub const MATERIALIZED_VIEW_SOURCE_TABLE_ID_KEY: &str = "source_table_id";
+
+/// The option key of a table, which lists the ids of the materialized views it is the source of.
+pub const MATERIALIZED_VIEWS_KEY: &str = "materialized_views";
+
+/// The option keys of the table which the result of a materialized view is appended to.
+pub const MATERIALIZED_VIEW_TARGET_DATABASE_KEY: &str = "target_database";
+pub const MATERIALIZED_VIEW_TARGET_TABLE_KEY: &str = "target_table";
+
+/// A materialized view holds no data, the blocks inserted into its source table are pushed
+/// through its query and the result is appended to its target table.
+pub struct MaterializedViewTable {
+    table_info: TableInfo,
+    query: String,
+    source_id: MetaId,
+    target: (String, String),
+}
+
+impl MaterializedViewTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let option = |key: &str| -> Result<String> {
+            table_info.options().get(key).cloned().ok_or_else(|| {
+                ErrorCode::LogicalError(format!(
+                    "materialized view {} has no {}",
+                    table_info.name, key
+                ))
+            })
+        };
+
+        let query = option(VIEW_QUERY_KEY)?;
+        let source_id = option(MATERIALIZED_VIEW_SOURCE_TABLE_ID_KEY)?
+            .parse::<MetaId>()
+            .map_err(|e| {
+                ErrorCode::LogicalError(format!(
+                    "invalid {} of materialized view {}: {}",
+                    MATERIALIZED_VIEW_SOURCE_TABLE_ID_KEY, table_info.name, e
+                ))
+            })?;
+        let target = (
+            option(MATERIALIZED_VIEW_TARGET_DATABASE_KEY)?,
+            option(MATERIALIZED_VIEW_TARGET_TABLE_KEY)?,
+        );
+
+        Ok(Box::new(MaterializedViewTable {
+            table_info,
+            query,
+            source_id,
+            target,
+        }))
+    }
+
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+
+    /// The id of the table whose inserts are pushed through the view.
+    pub fn get_source_id(&self) -> MetaId {
+        self.source_id
+    }
+
+    /// The (database, table) the view appends to, it is read in place of the view.
+    pub fn get_target(&self) -> (&str, &str) {
+        (&self.target.0, &self.target.1)
+    }
+
+    /// Add the view to the materialized views of its source table.
+    pub async fn attach_to_source(&self, ctx: &QueryContext) -> Result<()> {
+        self.update_source(ctx, true).await
+    }
+
+    /// Remove the view from the materialized views of its source table, if it still exists.
+    pub async fn detach_from_source(&self, ctx: &QueryContext) -> Result<()> {
+        match self.update_source(ctx, false).await {
+            Err(e) if e.code() == ErrorCode::UnknownTableCode() => Ok(()),
+            res => res,
+        }
+    }
+
+    async fn update_source(&self, ctx: &QueryContext, attach: bool) -> Result<()> {
+        let catalog = ctx.get_catalog();
+        let (ident, meta) = catalog.get_table_meta_by_id(self.source_id).await?;
+
+        let mut view_ids = parse_view_ids(meta.options.get(MATERIALIZED_VIEWS_KEY))?;
+        view_ids.retain(|id| *id != self.get_id());
+        if attach {
+            view_ids.push(self.get_id());
+        }
+
+        let view_ids = view_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        catalog
+            .upsert_table_option(UpsertTableOptionReq::new(
+                &ident,
+                MATERIALIZED_VIEWS_KEY,
+                view_ids,
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Ids of the materialized views which the inserts into the table are pushed through.
+pub fn materialized_view_ids(table_info: &TableInfo) -> Result<Vec<MetaId>> {
+    parse_view_ids(table_info.options().get(MATERIALIZED_VIEWS_KEY))
+}
+
+fn parse_view_ids(view_ids: Option<&String>) -> Result<Vec<MetaId>> {
+    match view_ids {
+        None => Ok(vec![]),
+        Some(ids) if ids.is_empty() => Ok(vec![]),
+        Some(ids) => ids
+            .split(',')
+            .map(|id| {
+                id.parse::<MetaId>().map_err(|e| {
+                    ErrorCode::LogicalError(format!("invalid {}: {}", MATERIALIZED_VIEWS_KEY, e))
+                })
+            })
+            .collect(),
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for MaterializedViewTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        Err(ErrorCode::LogicalError(format!(
+            "materialized view {} must be read from its target table, it's a bug",
+            self.name()
+        )))
+    }
+}
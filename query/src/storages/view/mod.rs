@@ -0,0 +1,27 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod materialized_view_table;
+mod view_table;
+
+pub use materialized_view_table::materialized_view_ids;
+pub use materialized_view_table::MaterializedViewTable;
+pub use materialized_view_table::MATERIALIZED_VIEWS_KEY;
+pub use materialized_view_table::MATERIALIZED_VIEW_ENGINE;
+pub use materialized_view_table::MATERIALIZED_VIEW_SOURCE_TABLE_ID_KEY;
+pub use materialized_view_table::MATERIALIZED_VIEW_TARGET_DATABASE_KEY;
+pub use materialized_view_table::MATERIALIZED_VIEW_TARGET_TABLE_KEY;
+pub use view_table::ViewTable;
+pub use view_table::VIEW_ENGINE;
+pub use view_table::VIEW_QUERY_KEY;
@@ -41,6 +41,8 @@ use crate::table_functions::generate_block_parts;
 use crate::table_functions::table_function_factory::TableArgs;
 use crate::table_functions::TableFunction;
 
+const MAX_MORSELS_PER_THREAD: u64 = 16;
+
 pub struct NumbersTable {
     table_info: TableInfo,
     total: u64,
@@ -119,8 +121,14 @@ impl Table for NumbersTable {
             self.total as usize,
             ((self.total) * size_of::<u64>() as u64) as usize,
         );
-        let parts =
-            generate_block_parts(0, ctx.get_settings().get_max_threads()? as u64, self.total);
+
+        // Split the numbers into morsels of at least one block, so that the pipeline workers
+        // which finish early can steal the remaining morsels of the others.
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()?;
+        let max_block_size = settings.get_max_block_size()?.max(1);
+        let morsels = (self.total / max_block_size).min(max_threads * MAX_MORSELS_PER_THREAD);
+        let parts = generate_block_parts(0, morsels.max(max_threads), self.total);
 
         Ok((statistics, parts))
     }
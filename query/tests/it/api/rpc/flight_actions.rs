@@ -19,6 +19,7 @@ use common_base::tokio;
 use common_datavalues::DataValue;
 use common_exception::Result;
 use common_planners::Expression;
+use databend_query::api::FetchProfileAction;
 use databend_query::api::FlightAction;
 use databend_query::api::ShuffleAction;
 
@@ -35,6 +36,7 @@ async fn test_shuffle_action_try_into() -> Result<()> {
         plan: parse_query("SELECT number FROM numbers(5)", &ctx)?,
         sinks: vec![String::from("stream_id")],
         scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+        profile: false,
     };
 
     let from_action = FlightAction::PrepareShuffleAction(shuffle_action);
@@ -42,6 +44,7 @@ async fn test_shuffle_action_try_into() -> Result<()> {
     let from_action: FlightAction = to_action.try_into()?;
     match from_action {
         FlightAction::CancelAction(_) => panic!(),
+        FlightAction::FetchProfileAction(_) => panic!(),
         FlightAction::BroadcastAction(_) => panic!(),
         FlightAction::PrepareShuffleAction(action) => {
             assert_eq!(action.query_id, "query_id");
@@ -60,3 +63,23 @@ async fn test_shuffle_action_try_into() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_fetch_profile_action_try_into() -> Result<()> {
+    let fetch_profile_action = FetchProfileAction {
+        query_id: String::from("query_id"),
+    };
+
+    let from_action = FlightAction::FetchProfileAction(fetch_profile_action);
+    let to_action: Action = from_action.try_into()?;
+    let from_action: FlightAction = to_action.try_into()?;
+    match from_action {
+        FlightAction::FetchProfileAction(action) => {
+            assert_eq!(action.query_id, "query_id");
+            assert!(!FlightAction::FetchProfileAction(action).get_profile());
+        }
+        _ => panic!(),
+    }
+
+    Ok(())
+}
@@ -169,6 +169,7 @@ fn do_action_request(query_id: &str, stage_id: &str) -> Result<Request<Action>>
         plan: parse_query("SELECT number FROM numbers(5)", &ctx)?,
         sinks: vec![String::from("stream_id")],
         scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+        profile: false,
     });
 
     Ok(Request::new(flight_action.try_into()?))
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_meta_types::CreateDatabaseReq;
+use common_meta_types::CreateTableReq;
+use common_meta_types::DropDatabaseReq;
+use common_meta_types::DropTableReq;
+use common_meta_types::TableMeta;
+use databend_query::catalogs::Catalog;
+use databend_query::catalogs::DroppedTableGC;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_purge_tables_of_dropped_database() -> Result<()> {
+    let sessions = SessionManagerBuilder::create()
+        .dropped_table_retention_secs(0)
+        .build()?;
+    let ctx = sessions
+        .create_session("TestSession")?
+        .create_context()
+        .await?;
+    let catalog = ctx.get_catalog();
+
+    catalog
+        .create_database(CreateDatabaseReq {
+            if_not_exists: false,
+            db: "db1".to_string(),
+            engine: "".to_string(),
+            options: Default::default(),
+        })
+        .await?;
+    catalog
+        .create_table(CreateTableReq {
+            if_not_exists: false,
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+            table_meta: TableMeta {
+                schema: DataSchemaRefExt::create(vec![DataField::new(
+                    "a",
+                    DataType::UInt64,
+                    false,
+                )]),
+                engine: "NULL".to_string(),
+                options: Default::default(),
+            },
+        })
+        .await?;
+    catalog
+        .drop_table(DropTableReq {
+            if_exists: false,
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+        })
+        .await?;
+    catalog
+        .drop_database(DropDatabaseReq {
+            if_exists: false,
+            db: "db1".to_string(),
+        })
+        .await?;
+    assert_eq!(catalog.list_all_dropped_tables().await?.len(), 1);
+
+    let gc = DroppedTableGC::create(sessions.clone());
+    assert_eq!(gc.purge_expired().await?, 1);
+    assert!(catalog.list_all_dropped_tables().await?.is_empty());
+
+    // Another node running the GC finds nothing left to purge.
+    let gc = DroppedTableGC::create(sessions);
+    assert_eq!(gc.purge_expired().await?, 0);
+    Ok(())
+}
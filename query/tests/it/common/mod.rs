@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod hashtable;
+mod result_cache;
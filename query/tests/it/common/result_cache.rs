@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::common::QueryResultCache;
+use databend_query::common::QueryResultCacheKey;
+use databend_query::configs::Config;
+use databend_query::sql::PlanParser;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_result_cache_key() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let tests = vec![
+        ("select 1 + 1", true),
+        ("select now()", false),
+        ("select rand()", false),
+        ("select database()", false),
+        ("select current_user()", false),
+        ("select version()", false),
+        ("select number from numbers(10)", false),
+        ("select * from system.settings", false),
+    ];
+
+    for (query, cacheable) in tests {
+        let plan = PlanParser::parse(query, ctx.clone()).await?;
+        let key = QueryResultCacheKey::try_create(&plan, "default", "root")?;
+        assert_eq!(key.is_some(), cacheable, "{}", query);
+    }
+
+    // The same query gets the same key.
+    let plan = PlanParser::parse("select 1 + 1", ctx.clone()).await?;
+    let key_1 = QueryResultCacheKey::try_create(&plan, "default", "root")?;
+    let key_2 = QueryResultCacheKey::try_create(&plan, "default", "root")?;
+    assert_eq!(key_1, key_2);
+
+    // The current database and user are part of the key.
+    let key_3 = QueryResultCacheKey::try_create(&plan, "db1", "root")?;
+    let key_4 = QueryResultCacheKey::try_create(&plan, "default", "user1")?;
+    assert_ne!(key_1, key_3);
+    assert_ne!(key_1, key_4);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_result_cache_memory() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let plan = PlanParser::parse("select 1 + 1", ctx.clone()).await?;
+    let key = QueryResultCacheKey::try_create(&plan, "default", "root")?.unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1i64, 2, 3])]);
+
+    let cache = QueryResultCache::create_global(Config::default())?;
+    assert!(cache.get(&key)?.is_none());
+
+    cache.put(
+        &key,
+        "select 1 + 1",
+        schema.clone(),
+        vec![block],
+        Duration::from_secs(60),
+    )?;
+
+    let blocks = cache.get(&key)?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+", //
+            "| a |", "+---+", "| 1 |", "| 2 |", "| 3 |", "+---+",
+        ],
+        &blocks,
+    );
+
+    let entries = cache.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].result_rows, 3);
+    assert_eq!(entries[0].get_hits(), 1);
+
+    // Expired entries are never returned.
+    cache.put(&key, "select 1 + 1", schema, vec![], Duration::from_secs(0))?;
+    assert!(cache.get(&key)?.is_none());
+    assert!(cache.entries().is_empty());
+
+    Ok(())
+}
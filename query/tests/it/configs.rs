@@ -62,6 +62,12 @@ table_engine_memory_enabled = true
 table_engine_github_enabled = true
 wait_timeout_mills = 5000
 max_query_log_size = 10000
+query_result_cache_storage = \"memory\"
+query_result_cache_max_bytes = 268435456
+query_result_cache_disk_path = \"_cache/query_result\"
+dropped_table_retention_secs = 86400
+dropped_table_gc_interval_secs = 600
+ttl_gc_interval_secs = 3600
 
 [log]
 log_level = \"INFO\"
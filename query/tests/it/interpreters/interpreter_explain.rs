@@ -57,3 +57,43 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_analyze_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    ctx.get_settings().set_max_threads(1)?;
+
+    static TEST_QUERY: &str = "EXPLAIN ANALYZE SELECT number FROM numbers(10) WHERE number > 4";
+
+    if let PlanNode::Explain(plan) = parse_query(TEST_QUERY, &ctx)? {
+        assert_eq!(plan.typ, ExplainType::Analyze);
+        let executor = ExplainInterpreter::try_create(ctx, plan)?;
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let block = &result[0];
+        let lines = (0..block.num_rows())
+            .map(|row| Ok(block.column(0).try_get(row)?.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let expected = vec![
+            "ProjectionTransform × 1 processor (rows in: 5, rows out: 5,",
+            "  FilterTransform × 1 processor (rows in: 10, rows out: 5,",
+            "    SourceTransform × 1 processor (rows in: 10, rows out: 10,",
+        ];
+        for (line, expected) in lines.iter().zip(expected.iter()) {
+            assert!(line.starts_with(expected), "{} {}", line, expected);
+            assert!(line.contains("wall: ") && line.contains("cpu: "));
+        }
+
+        // The executor profile of the workers follows the pipeline.
+        assert!(lines.len() > expected.len());
+        assert!(lines[expected.len()..]
+            .iter()
+            .all(|line| line.contains("rows out: ")));
+    } else {
+        panic!()
+    }
+
+    Ok(())
+}
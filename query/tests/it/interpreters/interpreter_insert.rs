@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datablocks::DataBlock;
 use common_exception::Result;
 use common_planners::*;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
 use databend_query::sql::*;
 use futures::TryStreamExt;
 
@@ -84,3 +89,148 @@ async fn test_insert_into_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_insert_into_not_null_and_default_columns() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create table.
+    {
+        static TEST_QUERY: &str = "create table default.t(a Int32 not null, b Int32 default a + 1, c String not null default 'x') Engine = Memory";
+        if let PlanNode::CreateTable(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+    }
+
+    // Insert with the missing columns filled by defaults.
+    {
+        static TEST_QUERY: &str = "insert into default.t(a) values(1), (2)";
+        let plan_node = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan_node)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    {
+        static TEST_QUERY: &str = "insert into default.t(a) select number + 10 from numbers(1)";
+        let plan_node = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan_node)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Insert NULL into the NOT NULL column.
+    {
+        static TEST_QUERY: &str = "insert into default.t(a, c) values(3, null)";
+        let plan_node = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan_node)?;
+        let result = executor.execute(None).await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().message(),
+            "Cannot insert NULL into column `c` which is NOT NULL"
+        );
+    }
+
+    // select.
+    {
+        static TEST_QUERY: &str = "select * from default.t";
+        if let PlanNode::Select(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = SelectInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec![
+                "+----+----+---+",
+                "| a  | b  | c |",
+                "+----+----+---+",
+                "| 1  | 2  | x |",
+                "| 10 | 11 | x |",
+                "| 2  | 3  | x |",
+                "+----+----+---+",
+            ];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
+
+/// Each statement runs in a query of its own, as the tables are cached by a query.
+async fn execute_query(ctx: &Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let shared =
+        QueryContextShared::try_create(ctx.get_config(), ctx.get_session(), ctx.get_cluster());
+    let ctx = QueryContext::from_shared(shared);
+    let plan_node = PlanParser::parse(query, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx, plan_node)?;
+    executor.execute(None).await?.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test]
+async fn test_insert_into_materialized_views() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "create table default.events(a Int64, b Int64) Engine = Memory",
+        "create table default.copies(a Int64, b Int64) Engine = Memory",
+        "create table default.strict(a Int64 not null, b Int64) Engine = Memory",
+        "create materialized view default.mv to default.copies as select a, b from default.events",
+        // The rows are pushed block by block.
+        "SET max_block_size=2",
+        "insert into default.events select number, number * 10 from numbers(5)",
+        // The view follows its source table across renames.
+        "rename table default.events to default.events2",
+        "insert into default.events2 values(10, 100)",
+    ] {
+        execute_query(&ctx, query).await?;
+    }
+
+    let expected = vec![
+        "+---------+--------+--------+",
+        "| count() | sum(a) | sum(b) |",
+        "+---------+--------+--------+",
+        "| 6       | 20     | 200    |",
+        "+---------+--------+--------+",
+    ];
+    let result = execute_query(&ctx, "select count(), sum(a), sum(b) from default.copies").await?;
+    common_datablocks::assert_blocks_eq(expected.clone(), result.as_slice());
+
+    // A view failing to push fails the whole insert, neither the table nor the other views
+    // keep the rows.
+    execute_query(
+        &ctx,
+        "create materialized view default.mv_strict to default.strict as select b, a from default.events2",
+    )
+    .await?;
+    let result = execute_query(&ctx, "insert into default.events2 values(11, null)").await;
+    let cause = result.err().unwrap();
+    assert_eq!(cause.code(), 62);
+    assert!(cause
+        .message()
+        .ends_with("Cannot insert NULL into column `a` which is NOT NULL"));
+
+    let result = execute_query(&ctx, "select count(), sum(a), sum(b) from default.copies").await?;
+    common_datablocks::assert_blocks_eq(expected.clone(), result.as_slice());
+    let result = execute_query(&ctx, "select count() from default.events2").await?;
+    let events = vec![
+        "+---------+",
+        "| count() |",
+        "+---------+",
+        "| 6       |",
+        "+---------+",
+    ];
+    common_datablocks::assert_blocks_eq(events, result.as_slice());
+
+    // The dropped views are not pushed to any more.
+    for query in [
+        "drop view default.mv_strict",
+        "drop view default.mv",
+        "insert into default.events2 values(11, null)",
+    ] {
+        execute_query(&ctx, query).await?;
+    }
+    let result = execute_query(&ctx, "select count(), sum(a), sum(b) from default.copies").await?;
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::*;
+use databend_query::catalogs::Catalog;
 use databend_query::interpreters::*;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
 
 use crate::tests::parse_query;
 
@@ -54,3 +57,64 @@ async fn test_create_table_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_table_as_select_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    static TEST_CREATE_QUERY: &str = "\
+        CREATE TABLE default.a Engine = Memory \
+        AS SELECT number AS c1, number * 2 AS c2 FROM numbers(3)\
+    ";
+
+    if let PlanNode::CreateTable(plan) = parse_query(TEST_CREATE_QUERY, &ctx)? {
+        let schema = plan.schema();
+        assert_eq!(schema.fields().len(), 2);
+        assert_eq!(schema.field(0).name(), "c1");
+        assert_eq!(schema.field(1).name(), "c2");
+
+        let interpreter = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+        let mut stream = interpreter.execute(None).await?;
+        while let Some(_block) = stream.next().await {}
+    } else {
+        panic!()
+    }
+
+    // The staging table is renamed to the requested name.
+    let catalog = ctx.get_catalog();
+    let tables = catalog.list_tables("default").await?;
+    let names: Vec<&str> = tables.iter().map(|t| t.name()).collect();
+    assert_eq!(names, vec!["a"]);
+
+    if let PlanNode::Select(plan) = parse_query("SELECT * FROM default.a", &ctx)? {
+        let interpreter = SelectInterpreter::try_create(ctx.clone(), plan)?;
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----+----+",
+            "| c1 | c2 |",
+            "+----+----+",
+            "| 0  | 0  |",
+            "| 1  | 2  |",
+            "| 2  | 4  |",
+            "+----+----+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    } else {
+        panic!()
+    }
+
+    // The table exists already.
+    if let PlanNode::CreateTable(plan) = parse_query(TEST_CREATE_QUERY, &ctx)? {
+        let interpreter = CreateTableInterpreter::try_create(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(
+            res.err().unwrap().code(),
+            ErrorCode::TableAlreadyExistsCode()
+        );
+    } else {
+        panic!()
+    }
+
+    Ok(())
+}
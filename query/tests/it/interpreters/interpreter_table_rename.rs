@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::catalogs::Catalog;
+use databend_query::interpreters::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::parse_query;
+
+#[tokio::test]
+async fn test_rename_table_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create table.
+    {
+        static TEST_CREATE_QUERY: &str = "\
+            CREATE TABLE default.a(\
+                a bigint, b int, c varchar(255), d smallint, e Date\
+            ) Engine = Null\
+        ";
+
+        if let PlanNode::CreateTable(plan) = parse_query(TEST_CREATE_QUERY, &ctx)? {
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+    }
+
+    // Rename table.
+    {
+        if let PlanNode::RenameTable(plan) = parse_query("RENAME TABLE a TO b", &ctx)? {
+            let executor = RenameTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "RenameTableInterpreter");
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+
+        let catalog = ctx.get_catalog();
+        let table = catalog.get_table("default", "b").await?;
+        assert_eq!(table.name(), "b");
+
+        let res = catalog.get_table("default", "a").await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UnknownTableCode());
+    }
+
+    // Rename into the system database is not allowed.
+    {
+        if let PlanNode::RenameTable(plan) = parse_query("RENAME TABLE b TO system.tables", &ctx)? {
+            let executor = RenameTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute(None).await;
+            assert!(res.is_err());
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
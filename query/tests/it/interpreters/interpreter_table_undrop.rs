@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::catalogs::Catalog;
+use databend_query::interpreters::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::parse_query;
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_undrop_table_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create and drop table.
+    {
+        static TEST_CREATE_QUERY: &str = "\
+            CREATE TABLE default.a(\
+                a bigint, b int, c varchar(255), d smallint, e Date\
+            ) Engine = Null\
+        ";
+
+        if let PlanNode::CreateTable(plan) = parse_query(TEST_CREATE_QUERY, &ctx)? {
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+
+        if let PlanNode::DropTable(plan) = parse_query("DROP TABLE a", &ctx)? {
+            let executor = DropTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        }
+
+        let catalog = ctx.get_catalog();
+        let dropped = catalog.list_dropped_tables("default").await?;
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(dropped[0].table_info.name, "a");
+    }
+
+    // Undrop table.
+    {
+        if let PlanNode::UndropTable(plan) = parse_query("UNDROP TABLE a", &ctx)? {
+            let executor = UndropTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "UndropTableInterpreter");
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+
+        let catalog = ctx.get_catalog();
+        let table = catalog.get_table("default", "a").await?;
+        assert_eq!(table.name(), "a");
+        assert!(catalog.list_dropped_tables("default").await?.is_empty());
+    }
+
+    // Nothing left to undrop.
+    {
+        if let PlanNode::UndropTable(plan) = parse_query("UNDROP TABLE a", &ctx)? {
+            let executor = UndropTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute(None).await;
+            assert_eq!(res.err().unwrap().code(), ErrorCode::UnknownTableCode());
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_undrop_expired_table_interpreter() -> Result<()> {
+    let sessions = SessionManagerBuilder::create()
+        .dropped_table_retention_secs(0)
+        .build()?;
+    let ctx = sessions
+        .create_session("TestSession")?
+        .create_context()
+        .await?;
+
+    for query in [
+        "CREATE TABLE default.a(a bigint) Engine = Null",
+        "DROP TABLE a",
+    ] {
+        let plan = parse_query(query, &ctx)?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // the retention period is expired, the GC may be purging its data
+    if let PlanNode::UndropTable(plan) = parse_query("UNDROP TABLE a", &ctx)? {
+        let executor = UndropTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+        let res = executor.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UnknownTableCode());
+    } else {
+        panic!()
+    }
+
+    let catalog = ctx.get_catalog();
+    assert_eq!(catalog.list_dropped_tables("default").await?.len(), 1);
+
+    Ok(())
+}
@@ -26,6 +26,8 @@ mod interpreter_show_create_table;
 mod interpreter_stage_create;
 mod interpreter_table_create;
 mod interpreter_table_drop;
+mod interpreter_table_rename;
+mod interpreter_table_undrop;
 mod interpreter_truncate_table;
 mod interpreter_use_database;
 mod interpreter_user_alter;
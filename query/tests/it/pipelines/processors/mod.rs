@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod morsel_queue;
 mod pipe;
 mod pipeline_builder;
 mod pipeline_display;
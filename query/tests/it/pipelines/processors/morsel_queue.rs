@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_planners::Part;
+use databend_query::pipelines::processors::*;
+use pretty_assertions::assert_eq;
+
+fn create_parts(num: usize) -> Vec<Part> {
+    (0..num)
+        .map(|i| Part {
+            name: format!("{}", i),
+            version: 0,
+        })
+        .collect()
+}
+
+fn part_names(parts: &[Part]) -> Vec<String> {
+    parts.iter().map(|part| part.name.clone()).collect()
+}
+
+#[test]
+fn test_morsel_queue_without_worker() {
+    let profile = ExecutorProfile::create();
+    let queue = MorselQueue::create(2);
+    queue.push_partitions(create_parts(3));
+
+    // The partitions are popped in order.
+    let parts = queue.pop_partitions(2, &profile);
+    assert_eq!(part_names(&parts), vec!["0", "1"]);
+    let parts = queue.pop_partitions(2, &profile);
+    assert_eq!(part_names(&parts), vec!["2"]);
+    let parts = queue.pop_partitions(2, &profile);
+    assert!(parts.is_empty());
+
+    let workers = profile.get_workers();
+    assert_eq!(workers.len(), 1);
+    assert_eq!(workers[0].0, None);
+    assert_eq!(workers[0].1.morsels, 3);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_morsel_queue_work_stealing() {
+    let profile = ExecutorProfile::create();
+    let queue = MorselQueue::create(2);
+    queue.push_partitions(create_parts(8));
+
+    // The worker 0 refills its local queue with a batch of the injector.
+    let parts = run_as_worker(0, async { queue.pop_partitions(1, &profile) }).await;
+    assert_eq!(part_names(&parts), vec!["0"]);
+
+    // The worker 1 drains the injector, then steals the partitions of the worker 0.
+    let parts = run_as_worker(1, async { queue.pop_partitions(8, &profile) }).await;
+    assert_eq!(parts.len(), 7);
+
+    let parts = run_as_worker(0, async { queue.pop_partitions(1, &profile) }).await;
+    assert!(parts.is_empty());
+
+    let workers = profile.get_workers();
+    assert_eq!(workers.len(), 2);
+    assert_eq!(workers[0].0, Some(0));
+    assert_eq!(workers[0].1.morsels, 1);
+    assert_eq!(workers[1].0, Some(1));
+    assert!(workers[1].1.stolen_morsels > 0);
+    assert_eq!(workers[1].1.morsels + workers[1].1.stolen_morsels, 7);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_current_worker() {
+    assert_eq!(current_worker(), None);
+    assert_eq!(run_as_worker(3, async { current_worker() }).await, Some(3));
+}
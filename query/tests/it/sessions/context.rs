@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_base::tokio::sync::Notify;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use databend_query::api::DatabendQueryFlightDispatcher;
+use databend_query::api::RpcService;
+use databend_query::interpreters::ScheduledStream;
+use databend_query::servers::Server;
+use futures::TryStreamExt;
+
+use crate::tests::create_query_context_with_cluster;
+use crate::tests::ClusterDescriptor;
+use crate::tests::SessionManagerBuilder;
+
+fn create_source() -> SendableDataBlockStream {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1i64, 2, 3])]);
+    Box::pin(DataBlockStream::create(schema, None, vec![block]))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_killed_query_aborts_new_sources() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let session = sessions.create_session("TestSession")?;
+    let ctx = session.create_context().await?;
+
+    session.force_kill_query();
+    assert!(ctx.is_killed());
+
+    // The source created after the kill is aborted at once.
+    let stream = ctx.try_create_abortable(create_source())?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::AbortedQuery("").code()
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_dropped_scheduled_stream_cancels_remote_query() -> Result<()> {
+    let remote_sessions = SessionManagerBuilder::create().build()?;
+    let mut rpc_service = RpcService {
+        abort_notify: Arc::new(Notify::new()),
+        dispatcher: Arc::new(DatabendQueryFlightDispatcher::create()),
+        sessions: remote_sessions.clone(),
+    };
+    let address = rpc_service
+        .start(SocketAddr::from_str("127.0.0.1:0")?)
+        .await?
+        .to_string();
+
+    let ctx = create_query_context_with_cluster(
+        ClusterDescriptor::new()
+            .with_node("remote", address.clone())
+            .with_local_id("local"),
+    )?;
+
+    // The fragment of the query which is running on the remote node.
+    let remote_session = remote_sessions.create_rpc_session(ctx.get_id(), false)?;
+    let remote_ctx = remote_session.create_context().await?;
+
+    let mut scheduled = HashMap::new();
+    let node = Arc::new(NodeInfo::create("remote".to_string(), 0, address));
+    scheduled.insert("remote".to_string(), node);
+
+    // The stream is dropped before its end, e.g. the local source is killed by the timeout.
+    let stream = ScheduledStream::create(ctx.clone(), scheduled, create_source());
+    tokio::task::spawn_blocking(move || drop(stream))
+        .await
+        .map_err(|e| ErrorCode::TokioError(e.to_string()))?;
+
+    assert!(remote_ctx.is_killed());
+    Ok(())
+}
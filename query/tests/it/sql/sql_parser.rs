@@ -20,22 +20,30 @@ use common_meta_types::FileFormat;
 use common_meta_types::StageParams;
 use common_meta_types::UserPrivilege;
 use common_meta_types::UserPrivilegeType;
+use databend_query::sql::statements::DfAlterTable;
+use databend_query::sql::statements::DfAlterTableOperation;
 use databend_query::sql::statements::DfAlterUser;
 use databend_query::sql::statements::DfCopy;
 use databend_query::sql::statements::DfCreateDatabase;
+use databend_query::sql::statements::DfCreateMaterializedView;
 use databend_query::sql::statements::DfCreateStage;
 use databend_query::sql::statements::DfCreateTable;
 use databend_query::sql::statements::DfCreateUser;
+use databend_query::sql::statements::DfCreateView;
 use databend_query::sql::statements::DfDescribeTable;
 use databend_query::sql::statements::DfDropDatabase;
 use databend_query::sql::statements::DfDropTable;
 use databend_query::sql::statements::DfDropUser;
+use databend_query::sql::statements::DfDropView;
 use databend_query::sql::statements::DfGrantObject;
 use databend_query::sql::statements::DfGrantStatement;
+use databend_query::sql::statements::DfRenameDatabase;
+use databend_query::sql::statements::DfRenameTable;
 use databend_query::sql::statements::DfRevokeStatement;
 use databend_query::sql::statements::DfShowDatabases;
 use databend_query::sql::statements::DfShowTables;
 use databend_query::sql::statements::DfTruncateTable;
+use databend_query::sql::statements::DfUndropTable;
 use databend_query::sql::statements::DfUseDatabase;
 use databend_query::sql::*;
 use sqlparser::ast::*;
@@ -129,20 +137,48 @@ fn drop_database() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rename_database() -> Result<()> {
+    {
+        let sql = "ALTER DATABASE db1 RENAME TO db2";
+        let expected = DfStatement::RenameDatabase(DfRenameDatabase {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("db1")]),
+            new_name: ObjectName(vec![Ident::new("db2")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "ALTER DATABASE IF EXISTS db1 RENAME TO db2";
+        let expected = DfStatement::RenameDatabase(DfRenameDatabase {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1")]),
+            new_name: ObjectName(vec![Ident::new("db2")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
 #[test]
 fn create_table() -> Result<()> {
     // positive case
     let sql = "CREATE TABLE t(c1 int) ENGINE = CSV location = '/data/33.csv' ";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        temporary: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![make_column_def("c1", DataType::Int(None))],
         engine: "CSV".to_string(),
+        partition_by: None,
+        ttl: None,
         options: vec![SqlOption {
             name: Ident::new("location".to_string()),
             value: Value::SingleQuotedString("/data/33.csv".into()),
         }],
         like: None,
+        query: None,
     });
     expect_parse_ok(sql, expected)?;
 
@@ -150,6 +186,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE t(c1 int, c2 bigint, c3 varchar(255) ) ENGINE = Parquet location = 'foo.parquet' comment = 'foo'";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        temporary: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![
             make_column_def("c1", DataType::Int(None)),
@@ -157,6 +194,8 @@ fn create_table() -> Result<()> {
             make_column_def("c3", DataType::Varchar(Some(255))),
         ],
         engine: "Parquet".to_string(),
+        partition_by: None,
+        ttl: None,
         options: vec![
             SqlOption {
                 name: Ident::new("location".to_string()),
@@ -168,6 +207,7 @@ fn create_table() -> Result<()> {
             },
         ],
         like: None,
+        query: None,
     });
     expect_parse_ok(sql, expected)?;
 
@@ -175,14 +215,133 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE db1.test1 LIKE db2.test2 ENGINE = Parquet location = 'batcave'";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        temporary: false,
         name: ObjectName(vec![Ident::new("db1"), Ident::new("test1")]),
         columns: vec![],
         engine: "Parquet".to_string(),
+        partition_by: None,
+        ttl: None,
         options: vec![SqlOption {
             name: Ident::new("location".to_string()),
             value: Value::SingleQuotedString("batcave".into()),
         }],
         like: Some(ObjectName(vec![Ident::new("db2"), Ident::new("test2")])),
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // create table as select statement
+    let (statements, _) = DfParser::parse_sql("SELECT a, b FROM db2.test2")?;
+    let query = match &statements[0] {
+        DfStatement::Query(query) => query.clone(),
+        _ => panic!("expect query statement"),
+    };
+    let sql = "CREATE TABLE db1.test1 ENGINE = Memory AS SELECT a, b FROM db2.test2";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        temporary: false,
+        name: ObjectName(vec![Ident::new("db1"), Ident::new("test1")]),
+        columns: vec![],
+        engine: "Memory".to_string(),
+        partition_by: None,
+        ttl: None,
+        options: vec![],
+        like: None,
+        query: Some(query),
+    });
+    expect_parse_ok(sql, expected)?;
+
+    let sql = "CREATE TABLE t1 LIKE t2 AS SELECT * FROM t2";
+    expect_parse_err(
+        sql,
+        "sql parser error: mix create table like statement and select statement.".to_string(),
+    )?;
+
+    // create temporary table statement, the engine is Memory by default
+    let sql = "CREATE TEMPORARY TABLE IF NOT EXISTS t(c1 int)";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: true,
+        temporary: true,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", DataType::Int(None))],
+        engine: "MEMORY".to_string(),
+        partition_by: None,
+        ttl: None,
+        options: vec![],
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // create partitioned table statement
+    let sql = "CREATE TABLE t(c1 int) PARTITION BY c1 % 10";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        temporary: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", DataType::Int(None))],
+        engine: "FUSE".to_string(),
+        partition_by: Some(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("c1"))),
+            op: BinaryOperator::Modulo,
+            right: Box::new(Expr::Value(Value::Number("10".to_owned(), false))),
+        }),
+        ttl: None,
+        options: vec![],
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // create table with ttl statement
+    let sql = "CREATE TABLE t(c1 Date) TTL c1 + INTERVAL '90' DAY";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        temporary: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", DataType::Date)],
+        engine: "FUSE".to_string(),
+        partition_by: None,
+        ttl: Some(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("c1"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Value(Value::Interval {
+                value: "90".to_string(),
+                leading_field: Some(DateTimeField::Day),
+                leading_precision: None,
+                last_field: None,
+                fractional_seconds_precision: None,
+            })),
+        }),
+        options: vec![],
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // create table with ttl statement, the interval is not quoted
+    let sql = "CREATE TABLE t(ts Date) TTL ts + INTERVAL 90 DAY";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        temporary: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("ts", DataType::Date)],
+        engine: "FUSE".to_string(),
+        partition_by: None,
+        ttl: Some(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("ts"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Value(Value::Interval {
+                value: "90".to_string(),
+                leading_field: Some(DateTimeField::Day),
+                leading_precision: None,
+                last_field: None,
+                fractional_seconds_precision: None,
+            })),
+        }),
+        options: vec![],
+        like: None,
+        query: None,
     });
     expect_parse_ok(sql, expected)?;
 
@@ -211,6 +370,159 @@ fn drop_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn create_view() -> Result<()> {
+    use sqlparser::dialect::GenericDialect;
+    use sqlparser::parser::Parser;
+    use sqlparser::tokenizer::Tokenizer;
+
+    let parse_sql_to_query = |query: &str| -> Box<Query> {
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, query);
+        let tokens = tokenizer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens, &dialect);
+        Box::new(parser.parse_query().unwrap())
+    };
+
+    {
+        let sql = "CREATE VIEW v1 AS SELECT a FROM t1";
+        let query = parse_sql_to_query("SELECT a FROM t1");
+        let expected = DfStatement::CreateView(DfCreateView {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("v1")]),
+            query,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "CREATE VIEW IF NOT EXISTS db1.v1 AS SELECT a FROM t1";
+        let query = parse_sql_to_query("SELECT a FROM t1");
+        let expected = DfStatement::CreateView(DfCreateView {
+            if_not_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v1")]),
+            query,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    let sql = "CREATE VIEW v1 SELECT a FROM t1";
+    expect_parse_err(
+        sql,
+        "sql parser error: Expected AS, found: SELECT".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn create_materialized_view() -> Result<()> {
+    use sqlparser::dialect::GenericDialect;
+    use sqlparser::parser::Parser;
+    use sqlparser::tokenizer::Tokenizer;
+
+    let parse_sql_to_query = |query: &str| -> Box<Query> {
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, query);
+        let tokens = tokenizer.tokenize().unwrap();
+        let mut parser = Parser::new(tokens, &dialect);
+        Box::new(parser.parse_query().unwrap())
+    };
+
+    {
+        let sql = "CREATE MATERIALIZED VIEW mv TO t2 AS SELECT a, count() FROM t1 GROUP BY a";
+        let query = parse_sql_to_query("SELECT a, count() FROM t1 GROUP BY a");
+        let expected = DfStatement::CreateMaterializedView(DfCreateMaterializedView {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("mv")]),
+            target: ObjectName(vec![Ident::new("t2")]),
+            query,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "CREATE MATERIALIZED VIEW IF NOT EXISTS db1.mv TO db2.t2 AS SELECT a FROM t1";
+        let query = parse_sql_to_query("SELECT a FROM t1");
+        let expected = DfStatement::CreateMaterializedView(DfCreateMaterializedView {
+            if_not_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("mv")]),
+            target: ObjectName(vec![Ident::new("db2"), Ident::new("t2")]),
+            query,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    let sql = "CREATE MATERIALIZED VIEW mv AS SELECT a FROM t1";
+    expect_parse_err(sql, "sql parser error: Expected TO, found: AS".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn drop_view() -> Result<()> {
+    {
+        let sql = "DROP VIEW v1";
+        let expected = DfStatement::DropView(DfDropView {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("v1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "DROP VIEW IF EXISTS db1.v1";
+        let expected = DfStatement::DropView(DfDropView {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn rename_table() -> Result<()> {
+    {
+        let sql = "RENAME TABLE t1 TO t2";
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("t1")]),
+            new_name: ObjectName(vec![Ident::new("t2")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "RENAME TABLE IF EXISTS db1.t1 TO db2.t2";
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            new_name: ObjectName(vec![Ident::new("db2"), Ident::new("t2")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn undrop_table() -> Result<()> {
+    {
+        let sql = "UNDROP TABLE t1";
+        let expected = DfStatement::UndropTable(DfUndropTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "UNDROP TABLE db1.t1";
+        let expected = DfStatement::UndropTable(DfUndropTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
 #[test]
 fn describe_table() -> Result<()> {
     {
@@ -302,6 +614,16 @@ fn show_tables_test() -> Result<()> {
         "SHOW TABLES IN `ss`",
         DfStatement::ShowTables(DfShowTables::FromOrIn(name_two)),
     )?;
+    expect_parse_ok(
+        "SHOW TABLES HISTORY",
+        DfStatement::ShowTables(DfShowTables::History(None)),
+    )?;
+    expect_parse_ok(
+        "SHOW TABLES HISTORY FROM db1",
+        DfStatement::ShowTables(DfShowTables::History(Some(ObjectName(vec![Ident::new(
+            "db1",
+        )])))),
+    )?;
     Ok(())
 }
 
@@ -336,6 +658,83 @@ fn truncate_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn alter_table() -> Result<()> {
+    {
+        let sql = "ALTER TABLE db1.t1 ADD COLUMN c2 BIGINT NOT NULL DEFAULT 1";
+        let mut column = make_column_def("c2", DataType::BigInt(None));
+        column.options = vec![
+            ColumnOptionDef {
+                name: None,
+                option: ColumnOption::NotNull,
+            },
+            ColumnOptionDef {
+                name: None,
+                option: ColumnOption::Default(Expr::Value(Value::Number("1".to_owned(), false))),
+            },
+        ];
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            operation: DfAlterTableOperation::AddColumn(column),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 DROP COLUMN c1";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: DfAlterTableOperation::DropColumn(Ident::new("c1")),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 RENAME COLUMN c1 TO c2";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: DfAlterTableOperation::RenameColumn {
+                old_name: Ident::new("c1"),
+                new_name: Ident::new("c2"),
+            },
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 MODIFY COLUMN c1 VARCHAR";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: DfAlterTableOperation::ModifyColumn(make_column_def(
+                "c1",
+                DataType::Varchar(None),
+            )),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 DROP PARTITION 202101";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: DfAlterTableOperation::DropPartition(Expr::Value(Value::Number(
+                "202101".to_owned(),
+                false,
+            ))),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err(
+        "ALTER TABLE t1 RENAME c1 TO c2",
+        String::from(
+            "sql parser error: Expected ADD, DROP, RENAME COLUMN or MODIFY after ALTER TABLE, found: RENAME",
+        ),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn hint_test() -> Result<()> {
     {
@@ -1045,3 +1444,123 @@ fn create_stage_test() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn variant_access_test() -> Result<()> {
+    let (expected, _) =
+        DfParser::parse_sql("SELECT get_path(t.v, 'a.b[0]'), get_path(v, '[1].c') FROM t")?;
+    expect_parse_ok("SELECT t.v:a.b[0], v:[1].c FROM t", expected[0].clone())?;
+
+    // the whitespaces end the path
+    let (expected, _) = DfParser::parse_sql("SELECT get_path(v, 'a') AS b FROM t")?;
+    expect_parse_ok("SELECT v:a AS b FROM t", expected[0].clone())?;
+    Ok(())
+}
+
+#[test]
+fn array_literal_test() -> Result<()> {
+    let (expected, _) = DfParser::parse_sql("SELECT array(1, 2), array(array(1), array()) FROM t")?;
+    expect_parse_ok("SELECT [1, 2], ARRAY[[1], []] FROM t", expected[0].clone())?;
+
+    let sql = "CREATE TABLE t(c1 Array(Int32)) ENGINE = Fuse";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        temporary: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def(
+            "c1",
+            DataType::Array(Box::new(DataType::Custom(ObjectName(vec![Ident::new(
+                "Int32",
+            )])))),
+        )],
+        engine: "Fuse".to_string(),
+        partition_by: None,
+        ttl: None,
+        options: vec![],
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+    Ok(())
+}
+
+#[test]
+fn low_cardinality_type_test() -> Result<()> {
+    let sql = "CREATE TABLE t(c LowCardinality(String)) ENGINE = Fuse";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        temporary: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def(
+            "c",
+            DataType::Custom(ObjectName(vec![
+                Ident::new("LowCardinality"),
+                Ident::new("STRING"),
+            ])),
+        )],
+        engine: "Fuse".to_string(),
+        partition_by: None,
+        ttl: None,
+        options: vec![],
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+    Ok(())
+}
+
+#[test]
+fn map_struct_type_test() -> Result<()> {
+    let (expected, _) = DfParser::parse_sql(
+        "SELECT arrayElement(m, 'a'), arrayElement(t.tags, 1), arrayElement(m, 'a') + 1 FROM t",
+    )?;
+    expect_parse_ok(
+        "SELECT m['a'], t.tags[1], m ['a'] + 1 FROM t",
+        expected[0].clone(),
+    )?;
+
+    let sql = "CREATE TABLE t(m Map(String, Array(Int32)), s Struct(a Int32, b DateTime64(3))) ENGINE = Fuse";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        temporary: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def(
+                "m",
+                DataType::Custom(ObjectName(vec![
+                    Ident::new("Map"),
+                    Ident::new("STRING"),
+                    Ident::new("Array(Int32)"),
+                ])),
+            ),
+            make_column_def(
+                "s",
+                DataType::Custom(ObjectName(vec![
+                    Ident::new("Struct"),
+                    Ident::new("a Int32"),
+                    Ident::new("b DateTime64(3)"),
+                ])),
+            ),
+        ],
+        engine: "Fuse".to_string(),
+        partition_by: None,
+        ttl: None,
+        options: vec![],
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+    Ok(())
+}
+
+#[test]
+fn regexp_operator_test() -> Result<()> {
+    let (expected, _) = DfParser::parse_sql(
+        "SELECT a ~ '^x', (a) ~ 'y', a !~ 'z', regexp_like(a, 'x') FROM t WHERE a !~ '^b'",
+    )?;
+    expect_parse_ok(
+        "SELECT a REGEXP '^x', (a) rlike 'y', a NOT REGEXP 'z', regexp_like(a, 'x') FROM t WHERE a NOT RLIKE '^b'",
+        expected[0].clone(),
+    )?;
+    Ok(())
+}
@@ -56,6 +56,16 @@ async fn test_statement_select_analyze() -> Result<()> {
             query: "SELECT * FROM system.databases ORDER BY name = 'xxx'",
             expect: "QueryAnalyzeState { before_order_by: [name, (name = xxx)], order_by: [(name = 'xxx')], projection: [name] }",
         },
+        TestCase {
+            name: "Filter with in list",
+            query: "SELECT * FROM system.databases WHERE name NOT IN ('xxx', 'yyy')",
+            expect: "QueryAnalyzeState { filter: not in(name, xxx, yyy), before_projection: [name], projection: [name] }",
+        },
+        TestCase {
+            name: "Case when query",
+            query: "SELECT CASE number WHEN 1 THEN 'one' WHEN 2 THEN 'two' ELSE 'other' END FROM numbers(10)",
+            expect: "QueryAnalyzeState { before_projection: [multiIf((number = 1), one, (number = 2), two, other)], projection: [multiIf((number = 1), one, (number = 2), two, other)] }",
+        },
         TestCase {
             name: "Group by query with filter",
             query: "SELECT number % 2 AS number FROM numbers(10) WHERE number > 2 GROUP BY number",
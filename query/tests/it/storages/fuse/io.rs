@@ -22,7 +22,12 @@ use common_datavalues::series::Series;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::modular;
 use databend_query::storages::fuse::io::BlockStreamWriter;
+use databend_query::storages::fuse::partition::BlockPartitioner;
 use databend_query::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
 use futures::StreamExt;
 use tempfile::TempDir;
@@ -42,8 +47,10 @@ async fn test_fuse_table_block_appender() {
         local_fs.clone(),
         Box::pin(block_stream),
         schema.clone(),
+        vec![0],
         DEFAULT_CHUNK_BLOCK_NUM,
         0,
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -67,8 +74,10 @@ async fn test_fuse_table_block_appender() {
         local_fs.clone(),
         Box::pin(block_stream),
         schema.clone(),
+        vec![0],
         chunk_size,
         0,
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -86,8 +95,10 @@ async fn test_fuse_table_block_appender() {
         local_fs,
         Box::pin(block_stream),
         schema,
+        vec![0],
         DEFAULT_CHUNK_BLOCK_NUM,
         0,
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -96,6 +107,76 @@ async fn test_fuse_table_block_appender() {
     assert!(segments.is_empty())
 }
 
+#[tokio::test]
+async fn test_fuse_table_block_appender_partitioned() -> common_exception::Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let local_fs = common_dal::Local::with_path(tmp_dir.path().to_owned());
+    let local_fs = Arc::new(local_fs);
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let partitioner = BlockPartitioner::try_create(schema.clone(), &modular(col("a"), lit(2)))?;
+
+    // one segment for each partition, in the order the partitions are met
+    let blocks = vec![
+        Ok(DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![1, 2, 3]),
+        ])),
+        Ok(DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![5, 6]),
+        ])),
+    ];
+    let segments = BlockStreamWriter::write_block_stream(
+        local_fs,
+        Box::pin(futures::stream::iter(blocks)),
+        schema,
+        vec![0],
+        DEFAULT_CHUNK_BLOCK_NUM,
+        0,
+        Some(partitioner),
+    )
+    .await
+    .collect::<Vec<_>>()
+    .await
+    .into_iter()
+    .collect::<common_exception::Result<Vec<_>>>()?;
+
+    let partition = |v: &Option<DataValue>| v.as_ref().map(|v| v.as_i64().unwrap());
+    assert_eq!(segments.len(), 2);
+    assert_eq!(partition(&segments[0].partition), Some(1));
+    assert_eq!(segments[0].summary.row_count, 3);
+    assert_eq!(partition(&segments[1].partition), Some(0));
+    assert_eq!(segments[1].summary.row_count, 2);
+    Ok(())
+}
+
+#[test]
+fn test_block_partitioner_with_nulls() -> common_exception::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, true)]);
+    let partitioner = BlockPartitioner::try_create(schema.clone(), &col("a"))?;
+
+    // the NULL rows make a partition of their own, not the one of the default value
+    let blocks = vec![
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![
+            Some(1),
+            None,
+            Some(0),
+            None,
+            Some(1),
+        ])]),
+        DataBlock::create_by_array(schema, vec![Series::new(vec![Some(0), None])]),
+    ];
+    let partitions = partitioner.partition(blocks)?;
+
+    let rows = |blocks: &[DataBlock]| blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+    assert_eq!(partitions.len(), 3);
+    assert_eq!(partitions[0].0, DataValue::Int32(Some(1)));
+    assert_eq!(rows(&partitions[0].1), 2);
+    assert!(partitions[1].0.is_null());
+    assert_eq!(rows(&partitions[1].1), 3);
+    assert_eq!(partitions[2].0, DataValue::Int32(Some(0)));
+    assert_eq!(rows(&partitions[2].1), 2);
+    Ok(())
+}
+
 #[test]
 fn test_fuse_table_block_appender_reshape() -> common_exception::Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
@@ -19,3 +19,4 @@ mod pruning;
 mod statistics;
 mod table;
 mod table_test_fixture;
+mod ttl_gc;
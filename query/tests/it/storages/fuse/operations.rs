@@ -47,20 +47,27 @@ fn test_to_partitions() -> Result<()> {
             .iter()
             .map(|(_, col_stats)| col_stats.in_memory_size)
             .sum(),
+        file_size: 0,
         col_stats: cols_stats.clone(),
         location: BlockLocation {
             location: "".to_string(),
             meta_size: 0,
         },
+        col_ids: vec![],
+        col_types: vec![],
     };
 
+    let col_ids = (0..num_of_col)
+        .into_iter()
+        .map(|col_id| col_id as u32)
+        .collect::<Vec<_>>();
     let blocks_metas = (0..num_of_block)
         .into_iter()
         .map(|_| block_meta.clone())
         .collect::<Vec<_>>();
 
     // CASE I:  no projection
-    let (s, _) = FuseTable::to_partitions(&blocks_metas, None);
+    let (s, _) = FuseTable::to_partitions(&blocks_metas, &col_ids, None)?;
     let expected_block_size: u64 = cols_stats
         .iter()
         .map(|(_, col_stats)| col_stats.in_memory_size)
@@ -88,7 +95,7 @@ fn test_to_partitions() -> Result<()> {
         limit: None,
         order_by: vec![],
     });
-    let (stats, _) = FuseTable::to_partitions(&blocks_metas, push_down);
+    let (stats, _) = FuseTable::to_partitions(&blocks_metas, &col_ids, push_down)?;
     assert_eq!(expected_block_size * num_of_block, stats.read_bytes as u64);
     Ok(())
 }
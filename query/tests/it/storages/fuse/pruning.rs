@@ -27,6 +27,7 @@ use common_planners::col;
 use common_planners::lit;
 use common_planners::Extras;
 use databend_query::catalogs::Catalog;
+use databend_query::storages::fuse::column_ids;
 use databend_query::storages::fuse::io;
 use databend_query::storages::fuse::pruning::apply_block_pruning;
 use databend_query::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
@@ -100,9 +101,12 @@ async fn test_block_pruner() -> Result<()> {
 
     // no pruning
     let push_downs = None;
+    let col_ids = column_ids(table.get_table_info())?;
     let blocks = apply_block_pruning(
         &snapshot,
         table.get_table_info().schema(),
+        &col_ids,
+        None,
         &push_downs,
         da.clone(),
     )
@@ -119,6 +123,8 @@ async fn test_block_pruner() -> Result<()> {
     let blocks = apply_block_pruning(
         &snapshot,
         table.get_table_info().schema(),
+        &col_ids,
+        None,
         &Some(extra),
         da.clone(),
     )
@@ -130,8 +136,15 @@ async fn test_block_pruner() -> Result<()> {
     let pred = col("a").gt(lit(3)).and(col("b").gt(lit(3)));
     extra.filters = vec![pred];
 
-    let blocks =
-        apply_block_pruning(&snapshot, table.get_table_info().schema(), &Some(extra), da).await?;
+    let blocks = apply_block_pruning(
+        &snapshot,
+        table.get_table_info().schema(),
+        &col_ids,
+        None,
+        &Some(extra),
+        da,
+    )
+    .await?;
     assert_eq!(num - 1, blocks.len() as u64);
 
     Ok(())
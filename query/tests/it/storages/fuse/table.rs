@@ -13,13 +13,20 @@
 //  limitations under the License.
 //
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::ReadDataSourcePlan;
 use common_planners::TruncateTablePlan;
+use common_streams::SendableDataBlockStream;
 use databend_query::catalogs::Catalog;
 use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
+use databend_query::storages::fuse::FuseTable;
 use databend_query::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
@@ -283,3 +290,73 @@ async fn test_fuse_table_compact() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fuse_table_expire() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db_name = fixture.default_db_name();
+
+    let query = format!(
+        "create table {}.t_ttl(d Date) TTL d + INTERVAL '10' DAY",
+        db_name
+    );
+    execute_query(&query, ctx.clone()).await?;
+
+    // one block per insertion, the first one expires fully and the last one partially
+    for values in [
+        "('1970-01-01'), ('1970-01-02')",
+        "('1970-04-11')",
+        "('1970-01-06'), ('1970-07-20')",
+    ] {
+        let query = format!("insert into {}.t_ttl values {}", db_name, values);
+        execute_query(&query, ctx.clone()).await?;
+    }
+
+    let now = 20 * 24 * 3600;
+    let table = ctx.get_catalog().get_table(&db_name, "t_ttl").await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    assert_eq!(fuse_table.do_expire(ctx.clone(), now).await?, 3);
+
+    // another node expiring the same version of the table fails to commit
+    assert!(fuse_table.do_expire(ctx.clone(), now).await.is_err());
+
+    let table = ctx.get_catalog().get_table(&db_name, "t_ttl").await?;
+    let (_, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 2);
+
+    // nothing left to expire
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    assert_eq!(fuse_table.do_expire(ctx.clone(), now).await?, 0);
+
+    let query = format!("select toUInt32(d) as d from {}.t_ttl order by d", db_name);
+    let stream = execute_query(&query, ctx.clone()).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+-----+", "| d   |", "+-----+", "| 100 |", "| 200 |", "+-----+",
+    ];
+    assert_blocks_eq(expected, blocks.as_slice());
+
+    // the TTL column can't be dropped, renamed or modified
+    for alter in [
+        "drop column d",
+        "rename column d to d2",
+        "modify column d Date",
+    ] {
+        let query = format!("alter table {}.t_ttl {}", db_name, alter);
+        let res = execute_query(&query, ctx.clone()).await;
+        assert_eq!(
+            res.err().map(|e| e.code()),
+            Some(ErrorCode::BadArgumentsCode())
+        );
+    }
+
+    Ok(())
+}
+
+async fn execute_query(query: &str, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
+    let plan = PlanParser::parse(query, ctx.clone()).await?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute(None)
+        .await
+}
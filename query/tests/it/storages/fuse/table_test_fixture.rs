@@ -92,6 +92,7 @@ impl TestFixture {
     pub fn default_crate_table_plan(&self) -> CreateTablePlan {
         CreateTablePlan {
             if_not_exists: false,
+            temporary: false,
             db: self.default_db_name(),
             table: self.default_table_name(),
             table_meta: TableMeta {
@@ -100,6 +101,7 @@ impl TestFixture {
                 // make sure blocks will not be merged
                 options: [(TBL_OPT_KEY_CHUNK_BLOCK_NUM.to_owned(), "1".to_owned())].into(),
             },
+            as_select: None,
         }
     }
 
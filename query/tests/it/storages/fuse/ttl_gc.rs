@@ -0,0 +1,44 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::sync::Arc;
+
+use common_meta_types::NodeInfo;
+use databend_query::clusters::Cluster;
+use databend_query::storages::fuse::TtlGC;
+
+#[test]
+fn test_ttl_gc_runs_on_one_node() {
+    let nodes = vec![
+        Arc::new(NodeInfo::create("node2".to_string(), 1, "".to_string())),
+        Arc::new(NodeInfo::create("node1".to_string(), 1, "".to_string())),
+        Arc::new(NodeInfo::create("node3".to_string(), 1, "".to_string())),
+    ];
+
+    assert!(TtlGC::runs_on(&Cluster::create(
+        nodes.clone(),
+        "node1".to_string()
+    )));
+    assert!(!TtlGC::runs_on(&Cluster::create(
+        nodes.clone(),
+        "node2".to_string()
+    )));
+    assert!(!TtlGC::runs_on(&Cluster::create(
+        nodes,
+        "node3".to_string()
+    )));
+
+    // a standalone node always runs
+    assert!(TtlGC::runs_on(Cluster::empty().as_ref()));
+}
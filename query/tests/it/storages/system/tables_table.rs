@@ -33,25 +33,27 @@ async fn test_tables_table() -> Result<()> {
     assert_eq!(block.num_columns(), 3);
 
     let expected = vec![
-        "+----------+--------------+--------------------+",
-        "| database | name         | engine             |",
-        "+----------+--------------+--------------------+",
-        "| system   | clusters     | SystemClusters     |",
-        "| system   | columns      | SystemColumns      |",
-        "| system   | configs      | SystemConfigs      |",
-        "| system   | contributors | SystemContributors |",
-        "| system   | credits      | SystemCredits      |",
-        "| system   | databases    | SystemDatabases    |",
-        "| system   | functions    | SystemFunctions    |",
-        "| system   | metrics      | SystemMetrics      |",
-        "| system   | one          | SystemOne          |",
-        "| system   | processes    | SystemProcesses    |",
-        "| system   | query_log    | SystemQueryLog     |",
-        "| system   | settings     | SystemSettings     |",
-        "| system   | tables       | SystemTables       |",
-        "| system   | tracing      | SystemTracing      |",
-        "| system   | users        | SystemUsers        |",
-        "+----------+--------------+--------------------+",
+        "+----------+---------------------+-------------------------+",
+        "| database | name                | engine                  |",
+        "+----------+---------------------+-------------------------+",
+        "| system   | clusters            | SystemClusters          |",
+        "| system   | columns             | SystemColumns           |",
+        "| system   | configs             | SystemConfigs           |",
+        "| system   | contributors        | SystemContributors      |",
+        "| system   | credits             | SystemCredits           |",
+        "| system   | databases           | SystemDatabases         |",
+        "| system   | functions           | SystemFunctions         |",
+        "| system   | metrics             | SystemMetrics           |",
+        "| system   | one                 | SystemOne               |",
+        "| system   | processes           | SystemProcesses         |",
+        "| system   | query_cache         | SystemQueryCache        |",
+        "| system   | query_log           | SystemQueryLog          |",
+        "| system   | settings            | SystemSettings          |",
+        "| system   | tables              | SystemTables            |",
+        "| system   | tables_with_history | SystemTablesWithHistory |",
+        "| system   | tracing             | SystemTracing           |",
+        "| system   | users               | SystemUsers             |",
+        "+----------+---------------------+-------------------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
 
@@ -84,6 +84,12 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::inner_create(new_config)
     }
 
+    pub fn dropped_table_retention_secs(self, secs: u64) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.dropped_table_retention_secs = secs;
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
     pub fn disk_storage_path(self, path: String) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.storage.disk.data_path = path;
@@ -6,3 +6,10 @@ copy into default.test_csv from '@s3_stage/tests/data/sample.csv' format CSV fie
 select max(id), min(name), avg(rank)  from default.test_csv;
 
 drop table default.test_csv;
+
+create table default.test_csv2 (id int NOT NULL, name varchar(255), rank int, flag int NOT NULL DEFAULT 7);
+copy into default.test_csv2(id, name, rank) from '@s3_stage/tests/data/sample.csv' format CSV field_delimitor = ',';
+
+select count(), sum(flag) from default.test_csv2;
+
+drop table default.test_csv2;
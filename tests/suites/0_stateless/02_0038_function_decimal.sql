@@ -0,0 +1,20 @@
+SELECT toTypeName(CAST(1.5 AS DECIMAL(10, 2)));
+SELECT CAST('1.5' AS DECIMAL(10, 2)) + CAST(2 AS DECIMAL(5, 1));
+SELECT CAST(1.5 AS DECIMAL(10, 2)) * 3;
+SELECT CAST(1 AS DECIMAL(10, 2)) / 3;
+SELECT CAST(-1.25 AS DECIMAL(10, 2)) - 1;
+SELECT CAST(1.25 AS DECIMAL(10, 2)) > CAST(1.2 AS DECIMAL(10, 1));
+SELECT CAST(CAST(2.75 AS DECIMAL(10, 2)) AS Float64);
+SELECT CAST('99999999999999999999999999999999999999' AS DECIMAL(38, 0)) + 1; -- {ErrorCode 49}
+SELECT CAST('-99999999999999999999999999999999999999' AS DECIMAL(38, 0)) * 10; -- {ErrorCode 49}
+
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(a DECIMAL(10, 2)) Engine = Fuse;
+INSERT INTO t1 VALUES(1.25), (2.5), (-0.75);
+SELECT sum(a), avg(a) FROM t1;
+SELECT a FROM t1 WHERE a > 1 AND a < 2;
+
+DROP DATABASE db1;
@@ -0,0 +1,26 @@
+SELECT toTypeName(toDateTime64('2021-08-30 10:47:42.123', 3));
+SELECT toDateTime64('2021-08-30 10:47:42.123456', 6);
+SELECT toDateTime64('2021-08-30 10:47:42.123456', 3, 'Asia/Shanghai');
+SELECT toHour(toDateTime64('2021-08-30 10:47:42.123', 3, 'Asia/Shanghai'));
+SELECT toDateTime64(1630320462, 3);
+SELECT toDateTime64('2021-08-30 10:47:42.5', 1) + INTERVAL 1 SECOND;
+SELECT toDateTime64('2021-08-30 10:47:42.5', 1) > toDateTime64('2021-08-30 10:47:42.25', 2);
+SELECT toDateTime64(0, 10); -- {ErrorCode 6}
+SELECT toDateTime64(0, 4294967296); -- {ErrorCode 6}
+SELECT toYear(toDateTime64(9223372036854775807, 0)); -- {ErrorCode 49}
+
+SET timezone = 'Asia/Shanghai';
+SELECT toTypeName(toDateTime64(0, 3));
+SELECT toDateTime64(0, 3);
+SET timezone = 'UTC';
+
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(a DateTime64(3)) Engine = Fuse;
+INSERT INTO t1 VALUES('2021-08-30 10:47:42.123'), ('2021-08-30 10:47:42.456');
+SELECT max(a), min(a) FROM t1;
+SELECT a FROM t1 WHERE a > toDateTime64('2021-08-30 10:47:42.2', 1);
+
+DROP DATABASE db1;
@@ -0,0 +1,19 @@
+SELECT parse_json('{"a": {"b": [1, "x"]}}');
+SELECT get(parse_json('[10, 20]'), 1);
+SELECT get_path(parse_json('{"a": {"b": [1, "x"]}}'), 'a.b[1]');
+SELECT json_extract_path_text('{"a": {"b": [1, "x"]}}', 'a.b[1]');
+SELECT get_path(parse_json('{"a": {"b": 1}}'), 'a..b'); -- {ErrorCode 6}
+SELECT try_parse_json('{a}');
+SELECT toTypeName(parse_json('1'));
+
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(id Int32, v Variant) Engine = Fuse;
+INSERT INTO t1 VALUES(1, '{"name": "a", "tags": ["x", "y"], "n": 1}'), (2, '{"name": "b", "tags": [], "n": 2.5}');
+SELECT id, v:name, v:tags[0] FROM t1 ORDER BY id;
+SELECT id FROM t1 WHERE v:name = 'b';
+SELECT sum(CAST(v:n AS Float64)) FROM t1;
+
+DROP DATABASE db1;
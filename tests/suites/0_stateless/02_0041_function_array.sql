@@ -0,0 +1,27 @@
+SELECT [1, 2, 3];
+SELECT ARRAY['a', 'b'];
+SELECT length([1, 2, 3]), length([]);
+SELECT has([1, 2, 3], 2), has([1, 2, 3], 4);
+SELECT indexOf(['a', 'b', 'c'], 'c'), indexOf(['a', 'b'], 'd');
+SELECT arrayConcat([1, 2], [3], []);
+SELECT arraySlice([1, 2, 3, 4, 5], 2, 3), arraySlice([1, 2, 3, 4, 5], -2);
+SELECT arrayJoin([1, 2, 3]) AS x;
+SELECT number, unnest([number, number * 10]) AS x FROM numbers(2) ORDER BY number, x;
+SELECT count() FROM (SELECT arrayJoin(arraySlice([number], 1, number)) FROM numbers(5) LIMIT 2);
+SELECT number FROM numbers(2) WHERE arrayJoin([number, 1]) = 1; -- {ErrorCode 5}
+SELECT count() FROM numbers(2) GROUP BY arrayJoin([number, 1]); -- {ErrorCode 5}
+SELECT sum(arrayJoin([number, 1])) FROM numbers(2); -- {ErrorCode 5}
+SELECT number FROM numbers(2) ORDER BY arrayJoin([number, 1]); -- {ErrorCode 5}
+
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(id Int32, tags Array(String)) Engine = Fuse;
+INSERT INTO t1 VALUES(1, ['x', 'y']), (2, []), (3, ['z']);
+SELECT id, tags, length(tags) FROM t1 ORDER BY id;
+SELECT id FROM t1 WHERE has(tags, 'z');
+SELECT id, arrayJoin(tags) AS tag FROM t1 ORDER BY id, tag;
+SELECT groupArray(id) FROM (SELECT id FROM t1 ORDER BY id);
+
+DROP DATABASE db1;
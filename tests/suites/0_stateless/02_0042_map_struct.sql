@@ -0,0 +1,16 @@
+SELECT map('a', 1, 'b', 2);
+SELECT mapKeys(map('a', 1, 'b', 2)), mapValues(map('a', 1, 'b', 2));
+SELECT arrayElement(map('a', 1, 'b', 2), 'b'), arrayElement(map('a', 1), 'c');
+
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(id Int32, m Map(String, Int32), s Struct(a Int32, b String)) Engine = Fuse;
+INSERT INTO t1 VALUES(1, map('x', 1, 'y', 2), tuple(10, 'p')), (2, map('z', 3), tuple(20, 'q'));
+SELECT id, m, s FROM t1 ORDER BY id;
+SELECT id, m['x'], mapKeys(m), s.a, s.b FROM t1 ORDER BY id;
+SELECT id FROM t1 WHERE s.a > 10;
+SELECT id FROM t1 WHERE m['z'] = 3;
+
+DROP DATABASE db1;
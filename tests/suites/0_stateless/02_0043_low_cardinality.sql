@@ -0,0 +1,16 @@
+SELECT toTypeName(toLowCardinality('a'));
+
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(id Int32, country LowCardinality(String)) Engine = Fuse;
+INSERT INTO t1 VALUES(1, 'cn'), (2, 'us'), (3, 'cn'), (4, 'de');
+INSERT INTO t1 VALUES(5, 'us'), (6, 'fr');
+SELECT id, country FROM t1 ORDER BY id;
+SELECT country, count() FROM t1 GROUP BY country ORDER BY country;
+SELECT id FROM t1 WHERE country = 'cn' ORDER BY id;
+SELECT id FROM t1 WHERE country > 'de' ORDER BY id;
+SELECT length(country) FROM t1 WHERE id = 1;
+
+DROP DATABASE db1;
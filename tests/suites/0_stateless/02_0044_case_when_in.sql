@@ -0,0 +1,9 @@
+SELECT number, CASE WHEN number < 2 THEN 'small' WHEN number < 4 THEN 'medium' ELSE 'large' END FROM numbers(5) ORDER BY number;
+SELECT number, CASE number WHEN 1 THEN 'one' WHEN 3 THEN 'three' END FROM numbers(4) ORDER BY number;
+SELECT CASE WHEN number = 0 THEN 1 ELSE 2.5 END FROM numbers(2) ORDER BY number;
+
+SELECT number FROM numbers(10) WHERE number IN (1, 3, 5) ORDER BY number;
+SELECT number FROM numbers(5) WHERE number NOT IN (1, 3) ORDER BY number;
+SELECT 'a' IN ('a', 'b'), 'c' IN ('a', 'b'), 1 NOT IN (2, 3);
+SELECT number FROM numbers(10) WHERE number IN (SELECT number * 3 FROM numbers(3)) ORDER BY number;
+SELECT number FROM numbers(5) WHERE number NOT IN (SELECT number FROM numbers(3)) ORDER BY number;
@@ -0,0 +1,5 @@
+SELECT coalesce(NULL, 2, 3), coalesce(NULL, NULL, 'a'), ifNull(NULL, 1), ifNull(2, 1);
+SELECT nullIf(1, 1), nullIf(1, 2), assumeNotNull(3);
+SELECT multiIf(number = 0, 'zero', number = 1, 'one', 'many') FROM numbers(3) ORDER BY number;
+SELECT greatest(1, 3, 2), least(1, 3, 2), greatest('a', 'c', 'b'), least(3, NULL);
+SELECT number, greatest(number, 2), least(number, 2) FROM numbers(4) ORDER BY number;
@@ -0,0 +1,7 @@
+SELECT 'abc' REGEXP '^a', 'abc' RLIKE 'd', 'abc' NOT REGEXP 'd', regexp_like('ABC', '^abc$', 'i');
+SELECT number FROM numbers(12) WHERE toString(number) REGEXP '^1' ORDER BY number;
+SELECT regexp_replace('a b c', ' ', '-'), regexp_replace('ab ab ab', '(a)(b)', '$2$1', 2, 1);
+SELECT regexp_substr('abc def ghi', '[a-z]+', 1, 2), regexp_instr('abc def ghi', 'd'), regexp_instr('abc def ghi', 'd', 1, 1, 1);
+SELECT regexp_extract('key=value', '(\\w+)=(\\w+)', 2), regexp_extract('nothing', '(\\w+)=(\\w+)');
+SELECT extractAll('a1 b22 c333', '[a-z](\\d+)');
+SELECT regexp_like(NULL, 'a'), regexp_replace(NULL, 'a', 'b');
@@ -0,0 +1,6 @@
+SELECT upper('straße'), lower('ΑΒΓ'), ucase('abc'), lcase('ABC'), initcap('hello wORLD');
+SELECT split_part('a,b,c', ',', 2), split_part('a,b,c', ',', -1), split_part('a,b,c', ',', 4);
+SELECT splitByChar(',', 'a,b,c');
+SELECT translate('12345', '143', 'ax'), startsWith('привет', 'при'), endsWith('привет', 'при');
+SELECT positionUTF8('привет, мир', 'мир'), positionCaseInsensitiveUTF8('Привет, МИР', 'мир'), position('мир' IN 'привет, мир');
+SELECT format('{} + {} = {}', 1, 2, 3), format('{1}{0}', 'a', 'b');
@@ -0,0 +1,32 @@
+DROP DATABASE IF EXISTS db_03_0013;
+CREATE DATABASE db_03_0013;
+USE db_03_0013;
+
+CREATE TABLE t(a UInt64);
+INSERT INTO t VALUES (1), (2);
+
+SET enable_query_result_cache = 1;
+
+SELECT sum(a) FROM t;
+SELECT sum(a) FROM t;
+SELECT result_rows, hits FROM system.query_cache WHERE query = 'SELECT sum(a) FROM t';
+
+-- a new snapshot invalidates the cached result
+INSERT INTO t VALUES (3);
+SELECT sum(a) FROM t;
+
+-- a result truncated by read limits is never cached
+INSERT INTO t VALUES (4);
+SET max_threads = 1;
+SET max_rows_to_read = 1;
+SET read_overflow_mode = 'break';
+SELECT count() < 4 FROM t;
+SELECT count() FROM system.query_cache WHERE query = 'SELECT count() < 4 FROM t';
+SET max_rows_to_read = 0;
+SET read_overflow_mode = 'throw';
+SELECT count() < 4 FROM t;
+
+SET enable_query_result_cache = 0;
+
+DROP TABLE t;
+DROP DATABASE db_03_0013;
@@ -0,0 +1,5 @@
+SET max_execution_time = 1;
+SELECT sleep(2) FROM numbers(1); -- {ErrorCode 60}
+SELECT count() FROM numbers(10);
+SET max_execution_time = 0;
+SELECT sleep(2) FROM numbers(1);
@@ -0,0 +1,22 @@
+SET max_rows_to_read = 100;
+SELECT sum(number) FROM numbers(1000); -- {ErrorCode 61}
+SELECT sum(number) FROM numbers(100);
+
+CREATE TABLE IF NOT EXISTS t_03_0015(a UInt64) Engine = Memory;
+INSERT INTO t_03_0015 SELECT number FROM numbers(1000); -- {ErrorCode 61}
+CREATE TABLE t_03_0015_ctas Engine = Memory AS SELECT number FROM numbers(1000); -- {ErrorCode 61}
+INSERT INTO t_03_0015 SELECT number FROM numbers(100);
+SELECT count() FROM t_03_0015;
+DROP TABLE t_03_0015;
+DROP TABLE IF EXISTS t_03_0015_ctas;
+
+SET read_overflow_mode = 'brake'; -- {ErrorCode 6}
+
+SET max_threads = 1;
+SET max_block_size = 10;
+SET read_overflow_mode = 'break';
+SELECT count() < 1000 FROM (SELECT number FROM numbers(1000));
+
+SET max_rows_to_read = 0;
+SET read_overflow_mode = 'throw';
+SELECT sum(number) FROM numbers(1000);
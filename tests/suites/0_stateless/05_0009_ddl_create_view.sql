@@ -0,0 +1,47 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(a int, b varchar) Engine = Memory;
+INSERT INTO t1 VALUES(1, 'x'),(2, 'y'),(3, 'z');
+
+CREATE VIEW v1 AS SELECT a, b FROM t1 WHERE a > 1;
+SELECT * FROM v1 ORDER BY a;
+SELECT max(a) FROM v1;
+SELECT v.b FROM v1 AS v WHERE v.a = 3;
+
+-- the view reads the latest data of its tables
+INSERT INTO t1 VALUES(4, 'w');
+SELECT count() FROM v1;
+
+SELECT name, engine FROM system.tables WHERE database = 'db1' ORDER BY name;
+SHOW CREATE TABLE v1;
+
+-- the view is bound to db1 even when it is queried from another database
+USE default;
+SELECT count() FROM db1.v1;
+USE db1;
+
+-- the tables of the subqueries are bound to db1 as well
+CREATE VIEW v2 AS SELECT a FROM t1 WHERE a IN (SELECT a FROM t1 WHERE b = 'y');
+USE default;
+SELECT a FROM db1.v2;
+USE db1;
+DROP VIEW v2;
+
+-- a view which references itself can not be expanded
+CREATE VIEW v3 AS SELECT a FROM t1;
+CREATE VIEW v4 AS SELECT a FROM v3;
+DROP VIEW v3;
+RENAME TABLE v4 TO v3;
+SELECT a FROM v3; -- {ErrorCode 6}
+DROP VIEW v3;
+
+CREATE VIEW v1 AS SELECT a FROM t1; -- {ErrorCode 4003}
+CREATE VIEW IF NOT EXISTS v1 AS SELECT a FROM t1;
+DROP VIEW t1; -- {ErrorCode 6}
+DROP VIEW v1;
+DROP VIEW IF EXISTS v1;
+SELECT count() FROM system.tables WHERE database = 'db1';
+
+DROP DATABASE db1;
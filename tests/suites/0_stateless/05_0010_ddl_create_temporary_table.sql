@@ -0,0 +1,29 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(a int) Engine = Memory;
+INSERT INTO t1 VALUES(1),(2),(3);
+
+-- the temporary table shadows t1 in this session
+CREATE TEMPORARY TABLE t1(a int, b varchar);
+INSERT INTO t1 VALUES(10, 'x'),(20, 'y');
+SELECT * FROM t1 ORDER BY a;
+SELECT count() FROM db1.t1;
+
+CREATE TEMPORARY TABLE t1(a int); -- {ErrorCode 4003}
+CREATE TEMPORARY TABLE IF NOT EXISTS t1(a int);
+CREATE TEMPORARY TABLE t2(a int) Engine = Fuse; -- {ErrorCode 6}
+CREATE TEMPORARY TABLE t3 AS SELECT number FROM numbers(5);
+SELECT sum(number) FROM t3;
+
+-- temporary tables are not written to the catalog
+SELECT name FROM system.tables WHERE database = 'db1' ORDER BY name;
+
+-- dropping the temporary table uncovers t1
+DROP TABLE t1;
+SELECT count() FROM t1;
+DROP TABLE t3;
+SELECT count() FROM t3; -- {ErrorCode 25}
+
+DROP DATABASE db1;
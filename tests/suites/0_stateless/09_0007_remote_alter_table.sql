@@ -0,0 +1,46 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a int, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2');
+
+ALTER TABLE t ADD COLUMN c int DEFAULT 10;
+SELECT * FROM t ORDER BY a;
+INSERT INTO t(a,b,c) VALUES(3, 'v3', 30);
+SELECT * FROM t ORDER BY a;
+
+ALTER TABLE t DROP COLUMN b;
+SELECT * FROM t ORDER BY a;
+
+ALTER TABLE t RENAME COLUMN c TO d;
+SELECT a, d FROM t WHERE d > 20;
+
+ALTER TABLE t MODIFY COLUMN a varchar;
+SELECT a FROM t ORDER BY a;
+
+ALTER TABLE t ADD COLUMN a int; -- {ErrorCode 6}
+ALTER TABLE t DROP COLUMN b; -- {ErrorCode 58}
+ALTER TABLE t RENAME COLUMN b TO e; -- {ErrorCode 58}
+ALTER TABLE t RENAME COLUMN a TO d; -- {ErrorCode 6}
+ALTER TABLE t1 ADD COLUMN e int; -- {ErrorCode 25}
+
+CREATE TABLE IF NOT EXISTS t2(a int, b int, c Array(Int32)) Engine = fuse;
+INSERT INTO t2(a,b,c) VALUES(1, NULL, [1]),(2, 3, [2]);
+ALTER TABLE t2 MODIFY COLUMN c int; -- {ErrorCode 6}
+ALTER TABLE t2 MODIFY COLUMN b int NOT NULL; -- {ErrorCode 6}
+ALTER TABLE t2 MODIFY COLUMN a bigint NOT NULL; -- {ErrorCode 6}
+ALTER TABLE t2 MODIFY COLUMN a int NOT NULL;
+SELECT a FROM t2 ORDER BY a;
+
+CREATE TABLE IF NOT EXISTS t3(a varchar NOT NULL, b bigint NOT NULL) Engine = fuse;
+INSERT INTO t3(a,b) VALUES('1', 1),('abc', 4294967296);
+ALTER TABLE t3 MODIFY COLUMN a int NOT NULL; -- {ErrorCode 6}
+ALTER TABLE t3 MODIFY COLUMN b int NOT NULL; -- {ErrorCode 6}
+ALTER TABLE t3 MODIFY COLUMN b double NOT NULL;
+SELECT a, b = 4294967296 FROM t3 ORDER BY a;
+
+DROP TABLE t;
+DROP TABLE t2;
+DROP TABLE t3;
+DROP DATABASE db1;
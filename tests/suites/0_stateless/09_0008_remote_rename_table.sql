@@ -0,0 +1,34 @@
+DROP DATABASE IF EXISTS db1;
+DROP DATABASE IF EXISTS db2;
+DROP DATABASE IF EXISTS db3;
+CREATE DATABASE db1;
+CREATE DATABASE db2;
+USE db1;
+
+CREATE TABLE t(a int) Engine = fuse;
+CREATE TABLE t_new(a int) Engine = fuse;
+INSERT INTO t VALUES(1),(2);
+INSERT INTO t_new VALUES(3);
+
+-- swap the freshly built table into place
+RENAME TABLE t TO t_old;
+RENAME TABLE t_new TO t;
+SELECT * FROM t;
+SELECT * FROM t_old ORDER BY a;
+
+RENAME TABLE t TO t_old; -- {ErrorCode 4003}
+RENAME TABLE t1 TO t2; -- {ErrorCode 25}
+RENAME TABLE IF EXISTS t1 TO t2;
+
+RENAME TABLE db1.t_old TO db2.t;
+SELECT * FROM db2.t ORDER BY a;
+SELECT * FROM t_old; -- {ErrorCode 25}
+
+ALTER DATABASE db2 RENAME TO db3;
+SELECT * FROM db3.t ORDER BY a;
+ALTER DATABASE db2 RENAME TO db4; -- {ErrorCode 3}
+ALTER DATABASE IF EXISTS db2 RENAME TO db4;
+ALTER DATABASE db3 RENAME TO db1; -- {ErrorCode 4001}
+
+DROP DATABASE db1;
+DROP DATABASE db3;
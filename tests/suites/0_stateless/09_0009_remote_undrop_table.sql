@@ -0,0 +1,26 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t(a int) Engine = fuse;
+INSERT INTO t VALUES(1),(2);
+DROP TABLE t;
+SELECT * FROM t; -- {ErrorCode 25}
+
+SELECT name, dropped_on IS NOT NULL FROM system.tables_with_history WHERE database = 'db1';
+
+UNDROP TABLE t;
+SELECT * FROM t ORDER BY a;
+UNDROP TABLE t; -- {ErrorCode 25}
+
+-- the name is taken by a newer table
+DROP TABLE t;
+CREATE TABLE t(a int) Engine = fuse;
+INSERT INTO t VALUES(3);
+UNDROP TABLE t; -- {ErrorCode 4003}
+RENAME TABLE t TO t_new;
+UNDROP TABLE db1.t;
+SELECT * FROM t ORDER BY a;
+SELECT * FROM t_new;
+
+DROP DATABASE db1;
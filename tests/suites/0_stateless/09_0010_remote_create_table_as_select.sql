@@ -0,0 +1,24 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE src(a int, b varchar) Engine = fuse;
+INSERT INTO src VALUES(1, 'x'),(2, 'y'),(3, 'z');
+
+CREATE TABLE t1 AS SELECT a, b FROM src WHERE a > 1;
+SELECT * FROM t1 ORDER BY a;
+DESC t1;
+
+-- column definitions take precedence over the inferred schema
+CREATE TABLE t2(a bigint, c varchar) Engine = Memory AS SELECT a, b FROM src;
+SELECT * FROM t2 ORDER BY a;
+
+CREATE TABLE t1 AS SELECT a FROM src; -- {ErrorCode 4003}
+CREATE TABLE IF NOT EXISTS t1 AS SELECT a FROM src;
+SELECT count() FROM t1;
+
+-- nothing is left behind by a failed query
+CREATE TABLE t3 AS SELECT a FROM not_exists; -- {ErrorCode 25}
+SELECT count() FROM system.tables WHERE database = 'db1';
+
+DROP DATABASE db1;
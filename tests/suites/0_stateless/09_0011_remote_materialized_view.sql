@@ -0,0 +1,32 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE events(user_id int, amount int) Engine = fuse;
+CREATE TABLE totals(user_id int, total bigint) Engine = fuse;
+
+-- the rows inserted before the view is created are not pushed
+INSERT INTO events VALUES(1, 100);
+CREATE MATERIALIZED VIEW mv TO totals AS SELECT user_id, sum(amount) FROM events GROUP BY user_id;
+
+INSERT INTO events VALUES(1, 10),(2, 20),(1, 5);
+SELECT * FROM totals ORDER BY user_id;
+
+INSERT INTO events SELECT user_id + 1, amount FROM events WHERE user_id = 2;
+SELECT * FROM totals ORDER BY user_id;
+
+-- the view is read from its target table
+SELECT count() FROM mv;
+
+SELECT name, engine FROM system.tables WHERE database = 'db1' ORDER BY name;
+SHOW CREATE TABLE mv;
+
+CREATE MATERIALIZED VIEW mv TO totals AS SELECT user_id, amount FROM events; -- {ErrorCode 4003}
+CREATE MATERIALIZED VIEW mv2 TO totals AS SELECT user_id FROM events; -- {ErrorCode 6}
+CREATE MATERIALIZED VIEW mv2 TO totals AS SELECT a.user_id, b.total FROM events AS a, totals AS b; -- {ErrorCode 6}
+
+DROP VIEW mv;
+INSERT INTO events VALUES(4, 1);
+SELECT count() FROM totals;
+
+DROP DATABASE db1;
@@ -0,0 +1,30 @@
+DROP DATABASE IF EXISTS db_09_0012;
+CREATE DATABASE db_09_0012;
+USE db_09_0012;
+
+CREATE TABLE t(a uint64, b varchar) PARTITION BY a % 3;
+
+-- one insert, written as one segment of one block for each partition
+INSERT INTO t VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (6, 'e');
+SELECT block_count, row_count FROM fuse_history('db_09_0012', 't') ORDER BY row_count DESC LIMIT 1;
+
+SELECT a, b FROM t WHERE a % 3 = 1 ORDER BY a;
+SELECT count() FROM t WHERE a % 3 = 2;
+
+-- only the snapshot is rewritten
+ALTER TABLE t DROP PARTITION 0;
+SELECT a FROM t ORDER BY a;
+ALTER TABLE t DROP PARTITION 0;
+SELECT count() FROM t;
+
+-- the columns of the partition expression can not be changed
+ALTER TABLE t DROP COLUMN a; -- {ErrorCode 6}
+ALTER TABLE t DROP COLUMN b;
+SELECT a FROM t ORDER BY a;
+
+CREATE TABLE t2(a uint64) PARTITION BY c; -- {ErrorCode 6}
+CREATE TABLE t2(a uint64) Engine = Memory PARTITION BY a; -- {ErrorCode 6}
+CREATE TABLE t2(a uint64);
+ALTER TABLE t2 DROP PARTITION 0; -- {ErrorCode 6}
+
+DROP DATABASE db_09_0012;
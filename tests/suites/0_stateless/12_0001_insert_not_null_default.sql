@@ -0,0 +1,16 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE t1(a int NOT NULL, b int DEFAULT a + 1, c varchar NOT NULL DEFAULT 'x', d int) Engine = Memory;
+
+INSERT INTO t1 (a) VALUES(1),(2);
+INSERT INTO t1 (a, d) SELECT number + 10, number FROM numbers(2);
+SELECT * FROM t1 ORDER BY a;
+
+INSERT INTO t1 (a, c) VALUES(3, NULL); -- {ErrorCode 62}
+INSERT INTO t1 (a, d) VALUES(NULL, 3); -- {ErrorCode 62}
+INSERT INTO t1 (a) SELECT NULL; -- {ErrorCode 62}
+SELECT count() FROM t1;
+
+DROP DATABASE db1;